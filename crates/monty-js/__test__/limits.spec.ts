@@ -19,6 +19,46 @@ test('resource limits custom', (t) => {
   t.is(m.run({ limits }), 2)
 })
 
+test('output limit', (t) => {
+  const m = new Monty("for i in range(1000):\n    print('abcdefghi')")
+  const output: string[] = []
+  const limits: ResourceLimits = { maxOutputBytes: 55 }
+  const error = t.throws(() => m.run({ limits, printCallback: (_, text) => output.push(text) }), {
+    instanceOf: MontyRuntimeError,
+  })
+  t.deepEqual(error.exception, { typeName: 'OSError', message: 'output limit exceeded: 59 bytes > 55 bytes' })
+  t.is(output.join(''), 'abcdefghi\n'.repeat(5))
+})
+
+test('iteration limit', (t) => {
+  const m = new Monty('sum(range(1000))')
+  const limits: ResourceLimits = { maxIterations: 100 }
+  const error = t.throws(() => m.run({ limits }), { instanceOf: MontyRuntimeError })
+  t.deepEqual(error.exception, { typeName: 'TimeoutError', message: 'iteration limit exceeded: 101 > 100' })
+})
+
+test('integer size limits', (t) => {
+  const limits: ResourceLimits = { maxIntBits: 1_000_000, intMaxStrDigits: 4300 }
+  let error = t.throws(() => new Monty('2 ** (10**7)').run({ limits }), { instanceOf: MontyRuntimeError })
+  t.deepEqual(error.exception, {
+    typeName: 'ValueError',
+    message: 'Exceeds the limit (1000000 bits) for integer size: value has 10000001 bits',
+  })
+  error = t.throws(() => new Monty('str(10**5000)').run({ limits }), { instanceOf: MontyRuntimeError })
+  t.deepEqual(error.exception, {
+    typeName: 'ValueError',
+    message: 'Exceeds the limit (4300 digits) for integer string conversion',
+  })
+})
+
+test('repr max items', (t) => {
+  const m = new Monty('x = list(range(10_000))\nprint(x)\nlen(repr(x))')
+  const output: string[] = []
+  const limits: ResourceLimits = { reprMaxItems: 6 }
+  t.is(m.run({ limits, printCallback: (_, text) => output.push(text) }), 58_890)
+  t.is(output.join(''), '[0, 1, 2, ..., 9997, 9998, 9999]\n')
+})
+
 test('run with limits', (t) => {
   const m = new Monty('1 + 1')
   const limits: ResourceLimits = { maxDurationSecs: 5.0 }
@@ -1,7 +1,7 @@
 //! Resource limits handling for the Monty TypeScript/JavaScript bindings.
 //!
 //! Provides utilities to extract and apply resource limits from JavaScript objects,
-//! including time limits, memory limits, and recursion depth.
+//! including time limits, memory limits, recursion depth, and output and integer size limits.
 
 use std::time::Duration;
 
@@ -24,6 +24,16 @@ pub struct JsResourceLimits {
     pub gc_interval: Option<u32>,
     /// Maximum function call stack depth (default: 1000).
     pub max_recursion_depth: Option<u32>,
+    /// Maximum total bytes written by `print()`.
+    pub max_output_bytes: Option<u32>,
+    /// Maximum total number of iteration steps over all iterators.
+    pub max_iterations: Option<u32>,
+    /// Maximum number of bits in an integer.
+    pub max_int_bits: Option<u32>,
+    /// Maximum items shown in a container repr in `print()` output before eliding with `...`.
+    pub repr_max_items: Option<u32>,
+    /// Maximum decimal digits when converting an int to or from a string.
+    pub int_max_str_digits: Option<u32>,
 }
 
 impl From<JsResourceLimits> for ResourceLimits {
@@ -47,6 +57,21 @@ impl From<JsResourceLimits> for ResourceLimits {
         if let Some(interval) = js_limits.gc_interval {
             limits = limits.gc_interval(interval as usize);
         }
+        if let Some(max) = js_limits.max_output_bytes {
+            limits = limits.max_output_bytes(max as usize);
+        }
+        if let Some(max) = js_limits.max_iterations {
+            limits = limits.max_iterations(max as usize);
+        }
+        if let Some(max) = js_limits.max_int_bits {
+            limits = limits.max_int_bits(max as usize);
+        }
+        if let Some(max) = js_limits.repr_max_items {
+            limits = limits.repr_max_items(max as usize);
+        }
+        if let Some(max) = js_limits.int_max_str_digits {
+            limits = limits.int_max_str_digits(max as usize);
+        }
 
         limits
     }
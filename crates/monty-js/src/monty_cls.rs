@@ -46,8 +46,9 @@
 use std::borrow::Cow;
 
 use monty::{
-    ExcType, ExternalResult, LimitedTracker, MontyException, MontyObject, MontyRepl as CoreMontyRepl, MontyRun,
-    NoLimitTracker, PrintWriter, PrintWriterCallback, ResourceTracker, RunProgress, Snapshot,
+    dump_versioned, load_versioned, ExcType, ExternalResult, LimitedTracker, MontyException, MontyObject,
+    MontyRepl as CoreMontyRepl, MontyRun, NoLimitTracker, PrintWriter, PrintWriterCallback, ResourceTracker,
+    RunProgress, Snapshot,
 };
 use monty_type_checking::{type_check, SourceFile};
 use napi::bindgen_prelude::*;
@@ -358,7 +359,7 @@ impl Monty {
             external_function_names: self.external_function_names.clone(),
         };
         let bytes =
-            postcard::to_allocvec(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
+            dump_versioned(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
         Ok(Buffer::from(bytes))
     }
 
@@ -369,7 +370,7 @@ impl Monty {
     #[napi(factory)]
     pub fn load(data: Buffer) -> Result<Self> {
         let serialized: SerializedMonty =
-            postcard::from_bytes(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
+            load_versioned(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
 
         Ok(Self {
             runner: serialized.runner,
@@ -578,7 +579,7 @@ impl MontyRepl {
             script_name: &self.script_name,
         };
         let bytes =
-            postcard::to_allocvec(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
+            dump_versioned(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
         Ok(Buffer::from(bytes))
     }
 
@@ -586,7 +587,7 @@ impl MontyRepl {
     #[napi(factory)]
     pub fn load(data: Buffer) -> Result<Self> {
         let serialized: SerializedReplOwned =
-            postcard::from_bytes(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
+            load_versioned(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
         Ok(Self {
             repl: serialized.repl,
             script_name: serialized.script_name,
@@ -861,7 +862,7 @@ impl MontySnapshot {
         };
 
         let bytes =
-            postcard::to_allocvec(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
+            dump_versioned(&serialized).map_err(|e| Error::from_reason(format!("Serialization failed: {e}")))?;
         Ok(Buffer::from(bytes))
     }
 
@@ -873,7 +874,7 @@ impl MontySnapshot {
     #[napi(factory)]
     pub fn load(data: Buffer, options: Option<SnapshotLoadOptions>) -> Result<Self> {
         let serialized: SerializedSnapshotOwned =
-            postcard::from_bytes(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
+            load_versioned(&data).map_err(|e| Error::from_reason(format!("Deserialization failed: {e}")))?;
 
         Ok(Self {
             snapshot: serialized.snapshot,
@@ -204,6 +204,21 @@ class ResourceLimits(TypedDict, total=False):
     max_recursion_depth: int
     """Maximum function call stack depth (default: 1000)."""
 
+    max_output_bytes: int
+    """Maximum total bytes written by `print()`, exceeding it raises `OSError`."""
+
+    max_iterations: int
+    """Maximum total number of iteration steps over all iterators, exceeding it raises `TimeoutError`."""
+
+    max_int_bits: int
+    """Maximum number of bits in an integer, exceeding it raises `ValueError`."""
+
+    repr_max_items: int
+    """Maximum items shown when `print()` output reprs a list, tuple, dict or set; the middle is elided with `...`."""
+
+    int_max_str_digits: int
+    """Maximum decimal digits when converting an int to or from a string, like `sys.set_int_max_str_digits()`."""
+
 
 class ExternalReturnValue(TypedDict):
     return_value: Any
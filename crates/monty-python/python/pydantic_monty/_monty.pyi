@@ -172,6 +172,41 @@ class Monty:
             ValueError: If deserialization fails.
         """
 
+    def dump_json(self) -> str:
+        """
+        Serialize the Monty instance to a JSON string.
+
+        Like `dump()`, but human-readable for debugging and interop with other languages.
+        The data can be restored with `Monty.load_json()`.
+
+        Returns:
+            A JSON string containing the serialized Monty instance.
+
+        Raises:
+            ValueError: If serialization fails.
+        """
+
+    @staticmethod
+    def load_json(
+        data: str,
+        *,
+        dataclass_registry: list[type] | None = None,
+    ) -> 'Monty':
+        """
+        Deserialize a Monty instance from a JSON string produced by `dump_json()`.
+
+        Arguments:
+            data: The serialized Monty data from `dump_json()`
+            dataclass_registry: Optional list of dataclass types to register for proper
+                isinstance() support on output, see `register_dataclass()` above.
+
+        Returns:
+            A new Monty instance.
+
+        Raises:
+            ValueError: If deserialization fails.
+        """
+
     def register_dataclass(self, cls: type) -> None:
         """
         Register a dataclass type for proper isinstance() support on output.
@@ -220,16 +255,40 @@ class MontyRepl:
     def script_name(self) -> str:
         """The name of the script being executed."""
 
+    @overload
     def feed(
         self,
         code: str,
         *,
         print_callback: Callable[[Literal['stdout'], str], None] | None = None,
+        capture_output: Literal[False] = False,
     ) -> Any:
         """
         Execute one incremental snippet and return its output.
         """
 
+    @overload
+    def feed(self, code: str, *, capture_output: Literal[True]) -> tuple[Any, str]:
+        """
+        Execute one incremental snippet, returning its output and everything it printed.
+
+        Printed output is collected instead of being written to stdout or the print callback.
+        """
+
+    def feed_all(
+        self,
+        snippets: list[str],
+        *,
+        print_callback: Callable[[Literal['stdout'], str], None] | None = None,
+    ) -> list[Any]:
+        """
+        Execute several snippets in order and return a list of their outputs.
+
+        Execution stops at the first snippet which raises. The raised exception has a
+        `snippet_index` attribute with the index of that snippet, and an `outputs` attribute
+        with the outputs of the snippets before it.
+        """
+
     def dump(self) -> bytes:
         """Serialize the REPL session to bytes."""
 
@@ -476,6 +535,18 @@ class MontyError(Exception):
     This exception is raised internally by Monty and cannot be constructed directly.
     """
 
+    @property
+    def monty_type(self) -> str:
+        """The name of the Monty exception type, e.g. `'ValueError'`."""
+
+    @property
+    def message(self) -> str | None:
+        """The exception message, or `None` if the exception has no message."""
+
+    @property
+    def location(self) -> tuple[int, int] | None:
+        """The 1-based `(line, column)` where the exception was raised, or `None` if unknown."""
+
     def exception(self) -> BaseException:
         """Returns the inner exception as a Python exception object."""
 
@@ -529,6 +600,9 @@ class MontyRuntimeError(MontyError):
 
     Inherits exception(), __str__() from MontyError.
     Additionally provides traceback() and display() methods.
+
+    Where possible, raised instances are also instances of the matching native Python
+    exception class, so e.g. a Monty `KeyError` can be caught with `except KeyError:`.
     """
 
     def traceback(self) -> list[Frame]:
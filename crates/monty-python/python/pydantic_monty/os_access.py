@@ -29,6 +29,8 @@ OsFunction = Literal[
     'Path.absolute',
     'os.getenv',
     'os.environ',
+    'time.time',
+    'time.monotonic',
 ]
 
 
@@ -172,6 +174,10 @@ class AbstractOS(ABC):
                 return self.getenv(*args)
             case 'os.environ':
                 return self.get_environ()
+            case 'time.time':
+                return self.time()
+            case 'time.monotonic':
+                return self.monotonic()
 
     @abstractmethod
     def path_exists(self, path: PurePosixPath) -> bool:
@@ -422,6 +428,30 @@ class AbstractOS(ABC):
         """
         raise NotImplementedError
 
+    def time(self) -> float:
+        """Get the current time, used by `time.time()`.
+
+        Defaults to the host's clock, override this to control the time seen by Monty code.
+
+        Returns:
+            The time in seconds since the epoch.
+        """
+        import time
+
+        return time.time()
+
+    def monotonic(self) -> float:
+        """Get the value of a monotonic clock, used by `time.monotonic()`.
+
+        Defaults to the host's clock, override this to control the time seen by Monty code.
+
+        Returns:
+            The clock value in seconds.
+        """
+        import time
+
+        return time.monotonic()
+
 
 class AbstractFile(Protocol):
     """Protocol defining the interface for files used with OSAccess.
@@ -18,8 +18,10 @@ use monty_type_checking::TypeCheckingDiagnostics;
 use pyo3::{
     PyClassInitializer, PyTypeCheck,
     exceptions::{self},
+    intern,
     prelude::*,
-    types::{PyDict, PyList, PyString},
+    sync::PyOnceLock,
+    types::{PyDict, PyList, PyString, PyTuple, PyType},
 };
 
 use crate::dataclass::get_frozen_instance_error;
@@ -81,12 +83,35 @@ impl MontyError {
         py_err.into_value(py).into_any()
     }
 
+    /// The name of the Monty exception type, e.g. `'ValueError'`.
+    #[getter]
+    fn monty_type(&self) -> &'static str {
+        self.exc_type().into()
+    }
+
+    /// The exception message, or `None` if the exception has no message.
+    #[getter(message)]
+    fn py_message(&self) -> Option<&str> {
+        self.message()
+    }
+
+    /// The `(line, column)` where the exception was raised, or `None` if unknown.
+    ///
+    /// Both are 1-based, taken from the innermost frame of the traceback.
+    #[getter]
+    fn location(&self) -> Option<(u16, u16)> {
+        self.exc
+            .traceback()
+            .last()
+            .map(|frame| (frame.start.line, frame.start.column))
+    }
+
     fn __str__(&self) -> String {
         self.message().unwrap_or_default().to_string()
     }
 
     fn __repr__(&self) -> String {
-        let exc_type_name = self.exc_type();
+        let exc_type_name = self.exc.type_name();
         if let Some(msg) = self.message() {
             format!("MontyError({exc_type_name}: {msg})")
         } else {
@@ -205,7 +230,10 @@ impl MontyTypingError {
 ///
 /// Inherits from `MontyError`. Additionally provides `traceback()` to access
 /// the Monty stack frames where the error occurred.
-#[pyclass(extends=MontyError, module="pydantic_monty")]
+///
+/// Raised instances also inherit from the matching native Python exception class
+/// where possible, see `runtime_error_subclass`.
+#[pyclass(extends=MontyError, module="pydantic_monty", subclass)]
 pub struct MontyRuntimeError {
     /// The traceback frames where the error occurred (pre-converted to Python objects).
     frames: Vec<Py<PyFrame>>,
@@ -227,18 +255,101 @@ impl MontyRuntimeError {
             Err(e) => return e,
         };
 
+        let exc_type = exc.exc_type();
+        let cause = exc.cause().map(|cause| exc_monty_to_py(py, cause.clone()));
         let base_error = MontyError::new(exc);
         // Create the MontyRuntimeError with proper initialization
         let runtime_error = Self { frames };
 
         let init = pyo3::PyClassInitializer::from(base_error).add_subclass(runtime_error);
         match Py::new(py, init) {
-            Ok(err) => PyErr::from_value(err.into_bound(py).into_any()),
+            Ok(err) => {
+                let err = err.into_bound(py).into_any();
+                if let Some(subclass) = runtime_error_subclass(py, exc_type) {
+                    // if switching the class fails, the plain `MontyRuntimeError` is still raised
+                    let _ = err.setattr(intern!(py, "__class__"), subclass);
+                }
+                let err = PyErr::from_value(err);
+                err.set_cause(py, cause);
+                err
+            }
             Err(e) => e,
         }
     }
 }
 
+/// Returns the native Python exception class matching `exc_type`.
+///
+/// Returns `None` for `Exception` and `BaseException`, which `MontyError` already inherits from.
+fn native_exc_type(py: Python<'_>, exc_type: ExcType) -> Option<Bound<'_, PyType>> {
+    let native = match exc_type {
+        ExcType::Exception | ExcType::BaseException => return None,
+        ExcType::SystemExit => py.get_type::<exceptions::PySystemExit>(),
+        ExcType::KeyboardInterrupt => py.get_type::<exceptions::PyKeyboardInterrupt>(),
+        ExcType::ArithmeticError => py.get_type::<exceptions::PyArithmeticError>(),
+        ExcType::OverflowError => py.get_type::<exceptions::PyOverflowError>(),
+        ExcType::ZeroDivisionError => py.get_type::<exceptions::PyZeroDivisionError>(),
+        ExcType::LookupError => py.get_type::<exceptions::PyLookupError>(),
+        ExcType::IndexError => py.get_type::<exceptions::PyIndexError>(),
+        ExcType::KeyError => py.get_type::<exceptions::PyKeyError>(),
+        ExcType::RuntimeError => py.get_type::<exceptions::PyRuntimeError>(),
+        ExcType::NotImplementedError => py.get_type::<exceptions::PyNotImplementedError>(),
+        ExcType::RecursionError => py.get_type::<exceptions::PyRecursionError>(),
+        ExcType::AssertionError => py.get_type::<exceptions::PyAssertionError>(),
+        ExcType::AttributeError => py.get_type::<exceptions::PyAttributeError>(),
+        ExcType::FrozenInstanceError => get_frozen_instance_error(py).ok()?.cast::<PyType>().ok()?.clone(),
+        ExcType::MemoryError => py.get_type::<exceptions::PyMemoryError>(),
+        ExcType::NameError => py.get_type::<exceptions::PyNameError>(),
+        ExcType::UnboundLocalError => py.get_type::<exceptions::PyUnboundLocalError>(),
+        ExcType::StopIteration => py.get_type::<exceptions::PyStopIteration>(),
+        ExcType::SyntaxError => py.get_type::<exceptions::PySyntaxError>(),
+        ExcType::TimeoutError => py.get_type::<exceptions::PyTimeoutError>(),
+        ExcType::TypeError => py.get_type::<exceptions::PyTypeError>(),
+        ExcType::ValueError => py.get_type::<exceptions::PyValueError>(),
+        ExcType::UnicodeDecodeError => py.get_type::<exceptions::PyUnicodeDecodeError>(),
+        ExcType::ImportError => py.get_type::<exceptions::PyImportError>(),
+        ExcType::ModuleNotFoundError => py.get_type::<exceptions::PyModuleNotFoundError>(),
+        ExcType::OSError => py.get_type::<exceptions::PyOSError>(),
+        ExcType::FileNotFoundError => py.get_type::<exceptions::PyFileNotFoundError>(),
+        ExcType::FileExistsError => py.get_type::<exceptions::PyFileExistsError>(),
+        ExcType::IsADirectoryError => py.get_type::<exceptions::PyIsADirectoryError>(),
+        ExcType::NotADirectoryError => py.get_type::<exceptions::PyNotADirectoryError>(),
+    };
+    Some(native)
+}
+
+/// Returns a subclass of both `MontyRuntimeError` and the native exception class matching `exc_type`.
+///
+/// Raising instances of this subclass lets host code catch Monty exceptions with existing
+/// `except ValueError:` handlers, while `except MontyError:` keeps working.
+///
+/// Returns `None` if the native class's instance layout can't be combined with `MontyRuntimeError`,
+/// e.g. `OSError` and `AttributeError` which store extra fields, in which case a plain
+/// `MontyRuntimeError` is raised. Subclasses are created once and cached.
+fn runtime_error_subclass(py: Python<'_>, exc_type: ExcType) -> Option<Bound<'_, PyType>> {
+    static SUBCLASSES: PyOnceLock<Py<PyDict>> = PyOnceLock::new();
+
+    let subclasses = SUBCLASSES.get_or_init(py, || PyDict::new(py).unbind()).bind(py);
+    let name: &'static str = exc_type.into();
+    if let Ok(Some(cached)) = subclasses.get_item(name) {
+        return cached.cast_into::<PyType>().ok();
+    }
+
+    let subclass = native_exc_type(py, exc_type).and_then(|native| {
+        // empty `__slots__` keeps the instance layout identical to `MontyRuntimeError`,
+        // so an instance's `__class__` can be switched to the subclass
+        let namespace = PyDict::new(py);
+        namespace.set_item("__slots__", PyTuple::empty(py)).ok()?;
+        namespace.set_item("__module__", "pydantic_monty").ok()?;
+        let bases = (py.get_type::<MontyRuntimeError>(), native);
+        let subclass = py.get_type::<PyType>().call1((name, bases, namespace)).ok()?;
+        subclass.cast_into::<PyType>().ok()
+    });
+    // caching `None` too avoids retrying subclasses which can never be created
+    let _ = subclasses.set_item(name, subclass.as_ref());
+    subclass
+}
+
 #[pymethods]
 impl MontyRuntimeError {
     /// Returns the Monty traceback as a list of Frame objects.
@@ -267,7 +378,7 @@ impl MontyRuntimeError {
     #[expect(clippy::needless_pass_by_value, reason = "required by macro")]
     fn __str__(slf: PyRef<'_, Self>) -> String {
         let parent = slf.as_super();
-        let exc_type_name = parent.exc_type();
+        let exc_type_name = parent.exc.type_name();
         if let Some(msg) = parent.message()
             && !msg.is_empty()
         {
@@ -279,7 +390,7 @@ impl MontyRuntimeError {
     #[expect(clippy::needless_pass_by_value, reason = "required by macro")]
     fn __repr__(slf: PyRef<'_, Self>) -> String {
         let parent = slf.as_super();
-        let exc_type_name = parent.exc_type();
+        let exc_type_name = parent.exc.type_name();
         if let Some(msg) = parent.message()
             && !msg.is_empty()
         {
@@ -365,9 +476,10 @@ impl PyFrame {
 /// since PyO3 doesn't provide direct traceback manipulation.
 pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
     let exc_type = exc.exc_type();
+    let cause = exc.cause().map(|cause| exc_monty_to_py(py, cause.clone()));
     let msg = exc.into_message().unwrap_or_default();
 
-    match exc_type {
+    let err = match exc_type {
         ExcType::Exception => exceptions::PyException::new_err(msg),
         ExcType::BaseException => exceptions::PyBaseException::new_err(msg),
         ExcType::SystemExit => exceptions::PySystemExit::new_err(msg),
@@ -387,10 +499,11 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
             if let Ok(exc_cls) = get_frozen_instance_error(py)
                 && let Ok(exc_instance) = exc_cls.call1((PyString::new(py, &msg),))
             {
-                return PyErr::from_value(exc_instance);
+                PyErr::from_value(exc_instance)
+            } else {
+                // if creating the right exception fails, fallback to AttributeError which it's a subclass of
+                exceptions::PyAttributeError::new_err(msg)
             }
-            // if creating the right exception fails, fallback to AttributeError which it's a subclass of
-            exceptions::PyAttributeError::new_err(msg)
         }
         ExcType::MemoryError => exceptions::PyMemoryError::new_err(msg),
         ExcType::NameError => exceptions::PyNameError::new_err(msg),
@@ -408,7 +521,9 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
         ExcType::FileExistsError => exceptions::PyFileExistsError::new_err(msg),
         ExcType::IsADirectoryError => exceptions::PyIsADirectoryError::new_err(msg),
         ExcType::NotADirectoryError => exceptions::PyNotADirectoryError::new_err(msg),
-    }
+    };
+    err.set_cause(py, cause);
+    err
 }
 
 /// Converts a python exception to monty.
@@ -21,6 +21,11 @@ use crate::exceptions::exc_py_to_monty;
 /// - `max_memory`: Maximum heap memory in bytes (int)
 /// - `gc_interval`: Run garbage collection every N allocations (int)
 /// - `max_recursion_depth`: Maximum function call stack depth (int, default: 1000)
+/// - `max_output_bytes`: Maximum total bytes written by `print()` (int)
+/// - `max_iterations`: Maximum total number of iteration steps (int)
+/// - `max_int_bits`: Maximum number of bits in an integer (int)
+/// - `repr_max_items`: Maximum items shown in a container repr in `print()` output before eliding with `...` (int)
+/// - `int_max_str_digits`: Maximum decimal digits when converting an int to or from a string (int)
 ///
 /// If a key is missing or set to `None`, that limit is not applied
 /// (except `max_recursion_depth` which defaults to 1000).
@@ -33,6 +38,11 @@ pub fn extract_limits(dict: &Bound<'_, PyDict>) -> PyResult<monty::ResourceLimit
     let gc_interval = extract_optional_usize(dict, "gc_interval")?;
     let max_recursion_depth =
         extract_optional_usize(dict, "max_recursion_depth")?.or(Some(DEFAULT_MAX_RECURSION_DEPTH));
+    let max_output_bytes = extract_optional_usize(dict, "max_output_bytes")?;
+    let max_iterations = extract_optional_usize(dict, "max_iterations")?;
+    let max_int_bits = extract_optional_usize(dict, "max_int_bits")?;
+    let repr_max_items = extract_optional_usize(dict, "repr_max_items")?;
+    let int_max_str_digits = extract_optional_usize(dict, "int_max_str_digits")?;
 
     let mut limits = monty::ResourceLimits::new().max_recursion_depth(max_recursion_depth);
 
@@ -48,6 +58,21 @@ pub fn extract_limits(dict: &Bound<'_, PyDict>) -> PyResult<monty::ResourceLimit
     if let Some(interval) = gc_interval {
         limits = limits.gc_interval(interval);
     }
+    if let Some(max) = max_output_bytes {
+        limits = limits.max_output_bytes(max);
+    }
+    if let Some(max) = max_iterations {
+        limits = limits.max_iterations(max);
+    }
+    if let Some(max) = max_int_bits {
+        limits = limits.max_int_bits(max);
+    }
+    if let Some(max) = repr_max_items {
+        limits = limits.repr_max_items(max);
+    }
+    if let Some(max) = int_max_str_digits {
+        limits = limits.int_max_str_digits(max);
+    }
 
     Ok(limits)
 }
@@ -139,4 +164,24 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
     fn check_large_result(&self, estimated_bytes: usize) -> Result<(), ResourceError> {
         self.inner.check_large_result(estimated_bytes)
     }
+
+    fn on_output(&mut self, bytes: usize) -> Result<(), ResourceError> {
+        self.inner.on_output(bytes)
+    }
+
+    fn on_iteration(&mut self) -> Result<(), ResourceError> {
+        self.inner.on_iteration()
+    }
+
+    fn check_int_bits(&self, bits: u64) -> Result<(), ResourceError> {
+        self.inner.check_int_bits(bits)
+    }
+
+    fn repr_max_items(&self) -> Option<usize> {
+        self.inner.repr_max_items()
+    }
+
+    fn int_max_str_digits(&self) -> Option<usize> {
+        self.inner.int_max_str_digits()
+    }
 }
@@ -9,7 +9,9 @@ use ::monty::{
     ExternalResult, LimitedTracker, MontyException, MontyObject, MontyRepl as CoreMontyRepl, MontyRun, NoLimitTracker,
     PrintWriter, PrintWriterCallback, ResourceTracker, RunProgress, Snapshot,
 };
-use monty::{ExcType, FutureSnapshot, OsFunction};
+use monty::{
+    ExcType, FutureSnapshot, OsFunction, dump_json_versioned, dump_versioned, load_json_versioned, load_versioned,
+};
 use monty_type_checking::{SourceFile, type_check};
 use pyo3::{
     IntoPyObjectExt,
@@ -240,7 +242,7 @@ impl PyMonty {
             input_names: self.input_names.clone(),
             external_function_names: self.external_function_names.clone(),
         };
-        let bytes = postcard::to_allocvec(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let bytes = dump_versioned(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyBytes::new(py, &bytes))
     }
 
@@ -263,8 +265,43 @@ impl PyMonty {
         dataclass_registry: Option<&Bound<'_, PyList>>,
     ) -> PyResult<Self> {
         let bytes = data.as_bytes();
+        let serialized: SerializedMonty = load_versioned(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self {
+            runner: serialized.runner,
+            script_name: serialized.script_name,
+            input_names: serialized.input_names,
+            external_function_names: serialized.external_function_names,
+            dc_registry: DcRegistry::from_list(py, dataclass_registry)?,
+        })
+    }
+
+    /// Serializes the Monty instance to a JSON string.
+    ///
+    /// Like `dump()`, but human-readable for debugging and interop with other languages.
+    /// The data can be restored with `Monty.load_json()`.
+    ///
+    /// # Raises
+    /// `ValueError` if serialization fails.
+    fn dump_json(&self) -> PyResult<String> {
+        let serialized = SerializedMonty {
+            runner: self.runner.clone(),
+            script_name: self.script_name.clone(),
+            input_names: self.input_names.clone(),
+            external_function_names: self.external_function_names.clone(),
+        };
+        dump_json_versioned(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Deserializes a Monty instance from a JSON string produced by `dump_json()`.
+    ///
+    /// # Raises
+    /// `ValueError` if deserialization fails.
+    #[staticmethod]
+    #[pyo3(signature = (data, *, dataclass_registry=None))]
+    fn load_json(py: Python<'_>, data: &str, dataclass_registry: Option<&Bound<'_, PyList>>) -> PyResult<Self> {
         let serialized: SerializedMonty =
-            postcard::from_bytes(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            load_json_versioned(data).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         Ok(Self {
             runner: serialized.runner,
@@ -729,31 +766,54 @@ impl PyMontyRepl {
     ///
     /// The snippet is compiled against existing session state and executed once
     /// without replaying previously fed snippets.
-    #[pyo3(signature = (code, *, print_callback=None))]
-    fn feed<'py>(&self, py: Python<'py>, code: &str, print_callback: Option<Py<PyAny>>) -> PyResult<Bound<'py, PyAny>> {
-        let print_callback = print_callback.or_else(|| self.print_callback.as_ref().map(|cb| cb.clone_ref(py)));
-
-        let mut print_cb;
-        let mut print_writer = match print_callback {
-            Some(cb) => {
-                print_cb = CallbackStringPrint::from_py(cb);
-                PrintWriter::Callback(&mut print_cb)
-            }
-            None => PrintWriter::Stdout,
-        };
-
-        let mut repl = self
-            .repl
-            .try_lock()
-            .map_err(|_| PyRuntimeError::new_err("REPL session is currently executing another snippet"))?;
-
-        let output = match &mut *repl {
-            EitherRepl::NoLimit(repl) => repl.feed(code, &mut print_writer),
-            EitherRepl::Limited(repl) => repl.feed(code, &mut print_writer),
+    ///
+    /// With `capture_output=True`, printed output is collected instead of being written to
+    /// stdout or the print callback, and `(output, printed)` is returned.
+    #[pyo3(signature = (code, *, print_callback=None, capture_output=false))]
+    fn feed<'py>(
+        &self,
+        py: Python<'py>,
+        code: &str,
+        print_callback: Option<Py<PyAny>>,
+        capture_output: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut outputs = Vec::with_capacity(1);
+        if !capture_output {
+            self.feed_snippets(py, &[code], print_callback, None, &mut outputs)??;
+            return Ok(outputs.remove(0).into_bound(py));
+        }
+        if print_callback.is_some() {
+            return Err(PyValueError::new_err(
+                "print_callback and capture_output cannot be used together",
+            ));
         }
-        .map_err(|e| MontyError::new_err(py, e))?;
+        let mut printed = String::new();
+        self.feed_snippets(py, &[code], None, Some(&mut printed), &mut outputs)??;
+        (outputs.remove(0), printed).into_bound_py_any(py)
+    }
 
-        Ok(monty_to_py(py, &output, &self.dc_registry)?.into_bound(py))
+    /// Feeds and executes several snippets in order, returning a list of their outputs.
+    ///
+    /// The session stays locked for the whole sequence, so no other snippet can run in between.
+    /// Execution stops at the first snippet which raises; the exception gets a `snippet_index`
+    /// attribute with the index of that snippet, and an `outputs` attribute with the outputs
+    /// of the snippets before it.
+    #[pyo3(signature = (snippets, *, print_callback=None))]
+    fn feed_all<'py>(
+        &self,
+        py: Python<'py>,
+        snippets: Vec<String>,
+        print_callback: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let snippets: Vec<&str> = snippets.iter().map(String::as_str).collect();
+        let mut outputs = Vec::with_capacity(snippets.len());
+        if let Err(err) = self.feed_snippets(py, &snippets, print_callback, None, &mut outputs)? {
+            let exc = err.value(py);
+            exc.setattr(intern!(py, "snippet_index"), outputs.len())?;
+            exc.setattr(intern!(py, "outputs"), PyList::new(py, outputs)?)?;
+            return Err(err);
+        }
+        PyList::new(py, outputs)
     }
 
     /// Serializes this REPL session to bytes.
@@ -770,7 +830,7 @@ impl PyMontyRepl {
             repl: &repl,
             script_name: &self.script_name,
         };
-        let bytes = postcard::to_allocvec(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let bytes = dump_versioned(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyBytes::new(py, &bytes))
     }
 
@@ -790,7 +850,7 @@ impl PyMontyRepl {
         }
 
         let serialized: SerializedReplOwned =
-            postcard::from_bytes(data.as_bytes()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            load_versioned(data.as_bytes()).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         Ok(Self {
             repl: Mutex::new(serialized.repl),
@@ -901,6 +961,56 @@ impl PyMontyRepl {
             })
             .collect::<PyResult<_>>()
     }
+
+    /// Runs `snippets` in order with the session locked, pushing each output onto `outputs`.
+    ///
+    /// The outer error is raised when the session is already executing another snippet.
+    /// The inner error is raised by the first failing snippet, whose index is `outputs.len()`.
+    /// If `captured` is set, printed output is collected into it rather than written out.
+    fn feed_snippets(
+        &self,
+        py: Python<'_>,
+        snippets: &[&str],
+        print_callback: Option<Py<PyAny>>,
+        captured: Option<&mut String>,
+        outputs: &mut Vec<Py<PyAny>>,
+    ) -> PyResult<PyResult<()>> {
+        let print_callback = print_callback.or_else(|| self.print_callback.as_ref().map(|cb| cb.clone_ref(py)));
+
+        let mut print_cb;
+        let mut print_writer = match print_callback {
+            _ if captured.is_some() => PrintWriter::Collect(String::new()),
+            Some(cb) => {
+                print_cb = CallbackStringPrint::from_py(cb);
+                PrintWriter::Callback(&mut print_cb)
+            }
+            None => PrintWriter::Stdout,
+        };
+
+        let mut repl = self
+            .repl
+            .try_lock()
+            .map_err(|_| PyRuntimeError::new_err("REPL session is currently executing another snippet"))?;
+
+        for code in snippets {
+            let result = match &mut *repl {
+                EitherRepl::NoLimit(repl) => repl.feed(code, &mut print_writer),
+                EitherRepl::Limited(repl) => repl.feed(code, &mut print_writer),
+            };
+            let output = match result {
+                Ok(output) => output,
+                Err(e) => return Ok(Err(MontyError::new_err(py, e))),
+            };
+            match monty_to_py(py, &output, &self.dc_registry) {
+                Ok(output) => outputs.push(output),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+        if let (Some(captured), PrintWriter::Collect(printed)) = (captured, print_writer) {
+            *captured = printed;
+        }
+        Ok(Ok(()))
+    }
 }
 
 /// Runtime execution snapshot, holds multiple resource tracker types since pyclass structs can't be generic.
@@ -1100,7 +1210,7 @@ impl PyMontySnapshot {
             kwargs,
             call_id: self.call_id,
         };
-        let bytes = postcard::to_allocvec(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let bytes = dump_versioned(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyBytes::new(py, &bytes))
     }
 
@@ -1142,7 +1252,7 @@ impl PyMontySnapshot {
         let bytes = data.as_bytes();
 
         let serialized: SerializedSnapshotOwned =
-            postcard::from_bytes(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            load_versioned(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         let dc_registry = DcRegistry::from_list(py, dataclass_registry)?;
 
@@ -1308,7 +1418,7 @@ impl PyMontyFutureSnapshot {
             snapshot: &snapshot,
             script_name: &self.script_name,
         };
-        let bytes = postcard::to_allocvec(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let bytes = dump_versioned(&serialized).map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyBytes::new(py, &bytes))
     }
 
@@ -1344,7 +1454,7 @@ impl PyMontyFutureSnapshot {
         let bytes = data.as_bytes();
 
         let serialized: SerializedSnapshotOwned =
-            postcard::from_bytes(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            load_versioned(bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         Ok(Self {
             snapshot: Mutex::new(serialized.snapshot),
@@ -93,6 +93,80 @@ def test_runtime_error():
     assert str(inner) == snapshot('runtime error')
 
 
+def test_native_key_error():
+    m = pydantic_monty.Monty("{}['missing']")
+    try:
+        m.run()
+    except KeyError as e:
+        assert isinstance(e, pydantic_monty.MontyRuntimeError)
+        assert e.display('type-msg') == snapshot('KeyError: missing')
+    else:
+        pytest.fail('KeyError not raised')
+
+
+def test_native_value_error_on_resume():
+    m = pydantic_monty.Monty("x = func()\nraise ValueError(f'bad {x}')", external_functions=['func'])
+    progress = m.start()
+    assert isinstance(progress, pydantic_monty.MontySnapshot)
+    with pytest.raises(ValueError) as exc_info:
+        progress.resume(return_value=1)
+    assert isinstance(exc_info.value, pydantic_monty.MontyError)
+    assert str(exc_info.value) == snapshot('ValueError: bad 1')
+
+
+def test_native_exception_fallback():
+    m = pydantic_monty.Monty("raise Exception('plain')")
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run()
+    assert type(exc_info.value) is pydantic_monty.MontyRuntimeError
+
+
+def test_raise_from_cause():
+    m = pydantic_monty.Monty("raise ValueError('b') from KeyError('a')")
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run()
+    assert exc_info.value.display('type-msg') == snapshot('ValueError: b')
+    cause = exc_info.value.__cause__
+    assert isinstance(cause, KeyError)
+    assert cause.args == snapshot(('a',))
+
+    inner = exc_info.value.exception()
+    assert isinstance(inner, ValueError)
+    assert isinstance(inner.__cause__, KeyError)
+
+
+def test_custom_exception_class():
+    code = """
+class MyError(ValueError):
+    pass
+
+raise MyError('bad')
+"""
+    m = pydantic_monty.Monty(code)
+    with pytest.raises(ValueError) as exc_info:
+        m.run()
+    assert isinstance(exc_info.value, pydantic_monty.MontyRuntimeError)
+    assert str(exc_info.value) == snapshot('MyError: bad')
+    assert exc_info.value.display('type-msg') == snapshot('MyError: bad')
+    assert exc_info.value.monty_type == snapshot('ValueError')
+
+
+def test_runtime_error_structured_info():
+    m = pydantic_monty.Monty("x = 1\nraise ValueError('x')")
+    with pytest.raises(pydantic_monty.MontyError) as exc_info:
+        m.run()
+    assert exc_info.value.monty_type == snapshot('ValueError')
+    assert exc_info.value.message == snapshot('x')
+    assert exc_info.value.location == snapshot((2, 1))
+
+
+def test_syntax_error_structured_info():
+    with pytest.raises(pydantic_monty.MontyError) as exc_info:
+        pydantic_monty.Monty('def')
+    assert exc_info.value.monty_type == snapshot('SyntaxError')
+    assert exc_info.value.message == snapshot('Expected an identifier at byte range 3..3')
+
+
 def test_not_implemented_error():
     m = pydantic_monty.Monty("raise NotImplementedError('not implemented')")
     with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
@@ -255,3 +255,54 @@ def test_timeout_enforced_in_builtin_loops(code: str):
     assert isinstance(exc_info.value.exception(), TimeoutError)
     # Should terminate promptly - well under 2 seconds
     assert elapsed < 2.0
+
+
+def test_output_limit():
+    code = """
+for i in range(1000):
+    print('abcdefghi')
+"""
+    m = pydantic_monty.Monty(code)
+    output: list[str] = []
+    limits = pydantic_monty.ResourceLimits(max_output_bytes=55, max_memory=1_000_000)
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run(limits=limits, print_callback=lambda _, text: output.append(text))
+    exc = exc_info.value.exception()
+    assert type(exc) is OSError
+    assert str(exc) == snapshot('output limit exceeded: 59 bytes > 55 bytes')
+    assert ''.join(output) == 'abcdefghi\n' * 5
+
+
+def test_repr_max_items():
+    m = pydantic_monty.Monty('x = list(range(10_000))\nprint(x)\nlen(repr(x))')
+    limits = pydantic_monty.ResourceLimits(repr_max_items=6)
+    output: list[str] = []
+    assert m.run(limits=limits, print_callback=lambda _, text: output.append(text)) == snapshot(58_890)
+    assert ''.join(output) == snapshot('[0, 1, 2, ..., 9997, 9998, 9999]\n')
+
+
+def test_int_bits_limit():
+    m = pydantic_monty.Monty('2 ** (10**7)')
+    limits = pydantic_monty.ResourceLimits(max_int_bits=1_000_000)
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run(limits=limits)
+    exc = exc_info.value.exception()
+    assert isinstance(exc, ValueError)
+    assert str(exc) == snapshot('Exceeds the limit (1000000 bits) for integer size: value has 10000001 bits')
+
+
+def test_int_max_str_digits():
+    limits = pydantic_monty.ResourceLimits(int_max_str_digits=4300)
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        pydantic_monty.Monty('str(10**5000)').run(limits=limits)
+    exc = exc_info.value.exception()
+    assert isinstance(exc, ValueError)
+    assert str(exc) == snapshot('Exceeds the limit (4300 digits) for integer string conversion')
+
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        pydantic_monty.Monty("int('1' * 5000)").run(limits=limits)
+    exc = exc_info.value.exception()
+    assert isinstance(exc, ValueError)
+    assert str(exc) == snapshot(
+        'Exceeds the limit (4300 digits) for integer string conversion: value has 5000 digits'
+    )
@@ -964,6 +964,26 @@ def test_get_environ_returns_dict():
     assert result == snapshot({'HOME': '/home/user', 'USER': 'testuser'})
 
 
+def test_time_uses_host_clock():
+    """time.time() defaults to the host's clock."""
+    import time
+
+    before = time.time()
+    result = Monty('import time; time.time()').run(os=OSAccess())
+    assert before <= result <= time.time()
+
+
+def test_time_overridden():
+    """Subclasses can control the time seen by Monty code."""
+
+    class FixedTimeOS(OSAccess):
+        def time(self) -> float:
+            return 0.0
+
+    result = Monty('import time; time.isoformat(time.time())').run(os=FixedTimeOS())
+    assert result == snapshot('1970-01-01T00:00:00')
+
+
 def test_get_environ_key_access():
     """os.environ['KEY'] returns the value."""
     fs = OSAccess(environ={'MY_VAR': 'my_value'})
@@ -437,6 +437,50 @@ def test_os_getenv_callback_with_default():
     assert result == snapshot('default_value')
 
 
+# =============================================================================
+# time module tests
+# =============================================================================
+
+
+def test_time_yields_oscall():
+    """time.time() yields an OS call without arguments."""
+    m = pydantic_monty.Monty('import time; time.time()')
+    result = m.start()
+
+    assert isinstance(result, pydantic_monty.MontySnapshot)
+    assert result.is_os_function is True
+    assert result.function_name == snapshot('time.time')
+    assert result.args == snapshot(())
+
+
+def test_time_formatted():
+    """The host's answer to time.time() can be formatted inside the sandbox."""
+
+    def os_handler(function_name: str, args: tuple[Any, ...], kwargs: dict[str, Any] | None = None) -> Any:
+        assert function_name == 'time.time'
+        return 1_700_000_000.0
+
+    m = pydantic_monty.Monty('import time; time.isoformat(time.time())')
+    assert m.run(os=os_handler) == snapshot('2023-11-14T22:13:20')
+
+
+def test_monotonic_elapsed():
+    """time.monotonic() yields to the host on every call."""
+    clock = iter([10.0, 12.5])
+
+    def os_handler(function_name: str, args: tuple[Any, ...], kwargs: dict[str, Any] | None = None) -> Any:
+        assert function_name == 'time.monotonic'
+        return next(clock)
+
+    code = """
+import time
+start = time.monotonic()
+time.monotonic() - start
+"""
+    m = pydantic_monty.Monty(code)
+    assert m.run(os=os_handler) == snapshot(2.5)
+
+
 # =============================================================================
 # os.environ tests
 # =============================================================================
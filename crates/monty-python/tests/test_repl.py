@@ -1,3 +1,4 @@
+import pytest
 from inline_snapshot import snapshot
 
 import pydantic_monty
@@ -32,3 +33,41 @@ def test_repl_create_with_start_inputs_feed_stateful():
     assert output == snapshot(None)
     assert repl.feed('counter = counter + 1') == snapshot(None)
     assert repl.feed('counter') == snapshot(1)
+
+
+def test_repl_feed_all():
+    repl, _ = pydantic_monty.MontyRepl.create('x = 1')
+
+    assert repl.feed_all(['x = x + 1', 'x * 10', 'x + 1']) == snapshot([None, 20, 3])
+
+
+def test_repl_feed_all_stops_at_error():
+    repl, _ = pydantic_monty.MontyRepl.create('x = 1')
+
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        repl.feed_all(['x + 1', '1 / 0', 'x = 100'])
+
+    assert exc_info.value.snippet_index == snapshot(1)
+    assert exc_info.value.outputs == snapshot([2])
+    assert exc_info.value.display('type-msg') == snapshot('ZeroDivisionError: division by zero')
+    assert repl.feed('x') == snapshot(1)
+
+
+def test_repl_feed_capture_output():
+    printed: list[str] = []
+    repl, _ = pydantic_monty.MontyRepl.create('x = 1', print_callback=lambda _, text: printed.append(text))
+
+    assert repl.feed('print("hi"); 42', capture_output=True) == snapshot((42, 'hi\n'))
+    assert repl.feed('x', capture_output=True) == snapshot((1, ''))
+    assert printed == snapshot([])
+
+    repl.feed('print("later")')
+    assert ''.join(printed) == snapshot('later\n')
+
+
+def test_repl_feed_capture_output_with_print_callback():
+    repl, _ = pydantic_monty.MontyRepl.create('x = 1')
+
+    with pytest.raises(ValueError) as exc_info:
+        repl.feed('x', print_callback=lambda _, __: None, capture_output=True)
+    assert str(exc_info.value) == snapshot('print_callback and capture_output cannot be used together')
@@ -1,3 +1,4 @@
+import json
 from dataclasses import dataclass, is_dataclass
 from typing import Any
 
@@ -46,7 +47,15 @@ def test_monty_dump_load_preserves_external_functions():
 def test_monty_load_invalid_data():
     with pytest.raises(ValueError) as exc_info:
         pydantic_monty.Monty.load(b'invalid data')
-    assert str(exc_info.value) == snapshot('Hit the end of buffer, expected more data')
+    assert str(exc_info.value) == snapshot('serialized data has format version 105, expected 1')
+
+
+def test_monty_load_rejects_different_format_version():
+    data = bytearray(pydantic_monty.Monty('1').dump())
+    data[0] += 1
+    with pytest.raises(ValueError) as exc_info:
+        pydantic_monty.Monty.load(bytes(data))
+    assert str(exc_info.value) == snapshot('serialized data has format version 2, expected 1')
 
 
 def test_progress_dump_load_roundtrip():
@@ -168,6 +177,35 @@ def test_progress_load_without_print_callback():
     assert result.output == snapshot(42)
 
 
+def test_monty_dump_load_json_roundtrip():
+    m = pydantic_monty.Monty("[x + 1, b'hi', {'a': (1.5, None)}]", inputs=['x'], script_name='custom.py')
+    data = m.dump_json()
+
+    assert isinstance(data, str)
+    assert json.loads(data)['data']['script_name'] == snapshot('custom.py')
+
+    m2 = pydantic_monty.Monty.load_json(data)
+    assert m2.run(inputs={'x': 41}) == snapshot([42, b'hi', {'a': (1.5, None)}])
+
+
+def test_monty_load_json_invalid_data():
+    with pytest.raises(ValueError):
+        pydantic_monty.Monty.load_json('not json')
+
+
+def test_monty_dump_load_json_non_finite_floats():
+    m = pydantic_monty.Monty("[1e400, -1e400, x != x]", inputs=['x'])
+    m2 = pydantic_monty.Monty.load_json(m.dump_json())
+    assert m2.run(inputs={'x': 1.0}) == snapshot([float('inf'), float('-inf'), False])
+
+
+def test_monty_load_json_wrong_format_version():
+    data = json.loads(pydantic_monty.Monty('1').dump_json())
+    data['format_version'] = 0
+    with pytest.raises(ValueError, match='^serialized data has format version 0, expected 1$'):
+        pydantic_monty.Monty.load_json(json.dumps(data))
+
+
 @pytest.mark.parametrize(
     'code,expected',
     [
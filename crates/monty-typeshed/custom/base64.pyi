@@ -0,0 +1,10 @@
+def b64encode(s: bytes, /) -> bytes:
+    """Encode the bytes-like object s using Base64 and return a bytes object."""
+    ...
+
+def b64decode(s: str | bytes, /) -> bytes:
+    """Decode the Base64 encoded bytes-like object or ASCII string s.
+
+    Characters outside the standard alphabet raise ValueError.
+    """
+    ...
@@ -0,0 +1,20 @@
+from collections.abc import Sequence
+from typing import TypeVar
+
+_T = TypeVar('_T')
+
+def bisect_left(a: Sequence[_T], x: _T, lo: int = 0, hi: int | None = None) -> int:
+    """Return the index where to insert item x in sorted sequence a, before any equal items."""
+    ...
+
+def bisect_right(a: Sequence[_T], x: _T, lo: int = 0, hi: int | None = None) -> int:
+    """Return the index where to insert item x in sorted sequence a, after any equal items."""
+    ...
+
+def insort_left(a: list[_T], x: _T, lo: int = 0, hi: int | None = None) -> None:
+    """Insert item x in sorted list a, keeping it sorted, before any equal items."""
+    ...
+
+def insort_right(a: list[_T], x: _T, lo: int = 0, hi: int | None = None) -> None:
+    """Insert item x in sorted list a, keeping it sorted, after any equal items."""
+    ...
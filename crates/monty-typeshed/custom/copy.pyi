@@ -0,0 +1,11 @@
+from typing import TypeVar
+
+_T = TypeVar('_T')
+
+def copy(x: _T) -> _T:
+    """Shallow copy operation on arbitrary Python objects."""
+    ...
+
+def deepcopy(x: _T) -> _T:
+    """Deep copy operation on arbitrary Python objects."""
+    ...
@@ -0,0 +1,35 @@
+from typing import final
+
+@final
+class _Hash:
+    """A hash object returned by md5() and sha256()."""
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def digest_size(self) -> int: ...
+    @property
+    def block_size(self) -> int: ...
+    def update(self, data: bytes, /) -> None:
+        """Update this hash object's state with the provided bytes."""
+        ...
+
+    def digest(self) -> bytes:
+        """Return the digest value as a bytes object."""
+        ...
+
+    def hexdigest(self) -> str:
+        """Return the digest value as a string of hexadecimal digits."""
+        ...
+
+    def copy(self) -> _Hash:
+        """Return a copy of the hash object."""
+        ...
+
+def md5(data: bytes = b'', /) -> _Hash:
+    """Return a new MD5 hash object, optionally initialized with data."""
+    ...
+
+def sha256(data: bytes = b'', /) -> _Hash:
+    """Return a new SHA-256 hash object, optionally initialized with data."""
+    ...
@@ -0,0 +1,19 @@
+from typing import Any, TypeVar
+
+_T = TypeVar('_T')
+
+def heappush(heap: list[_T], item: _T, /) -> None:
+    """Push item onto heap, maintaining the heap invariant."""
+    ...
+
+def heappop(heap: list[_T], /) -> _T:
+    """Pop the smallest item off the heap, maintaining the heap invariant."""
+    ...
+
+def heapify(heap: list[Any], /) -> None:
+    """Transform list into a heap, in-place, in O(len(heap)) time."""
+    ...
+
+def heappushpop(heap: list[_T], item: _T, /) -> _T:
+    """Push item on the heap, then pop and return the smallest item from the heap."""
+    ...
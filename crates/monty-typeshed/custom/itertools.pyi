@@ -0,0 +1,25 @@
+from collections.abc import Iterable, Iterator
+from typing import TypeVar, overload
+
+_T = TypeVar('_T')
+
+def count(start: int = 0, step: int = 1, /) -> Iterator[int]:
+    """Return an iterator of evenly spaced ints beginning with start."""
+    ...
+
+def cycle(iterable: Iterable[_T], /) -> Iterator[_T]:
+    """Return the elements of the iterable over and over."""
+    ...
+
+def repeat(object: _T, times: int = ..., /) -> Iterator[_T]:
+    """Return the object the given number of times, or forever if times isn't given."""
+    ...
+
+def chain(*iterables: Iterable[_T]) -> Iterator[_T]:
+    """Return the elements of each iterable in turn."""
+    ...
+
+@overload
+def islice(iterable: Iterable[_T], stop: int | None, /) -> Iterator[_T]: ...
+@overload
+def islice(iterable: Iterable[_T], start: int | None, stop: int | None, step: int | None = ..., /) -> Iterator[_T]: ...
@@ -10,6 +10,20 @@ environ: dict[str, str]
 def getenv(key: str) -> str | None: ...
 @overload
 def getenv(key: str, default: _T) -> str | _T: ...
+def read_line() -> str:
+    """Read the next line of host input, including the newline, or '' at the end of the input.
+
+    Monty extension, not available in CPython.
+    """
+    ...
+
+def read_chunk(size: int, /) -> str:
+    """Read the next chunk of at most `size` characters of host input, or '' at the end of the input.
+
+    Monty extension, not available in CPython.
+    """
+    ...
+
 @final
 class stat_result(structseq[float], tuple[int, int, int, int, int, int, int, float, float, float]):
     # The constructor of this class takes an iterable of variable length (though it must be at least 10).
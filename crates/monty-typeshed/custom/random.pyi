@@ -0,0 +1,24 @@
+from collections.abc import MutableSequence, Sequence
+from typing import TypeVar
+
+_T = TypeVar('_T')
+
+def seed(a: int | None = None, /) -> None:
+    """Initialize the generator, `None` restores the fixed default seed."""
+    ...
+
+def random() -> float:
+    """Get the next random number in the range 0.0 <= X < 1.0."""
+    ...
+
+def randint(a: int, b: int, /) -> int:
+    """Return random integer in range [a, b], including both end points."""
+    ...
+
+def choice(seq: Sequence[_T], /) -> _T:
+    """Choose a random element from a non-empty sequence."""
+    ...
+
+def shuffle(x: MutableSequence[_T], /) -> None:
+    """Shuffle list x in place, and return None."""
+    ...
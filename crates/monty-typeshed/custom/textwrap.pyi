@@ -0,0 +1,10 @@
+def wrap(text: str, width: int = 70) -> list[str]:
+    """Wrap a single paragraph of text, returning a list of wrapped lines.
+
+    Words longer than `width` are not broken, they get a line of their own.
+    """
+    ...
+
+def fill(text: str, width: int = 70) -> str:
+    """Fill a single paragraph of text, returning a new string of newline-separated lines."""
+    ...
@@ -0,0 +1,21 @@
+def time() -> float:
+    """Return the current time in seconds since the Epoch, as provided by the host."""
+    ...
+
+def monotonic() -> float:
+    """Monotonic clock, cannot go backward, as provided by the host."""
+    ...
+
+def isoformat(secs: float, /) -> str:
+    """Format a UTC timestamp as 'YYYY-MM-DDTHH:MM:SS[.ffffff]'.
+
+    Monty extension, not available in CPython.
+    """
+    ...
+
+def fromisoformat(string: str, /) -> float:
+    """Parse a UTC timestamp from 'YYYY-MM-DD[THH[:MM[:SS[.ffffff]]]][Z]'.
+
+    Monty extension, not available in CPython.
+    """
+    ...
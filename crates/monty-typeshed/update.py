@@ -127,13 +127,22 @@ VERSIONS = """\
 _collections_abc: 3.3-
 _typeshed: 3.0-  # not present at runtime, only for type checking
 asyncio: 3.4-
+base64: 3.0-
+bisect: 3.0-
 builtins: 3.0-
 collections: 3.0-
+copy: 3.0-
 dataclasses: 3.7-
+hashlib: 3.0-
+heapq: 3.0-
+itertools: 3.0-
 os: 3.0-
 pathlib: 3.4-
 pathlib.types: 3.14-
+random: 3.0-
 sys: 3.0-
+textwrap: 3.0-
+time: 3.0-
 typing: 3.5-
 typing_extensions: 3.7-
 types: 3.0-
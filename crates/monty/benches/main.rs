@@ -6,7 +6,7 @@ use std::ffi::CString;
 use codspeed_criterion_compat::{Bencher, Criterion, black_box, criterion_group, criterion_main};
 #[cfg(not(codspeed))]
 use criterion::{Bencher, Criterion, black_box, criterion_group, criterion_main};
-use monty::MontyRun;
+use monty::{HeapPool, MontyRun, NoLimitTracker, PrintWriter};
 #[cfg(not(codspeed))]
 use pprof::criterion::{Output, PProfProfiler};
 // CPython benchmarks are only run locally, not on CodSpeed CI (requires Python + pyo3 setup)
@@ -28,6 +28,26 @@ fn run_monty(bench: &mut Bencher, code: &str, expected: i64) {
     });
 }
 
+/// Runs a benchmark using the Monty interpreter, reusing one `HeapPool` across runs.
+/// Compare with `run_monty`, which builds a fresh heap for every run.
+fn run_monty_reusing(bench: &mut Bencher, code: &str, expected: i64) {
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut pool = HeapPool::default();
+    let r = ex
+        .run_reusing(&mut pool, vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap();
+    let int_value: i64 = r.as_ref().try_into().unwrap();
+    assert_eq!(int_value, expected);
+
+    bench.iter(|| {
+        let r = ex
+            .run_reusing(&mut pool, vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+            .unwrap();
+        let int_value: i64 = r.as_ref().try_into().unwrap();
+        black_box(int_value);
+    });
+}
+
 /// Runs a benchmark using CPython.
 /// Wraps code in main(), parses once, then benchmarks repeated execution.
 #[cfg(not(codspeed))]
@@ -97,6 +117,23 @@ for i in range(1_000):
 len(v)
 ";
 
+/// `+=` appends to the unshared string in place; compare with `STR_CONCAT_COPY`.
+const STR_IADD: &str = "
+v = ''
+for i in range(10_000):
+    v += 'x'
+len(v)
+";
+
+/// Same as `STR_IADD` but keeps an alias alive, forcing a copy on every iteration.
+const STR_CONCAT_COPY: &str = "
+v = ''
+for i in range(10_000):
+    w = v
+    v += 'x'
+len(v)
+";
+
 /// Comprehensive benchmark exercising most supported Python features.
 /// Code is shared with test_cases/bench__kitchen_sink.py
 const KITCHEN_SINK: &str = include_str!("../test_cases/bench__kitchen_sink.py");
@@ -122,6 +159,32 @@ for i in range(100_000):
 sum(a)
 ";
 
+/// Baseline for `LIST_APPEND_INT`: builds the same list without calling `append`.
+const LIST_COMP_INT: &str = "
+a = [i for i in range(100_000)]
+sum(a)
+";
+
+/// Builds a module containing a single `count`-element list display of int literals.
+fn list_literal_code(count: usize) -> String {
+    let elements: Vec<String> = (0..count).map(|i| i.to_string()).collect();
+    format!("a = [{}]\nsum(a)", elements.join(", "))
+}
+
+/// Instance attribute reads, which look up interned names in the attribute dict.
+const ATTR_LOOKUP: &str = "
+class P:
+    def __init__(self):
+        self.x = 1
+        self.y = 2
+
+p = P()
+total = 0
+for i in range(10_000):
+    total += p.x + p.y
+total
+";
+
 const FIB_25: &str = "
 def fib(n):
     if n <= 1:
@@ -176,10 +239,12 @@ fn end_to_end_cpython(bench: &mut Bencher) {
 /// Configures all benchmarks in a single group.
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("add_two__monty", |b| run_monty(b, ADD_TWO, 3));
+    c.bench_function("add_two_reusing__monty", |b| run_monty_reusing(b, ADD_TWO, 3));
     #[cfg(not(codspeed))]
     c.bench_function("add_two__cpython", |b| run_cpython(b, ADD_TWO, 3));
 
     c.bench_function("list_append__monty", |b| run_monty(b, LIST_APPEND, 42));
+    c.bench_function("list_append_reusing__monty", |b| run_monty_reusing(b, LIST_APPEND, 42));
     #[cfg(not(codspeed))]
     c.bench_function("list_append__cpython", |b| run_cpython(b, LIST_APPEND, 42));
 
@@ -187,6 +252,11 @@ fn criterion_benchmark(c: &mut Criterion) {
     #[cfg(not(codspeed))]
     c.bench_function("loop_mod_13__cpython", |b| run_cpython(b, LOOP_MOD_13, 77));
 
+    c.bench_function("str_iadd__monty", |b| run_monty(b, STR_IADD, 10_000));
+    #[cfg(not(codspeed))]
+    c.bench_function("str_iadd__cpython", |b| run_cpython(b, STR_IADD, 10_000));
+    c.bench_function("str_concat_copy__monty", |b| run_monty(b, STR_CONCAT_COPY, 10_000));
+
     c.bench_function("end_to_end__monty", end_to_end_monty);
     #[cfg(not(codspeed))]
     c.bench_function("end_to_end__cpython", end_to_end_cpython);
@@ -210,6 +280,18 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("list_append_int__cpython", |b| {
         run_cpython(b, LIST_APPEND_INT, 4_999_950_000);
     });
+    c.bench_function("list_comp_int__monty", |b| {
+        run_monty(b, LIST_COMP_INT, 4_999_950_000);
+    });
+
+    let list_literal = list_literal_code(10_000);
+    c.bench_function("list_literal_10k__monty", |b| run_monty(b, &list_literal, 49_995_000));
+    #[cfg(not(codspeed))]
+    c.bench_function("list_literal_10k__cpython", |b| {
+        run_cpython(b, &list_literal, 49_995_000)
+    });
+
+    c.bench_function("attr_lookup__monty", |b| run_monty(b, ATTR_LOOKUP, 30_000));
 
     c.bench_function("fib__monty", |b| run_monty(b, FIB_25, 75_025));
     #[cfg(not(codspeed))]
@@ -3,8 +3,8 @@
 use crate::{
     args::ArgValues,
     defer_drop,
-    exception_private::{ExcType, RunResult, SimpleException},
-    heap::Heap,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{Heap, HeapData},
     resource::ResourceTracker,
     types::{PyTrait, str::allocate_char},
     value::Value,
@@ -14,33 +14,35 @@ use crate::{
 ///
 /// Returns a string representing a character whose Unicode code point is the integer.
 /// The valid range for the argument is from 0 through 1,114,111 (0x10FFFF).
+///
+/// CPython also accepts the surrogate code points U+D800..U+DFFF, but Monty strings are
+/// always valid UTF-8 and cannot hold a lone surrogate, so those raise `ValueError`.
 pub fn builtin_chr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let value = args.get_one_arg("chr", heap)?;
     defer_drop!(value, heap);
 
-    match value {
-        Value::Int(n) => {
-            if *n < 0 || *n > 0x0010_FFFF {
-                Err(SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into())
-            } else if let Some(c) = char::from_u32(u32::try_from(*n).expect("chr() range check failed")) {
-                Ok(allocate_char(c, heap)?)
-            } else {
-                // This shouldn't happen for valid Unicode range, but handle it
-                Err(SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into())
-            }
-        }
-        Value::Bool(b) => {
-            // bool is subclass of int
-            let c = if *b { '\x01' } else { '\x00' };
-            Ok(allocate_char(c, heap)?)
-        }
-        _ => {
-            let type_name = value.py_type(heap);
-            Err(SimpleException::new_msg(
-                ExcType::TypeError,
-                format!("an integer is required (got type {type_name})"),
-            )
-            .into())
-        }
+    let n = match value {
+        Value::Int(n) => *n,
+        Value::Bool(b) => i64::from(*b),
+        // LongInts are always outside i64, so well outside the code point range
+        Value::Ref(id) if matches!(heap.get(*id), HeapData::LongInt(_)) => return Err(out_of_range()),
+        _ => return Err(ExcType::type_error_not_integer(value.py_type(heap))),
+    };
+    let Ok(code) = u32::try_from(n) else {
+        return Err(out_of_range());
+    };
+    match char::from_u32(code) {
+        Some(c) => Ok(allocate_char(c, heap)?),
+        None if code <= 0x0010_FFFF => Err(SimpleException::new_msg(
+            ExcType::ValueError,
+            format!("chr() arg {code:#x} is a surrogate code point, which is not supported"),
+        )
+        .into()),
+        None => Err(out_of_range()),
     }
 }
+
+/// Error for a code point outside `range(0x110000)`, matching CPython's message.
+fn out_of_range() -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, "chr() arg not in range(0x110000)").into()
+}
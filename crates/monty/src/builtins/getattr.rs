@@ -4,12 +4,12 @@ use crate::{
     ExcType,
     args::ArgValues,
     defer_drop,
-    exception_private::{RunResult, SimpleException},
-    heap::Heap,
+    exception_private::{RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap},
     intern::Interns,
     resource::ResourceTracker,
     types::{AttrCallResult, PyTrait},
-    value::Value,
+    value::{EitherStr, Value},
 };
 
 /// Implementation of the getattr() builtin function.
@@ -18,6 +18,9 @@ use crate::{
 /// If the attribute doesn't exist and a default is provided, returns the default.
 /// If no default is provided and the attribute doesn't exist, raises AttributeError.
 ///
+/// Only `AttributeError` (and subclasses) fall back to the default, matching CPython —
+/// any other exception raised while looking up the attribute propagates.
+///
 /// Note: name must be a string. Per Python docs, "Since private name mangling happens
 /// at compilation time, one must manually mangle a private attribute's (attributes with
 /// two leading underscores) name in order to retrieve it with getattr()."
@@ -39,28 +42,41 @@ pub fn builtin_getattr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, i
         too_many => return Err(ExcType::type_error_at_most("getattr", 3, too_many.len())),
     };
 
-    let Some(attr) = name.as_either_str(heap) else {
-        let ty = name.py_type(heap);
-        return Err(
-            SimpleException::new_msg(ExcType::TypeError, format!("attribute name must be string, not '{ty}'")).into(),
-        );
-    };
+    let attr = attr_name_arg(name, heap)?;
 
     match object.py_getattr(&attr, heap, interns) {
         Ok(AttrCallResult::Value(value)) => Ok(value),
-        Ok(_) => {
+        Ok(other) => {
             // getattr() only retrieves attribute values — OS calls, external calls,
             // method calls, and awaits are not supported here
             //
             // TODO: might need to support this case?
+            other.drop_with_heap(heap);
             Err(SimpleException::new_msg(ExcType::TypeError, "getattr(): attribute is not a simple value").into())
         }
-        Err(e) => {
-            if let Some(d) = default {
-                Ok(d.clone_with_heap(heap))
-            } else {
-                Err(e)
-            }
-        }
+        Err(e) => match default {
+            Some(d) if is_attribute_error(&e) => Ok(d.clone_with_heap(heap)),
+            _ => Err(e),
+        },
     }
 }
+
+/// Extracts the attribute name argument shared by `getattr()`, `setattr()` and `hasattr()`.
+///
+/// Returns `TypeError: attribute name must be string, not '<type>'` for non-`str` names,
+/// matching CPython.
+pub(super) fn attr_name_arg(name: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<EitherStr> {
+    name.as_either_str(heap).ok_or_else(|| {
+        let ty = name.py_type(heap);
+        SimpleException::new_msg(ExcType::TypeError, format!("attribute name must be string, not '{ty}'")).into()
+    })
+}
+
+/// Returns whether `error` is a catchable `AttributeError` (including subclasses like
+/// `FrozenInstanceError`).
+///
+/// Used by `getattr()` with a default and by `hasattr()`, which both only swallow
+/// attribute lookup failures and must let every other error propagate.
+pub(super) fn is_attribute_error(error: &RunError) -> bool {
+    matches!(error, RunError::Exc(exc) if exc.exc.exc_type().is_subclass_of(ExcType::AttributeError))
+}
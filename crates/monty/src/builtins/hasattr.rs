@@ -0,0 +1,40 @@
+//! Implementation of the hasattr() builtin function.
+
+use super::getattr::{attr_name_arg, is_attribute_error};
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::RunResult,
+    heap::{DropWithHeap, Heap},
+    intern::Interns,
+    resource::ResourceTracker,
+    value::Value,
+};
+
+/// Implementation of the hasattr() builtin function.
+///
+/// Returns `True` if looking up the named attribute on `object` succeeds and `False` if it
+/// raises `AttributeError`. Like CPython, any other exception raised during the lookup
+/// propagates rather than being reported as `False`.
+///
+/// Examples:
+/// ```python
+/// hasattr(point, 'x')       # True
+/// hasattr(point, 'z')       # False
+/// ```
+pub fn builtin_hasattr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let (object, name) = args.get_two_args("hasattr", heap)?;
+    defer_drop!(object, heap);
+    defer_drop!(name, heap);
+
+    let attr = attr_name_arg(name, heap)?;
+
+    match object.py_getattr(&attr, heap, interns) {
+        Ok(result) => {
+            result.drop_with_heap(heap);
+            Ok(Value::Bool(true))
+        }
+        Err(e) if is_attribute_error(&e) => Ok(Value::Bool(false)),
+        Err(e) => Err(e),
+    }
+}
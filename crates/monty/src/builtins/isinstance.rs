@@ -4,8 +4,8 @@ use super::Builtins;
 use crate::{
     args::ArgValues,
     defer_drop,
-    exception_private::{ExcType, RunResult},
-    heap::{Heap, HeapData},
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
     resource::ResourceTracker,
     types::{PyTrait, Type},
     value::Value,
@@ -20,8 +20,16 @@ pub fn builtin_isinstance(heap: &mut Heap<impl ResourceTracker>, args: ArgValues
     defer_drop!(classinfo, heap);
 
     let obj_type = obj.py_type(heap);
+    let (obj_class, obj_exc) = match obj {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Instance(instance) => (Some(instance.class_id()), None),
+            HeapData::Exception(exc) => (None, Some(exc)),
+            _ => (None, None),
+        },
+        _ => (None, None),
+    };
 
-    match isinstance_check(obj_type, classinfo, heap) {
+    match isinstance_check(obj_type, obj_class, obj_exc, classinfo, heap) {
         Ok(result) => Ok(Value::Bool(result)),
         Err(()) => Err(ExcType::isinstance_arg2_error()),
     }
@@ -29,6 +37,11 @@ pub fn builtin_isinstance(heap: &mut Heap<impl ResourceTracker>, args: ArgValues
 
 /// Recursively checks if obj_type matches classinfo for isinstance().
 ///
+/// `obj_class` is the class of the object when it is an instance of a class defined in
+/// the script, which matches a classinfo that is that same class object. `obj_exc` is the
+/// object when it is an exception, which matches exception classes defined in the script
+/// that it was raised as or inherits from.
+///
 /// Returns `Ok(true)` if the type matches, `Ok(false)` if it doesn't,
 /// or `Err(())` if classinfo is invalid (not a type or tuple of types).
 ///
@@ -36,8 +49,15 @@ pub fn builtin_isinstance(heap: &mut Heap<impl ResourceTracker>, args: ArgValues
 /// - Single types: `isinstance(x, int)`
 /// - Exception types: `isinstance(err, ValueError)`
 /// - Exception hierarchy: `isinstance(err, LookupError)` for KeyError/IndexError
+/// - Classes defined in the script: `isinstance(p, Point)` or `isinstance(err, MyError)`
 /// - Nested tuples: `isinstance(x, (int, (str, bytes)))`
-fn isinstance_check(obj_type: Type, classinfo: &Value, heap: &Heap<impl ResourceTracker>) -> Result<bool, ()> {
+fn isinstance_check(
+    obj_type: Type,
+    obj_class: Option<HeapId>,
+    obj_exc: Option<&SimpleException>,
+    classinfo: &Value,
+    heap: &Heap<impl ResourceTracker>,
+) -> Result<bool, ()> {
     match classinfo {
         // Single type: isinstance(x, int)
         Value::Builtin(Builtins::Type(t)) => Ok(obj_type.is_instance_of(*t)),
@@ -48,19 +68,23 @@ fn isinstance_check(obj_type: Type, classinfo: &Value, heap: &Heap<impl Resource
             Ok(matches!(obj_type, Type::Exception(exc_type) if exc_type.is_subclass_of(*handler_type)))
         }
 
-        // Tuple of types (possibly nested): isinstance(x, (int, (str, bytes)))
-        Value::Ref(id) => {
-            if let HeapData::Tuple(tuple) = heap.get(*id) {
+        Value::Ref(id) => match heap.get(*id) {
+            // Class defined in the script: isinstance(p, Point)
+            HeapData::Class(class) => match class.exception() {
+                Some(exception) => Ok(obj_exc.is_some_and(|exc| exception.is_class_of(exc))),
+                None => Ok(obj_class == Some(*id)),
+            },
+            // Tuple of types (possibly nested): isinstance(x, (int, (str, bytes)))
+            HeapData::Tuple(tuple) => {
                 for v in tuple.as_slice() {
-                    if isinstance_check(obj_type, v, heap)? {
+                    if isinstance_check(obj_type, obj_class, obj_exc, v, heap)? {
                         return Ok(true);
                     }
                 }
                 Ok(false)
-            } else {
-                Err(()) // Not a tuple - invalid
             }
-        }
+            _ => Err(()), // Not a class or tuple - invalid
+        },
         _ => Err(()), // Invalid classinfo
     }
 }
@@ -13,12 +13,15 @@ use crate::{
 
 /// Implementation of the len() builtin function.
 ///
-/// Returns the length of an object (number of items in a container).
+/// Returns the length of an object (number of items in a container), dispatching to `py_len`.
+/// Raises `OverflowError` for lengths beyond `i64::MAX`, which only a range can reach.
 pub fn builtin_len(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
     let value = args.get_one_arg("len", heap)?;
     defer_drop!(value, heap);
     if let Some(len) = value.py_len(heap, interns) {
-        Ok(Value::Int(i64::try_from(len).expect("len exceeds i64::MAX")))
+        i64::try_from(len).map(Value::Int).map_err(|_| {
+            SimpleException::new_msg(ExcType::OverflowError, "Python int too large to convert to C ssize_t").into()
+        })
     } else {
         let type_name = value.py_type(heap);
         Err(SimpleException::new_msg(ExcType::TypeError, format!("object of type '{type_name}' has no len()")).into())
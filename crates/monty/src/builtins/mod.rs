@@ -12,6 +12,7 @@ mod divmod;
 mod enumerate;
 mod filter;
 mod getattr;
+mod hasattr;
 mod hash;
 mod hex;
 mod id;
@@ -27,9 +28,11 @@ mod print;
 mod repr;
 mod reversed;
 mod round;
+mod setattr;
 mod sorted;
 mod sum;
 mod type_;
+mod vars;
 mod zip;
 
 use std::{fmt::Write, str::FromStr};
@@ -41,8 +44,7 @@ use crate::{
     bytecode::VM,
     exception_private::{ExcType, RunResult},
     resource::ResourceTracker,
-    types::Type,
-    value::Value,
+    types::{AttrCallResult, Type},
 };
 
 /// Enumerates every interpreter-native Python builtins
@@ -67,11 +69,13 @@ impl Builtins {
     /// * `args` - The arguments to pass to the callable
     /// * `interns` - String storage for looking up interned names in error messages
     /// * `print` - The print for print output
-    pub fn call(self, vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    ///
+    /// Returns an `AttrCallResult` since `sorted()` may need the host to compare dataclasses.
+    pub fn call(self, vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
         match self {
             Self::Function(b) => b.call(vm, args),
-            Self::ExcType(exc) => exc.call(vm.heap, args, vm.interns),
-            Self::Type(t) => t.call(vm.heap, args, vm.interns),
+            Self::ExcType(exc) => exc.call(vm.heap, args, vm.interns).map(AttrCallResult::Value),
+            Self::Type(t) => t.call(vm, args).map(AttrCallResult::Value),
         }
     }
 
@@ -167,7 +171,7 @@ pub enum BuiltinsFunctions {
     // frozenset - handled by Type enum
     Getattr,
     // Globals,
-    // Hasattr,
+    Hasattr,
     Hash,
     // Help,
     Hex,
@@ -197,7 +201,7 @@ pub enum BuiltinsFunctions {
     Reversed,
     Round,
     // set - handled by Type enum
-    // Setattr,
+    Setattr,
     // Slice,
     Sorted,
     // Staticmethod,
@@ -206,7 +210,7 @@ pub enum BuiltinsFunctions {
     // Super,
     // tuple - handled by Type enum
     Type,
-    // Vars,
+    Vars,
     Zip,
     // __import__ - not planned
 }
@@ -216,8 +220,8 @@ impl BuiltinsFunctions {
     ///
     /// The `interns` parameter provides access to interned string content for py_str and py_repr.
     /// The `print` parameter is used for print output.
-    pub(crate) fn call(self, vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
-        match self {
+    pub(crate) fn call(self, vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
+        let value = match self {
             Self::Abs => abs::builtin_abs(vm.heap, args),
             Self::All => all::builtin_all(vm.heap, args, vm.interns),
             Self::Any => any::builtin_any(vm.heap, args, vm.interns),
@@ -227,6 +231,7 @@ impl BuiltinsFunctions {
             Self::Enumerate => enumerate::builtin_enumerate(vm.heap, args, vm.interns),
             Self::Filter => filter::builtin_filter(vm, args),
             Self::Getattr => getattr::builtin_getattr(vm.heap, args, vm.interns),
+            Self::Hasattr => hasattr::builtin_hasattr(vm.heap, args, vm.interns),
             Self::Hash => hash::builtin_hash(vm.heap, args, vm.interns),
             Self::Hex => hex::builtin_hex(vm.heap, args),
             Self::Id => id::builtin_id(vm.heap, args),
@@ -235,7 +240,7 @@ impl BuiltinsFunctions {
             Self::Map => map::builtin_map(vm, args),
             Self::Max => min_max::builtin_max(vm.heap, args, vm.interns),
             Self::Min => min_max::builtin_min(vm.heap, args, vm.interns),
-            Self::Next => next::builtin_next(vm.heap, args, vm.interns),
+            Self::Next => next::builtin_next(vm, args),
             Self::Oct => oct::builtin_oct(vm.heap, args),
             Self::Ord => ord::builtin_ord(vm.heap, args, vm.interns),
             Self::Pow => pow::builtin_pow(vm.heap, args),
@@ -243,10 +248,13 @@ impl BuiltinsFunctions {
             Self::Repr => repr::builtin_repr(vm.heap, args, vm.interns),
             Self::Reversed => reversed::builtin_reversed(vm.heap, args, vm.interns),
             Self::Round => round::builtin_round(vm.heap, args),
-            Self::Sorted => sorted::builtin_sorted(vm, args),
+            Self::Setattr => setattr::builtin_setattr(vm.heap, args, vm.interns),
+            Self::Sorted => return sorted::builtin_sorted(vm, args),
             Self::Sum => sum::builtin_sum(vm.heap, args, vm.interns),
             Self::Type => type_::builtin_type(vm.heap, args),
+            Self::Vars => vars::builtin_vars(vm.heap, args, vm.interns),
             Self::Zip => zip::builtin_zip(vm.heap, args, vm.interns),
-        }
+        }?;
+        Ok(AttrCallResult::Value(value))
     }
 }
@@ -1,8 +1,14 @@
 //! Implementation of the next() builtin function.
 
 use crate::{
-    args::ArgValues, defer_drop, exception_private::RunResult, heap::Heap, intern::Interns, resource::ResourceTracker,
-    types::iter::iterator_next, value::Value,
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, HeapData},
+    resource::ResourceTracker,
+    types::{MontyIter, iter::iterator_next},
+    value::Value,
 };
 
 /// Implementation of the next() builtin function.
@@ -14,8 +20,32 @@ use crate::{
 ///   `StopIteration` when the iterator is exhausted.
 /// - `next(iterator, default)` - Returns the next item from the iterator, or
 ///   `default` if the iterator is exhausted.
-pub fn builtin_next(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
-    let (iterator, default) = args.get_one_two_args("next", heap)?;
-    defer_drop!(iterator, heap);
-    iterator_next(iterator, default, heap, interns)
+///
+/// Instances of classes defining `__next__` are advanced by calling it, and
+/// `iter(callable, sentinel)` iterators by calling the callable.
+pub fn builtin_next(vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (iterator, default) = args.get_one_two_args("next", vm.heap)?;
+    defer_drop!(iterator, vm);
+    let next = match iterator {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Instance(_) => Some(vm.instance_next(iterator)),
+            HeapData::Iter(iter) if iter.is_callable_iter() => Some(MontyIter::call_next(vm, *id)),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(next) = next {
+        return match next {
+            Ok(Some(item)) => {
+                default.drop_with_heap(vm.heap);
+                Ok(item)
+            }
+            Ok(None) => default.ok_or_else(ExcType::stop_iteration),
+            Err(e) => {
+                default.drop_with_heap(vm.heap);
+                Err(e)
+            }
+        };
+    }
+    iterator_next(iterator, default, vm.heap, vm.interns)
 }
@@ -3,7 +3,7 @@
 use crate::{
     args::ArgValues,
     defer_drop,
-    exception_private::{ExcType, RunResult, SimpleException},
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::{Heap, HeapData},
     intern::Interns,
     resource::ResourceTracker,
@@ -13,55 +13,59 @@ use crate::{
 
 /// Implementation of the ord() builtin function.
 ///
-/// Returns the Unicode code point of a one-character string.
+/// Returns the Unicode code point of a one-character string, or the value of
+/// the single byte in a one-byte bytes object.
 pub fn builtin_ord(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
     let value = args.get_one_arg("ord", heap)?;
     defer_drop!(value, heap);
 
     match value {
-        Value::InternString(string_id) => {
-            let s = interns.get_str(*string_id);
-            let mut chars = s.chars();
-            if let (Some(c), None) = (chars.next(), chars.next()) {
-                Ok(Value::Int(c as i64))
-            } else {
-                let len = s.chars().count();
-                Err(SimpleException::new_msg(
-                    ExcType::TypeError,
-                    format!("ord() expected a character, but string of length {len} found"),
-                )
-                .into())
-            }
-        }
-        Value::Ref(id) => {
-            if let HeapData::Str(s) = heap.get(*id) {
-                let mut chars = s.as_str().chars();
-                if let (Some(c), None) = (chars.next(), chars.next()) {
-                    Ok(Value::Int(c as i64))
-                } else {
-                    let len = s.as_str().chars().count();
-                    Err(SimpleException::new_msg(
-                        ExcType::TypeError,
-                        format!("ord() expected a character, but string of length {len} found"),
-                    )
-                    .into())
-                }
-            } else {
-                let type_name = value.py_type(heap);
-                Err(SimpleException::new_msg(
-                    ExcType::TypeError,
-                    format!("ord() expected string of length 1, but {type_name} found"),
-                )
-                .into())
-            }
-        }
-        _ => {
-            let type_name = value.py_type(heap);
-            Err(SimpleException::new_msg(
-                ExcType::TypeError,
-                format!("ord() expected string of length 1, but {type_name} found"),
-            )
-            .into())
-        }
+        Value::InternString(string_id) => str_ord(interns.get_str(*string_id)),
+        Value::InternBytes(bytes_id) => bytes_ord(interns.get_bytes(*bytes_id)),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Str(s) => str_ord(s.as_str()),
+            HeapData::Bytes(b) => bytes_ord(b.as_slice()),
+            _ => Err(expected_character(value, heap)),
+        },
+        _ => Err(expected_character(value, heap)),
     }
 }
+
+/// Returns the code point of `s` if it is exactly one character long.
+fn str_ord(s: &str) -> RunResult<Value> {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        Ok(Value::Int(i64::from(u32::from(c))))
+    } else {
+        Err(wrong_length(s.chars().count()))
+    }
+}
+
+/// Returns the value of the byte in `bytes` if it is exactly one byte long.
+fn bytes_ord(bytes: &[u8]) -> RunResult<Value> {
+    match bytes {
+        [b] => Ok(Value::Int(i64::from(*b))),
+        _ => Err(wrong_length(bytes.len())),
+    }
+}
+
+/// TypeError for a str or bytes argument that is not exactly one character long.
+///
+/// CPython uses the same wording for bytes as for str.
+fn wrong_length(len: usize) -> RunError {
+    SimpleException::new_msg(
+        ExcType::TypeError,
+        format!("ord() expected a character, but string of length {len} found"),
+    )
+    .into()
+}
+
+/// TypeError for an argument that is neither str nor bytes.
+fn expected_character(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunError {
+    let type_name = value.py_type(heap);
+    SimpleException::new_msg(
+        ExcType::TypeError,
+        format!("ord() expected string of length 1, but {type_name} found"),
+    )
+    .into()
+}
@@ -1,7 +1,8 @@
 //! Implementation of the pow() builtin function.
 
 use num_bigint::BigInt;
-use num_traits::{Signed, ToPrimitive, Zero};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
 use crate::{
     args::ArgValues,
@@ -10,7 +11,7 @@ use crate::{
     heap::{Heap, HeapData},
     resource::{ResourceTracker, check_pow_size},
     types::{LongInt, PyTrait},
-    value::Value,
+    value::{Value, extract_bigint},
 };
 
 /// Implementation of the pow() builtin function.
@@ -28,38 +29,7 @@ pub fn builtin_pow(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> Ru
             let exp = normalize_bool(exp);
             two_arg_pow(base, exp, heap)
         }
-        [base, exp, m] => {
-            let base = normalize_bool(base);
-            let exp = normalize_bool(exp);
-            let m = normalize_bool(m);
-            // Three-argument pow: modular exponentiation
-            match (base, exp, m) {
-                (Value::Int(b), Value::Int(e), Value::Int(m_val)) => {
-                    if *m_val == 0 {
-                        Err(SimpleException::new_msg(ExcType::ValueError, "pow() 3rd argument cannot be 0").into())
-                    } else if *e < 0 {
-                        Err(SimpleException::new_msg(
-                            ExcType::ValueError,
-                            "pow() 2nd argument cannot be negative when 3rd argument specified",
-                        )
-                        .into())
-                    } else {
-                        // Use modular exponentiation
-                        let result = mod_pow(
-                            *b,
-                            u64::try_from(*e).expect("pow exponent >= 0 but failed u64 conversion"),
-                            *m_val,
-                        );
-                        Ok(Value::Int(result))
-                    }
-                }
-                _ => Err(SimpleException::new_msg(
-                    ExcType::TypeError,
-                    "pow() 3rd argument not allowed unless all arguments are integers",
-                )
-                .into()),
-            }
-        }
+        [base, exp, m] => three_arg_pow(normalize_bool(base), normalize_bool(exp), normalize_bool(m), heap),
         args => Err(SimpleException::new_msg(
             ExcType::TypeError,
             format!("pow expected 2 or 3 arguments, got {}", args.len()),
@@ -68,6 +38,79 @@ pub fn builtin_pow(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> Ru
     }
 }
 
+/// Implements three-argument pow: `(base ** exp) % m` for integer arguments.
+///
+/// Uses an i64 fast path when all three arguments are small ints, and BigInt modular
+/// exponentiation when any of them is a LongInt. The result takes the sign of `m`.
+fn three_arg_pow(base: &Value, exp: &Value, m: &Value, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+    if let (Value::Int(b), Value::Int(e), Value::Int(m_val)) = (base, exp, m) {
+        check_mod_pow_args(*e < 0, *m_val == 0)?;
+        let exp_u64 = u64::try_from(*e).expect("pow exponent >= 0 but failed u64 conversion");
+        return Ok(Value::Int(mod_pow(*b, exp_u64, *m_val)));
+    }
+
+    let (Some(b), Some(e), Some(m_bi)) = (
+        extract_bigint(base, heap),
+        extract_bigint(exp, heap),
+        extract_bigint(m, heap),
+    ) else {
+        return Err(SimpleException::new_msg(
+            ExcType::TypeError,
+            "pow() 3rd argument not allowed unless all arguments are integers",
+        )
+        .into());
+    };
+    check_mod_pow_args(e.is_negative(), m_bi.is_zero())?;
+    let result = bigint_mod_pow(&b, &e, &m_bi, heap)?;
+    Ok(LongInt::new(result).into_value(heap)?)
+}
+
+/// Computes `(base ** exp) % modulo` for BigInts using square-and-multiply.
+///
+/// Unlike `BigInt::modpow`, this checks the time limit on every exponent bit: intermediates
+/// stay below `modulo ** 2`, so memory is bounded, but an exponent like `2**1000000` with a
+/// large modulus can otherwise run for minutes inside a single instruction.
+/// The result takes the sign of `modulo`, matching Python's `%`.
+fn bigint_mod_pow(
+    base: &BigInt,
+    exp: &BigInt,
+    modulo: &BigInt,
+    heap: &Heap<impl ResourceTracker>,
+) -> RunResult<BigInt> {
+    let modulo_abs = modulo.abs();
+    if modulo_abs.is_one() {
+        return Ok(BigInt::zero());
+    }
+    let base = base.mod_floor(&modulo_abs);
+    let mut result = BigInt::one();
+    for bit in (0..exp.bits()).rev() {
+        heap.check_time()?;
+        result = (&result * &result) % &modulo_abs;
+        if exp.bit(bit) {
+            result = (&result * &base) % &modulo_abs;
+        }
+    }
+    if modulo.is_negative() && !result.is_zero() {
+        result += modulo;
+    }
+    Ok(result)
+}
+
+/// Validates the exponent and modulus of three-argument pow.
+fn check_mod_pow_args(negative_exp: bool, zero_mod: bool) -> RunResult<()> {
+    if zero_mod {
+        Err(SimpleException::new_msg(ExcType::ValueError, "pow() 3rd argument cannot be 0").into())
+    } else if negative_exp {
+        Err(SimpleException::new_msg(
+            ExcType::ValueError,
+            "pow() 2nd argument cannot be negative when 3rd argument specified",
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Normalizes a `Bool` to its `Int` equivalent by reference.
 ///
 /// Returns `&Value::Int(0)` or `&Value::Int(1)` for bools (using static storage),
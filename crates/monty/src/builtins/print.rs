@@ -1,5 +1,7 @@
 //! Implementation of the print() builtin function.
 
+use std::borrow::Cow;
+
 use crate::{
     args::{ArgValues, KwargsValues},
     defer_drop,
@@ -20,6 +22,9 @@ use crate::{
 /// - `flush`: whether to flush the stream (accepted but ignored)
 ///
 /// The `file` kwarg is not supported.
+///
+/// Every write is counted against the tracker's output limit (see `ResourceTracker::on_output`),
+/// and containers longer than its `repr_max_items` are elided (see `Heap::format_for_display`).
 pub fn builtin_print(
     heap: &mut Heap<impl ResourceTracker>,
     args: ArgValues,
@@ -39,23 +44,46 @@ pub fn builtin_print(
         if first {
             first = false;
         } else if let Some(sep) = &sep {
-            print.stdout_write(sep.as_str().into())?;
+            write_output(print, heap, sep.as_str().into())?;
         } else {
-            print.stdout_push(' ')?;
+            push_output(print, heap, ' ')?;
         }
-        print.stdout_write(value.py_str(heap, interns))?;
+        // The output only reaches the host, so long containers may be elided
+        let text = heap.format_for_display(|| value.py_str_checked(heap, interns))?;
+        write_output(print, heap, text)?;
     }
 
     // Append end string
     if let Some(end) = end {
-        print.stdout_write(end.into())?;
+        write_output(print, heap, end.into())?;
     } else {
-        print.stdout_push('\n')?;
+        push_output(print, heap, '\n')?;
     }
 
     Ok(Value::None)
 }
 
+/// Writes `output` to the print writer after charging its size to the resource tracker.
+///
+/// The check happens before the write, so output that would exceed `max_output_bytes`
+/// is never emitted.
+fn write_output(
+    print: &mut PrintWriter<'_>,
+    heap: &mut Heap<impl ResourceTracker>,
+    output: Cow<'_, str>,
+) -> RunResult<()> {
+    heap.tracker_mut().on_output(output.len())?;
+    print.stdout_write(output)?;
+    Ok(())
+}
+
+/// Single-character counterpart of [`write_output`], used for default separators and terminators.
+fn push_output(print: &mut PrintWriter<'_>, heap: &mut Heap<impl ResourceTracker>, c: char) -> RunResult<()> {
+    heap.tracker_mut().on_output(c.len_utf8())?;
+    print.stdout_push(c)?;
+    Ok(())
+}
+
 /// Extracts sep and end kwargs from print() arguments.
 ///
 /// Consumes the kwargs, dropping all values after extraction.
@@ -17,6 +17,7 @@ use crate::{
 pub fn builtin_repr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
     let value = args.get_one_arg("repr", heap)?;
     defer_drop!(value, heap);
-    let heap_id = heap.allocate(HeapData::Str(value.py_repr(heap, interns).into_owned().into()))?;
+    let repr = value.py_repr_checked(heap, interns)?.into_owned();
+    let heap_id = heap.allocate(HeapData::Str(repr.into()))?;
     Ok(Value::Ref(heap_id))
 }
@@ -1,12 +1,16 @@
 //! Implementation of the round() builtin function.
 
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+
 use crate::{
     args::ArgValues,
     defer_drop,
     exception_private::{ExcType, RunResult, SimpleException},
     heap::Heap,
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{LongInt, PyTrait},
     value::Value,
 };
 
@@ -52,13 +56,10 @@ pub fn builtin_round(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) ->
                     // Positive or zero digits: return the integer unchanged
                     Ok(Value::Int(*n))
                 } else {
-                    // Negative digits: round to tens, hundreds, etc. using banker's rounding
-                    // -d is positive since d < 0; use try_from to safely convert
-                    let exp = u32::try_from(-d).unwrap_or(u32::MAX);
-                    let factor = 10_i64.saturating_pow(exp);
-                    let rounded_f = bankers_round(*n as f64 / factor as f64);
-                    let rounded = f64_to_i64(rounded_f) * factor;
-                    Ok(Value::Int(rounded))
+                    // Negative digits: round to tens, hundreds, etc. using banker's rounding.
+                    // The result can exceed i64, e.g. `round(2**63 - 1, -1)`, so promote to LongInt
+                    let rounded = round_int_to_digits(*n, d.unsigned_abs());
+                    Ok(LongInt::new(BigInt::from(rounded)).into_value(heap)?)
                 }
             } else {
                 // No digits specified: return the integer unchanged
@@ -94,6 +95,27 @@ pub fn builtin_round(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) ->
     }
 }
 
+/// Rounds an integer to `10**exp` using banker's rounding, for `round(n, -exp)`.
+///
+/// Works on exact integers rather than floats, so large values keep every digit. For
+/// `exp` above 38 the factor exceeds any i64 by far and the result is always 0.
+fn round_int_to_digits(n: i64, exp: u64) -> i128 {
+    let Some(factor) = u32::try_from(exp).ok().and_then(|exp| 10_i128.checked_pow(exp)) else {
+        return 0;
+    };
+    let n = i128::from(n);
+    let quotient = n.div_euclid(factor);
+    let remainder = n.rem_euclid(factor);
+    // Compare `remainder` with `factor / 2` without doubling, which could overflow
+    let rounded = match remainder.cmp(&(factor - remainder)) {
+        Ordering::Less => quotient,
+        Ordering::Greater => quotient + 1,
+        Ordering::Equal if quotient % 2 == 0 => quotient,
+        Ordering::Equal => quotient + 1,
+    };
+    rounded * factor
+}
+
 /// Implements banker's rounding (round half to even).
 ///
 /// This is the rounding mode used by Python's `round()` function.
@@ -0,0 +1,39 @@
+//! Implementation of the setattr() builtin function.
+
+use super::getattr::attr_name_arg;
+use crate::{
+    ExcType,
+    args::ArgValues,
+    defer_drop,
+    exception_private::RunResult,
+    heap::Heap,
+    intern::Interns,
+    resource::ResourceTracker,
+    value::Value,
+};
+
+/// Implementation of the setattr() builtin function.
+///
+/// Assigns `value` to the named attribute of `object`, equivalent to `object.name = value`.
+/// Routes through the same `py_set_attr` path as attribute assignment, so frozen
+/// dataclasses raise `FrozenInstanceError` and types without attribute storage raise
+/// `AttributeError`.
+///
+/// Examples:
+/// ```python
+/// setattr(point, 'x', 10)   # Same as point.x = 10
+/// ```
+pub fn builtin_setattr(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let positional = args.into_pos_only("setattr", heap)?;
+    defer_drop!(positional, heap);
+
+    let [object, name, value] = positional.as_slice() else {
+        return Err(ExcType::type_error_arg_count("setattr", 3, positional.len()));
+    };
+
+    let attr = attr_name_arg(name, heap)?;
+    // py_set_attr takes ownership of the value, so hand it a new reference
+    let value = value.clone_with_heap(heap);
+    object.py_set_attr(&attr, value, heap, interns)?;
+    Ok(Value::None)
+}
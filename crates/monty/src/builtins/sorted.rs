@@ -1,17 +1,15 @@
 //! Implementation of the sorted() builtin function.
 
-use itertools::Itertools;
-
 use crate::{
     args::ArgValues,
     bytecode::VM,
     defer_drop, defer_drop_mut,
     exception_private::{ExcType, RunResult, SimpleException},
-    heap::{DropWithHeap, Heap, HeapData, HeapGuard},
+    heap::{DropWithHeap, Heap, HeapGuard},
     intern::Interns,
     resource::ResourceTracker,
-    sorting::{apply_permutation, sort_indices},
-    types::{List, MontyIter, PyTrait},
+    sorting::{PendingSort, sort_keys},
+    types::{AttrCallResult, MontyIter, PyTrait},
     value::Value,
 };
 
@@ -20,7 +18,10 @@ use crate::{
 /// Returns a new sorted list from the items in an iterable.
 /// Supports `key` and `reverse` keyword arguments matching Python's
 /// `sorted(iterable, /, *, key=None, reverse=False)` signature.
-pub fn builtin_sorted(vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+///
+/// The sort itself is run by the VM, since it may suspend to let the host
+/// compare dataclasses (see `VM::sort`).
+pub fn builtin_sorted(vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
     let (iterable, key_fn, reverse) = parse_sorted_args(args, vm.heap, vm.interns)?;
     defer_drop!(key_fn, vm);
 
@@ -28,40 +29,14 @@ pub fn builtin_sorted(vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> Run
     let mut items_guard = HeapGuard::new(items, vm);
     let (items, vm) = items_guard.as_parts_mut();
 
-    {
-        // Compute key values if a key function was provided, otherwise we'll sort by the items themselves
-        let mut keys_guard;
-        let (compare_values, vm) = if let Some(f) = key_fn {
-            let keys: Vec<Value> = Vec::with_capacity(items.len());
-            // Use a HeapGuard to ensure that if key function evaluation fails partway through,
-            // we clean up any keys that were successfully computed
-            keys_guard = HeapGuard::new(keys, vm);
-            let (keys, vm) = keys_guard.as_parts_mut();
-            items
-                .iter()
-                .map(|item| {
-                    let item = item.clone_with_heap(vm.heap);
-                    vm.evaluate_function("sorted() key argument", f, ArgValues::One(item))
-                })
-                .process_results(|keys_iter| keys.extend(keys_iter))?;
-            keys_guard.as_parts()
-        } else {
-            (&*items, vm)
-        };
-
-        // Sort indices by comparing key values (or items themselves if no key)
-        let len = compare_values.len();
-        let mut indices: Vec<usize> = (0..len).collect();
-
-        sort_indices(&mut indices, compare_values, reverse, vm.heap, vm.interns)?;
-
-        // Rearrange items in-place according to the sorted permutation
-        apply_permutation(items, &mut indices);
-    }
+    // Compute key values if a key function was provided, otherwise we'll sort by the items themselves
+    let keys = match key_fn {
+        Some(f) => Some(sort_keys(items, f, vm)?),
+        None => None,
+    };
 
     let (items, vm) = items_guard.into_parts();
-    let heap_id = vm.heap.allocate(HeapData::List(List::new(items)))?;
-    Ok(Value::Ref(heap_id))
+    vm.sort(PendingSort::new(items, keys, reverse, None))
 }
 
 /// Parses the arguments for `sorted(iterable, /, *, key=None, reverse=False)`.
@@ -0,0 +1,38 @@
+//! Implementation of the vars() builtin function.
+
+use super::getattr::is_attribute_error;
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap},
+    intern::{Interns, StaticStrings},
+    resource::ResourceTracker,
+    types::AttrCallResult,
+    value::{EitherStr, Value},
+};
+
+/// Implementation of the vars() builtin function.
+///
+/// Returns `obj.__dict__`, looked up through the normal attribute machinery. For
+/// dataclass instances this is a dict of attribute names to values; it is a shallow
+/// copy (see `Dataclass::vars_dict`), so adding or removing keys doesn't affect the
+/// instance.
+///
+/// Values without a `__dict__` raise `TypeError`, matching CPython. The zero-argument
+/// form (equivalent to `locals()`) is not supported.
+pub fn builtin_vars(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+    let object = args.get_one_arg("vars", heap)?;
+    defer_drop!(object, heap);
+
+    let attr = EitherStr::from(StaticStrings::DunderDict);
+    match object.py_getattr(&attr, heap, interns) {
+        Ok(AttrCallResult::Value(value)) => Ok(value),
+        Ok(other) => {
+            other.drop_with_heap(heap);
+            Err(ExcType::type_error_vars_no_dict())
+        }
+        Err(e) if is_attribute_error(&e) => Err(ExcType::type_error_vars_no_dict()),
+        Err(e) => Err(e),
+    }
+}
@@ -141,10 +141,24 @@ impl CodeBuilder {
         self.track_stack_effect_u16(op, operand);
     }
 
+    /// Emits an instruction with two u16 operands.
+    ///
+    /// Used for BuildListConst/BuildTupleConst: const_id (u16) + count (u16)
+    pub fn emit_u16_u16(&mut self, op: Opcode, operand1: u16, operand2: u16) {
+        self.record_location();
+        self.bytecode.push(op as u8);
+        self.bytecode.extend_from_slice(&operand1.to_le_bytes());
+        self.bytecode.extend_from_slice(&operand2.to_le_bytes());
+        if let Some(effect) = op.stack_effect() {
+            self.adjust_stack(effect);
+        }
+    }
+
     /// Emits an instruction with a u16 operand followed by a u8 operand.
     ///
     /// Used for MakeFunction: func_id (u16) + defaults_count (u8)
     /// Used for CallAttr: attr_name_id (u16) + arg_count (u8)
+    /// Used for BuildClass: name_id (u16) + method_count (u8)
     pub fn emit_u16_u8(&mut self, op: Opcode, operand1: u16, operand2: u8) {
         self.record_location();
         self.bytecode.push(op as u8);
@@ -160,6 +174,10 @@ impl CodeBuilder {
                 // pops obj + args, pushes result: 1 - (1 + arg_count) = -arg_count
                 self.adjust_stack(-i16::from(operand2));
             }
+            Opcode::BuildClass => {
+                // pops the base and method_count name/function pairs, pushes class: -2 * method_count
+                self.adjust_stack(-2 * i16::from(operand2));
+            }
             _ => {
                 if let Some(effect) = op.stack_effect() {
                     self.adjust_stack(effect);
@@ -414,6 +432,12 @@ impl CodeBuilder {
         idx_u16
     }
 
+    /// Returns the number of constants added so far.
+    #[must_use]
+    pub fn const_count(&self) -> usize {
+        self.constants.len()
+    }
+
     /// Adds an exception handler entry.
     ///
     /// Entries should be added in innermost-first order for nested try blocks.
@@ -189,6 +189,17 @@ impl ConstPool {
     pub fn get(&self, index: u16) -> &Value {
         &self.values[index as usize]
     }
+
+    /// Returns `count` consecutive constants starting at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, see `get`.
+    #[must_use]
+    pub fn range(&self, start: u16, count: u16) -> &[Value] {
+        let start = start as usize;
+        &self.values[start..start + count as usize]
+    }
 }
 
 /// Source location for a bytecode instruction, used for tracebacks.
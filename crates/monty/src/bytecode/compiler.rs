@@ -28,7 +28,8 @@ use crate::{
     function::Function,
     intern::{Interns, StringId},
     modules::BuiltinModule,
-    parse::{CodeRange, ExceptHandler, Try},
+    parse::{ClassDef, CodeRange, ExceptHandler, MethodDef, MethodKind, Try},
+    types::Type,
     value::{EitherStr, Value},
 };
 
@@ -39,6 +40,12 @@ use crate::{
 /// such limit but we need one for our bytecode encoding.
 const MAX_CALL_ARGS: usize = 255;
 
+/// Minimum number of elements for a list or tuple display of literals to be built
+/// from the constant pool with `BuildListConst`/`BuildTupleConst`.
+///
+/// Shorter displays are cheaper to build on the stack than to copy into the pool.
+const MIN_CONST_DISPLAY_LEN: usize = 8;
+
 /// Compiles prepared AST nodes to bytecode.
 ///
 /// The compiler traverses the AST and emits bytecode instructions using
@@ -98,6 +105,8 @@ pub struct Compiler<'a> {
 ///   to jump past the loop's else block
 /// - `has_iterator_on_stack`: whether this loop has an iterator on the stack that
 ///   needs to be popped on break (true for `for` loops, false for `while` loops)
+/// - `except_handler_depth`: how many except handlers were already active when the
+///   loop started, so break/continue only unwind handlers entered inside the loop
 struct LoopInfo {
     /// Bytecode offset of loop start (for continue).
     start: usize,
@@ -106,6 +115,8 @@ struct LoopInfo {
     /// Whether this loop has an iterator on the stack.
     /// True for `for` loops, false for `while` loops.
     has_iterator_on_stack: bool,
+    /// Value of `Compiler::except_handler_depth` when the loop was entered.
+    except_handler_depth: usize,
 }
 
 /// A break or continue that needs to go through a finally block.
@@ -325,6 +336,17 @@ impl<'a> Compiler<'a> {
                 self.code.set_location(*target_position, None);
                 self.code.emit(Opcode::StoreSubscr);
             }
+            Node::DeleteSubscript {
+                target,
+                index,
+                target_position,
+            } => {
+                // Stack order for DeleteSubscr: obj, index
+                self.compile_name(target);
+                self.compile_expr(index)?;
+                self.code.set_location(*target_position, None);
+                self.code.emit(Opcode::DeleteSubscr);
+            }
             Node::AttrAssign {
                 object,
                 attr,
@@ -351,15 +373,21 @@ impl<'a> Compiler<'a> {
             } => self.compile_for(target, iter, body, or_else)?,
             Node::While { test, body, or_else } => self.compile_while(test, body, or_else)?,
             Node::Assert { test, msg } => self.compile_assert(test, msg.as_ref())?,
-            Node::Raise(expr) => {
-                if let Some(exc) = expr {
+            Node::Raise { exc, cause } => match (exc, cause) {
+                (Some(exc), Some(cause)) => {
+                    self.compile_expr(exc)?;
+                    self.compile_expr(cause)?;
+                    self.code.emit(Opcode::RaiseFrom);
+                }
+                (Some(exc), None) => {
                     self.compile_expr(exc)?;
                     self.code.emit(Opcode::Raise);
-                } else {
-                    self.code.emit(Opcode::Reraise);
                 }
-            }
+                // the parser rejects `raise from cause` without an exception
+                (None, _) => self.code.emit(Opcode::Reraise),
+            },
             Node::FunctionDef(func_def) => self.compile_function_def(func_def)?,
+            Node::ClassDef(class_def) => self.compile_class_def(class_def)?,
             Node::Try(try_block) => self.compile_try(try_block)?,
             Node::Import { module_name, binding } => self.compile_import(*module_name, binding),
             Node::ImportFrom {
@@ -457,6 +485,51 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Compiles a class definition.
+    ///
+    /// Pushes the base class (or `None`) and a `(name, function)` pair for each method, then
+    /// `BuildClass` collects them into the class object, which is stored to its name slot.
+    fn compile_class_def(&mut self, class_def: &ClassDef<PreparedFunctionDef>) -> Result<(), CompileError> {
+        if class_def.methods.len() > MAX_CALL_ARGS {
+            return Err(CompileError::new(
+                format!("more than {MAX_CALL_ARGS} methods in a class"),
+                class_def.name.position,
+            ));
+        }
+        match &class_def.base {
+            Some(base) => self.compile_expr(base)?,
+            None => self.code.emit(Opcode::LoadNone),
+        }
+        for MethodDef { kind, func } in &class_def.methods {
+            let name_const = self.code.add_const(Value::InternString(func.name.name_id));
+            self.code.emit_u16(Opcode::LoadConst, name_const);
+            let decorator = match kind {
+                MethodKind::Instance => None,
+                MethodKind::Property => Some(Type::Property),
+                MethodKind::Static => Some(Type::StaticMethod),
+                MethodKind::Class => Some(Type::ClassMethod),
+            };
+            if let Some(decorator) = decorator {
+                // `@decorator def f` is compiled as `f = decorator(f)`
+                let decorator = self.code.add_const(Value::Builtin(Builtins::Type(decorator)));
+                self.code.emit_u16(Opcode::LoadConst, decorator);
+                self.compile_lambda(func)?;
+                self.code.set_location(func.name.position, None);
+                self.code.emit_u8(Opcode::CallFunction, 1);
+            } else {
+                self.compile_lambda(func)?;
+            }
+        }
+        let method_count = u8::try_from(class_def.methods.len()).expect("class method count exceeds u8");
+        self.code.emit_u16_u8(
+            Opcode::BuildClass,
+            u16::try_from(class_def.name.name_id.index()).expect("name index exceeds u16"),
+            method_count,
+        );
+        self.compile_store(&class_def.name);
+        Ok(())
+    }
+
     /// Compiles a lambda expression.
     ///
     /// This is similar to `compile_function_def` but:
@@ -636,6 +709,10 @@ impl<'a> Compiler<'a> {
                 self.compile_chain_comparison(left, comparisons, expr_loc.position)?;
             }
 
+            Expr::BoolOp { op, values } => {
+                self.compile_bool_op(op, values)?;
+            }
+
             Expr::Not(operand) => {
                 self.compile_expr(operand)?;
                 // Restore the full expression's position for traceback caret range
@@ -665,23 +742,27 @@ impl<'a> Compiler<'a> {
             }
 
             Expr::List(elements) => {
-                for elem in elements {
-                    self.compile_expr(elem)?;
+                let count = u16::try_from(elements.len()).expect("elements count exceeds u16");
+                if let Some(start) = self.add_const_display(elements) {
+                    self.code.emit_u16_u16(Opcode::BuildListConst, start, count);
+                } else {
+                    for elem in elements {
+                        self.compile_expr(elem)?;
+                    }
+                    self.code.emit_u16(Opcode::BuildList, count);
                 }
-                self.code.emit_u16(
-                    Opcode::BuildList,
-                    u16::try_from(elements.len()).expect("elements count exceeds u16"),
-                );
             }
 
             Expr::Tuple(elements) => {
-                for elem in elements {
-                    self.compile_expr(elem)?;
+                let count = u16::try_from(elements.len()).expect("elements count exceeds u16");
+                if let Some(start) = self.add_const_display(elements) {
+                    self.code.emit_u16_u16(Opcode::BuildTupleConst, start, count);
+                } else {
+                    for elem in elements {
+                        self.compile_expr(elem)?;
+                    }
+                    self.code.emit_u16(Opcode::BuildTuple, count);
                 }
-                self.code.emit_u16(
-                    Opcode::BuildTuple,
-                    u16::try_from(elements.len()).expect("elements count exceeds u16"),
-                );
             }
 
             Expr::Dict(pairs) => {
@@ -853,6 +934,31 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Adds the elements of a list or tuple display to the constant pool if they are
+    /// all literals, returning the index of the first one.
+    ///
+    /// The elements are added contiguously so `BuildListConst`/`BuildTupleConst` can copy
+    /// them in one pass. Returns `None` for short displays, displays containing long
+    /// ints (which `LoadConst` converts to heap values), or when the pool would overflow.
+    fn add_const_display(&mut self, elements: &[ExprLoc]) -> Option<u16> {
+        if elements.len() < MIN_CONST_DISPLAY_LEN
+            || self.code.const_count() + elements.len() > usize::from(u16::MAX)
+            || !elements
+                .iter()
+                .all(|elem| matches!(elem.expr, Expr::Literal(literal) if !matches!(literal, Literal::LongInt(_))))
+        {
+            return None;
+        }
+        let mut start = None;
+        for elem in elements {
+            if let Expr::Literal(literal) = elem.expr {
+                let idx = self.code.add_const(Value::from(literal));
+                start.get_or_insert(idx);
+            }
+        }
+        start
+    }
+
     // ========================================================================
     // Variable Operations
     // ========================================================================
@@ -929,31 +1035,34 @@ impl<'a> Compiler<'a> {
         right: &ExprLoc,
         parent_pos: CodeRange,
     ) -> Result<(), CompileError> {
-        match op {
-            // Short-circuit AND: evaluate left, jump if falsy
-            Operator::And => {
-                self.compile_expr(left)?;
-                let end_jump = self.code.emit_jump(Opcode::JumpIfFalseOrPop);
-                self.compile_expr(right)?;
-                self.code.patch_jump(end_jump);
-            }
-
-            // Short-circuit OR: evaluate left, jump if truthy
-            Operator::Or => {
-                self.compile_expr(left)?;
-                let end_jump = self.code.emit_jump(Opcode::JumpIfTrueOrPop);
-                self.compile_expr(right)?;
-                self.code.patch_jump(end_jump);
-            }
+        self.compile_expr(left)?;
+        self.compile_expr(right)?;
+        // Restore the full expression's position for traceback caret range
+        self.code.set_location(parent_pos, None);
+        self.code.emit(operator_to_opcode(op));
+        Ok(())
+    }
 
-            // Regular binary operators
-            _ => {
-                self.compile_expr(left)?;
-                self.compile_expr(right)?;
-                // Restore the full expression's position for traceback caret range
-                self.code.set_location(parent_pos, None);
-                self.code.emit(operator_to_opcode(op));
-            }
+    /// Compiles a boolean operation chain like `a and b and c`.
+    ///
+    /// Each operand but the last is followed by a jump to the end if it short-circuits
+    /// the chain (falsy for `and`, truthy for `or`), leaving it as the result. Otherwise
+    /// it's popped and the next operand evaluated.
+    fn compile_bool_op(&mut self, op: &Operator, values: &[ExprLoc]) -> Result<(), CompileError> {
+        let jump_opcode = match op {
+            Operator::And => Opcode::JumpIfFalseOrPop,
+            Operator::Or => Opcode::JumpIfTrueOrPop,
+            _ => unreachable!("boolean operation with non-boolean operator {op:?}"),
+        };
+        let (last, rest) = values.split_last().expect("boolean operation has operands");
+        let mut end_jumps = Vec::with_capacity(rest.len());
+        for value in rest {
+            self.compile_expr(value)?;
+            end_jumps.push(self.code.emit_jump(jump_opcode));
+        }
+        self.compile_expr(last)?;
+        for jump in end_jumps {
+            self.code.patch_jump(jump);
         }
         Ok(())
     }
@@ -1785,6 +1894,7 @@ impl<'a> Compiler<'a> {
             start: loop_start,
             break_jumps: Vec::new(),
             has_iterator_on_stack: true,
+            except_handler_depth: self.except_handler_depth,
         });
 
         // ForIter: advance iterator or jump to end
@@ -1849,6 +1959,7 @@ impl<'a> Compiler<'a> {
             start: loop_start,
             break_jumps: Vec::new(),
             has_iterator_on_stack: false,
+            except_handler_depth: self.except_handler_depth,
         });
 
         self.compile_expr(test)?;
@@ -1893,10 +2004,13 @@ impl<'a> Compiler<'a> {
 
         let target_loop_depth = self.loop_stack.len() - 1;
 
-        // If inside except handlers, clean up ALL exception states
-        // Each nested except handler has pushed an exception onto the stack,
-        // so we need to clear/pop each one when breaking out
-        for _ in 0..self.except_handler_depth {
+        // If inside except handlers, clean up their exception states.
+        // Each except handler entered inside the loop has pushed an exception onto
+        // the stack, so we need to clear/pop each one when breaking out. Handlers that
+        // enclose the whole loop stay active.
+        let handlers_to_exit = self.except_handler_depth - self.loop_stack[target_loop_depth].except_handler_depth;
+        let stack_depth = self.code.stack_depth();
+        for _ in 0..handlers_to_exit {
             self.code.emit(Opcode::ClearException);
             self.code.emit(Opcode::Pop); // Pop the exception value
         }
@@ -1920,10 +2034,9 @@ impl<'a> Compiler<'a> {
                 jump,
                 target_loop_depth,
             });
-            // Set stack depth for unreachable cleanup code (see comment below)
-            if self.except_handler_depth > 0 {
-                self.code
-                    .set_stack_depth(u16::try_from(self.except_handler_depth).unwrap_or(u16::MAX));
+            // Restore stack depth for unreachable cleanup code (see comment below)
+            if handlers_to_exit > 0 {
+                self.code.set_stack_depth(stack_depth);
             }
             return Ok(());
         }
@@ -1934,10 +2047,10 @@ impl<'a> Compiler<'a> {
 
         // The code following this break is unreachable at runtime, but the compiler
         // will still emit cleanup code for each enclosing except handler (ClearException + Pop).
-        // Set stack depth so those unreachable pops don't cause negative stack tracking.
-        if self.except_handler_depth > 0 {
-            self.code
-                .set_stack_depth(u16::try_from(self.except_handler_depth).unwrap_or(u16::MAX));
+        // Restore the depth from before this statement's own cleanup so those unreachable
+        // pops are tracked against the stack they were compiled for.
+        if handlers_to_exit > 0 {
+            self.code.set_stack_depth(stack_depth);
         }
 
         Ok(())
@@ -1955,10 +2068,13 @@ impl<'a> Compiler<'a> {
 
         let target_loop_depth = self.loop_stack.len() - 1;
 
-        // If inside except handlers, clean up ALL exception states
-        // Each nested except handler has pushed an exception onto the stack,
-        // so we need to clear/pop each one when continuing
-        for _ in 0..self.except_handler_depth {
+        // If inside except handlers, clean up their exception states.
+        // Each except handler entered inside the loop has pushed an exception onto
+        // the stack, so we need to clear/pop each one when continuing. Handlers that
+        // enclose the whole loop stay active.
+        let handlers_to_exit = self.except_handler_depth - self.loop_stack[target_loop_depth].except_handler_depth;
+        let stack_depth = self.code.stack_depth();
+        for _ in 0..handlers_to_exit {
             self.code.emit(Opcode::ClearException);
             self.code.emit(Opcode::Pop); // Pop the exception value
         }
@@ -1975,10 +2091,9 @@ impl<'a> Compiler<'a> {
                 jump,
                 target_loop_depth,
             });
-            // Set stack depth for unreachable cleanup code (see comment below)
-            if self.except_handler_depth > 0 {
-                self.code
-                    .set_stack_depth(u16::try_from(self.except_handler_depth).unwrap_or(u16::MAX));
+            // Restore stack depth for unreachable cleanup code (see comment below)
+            if handlers_to_exit > 0 {
+                self.code.set_stack_depth(stack_depth);
             }
             return Ok(());
         }
@@ -1989,10 +2104,10 @@ impl<'a> Compiler<'a> {
 
         // The code following this continue is unreachable at runtime, but the compiler
         // will still emit cleanup code for each enclosing except handler (ClearException + Pop).
-        // Set stack depth so those unreachable pops don't cause negative stack tracking.
-        if self.except_handler_depth > 0 {
-            self.code
-                .set_stack_depth(u16::try_from(self.except_handler_depth).unwrap_or(u16::MAX));
+        // Restore the depth from before this statement's own cleanup so those unreachable
+        // pops are tracked against the stack they were compiled for.
+        if handlers_to_exit > 0 {
+            self.code.set_stack_depth(stack_depth);
         }
 
         Ok(())
@@ -2227,6 +2342,13 @@ impl<'a> Compiler<'a> {
 
     /// Compiles an assert statement.
     fn compile_assert(&mut self, test: &ExprLoc, msg: Option<&ExprLoc>) -> Result<(), CompileError> {
+        if msg.is_none()
+            && let Expr::CmpOp { left, op, right } = &test.expr
+            && !matches!(op, CmpOperator::ModEq(_))
+        {
+            return self.compile_assert_compare(test.position, left, op, right);
+        }
+
         // Compile test
         self.compile_expr(test)?;
         // Jump over raise if truthy
@@ -2252,6 +2374,42 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Compiles an assert statement without a message whose test is a single comparison.
+    ///
+    /// Both operands are kept on the stack so a failed assertion can show their reprs,
+    /// e.g. `AssertionError('assert 1 == 2')`, without evaluating them twice.
+    fn compile_assert_compare(
+        &mut self,
+        position: CodeRange,
+        left: &ExprLoc,
+        op: &CmpOperator,
+        right: &ExprLoc,
+    ) -> Result<(), CompileError> {
+        self.compile_expr(left)?;
+        self.compile_expr(right)?;
+        // Duplicate the operands: [l, r] -> [l, r, l, r]
+        self.code.emit(Opcode::Rot2); // [r, l]
+        self.code.emit(Opcode::Dup); // [r, l, l]
+        self.code.emit(Opcode::Rot3); // [l, r, l]
+        self.code.emit(Opcode::Rot3); // [l, l, r]
+        self.code.emit(Opcode::Dup); // [l, l, r, r]
+        self.code.emit(Opcode::Rot3); // [l, r, l, r]
+
+        self.code.set_location(position, None);
+        let compare = cmp_operator_to_opcode(op);
+        self.code.emit(compare);
+        let skip_jump = self.code.emit_jump(Opcode::JumpIfTrue);
+        let stack_depth = self.code.stack_depth();
+        self.code.emit_u8(Opcode::RaiseAssertCompare, compare as u8);
+
+        // The assertion passed, discard the operands
+        self.code.patch_jump(skip_jump);
+        self.code.set_stack_depth(stack_depth);
+        self.code.emit(Opcode::Pop);
+        self.code.emit(Opcode::Pop);
+        Ok(())
+    }
+
     /// Compiles f-string parts, returning the number of string parts to concatenate.
     ///
     /// Each part is compiled to leave a string value on the stack:
@@ -2284,8 +2442,12 @@ impl<'a> Compiler<'a> {
                     // Compile the expression
                     self.compile_expr(expr)?;
 
-                    // For debug expressions without explicit conversion, Python uses repr by default
-                    let effective_conversion = if debug_prefix.is_some() && matches!(conversion, ConversionFlag::None) {
+                    // For debug expressions without explicit conversion or format spec, Python uses repr
+                    // by default; with a spec the value is formatted directly, as in `f'{x=:.2f}'`
+                    let effective_conversion = if debug_prefix.is_some()
+                        && format_spec.is_none()
+                        && matches!(conversion, ConversionFlag::None)
+                    {
                         ConversionFlag::Repr
                     } else {
                         *conversion
@@ -2841,9 +3003,9 @@ fn operator_to_opcode(op: &Operator) -> Opcode {
         Operator::BitOr => Opcode::BinaryOr,
         Operator::BitXor => Opcode::BinaryXor,
         Operator::BitAnd => Opcode::BinaryAnd,
-        // And/Or are handled separately for short-circuit evaluation
+        // And/Or are parsed into `Expr::BoolOp` for short-circuit evaluation
         Operator::And | Operator::Or => {
-            unreachable!("And/Or operators handled in compile_binary_op")
+            unreachable!("And/Or operators handled in compile_bool_op")
         }
     }
 }
@@ -2851,7 +3013,7 @@ fn operator_to_opcode(op: &Operator) -> Opcode {
 /// Maps an `Operator` to its in-place (augmented assignment) `Opcode`.
 ///
 /// Returns `None` for operators that don't have an in-place opcode (currently `MatMult`,
-/// since matrix multiplication is not yet supported). Returns `Some(opcode)` for all
+/// since augmented matrix multiplication is not yet supported). Returns `Some(opcode)` for all
 /// other valid augmented assignment operators.
 ///
 /// # Panics
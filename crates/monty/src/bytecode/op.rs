@@ -168,6 +168,13 @@ pub enum Opcode {
     BuildList,
     /// Pop n items, build tuple. Operand: u16 count.
     BuildTuple,
+    /// Build list from n consecutive constants. Operands: u16 const_id + u16 count.
+    ///
+    /// Emitted for list displays whose elements are all immediate literals, so the
+    /// elements are copied straight into the new list without going through the stack.
+    BuildListConst,
+    /// Build tuple from n consecutive constants. Operands: u16 const_id + u16 count.
+    BuildTupleConst,
     /// Pop 2n items (k/v pairs), build dict. Operand: u16 count.
     BuildDict,
     /// Pop n items, build set. Operand: u16 count.
@@ -231,7 +238,8 @@ pub enum Opcode {
     BinarySubscr,
     /// a[b] = c: pop value, pop index, pop obj.
     StoreSubscr,
-    // NOTE: DeleteSubscr removed - `del` statement not supported by parser
+    /// del a[b]: pop index, pop obj.
+    DeleteSubscr,
     /// Pop obj, push obj.attr. Operand: u16 name_id.
     LoadAttr,
     /// Pop module, push module.attr for `from ... import`. Operand: u16 name_id.
@@ -327,12 +335,19 @@ pub enum Opcode {
     MakeFunction,
     /// Create closure. Operands: u16 func_id, u8 cell_count.
     MakeClosure,
+    /// Build class from TOS-2n..TOS (method name/function pairs) and the base class (or None) below
+    /// them. Operands: u16 name_id, u8 method_count.
+    BuildClass,
 
     // === Exception Handling ===
     // Note: No SetupTry/PopExceptHandler - we use static exception_table
     /// Raise TOS as exception.
     Raise,
-    // NOTE: RaiseFrom removed - `raise ... from ...` not supported by parser
+    /// Raise TOS1 as exception with TOS as its cause (`raise ... from ...`).
+    RaiseFrom,
+    /// Raise `AssertionError` for a failed `assert` comparison, showing TOS1 and TOS as its operands.
+    /// Operand: u8 comparison opcode, used to display the operator.
+    RaiseAssertCompare,
     /// Re-raise current exception (bare `raise`).
     Reraise,
     /// Clear current_exception when exiting except block.
@@ -406,18 +421,19 @@ impl Opcode {
     pub const fn stack_effect(self) -> Option<i16> {
         use Opcode::{
             Await, BinaryAdd, BinaryAnd, BinaryDiv, BinaryFloorDiv, BinaryLShift, BinaryMatMul, BinaryMod, BinaryMul,
-            BinaryOr, BinaryPow, BinaryRShift, BinarySub, BinarySubscr, BinaryXor, BuildDict, BuildFString, BuildList,
-            BuildSet, BuildSlice, BuildTuple, CallAttr, CallAttrExtended, CallAttrKw, CallBuiltinFunction,
-            CallBuiltinType, CallFunction, CallFunctionExtended, CallFunctionKw, CheckExcMatch, ClearException,
-            CompareEq, CompareGe, CompareGt, CompareIn, CompareIs, CompareIsNot, CompareLe, CompareLt, CompareModEq,
-            CompareNe, CompareNotIn, DeleteLocal, DictMerge, DictSetItem, Dup, ForIter, FormatValue, GetIter,
-            InplaceAdd, InplaceAnd, InplaceDiv, InplaceFloorDiv, InplaceLShift, InplaceMod, InplaceMul, InplaceOr,
-            InplacePow, InplaceRShift, InplaceSub, InplaceXor, Jump, JumpIfFalse, JumpIfFalseOrPop, JumpIfTrue,
-            JumpIfTrueOrPop, ListAppend, ListExtend, ListToTuple, LoadAttr, LoadAttrImport, LoadCell, LoadConst,
-            LoadFalse, LoadGlobal, LoadLocal, LoadLocal0, LoadLocal1, LoadLocal2, LoadLocal3, LoadLocalW, LoadModule,
-            LoadNone, LoadSmallInt, LoadTrue, MakeClosure, MakeFunction, Nop, Pop, Raise, RaiseImportError, Reraise,
-            ReturnValue, Rot2, Rot3, SetAdd, StoreAttr, StoreCell, StoreGlobal, StoreLocal, StoreLocalW, StoreSubscr,
-            UnaryInvert, UnaryNeg, UnaryNot, UnaryPos, UnpackEx, UnpackSequence,
+            BinaryOr, BinaryPow, BinaryRShift, BinarySub, BinarySubscr, BinaryXor, BuildClass, BuildDict, BuildFString,
+            BuildList, BuildListConst, BuildSet, BuildSlice, BuildTuple, BuildTupleConst, CallAttr, CallAttrExtended,
+            CallAttrKw, CallBuiltinFunction, CallBuiltinType, CallFunction, CallFunctionExtended, CallFunctionKw,
+            CheckExcMatch, ClearException, CompareEq, CompareGe, CompareGt, CompareIn, CompareIs, CompareIsNot,
+            CompareLe, CompareLt, CompareModEq, CompareNe, CompareNotIn, DeleteLocal, DeleteSubscr, DictMerge,
+            DictSetItem, Dup, ForIter, FormatValue, GetIter, InplaceAdd, InplaceAnd, InplaceDiv, InplaceFloorDiv,
+            InplaceLShift, InplaceMod, InplaceMul, InplaceOr, InplacePow, InplaceRShift, InplaceSub, InplaceXor, Jump,
+            JumpIfFalse, JumpIfFalseOrPop, JumpIfTrue, JumpIfTrueOrPop, ListAppend, ListExtend, ListToTuple, LoadAttr,
+            LoadAttrImport, LoadCell, LoadConst, LoadFalse, LoadGlobal, LoadLocal, LoadLocal0, LoadLocal1, LoadLocal2,
+            LoadLocal3, LoadLocalW, LoadModule, LoadNone, LoadSmallInt, LoadTrue, MakeClosure, MakeFunction, Nop, Pop,
+            Raise, RaiseAssertCompare, RaiseFrom, RaiseImportError, Reraise, ReturnValue, Rot2, Rot3, SetAdd,
+            StoreAttr, StoreCell, StoreGlobal, StoreLocal, StoreLocalW, StoreSubscr, UnaryInvert, UnaryNeg, UnaryNot,
+            UnaryPos, UnpackEx, UnpackSequence,
         };
         Some(match self {
             // Stack operations
@@ -450,7 +466,9 @@ impl Opcode {
             | InplaceAnd | InplaceOr | InplaceXor | InplaceLShift | InplaceRShift => -1,
 
             // Collection building - depends on operand, return None
-            BuildList | BuildTuple | BuildDict | BuildSet | BuildFString => return None,
+            BuildList | BuildTuple | BuildDict | BuildSet | BuildFString | BuildClass => return None,
+            // BuildListConst/BuildTupleConst: read constants, push 1
+            BuildListConst | BuildTupleConst => 1,
             // FormatValue: pops 1 value (+ optional fmt_spec), pushes 1. Variable.
             FormatValue => return None,
             // BuildSlice: pop 3, push 1 = -2
@@ -469,6 +487,7 @@ impl Opcode {
             // Subscript & Attribute
             BinarySubscr => -1,             // pop 2, push 1
             StoreSubscr => -3,              // pop 3, push 0
+            DeleteSubscr => -2,             // pop 2, push 0
             LoadAttr | LoadAttrImport => 0, // pop 1, push 1
             StoreAttr => -2,                // pop 2, push 0
 
@@ -492,10 +511,12 @@ impl Opcode {
             MakeFunction | MakeClosure => 1,
 
             // Exception handling
-            Raise => -1,         // pop exception
-            Reraise => 0,        // no stack change (reads from exception_stack)
-            ClearException => 0, // clears exception_stack, no operand stack change
-            CheckExcMatch => 0,  // pop exc_type, push bool (net 0, but exc stays)
+            Raise => -1,              // pop exception
+            RaiseFrom => -2,          // pop exception and cause
+            RaiseAssertCompare => -2, // pop both comparison operands
+            Reraise => 0,             // no stack change (reads from exception_stack)
+            ClearException => 0,      // clears exception_stack, no operand stack change
+            CheckExcMatch => 0,       // pop exc_type, push bool (net 0, but exc stays)
 
             // Return
             ReturnValue => -1,
@@ -511,6 +532,27 @@ impl Opcode {
             RaiseImportError => 0, // raises exception, no stack change before that
         })
     }
+
+    /// Returns the Python operator of a comparison opcode, e.g. `"=="` for `CompareEq`.
+    ///
+    /// Returns `None` for other opcodes, including `CompareModEq` whose operands
+    /// aren't the operands of its operator.
+    #[must_use]
+    pub const fn compare_symbol(self) -> Option<&'static str> {
+        Some(match self {
+            Self::CompareEq => "==",
+            Self::CompareNe => "!=",
+            Self::CompareLt => "<",
+            Self::CompareLe => "<=",
+            Self::CompareGt => ">",
+            Self::CompareGe => ">=",
+            Self::CompareIs => "is",
+            Self::CompareIsNot => "is not",
+            Self::CompareIn => "in",
+            Self::CompareNotIn => "not in",
+            _ => return None,
+        })
+    }
 }
 
 /// Error returned when attempting to convert an invalid byte to an Opcode.
@@ -608,6 +608,8 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
                 namespace_idx: f.namespace_idx,
                 cells: f.cells,
                 call_position: f.call_position,
+                is_init: f.is_init,
+                negate_result: f.negate_result,
             })
             .collect();
         let stack = std::mem::take(&mut self.stack);
@@ -682,6 +684,8 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
                         cells: sf.cells,
                         call_position: sf.call_position,
                         should_return: false,
+                        is_init: sf.is_init,
+                        negate_result: sf.negate_result,
                     }
                 })
                 .collect();
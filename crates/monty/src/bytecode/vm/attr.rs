@@ -7,6 +7,7 @@ use crate::{
     exception_private::{ExcType, RunError},
     intern::StringId,
     resource::ResourceTracker,
+    types::AttrCallResult,
     value::EitherStr,
 };
 
@@ -21,8 +22,13 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         defer_drop!(obj, this);
 
         let attr = EitherStr::Interned(name_id);
-        let result = obj.py_getattr(&attr, this.heap, this.interns)?;
-        Ok(result.into())
+        match obj.py_getattr(&attr, this.heap, this.interns)? {
+            AttrCallResult::CallFunction(func, args) => {
+                defer_drop!(func, this);
+                this.call_function(func, args)
+            }
+            result => Ok(result.into()),
+        }
     }
 
     /// Loads an attribute from a module for `from ... import` and pushes it onto the stack.
@@ -59,6 +65,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
 
         let value = this.pop();
         // py_set_attr takes ownership of value and drops it on error
-        obj.py_set_attr(name_id, value, this.heap, this.interns)
+        let attr = EitherStr::Interned(name_id);
+        obj.py_set_attr(&attr, value, this.heap, this.interns)
     }
 }
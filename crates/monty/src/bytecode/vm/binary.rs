@@ -1,21 +1,48 @@
 //! Binary and in-place operation helpers for the VM.
 
-use super::VM;
+use super::{VM, call::CallResult};
 use crate::{
+    args::ArgValues,
     defer_drop,
     exception_private::{ExcType, RunError},
-    heap::HeapGuard,
+    heap::{HeapData, HeapGuard},
     resource::ResourceTracker,
     types::PyTrait,
-    value::BitwiseOp,
+    value::{BitwiseOp, EitherStr, Value},
 };
 
+/// Highest refcount at which `+=` may append to a `str` or `bytes` buffer in place.
+///
+/// One reference is the operand popped by `InplaceAdd`, the other is the assignment target
+/// it was loaded from; both are replaced by the result, so nothing else sees the mutation.
+const UNSHARED_INPLACE_REFCOUNT: usize = 2;
+
+/// The special methods implementing a binary operator for classes: the method called on the
+/// left operand and the reflected one called on the right operand, e.g. `__add__` and `__radd__`.
+#[derive(Debug, Clone, Copy)]
+struct BinarySpecialMethod {
+    method: &'static str,
+    reflected: &'static str,
+}
+
+impl BinarySpecialMethod {
+    const ADD: Self = Self::new("__add__", "__radd__");
+    const SUB: Self = Self::new("__sub__", "__rsub__");
+    const MUL: Self = Self::new("__mul__", "__rmul__");
+    const DIV: Self = Self::new("__truediv__", "__rtruediv__");
+    const MATMUL: Self = Self::new("__matmul__", "__rmatmul__");
+
+    const fn new(method: &'static str, reflected: &'static str) -> Self {
+        Self { method, reflected }
+    }
+}
+
 impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Binary addition with proper refcount handling.
     ///
     /// Uses lazy type capture: only calls `py_type()` in error paths to avoid
     /// overhead on the success path (99%+ of operations).
-    pub(super) fn binary_add(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_add(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -24,14 +51,12 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         defer_drop!(lhs, this);
 
         match lhs.py_add(rhs, this.heap, this.interns) {
-            Ok(Some(v)) => {
-                this.push(v);
-                Ok(())
-            }
+            Ok(Some(v)) => Ok(CallResult::Push(v)),
             Ok(None) => {
-                let lhs_type = lhs.py_type(this.heap);
-                let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("+", lhs_type, rhs_type))
+                if let Some(result) = this.binary_special_method(BinarySpecialMethod::ADD, lhs, rhs) {
+                    return result;
+                }
+                Err(this.binary_operator_error("+", lhs, rhs))
             }
             Err(e) => Err(e.into()),
         }
@@ -40,7 +65,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Binary subtraction with proper refcount handling.
     ///
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_sub(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_sub(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -49,14 +74,12 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         defer_drop!(lhs, this);
 
         match lhs.py_sub(rhs, this.heap) {
-            Ok(Some(v)) => {
-                this.push(v);
-                Ok(())
-            }
+            Ok(Some(v)) => Ok(CallResult::Push(v)),
             Ok(None) => {
-                let lhs_type = lhs.py_type(this.heap);
-                let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("-", lhs_type, rhs_type))
+                if let Some(result) = this.binary_special_method(BinarySpecialMethod::SUB, lhs, rhs) {
+                    return result;
+                }
+                Err(this.binary_operator_error("-", lhs, rhs))
             }
             Err(e) => Err(e.into()),
         }
@@ -65,7 +88,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Binary multiplication with proper refcount handling.
     ///
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_mult(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_mult(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -74,14 +97,12 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         defer_drop!(lhs, this);
 
         match lhs.py_mult(rhs, this.heap, this.interns) {
-            Ok(Some(v)) => {
-                this.push(v);
-                Ok(())
-            }
+            Ok(Some(v)) => Ok(CallResult::Push(v)),
             Ok(None) => {
-                let lhs_type = lhs.py_type(this.heap);
-                let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("*", lhs_type, rhs_type))
+                if let Some(result) = this.binary_special_method(BinarySpecialMethod::MUL, lhs, rhs) {
+                    return result;
+                }
+                Err(this.binary_operator_error("*", lhs, rhs))
             }
             Err(e) => Err(e),
         }
@@ -90,7 +111,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Binary division with proper refcount handling.
     ///
     /// Uses lazy type capture: only calls `py_type()` in error paths.
-    pub(super) fn binary_div(&mut self) -> Result<(), RunError> {
+    pub(super) fn binary_div(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -99,14 +120,12 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         defer_drop!(lhs, this);
 
         match lhs.py_div(rhs, this.heap, this.interns) {
-            Ok(Some(v)) => {
-                this.push(v);
-                Ok(())
-            }
+            Ok(Some(v)) => Ok(CallResult::Push(v)),
             Ok(None) => {
-                let lhs_type = lhs.py_type(this.heap);
-                let rhs_type = rhs.py_type(this.heap);
-                Err(ExcType::binary_type_error("/", lhs_type, rhs_type))
+                if let Some(result) = this.binary_special_method(BinarySpecialMethod::DIV, lhs, rhs) {
+                    return result;
+                }
+                Err(this.binary_operator_error("/", lhs, rhs))
             }
             Err(e) => Err(e),
         }
@@ -209,11 +228,16 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// For mutable types like lists, `py_iadd` mutates in place and returns true.
     /// For immutable types, we fall back to regular addition.
     ///
+    /// `str` and `bytes` are immutable, so they are only extended in place while no other
+    /// reference can observe the change: at most the popped `lhs` and the augmented
+    /// assignment target, which is about to be overwritten with the result. This turns
+    /// `s += 'x'` in a loop into an amortised append instead of a copy per iteration.
+    ///
     /// Uses lazy type capture: only calls `py_type()` in error paths.
     ///
     /// Note: Cannot use `defer_drop!` for `lhs` here because on successful in-place
-    /// operation, we need to push `lhs` back onto the stack rather than drop it.
-    pub(super) fn inplace_add(&mut self) -> Result<(), RunError> {
+    /// operation, `lhs` is returned to be pushed back onto the stack rather than dropped.
+    pub(super) fn inplace_add(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -222,34 +246,92 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let mut lhs_guard = HeapGuard::new(this.pop(), this);
         let (lhs, this) = lhs_guard.as_parts_mut();
 
-        // Try in-place operation first (for mutable types like lists)
-        if lhs.py_iadd(rhs.clone_with_heap(this.heap), this.heap, lhs.ref_id(), this.interns)? {
+        // Try in-place operation first (for mutable types like lists, and unshared strings)
+        let can_mutate = match lhs {
+            Value::Ref(id) => {
+                !matches!(this.heap.get(*id), HeapData::Str(_) | HeapData::Bytes(_))
+                    || this.heap.get_refcount(*id) <= UNSHARED_INPLACE_REFCOUNT
+            }
+            _ => true,
+        };
+        if can_mutate && lhs.py_iadd(rhs.clone_with_heap(this.heap), this.heap, lhs.ref_id(), this.interns)? {
             // In-place operation succeeded - push lhs back
-            let (lhs, this) = lhs_guard.into_parts();
-            this.push(lhs);
-            return Ok(());
+            let (lhs, _) = lhs_guard.into_parts();
+            return Ok(CallResult::Push(lhs));
         }
 
-        // Next try regular addition
+        // Next try regular addition, then `__add__`/`__radd__` on instances and dataclasses
         if let Some(v) = lhs.py_add(rhs, this.heap, this.interns)? {
-            this.push(v);
-            return Ok(());
+            return Ok(CallResult::Push(v));
+        }
+        if let Some(result) = this.binary_special_method(BinarySpecialMethod::ADD, lhs, rhs) {
+            return result;
         }
 
-        let lhs_type = lhs.py_type(this.heap);
-        let rhs_type = rhs.py_type(this.heap);
-        Err(ExcType::binary_type_error("+=", lhs_type, rhs_type))
+        Err(this.binary_operator_error("+=", lhs, rhs))
     }
 
     /// Binary matrix multiplication (`@` operator).
     ///
-    /// Currently not implemented - returns a `NotImplementedError`.
-    /// Matrix multiplication requires numpy-like array types which Monty doesn't support.
-    pub(super) fn binary_matmul(&mut self) -> Result<(), RunError> {
-        let rhs = self.pop();
-        let lhs = self.pop();
-        lhs.drop_with_heap(self.heap);
-        rhs.drop_with_heap(self.heap);
-        Err(ExcType::not_implemented("matrix multiplication (@) is not supported").into())
+    /// No builtin type implements `@`, so the operator only works on objects defining
+    /// `__matmul__` or `__rmatmul__`, see `binary_special_method`.
+    pub(super) fn binary_matmul(&mut self) -> Result<CallResult, RunError> {
+        let this = self;
+
+        let rhs = this.pop();
+        defer_drop!(rhs, this);
+        let lhs = this.pop();
+        defer_drop!(lhs, this);
+
+        if let Some(result) = this.binary_special_method(BinarySpecialMethod::MATMUL, lhs, rhs) {
+            return result;
+        }
+        Err(this.binary_operator_error("@", lhs, rhs))
+    }
+
+    /// Builds the `TypeError` for a binary operator neither operand supports.
+    ///
+    /// Class instances are named by their class, as in CPython, rather than by `object`.
+    fn binary_operator_error(&self, op: &str, lhs: &Value, rhs: &Value) -> RunError {
+        let lhs_type = lhs.py_type(self.heap);
+        ExcType::binary_type_error_named(op, lhs_type, self.operand_type_name(lhs), self.operand_type_name(rhs))
+    }
+
+    /// Returns the type name of an operand for error messages, using the class name for instances.
+    fn operand_type_name(&self, value: &Value) -> String {
+        if let Value::Ref(id) = value
+            && let HeapData::Instance(instance) = self.heap.get(*id)
+        {
+            return instance.class_name(self.heap, self.interns).to_owned();
+        }
+        value.py_type(self.heap).to_string()
+    }
+
+    /// Dispatches a binary operator the builtin types don't support to the special methods of
+    /// class instances and dataclasses: `lhs.__add__(rhs)` is tried first, then `rhs.__radd__(lhs)`.
+    ///
+    /// Instances call the method defined by their class. Dataclass operands are sent to the host
+    /// as a method call with `self` first, like any other dataclass method. The host can't report
+    /// a missing method back, so a dataclass on the left always gets the forward method.
+    ///
+    /// Returns `None` if neither operand implements the operator.
+    fn binary_special_method(
+        &mut self,
+        special: BinarySpecialMethod,
+        lhs: &Value,
+        rhs: &Value,
+    ) -> Option<Result<CallResult, RunError>> {
+        for (name, value, other) in [(special.method, lhs, rhs), (special.reflected, rhs, lhs)] {
+            if let Some(result) = self.call_operator_method(value, name, other) {
+                return Some(result);
+            }
+            if let Value::Ref(id) = value
+                && matches!(self.heap.get(*id), HeapData::Dataclass(_))
+            {
+                let args = ArgValues::Two(value.clone_with_heap(self.heap), other.clone_with_heap(self.heap));
+                return Some(Ok(CallResult::MethodCall(EitherStr::Heap(name.to_owned()), args)));
+            }
+        }
+        None
     }
 }
@@ -18,9 +18,10 @@ use crate::{
     os::OsFunction,
     resource::ResourceTracker,
     types::{
-        AttrCallResult, Dict, PyTrait, Type,
+        AttrCallResult, ClassObject, Dict, Instance, InstanceAttr, PyTrait, Type,
         bytes::{bytes_fromhex, call_bytes_method},
         dict::dict_fromkeys,
+        list::list_sort,
         str::call_str_method,
     },
     value::{EitherStr, Value},
@@ -64,6 +65,9 @@ impl From<AttrCallResult> for CallResult {
             AttrCallResult::ExternalCall(ext_id, args) => Self::External(ext_id, args),
             AttrCallResult::MethodCall(name, args) => Self::MethodCall(name, args),
             AttrCallResult::AwaitValue(v) => Self::AwaitValue(v),
+            AttrCallResult::CallFunction(..) => {
+                unreachable!("script function calls from attribute lookups are made by the VM")
+            }
         }
     }
 }
@@ -92,11 +96,15 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     ///
     /// Calls a builtin function directly without stack manipulation for the callable.
     /// This is an optimization that avoids constant pool lookup and stack manipulation.
-    pub(super) fn exec_call_builtin_function(&mut self, builtin_id: u8, arg_count: usize) -> Result<Value, RunError> {
+    pub(super) fn exec_call_builtin_function(
+        &mut self,
+        builtin_id: u8,
+        arg_count: usize,
+    ) -> Result<CallResult, RunError> {
         // Convert u8 to BuiltinsFunctions via FromRepr
         if let Some(builtin) = BuiltinsFunctions::from_repr(builtin_id) {
             let args = self.pop_n_args(arg_count);
-            builtin.call(self, args)
+            builtin.call(self, args).map(Into::into)
         } else {
             Err(RunError::internal("CallBuiltinFunction: invalid builtin_id"))
         }
@@ -110,7 +118,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         // Convert u8 to Type via callable_from_u8
         if let Some(t) = Type::callable_from_u8(type_id) {
             let args = self.pop_n_args(arg_count);
-            t.call(self.heap, args, self.interns)
+            t.call(self, args)
         } else {
             Err(RunError::internal("CallBuiltinType: invalid type_id"))
         }
@@ -279,10 +287,54 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let attr = EitherStr::Interned(name_id);
 
         match obj {
+            Value::Ref(heap_id) if matches!(this.heap.get(heap_id), HeapData::Instance(_) | HeapData::Class(_)) => {
+                defer_drop!(obj, this);
+                let lookup = if let HeapData::Instance(_) = this.heap.get(heap_id) {
+                    Instance::lookup(heap_id, &attr, this.heap, this.interns)
+                } else {
+                    ClassObject::lookup(heap_id, &attr, this.heap, this.interns)
+                };
+                // Methods are called directly with `self` prepended, without binding them
+                let (callable, args) = match lookup {
+                    Ok(InstanceAttr::Method { func, self_value }) => (func, args.prepend(self_value)),
+                    Ok(InstanceAttr::Value(value)) => (value, args),
+                    Ok(InstanceAttr::Property(getter)) => {
+                        // The property's value is what gets called, so evaluate the getter first
+                        defer_drop!(getter, this);
+                        let self_arg = ArgValues::One(obj.clone_with_heap(this.heap));
+                        match this.evaluate_function("property getter", getter, self_arg) {
+                            Ok(value) => (value, args),
+                            Err(e) => {
+                                args.drop_with_heap(this.heap);
+                                return Err(e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        args.drop_with_heap(this.heap);
+                        return Err(e);
+                    }
+                };
+                defer_drop!(callable, this);
+                this.call_function(callable, args)
+            }
+            Value::Ref(heap_id)
+                if matches!(this.heap.get(heap_id), HeapData::List(_))
+                    && attr.static_string() == Some(StaticStrings::Sort) =>
+            {
+                defer_drop!(obj, this);
+                list_sort(obj, args, this).map(Into::into)
+            }
             Value::Ref(heap_id) => {
                 defer_drop!(obj, this);
-                let result = Heap::call_attr_raw(this, heap_id, &attr, args);
-                result.map(Into::into)
+                match Heap::call_attr_raw(this, heap_id, &attr, args)? {
+                    // Module functions are called here, after the module is back in the heap
+                    AttrCallResult::CallFunction(func, args) => {
+                        defer_drop!(func, this);
+                        this.call_function(func, args)
+                    }
+                    result => Ok(result.into()),
+                }
             }
             Value::InternString(string_id) => {
                 // Call string method on interned string literal using the unified dispatcher
@@ -338,6 +390,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
                         while self.frames.len() > stack_depth {
                             self.pop_frame();
                         }
+                        self.discard_pending_sort();
                         Err(RunError::internal(format!(
                             "{ctx}: external functions are not yet supported in this context"
                         )))
@@ -349,6 +402,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
             | CallResult::MethodCall(_, _)
             | CallResult::AwaitValue(_) => {
                 // External calls are not supported in this context since the caller doesn't support suspending
+                self.discard_pending_sort();
                 Err(RunError::internal(format!(
                     "{ctx}: external functions are not yet supported in this context"
                 )))
@@ -359,21 +413,16 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Calls a callable value with the given arguments.
     ///
     /// Dispatches based on the callable type:
-    /// - `Value::Builtin`: calls builtin directly, returns `Push`
+    /// - `Value::Builtin`: calls builtin directly, returns `Push` (or `MethodCall` when `sorted()`
+    ///   needs the host to compare dataclasses)
     /// - `Value::ModuleFunction`: calls module function directly, returns `Push`
     /// - `Value::ExtFunction`: returns `External` for caller to execute
     /// - `Value::DefFunction`: pushes a new frame, returns `FramePushed`
-    /// - `Value::Ref`: checks for closure/function on heap
-    fn call_function(&mut self, callable: &Value, args: ArgValues) -> Result<CallResult, RunError> {
+    /// - `Value::Ref`: checks for closure/function, class or bound method on heap
+    pub(super) fn call_function(&mut self, callable: &Value, args: ArgValues) -> Result<CallResult, RunError> {
         match callable {
-            Value::Builtin(builtin) => {
-                let result = builtin.call(self, args)?;
-                Ok(CallResult::Push(result))
-            }
-            Value::ModuleFunction(mf) => {
-                let result = mf.call(self.heap, args)?;
-                Ok(result.into())
-            }
+            Value::Builtin(builtin) => builtin.call(self, args).map(Into::into),
+            Value::ModuleFunction(mf) => mf.call(self, args).map(Into::into),
             Value::ExtFunction(ext_id) => {
                 // External function - return to caller to execute
                 Ok(CallResult::External(*ext_id, args))
@@ -394,9 +443,17 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         }
     }
 
-    /// Handles calling a heap-allocated callable (closure or function with defaults).
+    /// Handles calling a heap-allocated callable (closure, function with defaults, class or bound method).
     fn call_heap_callable(&mut self, heap_id: HeapId, args: ArgValues) -> Result<CallResult, RunError> {
         let (func_id, cells, defaults) = match self.heap.get(heap_id) {
+            HeapData::Class(_) => return self.call_class(heap_id, args),
+            HeapData::BoundMethod(method) => {
+                let func = method.func().clone_with_heap(self.heap);
+                let args = args.prepend(method.self_value().clone_with_heap(self.heap));
+                let this = self;
+                defer_drop!(func, this);
+                return this.call_function(func, args);
+            }
             HeapData::Closure(closure) => {
                 let cloned_cells = closure.cells.clone();
                 let cloned_defaults: Vec<Value> =
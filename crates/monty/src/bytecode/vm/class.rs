@@ -0,0 +1,266 @@
+//! Class definition and instantiation helpers for the VM.
+
+use super::{CallResult, VM};
+use crate::{
+    args::ArgValues,
+    builtins::Builtins,
+    defer_drop,
+    exception_private::{ExcType, RunError},
+    heap::{DropWithHeap, HeapData, HeapId},
+    intern::StringId,
+    resource::ResourceTracker,
+    types::{ClassObject, Dict, ExceptionClass, Instance, MontyIter, PyTrait},
+    value::Value,
+};
+
+impl<T: ResourceTracker> VM<'_, '_, T> {
+    /// Builds a class from the top 2n stack values (method name/function pairs) and the
+    /// base class below them, which is `None` for a class without a base.
+    pub(super) fn build_class(&mut self, name_id: StringId, method_count: usize) -> Result<(), RunError> {
+        let items = self.pop_n(method_count * 2);
+        let base = self.pop();
+        let exception = self.exception_base(name_id, &base);
+        base.drop_with_heap(self.heap);
+        let exception = match exception {
+            Ok(exception) => exception,
+            Err(e) => {
+                items.drop_with_heap(self.heap);
+                return Err(e);
+            }
+        };
+        let mut attrs = Dict::new();
+        let mut iter = items.into_iter();
+        while let (Some(name), Some(method)) = (iter.next(), iter.next()) {
+            // A later method with the same name replaces the earlier one, as in Python
+            if let Some(old) = attrs.set(name, method, self.heap, self.interns)? {
+                old.drop_with_heap(self.heap);
+            }
+        }
+        let class = ClassObject::new(name_id, attrs, exception);
+        let heap_id = self.heap.allocate(HeapData::Class(class))?;
+        self.push(Value::Ref(heap_id));
+        Ok(())
+    }
+
+    /// Resolves the base of class `name_id`, which must be an exception type if present.
+    ///
+    /// An exception class is given a new id, so exceptions can tell it apart from other
+    /// classes with the same name.
+    fn exception_base(&mut self, name_id: StringId, base: &Value) -> Result<Option<ExceptionClass>, RunError> {
+        match base {
+            Value::None => return Ok(None),
+            Value::Builtin(Builtins::ExcType(exc_type)) => {
+                let class_id = self.heap.new_class_id();
+                return Ok(Some(ExceptionClass::new(name_id, class_id, *exc_type)));
+            }
+            Value::Ref(id) => {
+                let class_id = self.heap.new_class_id();
+                if let HeapData::Class(class) = self.heap.get(*id)
+                    && let Some(exception) = class.exception()
+                {
+                    return Ok(Some(exception.subclass(name_id, class_id)));
+                }
+            }
+            _ => {}
+        }
+        Err(ExcType::not_implemented("inheriting from classes other than exceptions is not supported").into())
+    }
+
+    /// Calls a class, creating a new instance and running `__init__` on it.
+    ///
+    /// When `__init__` pushes a frame, the instance is kept on the stack just below it and
+    /// the frame is marked with `is_init`, so `ReturnValue` can substitute the instance for
+    /// `__init__`'s result (see `finish_init`).
+    pub(super) fn call_class(&mut self, class_id: HeapId, args: ArgValues) -> Result<CallResult, RunError> {
+        let HeapData::Class(class) = self.heap.get(class_id) else {
+            unreachable!("call_class called on a non-class")
+        };
+        if let Some(exception) = class.exception() {
+            let exception = exception.clone();
+            return exception.call(self.heap, args, self.interns).map(CallResult::Push);
+        }
+        let init = class
+            .attrs()
+            .get_by_str("__init__", self.heap, self.interns)
+            .map(|init| init.clone_with_heap(self.heap));
+
+        let Some(init) = init else {
+            if !args.is_empty() {
+                let class_name = class.name(self.interns);
+                args.drop_with_heap(self.heap);
+                return Err(ExcType::type_error(format!("{class_name}() takes no arguments")));
+            }
+            self.heap.inc_ref(class_id);
+            let instance_id = self.heap.allocate(HeapData::Instance(Instance::new(class_id)))?;
+            return Ok(CallResult::Push(Value::Ref(instance_id)));
+        };
+        let this = self;
+        defer_drop!(init, this);
+
+        this.heap.inc_ref(class_id);
+        let instance_id = match this.heap.allocate(HeapData::Instance(Instance::new(class_id))) {
+            Ok(instance_id) => instance_id,
+            Err(e) => {
+                args.drop_with_heap(this.heap);
+                return Err(e.into());
+            }
+        };
+        this.heap.inc_ref(instance_id);
+        let args = args.prepend(Value::Ref(instance_id));
+        this.push(Value::Ref(instance_id));
+
+        match this.call_function(init, args) {
+            Ok(CallResult::FramePushed) => {
+                this.current_frame_mut().is_init = true;
+                Ok(CallResult::FramePushed)
+            }
+            Ok(CallResult::Push(result)) => {
+                let instance = this.pop();
+                this.finish_init(instance, result).map(CallResult::Push)
+            }
+            Ok(_) => {
+                this.pop().drop_with_heap(this.heap);
+                Err(RunError::internal("__init__ must be a function defined in the script"))
+            }
+            Err(e) => {
+                this.pop().drop_with_heap(this.heap);
+                Err(e)
+            }
+        }
+    }
+
+    /// Completes a class call once `__init__` has returned `result`.
+    ///
+    /// Returns the instance, or a TypeError (dropping the instance) if `__init__` returned
+    /// something other than `None`.
+    pub(super) fn finish_init(&mut self, instance: Value, result: Value) -> Result<Value, RunError> {
+        if matches!(result, Value::None) {
+            return Ok(instance);
+        }
+        let result_type = result.py_type(self.heap);
+        result.drop_with_heap(self.heap);
+        instance.drop_with_heap(self.heap);
+        Err(ExcType::type_error(format!(
+            "__init__() should return None, not '{result_type}'"
+        )))
+    }
+
+    /// Calls the special method `name` (e.g. `__next__`) defined by the class of `value`.
+    ///
+    /// As in CPython, special methods are only looked up on the class, not on the instance
+    /// attributes. Returns `None` if `value` is not an instance or its class doesn't define `name`.
+    pub(crate) fn call_special_method(&mut self, value: &Value, name: &'static str) -> Option<Result<Value, RunError>> {
+        let method = self.special_method(value, name)?;
+        let this = self;
+        defer_drop!(method, this);
+        let args = ArgValues::One(value.clone_with_heap(this.heap));
+        Some(this.evaluate_function(name, method, args))
+    }
+
+    /// Like `call_special_method`, but passes `other` after `self`, e.g. for `__lt__`.
+    ///
+    /// Only for callers which can't suspend, such as the sort comparator. Operators use
+    /// `call_operator_method`, which pushes the method's frame instead of running it here.
+    pub(crate) fn call_binary_special_method(
+        &mut self,
+        value: &Value,
+        name: &'static str,
+        other: &Value,
+    ) -> Option<Result<Value, RunError>> {
+        let method = self.special_method(value, name)?;
+        let this = self;
+        defer_drop!(method, this);
+        let args = ArgValues::Two(value.clone_with_heap(this.heap), other.clone_with_heap(this.heap));
+        Some(this.evaluate_function(name, method, args))
+    }
+
+    /// Calls the special method `name` defined by the class of `value` for an operator,
+    /// passing `other` after `self`.
+    ///
+    /// A method defined in the script pushes a frame whose return value becomes the result of
+    /// the operator, so the method can call external functions like any other code.
+    /// Returns `None` if `value` is not an instance or its class doesn't define `name`.
+    pub(super) fn call_operator_method(
+        &mut self,
+        value: &Value,
+        name: &str,
+        other: &Value,
+    ) -> Option<Result<CallResult, RunError>> {
+        let method = self.special_method(value, name)?;
+        let this = self;
+        defer_drop!(method, this);
+        let args = ArgValues::Two(value.clone_with_heap(this.heap), other.clone_with_heap(this.heap));
+        Some(this.call_function(method, args))
+    }
+
+    /// Looks up the special method `name` on the class of `value`, if `value` is an instance.
+    fn special_method(&self, value: &Value, name: &str) -> Option<Value> {
+        let Value::Ref(id) = value else {
+            return None;
+        };
+        let HeapData::Instance(instance) = self.heap.get(*id) else {
+            return None;
+        };
+        let HeapData::Class(class) = self.heap.get(instance.class_id()) else {
+            unreachable!("instance class must be a class object")
+        };
+        Some(
+            class
+                .attrs()
+                .get_by_str(name, self.heap, self.interns)?
+                .clone_with_heap(self.heap),
+        )
+    }
+
+    /// Returns an iterator over `value`, implementing `iter(value)` and the `GetIter` opcode.
+    ///
+    /// Iterators are returned as is. Instances of classes defining `__iter__` are asked for
+    /// their iterator. If that returns an instance it is used as is and advanced with
+    /// `__next__` (see `instance_next`), anything else is wrapped in a `MontyIter`.
+    pub(crate) fn get_iter(&mut self, value: Value) -> Result<Value, RunError> {
+        let iterable = match self.call_special_method(&value, "__iter__") {
+            Some(result) => {
+                value.drop_with_heap(self.heap);
+                let iterable = result?;
+                if let Value::Ref(id) = iterable
+                    && matches!(self.heap.get(id), HeapData::Instance(_))
+                {
+                    return Ok(iterable);
+                }
+                iterable
+            }
+            None => {
+                // Iterators are their own iterators, as for `iter(iterator)`
+                if let Value::Ref(id) = value
+                    && matches!(self.heap.get(id), HeapData::Iter(_))
+                {
+                    return Ok(value);
+                }
+                value
+            }
+        };
+        let iter = MontyIter::new(iterable, self.heap, self.interns)?;
+        Ok(Value::Ref(self.heap.allocate(HeapData::Iter(iter))?))
+    }
+
+    /// Advances an instance used as an iterator by calling its `__next__` method.
+    ///
+    /// Returns `None` once `__next__` raises `StopIteration`, so for loops end cleanly.
+    pub(crate) fn instance_next(&mut self, iterator: &Value) -> Result<Option<Value>, RunError> {
+        match self.call_special_method(iterator, "__next__") {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(RunError::Exc(exc))) if exc.exc.exc_type() == ExcType::StopIteration => Ok(None),
+            Some(Err(e)) => Err(e),
+            None => {
+                let class_name = match iterator {
+                    Value::Ref(id) => match self.heap.get(*id) {
+                        HeapData::Instance(instance) => instance.class_name(self.heap, self.interns),
+                        _ => unreachable!("instance_next called on a non-instance"),
+                    },
+                    _ => unreachable!("instance_next called on a non-instance"),
+                };
+                Err(ExcType::type_error(format!("'{class_name}' object is not an iterator")))
+            }
+        }
+    }
+}
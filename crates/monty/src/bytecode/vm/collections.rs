@@ -35,6 +35,25 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         Ok(())
     }
 
+    /// Builds a list from a run of constants, see `Opcode::BuildListConst`.
+    ///
+    /// Constants are always immediates, so the items are copied into a vector of the
+    /// exact size without touching any refcounts.
+    pub(super) fn build_list_const(&mut self, constants: &[Value]) -> Result<(), RunError> {
+        let items = constants.iter().map(Value::clone_immediate).collect();
+        let heap_id = self.heap.allocate(HeapData::List(List::new(items)))?;
+        self.push(Value::Ref(heap_id));
+        Ok(())
+    }
+
+    /// Builds a tuple from a run of constants, see `Opcode::BuildTupleConst`.
+    pub(super) fn build_tuple_const(&mut self, constants: &[Value]) -> Result<(), RunError> {
+        let items = constants.iter().map(Value::clone_immediate).collect();
+        let value = allocate_tuple(items, self.heap)?;
+        self.push(value);
+        Ok(())
+    }
+
     /// Builds a dict from the top 2n stack values (key/value pairs).
     pub(super) fn build_dict(&mut self, count: usize) -> Result<(), RunError> {
         let items = self.pop_n(count * 2);
@@ -1,17 +1,70 @@
 //! Comparison operation helpers for the VM.
 
-use super::VM;
+use std::cmp::Ordering;
+
+use super::{VM, call::CallResult};
 use crate::{
+    args::ArgValues,
     defer_drop,
     exception_private::{ExcType, RunError},
+    heap::HeapData,
     resource::ResourceTracker,
     types::{LongInt, PyTrait},
-    value::Value,
+    value::{EitherStr, Value},
 };
 
+/// A rich comparison, which classes can customize with special methods such as `__lt__`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RichCompare {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl RichCompare {
+    /// The special method implementing the comparison.
+    fn method_name(self) -> &'static str {
+        match self {
+            Self::Eq => "__eq__",
+            Self::Ne => "__ne__",
+            Self::Lt => "__lt__",
+            Self::Le => "__le__",
+            Self::Gt => "__gt__",
+            Self::Ge => "__ge__",
+        }
+    }
+
+    /// The comparison with its operands swapped, which is tried on the right operand when
+    /// the left one doesn't implement the comparison: `a < b` becomes `b > a`.
+    fn reflected(self) -> Self {
+        match self {
+            Self::Eq | Self::Ne => self,
+            Self::Lt => Self::Gt,
+            Self::Le => Self::Ge,
+            Self::Gt => Self::Lt,
+            Self::Ge => Self::Le,
+        }
+    }
+
+    /// Whether `ordering` satisfies an ordering comparison.
+    fn check(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+        }
+    }
+}
+
 impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Equality comparison.
-    pub(super) fn compare_eq(&mut self) -> Result<(), RunError> {
+    pub(super) fn compare_eq(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -19,13 +72,15 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
+        if let Some(result) = this.rich_compare(RichCompare::Eq, lhs, rhs) {
+            return result;
+        }
         let result = lhs.py_eq(rhs, this.heap, this.interns)?;
-        this.push(Value::Bool(result));
-        Ok(())
+        Ok(CallResult::Push(Value::Bool(result)))
     }
 
     /// Inequality comparison.
-    pub(super) fn compare_ne(&mut self) -> Result<(), RunError> {
+    pub(super) fn compare_ne(&mut self) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -33,16 +88,15 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
+        if let Some(result) = this.rich_compare(RichCompare::Ne, lhs, rhs) {
+            return result;
+        }
         let result = !lhs.py_eq(rhs, this.heap, this.interns)?;
-        this.push(Value::Bool(result));
-        Ok(())
+        Ok(CallResult::Push(Value::Bool(result)))
     }
 
-    /// Ordering comparison with a predicate.
-    pub(super) fn compare_ord<F>(&mut self, check: F) -> Result<(), RunError>
-    where
-        F: FnOnce(std::cmp::Ordering) -> bool,
-    {
+    /// Ordering comparison (`<`, `<=`, `>` or `>=`).
+    pub(super) fn compare_ord(&mut self, op: RichCompare) -> Result<CallResult, RunError> {
         let this = self;
 
         let rhs = this.pop();
@@ -50,9 +104,126 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
-        let result = lhs.py_cmp(rhs, this.heap, this.interns)?.is_some_and(check);
-        this.push(Value::Bool(result));
-        Ok(())
+        if let Some(result) = this.rich_compare(op, lhs, rhs) {
+            return result;
+        }
+        let result = lhs
+            .py_cmp(rhs, this.heap, this.interns)?
+            .is_some_and(|ord| op.check(ord));
+        Ok(CallResult::Push(Value::Bool(result)))
+    }
+
+    /// Evaluates `left < right` through the `__lt__` (or reflected `__gt__`) special method
+    /// of class instances, as used by sorting.
+    ///
+    /// Returns `None` if neither operand is an instance whose class defines the comparison.
+    pub(crate) fn instance_lt(&mut self, left: &Value, right: &Value) -> Option<Result<bool, RunError>> {
+        let result = match self.call_binary_special_method(left, "__lt__", right) {
+            Some(result) => result,
+            None => self.call_binary_special_method(right, "__gt__", left)?,
+        };
+        Some(result.map(|value| {
+            let is_lt = value.py_bool(self.heap, self.interns);
+            value.drop_with_heap(self.heap);
+            is_lt
+        }))
+    }
+
+    /// Dispatches a comparison to the special methods of class instances and dataclasses.
+    ///
+    /// The left operand's method is tried first, then the reflected method of the right
+    /// operand. Instances call the method defined by their class, comparisons on dataclasses
+    /// are sent to the host as a method call (see `dataclass_compare`), and instances without
+    /// `__eq__` compare by identity.
+    ///
+    /// Returns `None` if neither operand customizes the comparison.
+    fn rich_compare(&mut self, op: RichCompare, lhs: &Value, rhs: &Value) -> Option<Result<CallResult, RunError>> {
+        if let Some(result) = self.instance_compare(op, lhs, rhs) {
+            return Some(result);
+        }
+        if let Some(result) = self.instance_compare(op.reflected(), rhs, lhs) {
+            return Some(result);
+        }
+        let (method_name, args) = self.dataclass_compare(op, lhs, rhs)?;
+        Some(Ok(CallResult::MethodCall(method_name, args)))
+    }
+
+    /// Returns the method call asking the host to evaluate `op` on dataclass operands.
+    ///
+    /// Ordering comparisons call the left operand's method if it's a dataclass, otherwise
+    /// the reflected method of the right one. `==` and `!=` are only sent to the host for
+    /// two instances of the same dataclass, since the `__eq__` generated by `@dataclass`
+    /// returns `NotImplemented` for anything else; other pairs keep using `py_eq`.
+    ///
+    /// Returns `None` if the comparison doesn't involve a dataclass method.
+    pub(super) fn dataclass_compare(
+        &mut self,
+        op: RichCompare,
+        lhs: &Value,
+        rhs: &Value,
+    ) -> Option<(EitherStr, ArgValues)> {
+        let dataclass_type = |value: &Value| match value {
+            Value::Ref(id) => match self.heap.get(*id) {
+                HeapData::Dataclass(dc) => Some(dc.type_id()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let (op, value, other) = if matches!(op, RichCompare::Eq | RichCompare::Ne) {
+            let type_id = dataclass_type(lhs)?;
+            if dataclass_type(rhs) != Some(type_id) {
+                return None;
+            }
+            (op, lhs, rhs)
+        } else if dataclass_type(lhs).is_some() {
+            (op, lhs, rhs)
+        } else if dataclass_type(rhs).is_some() {
+            (op.reflected(), rhs, lhs)
+        } else {
+            return None;
+        };
+        let method_name = EitherStr::Heap(op.method_name().to_owned());
+        let args = ArgValues::Two(value.clone_with_heap(self.heap), other.clone_with_heap(self.heap));
+        Some((method_name, args))
+    }
+
+    /// Calls the special method implementing `op` on `value` if it's an instance whose class
+    /// defines it, passing `other` as the argument.
+    ///
+    /// A class defining `__eq__` but not `__ne__` gets `!=` as the negation of `__eq__`,
+    /// like Python's default `__ne__`: the `__eq__` frame is marked to negate its result.
+    fn instance_compare(
+        &mut self,
+        op: RichCompare,
+        value: &Value,
+        other: &Value,
+    ) -> Option<Result<CallResult, RunError>> {
+        if let Some(result) = self.call_operator_method(value, op.method_name(), other) {
+            return Some(result);
+        }
+        if op != RichCompare::Ne {
+            return None;
+        }
+        Some(match self.call_operator_method(value, "__eq__", other)? {
+            Ok(CallResult::FramePushed) => {
+                self.current_frame_mut().negate_result = true;
+                Ok(CallResult::FramePushed)
+            }
+            Ok(CallResult::Push(is_equal)) => {
+                let ne = !is_equal.py_bool(self.heap, self.interns);
+                is_equal.drop_with_heap(self.heap);
+                Ok(CallResult::Push(Value::Bool(ne)))
+            }
+            Ok(CallResult::External(_, args) | CallResult::OsCall(_, args) | CallResult::MethodCall(_, args)) => {
+                args.drop_with_heap(self.heap);
+                Err(RunError::internal("__eq__ must be a function defined in the script"))
+            }
+            Ok(CallResult::AwaitValue(value)) => {
+                value.drop_with_heap(self.heap);
+                Err(RunError::internal("__eq__ must be a function defined in the script"))
+            }
+            Err(e) => Err(e),
+        })
     }
 
     /// Identity comparison (is/is not).
@@ -139,7 +310,11 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
                     this.push(Value::Bool(is_equal));
                     Ok(())
                 }
-                Ok(None) => Err(ExcType::type_error("unsupported operand type(s) for %")),
+                Ok(None) => {
+                    let lhs_type = lhs.py_type(this.heap);
+                    let rhs_type = rhs.py_type(this.heap);
+                    Err(ExcType::binary_type_error("%", lhs_type, rhs_type))
+                }
                 Err(e) => Err(e),
             }
         }
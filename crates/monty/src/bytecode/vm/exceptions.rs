@@ -3,12 +3,13 @@
 use super::VM;
 use crate::{
     builtins::Builtins,
+    bytecode::op::Opcode,
     defer_drop,
     exception_private::{ExcType, ExceptionRaise, RawStackFrame, RunError, SimpleException},
     heap::{HeapData, HeapGuard},
     intern::{StaticStrings, StringId},
     resource::ResourceTracker,
-    types::{PyTrait, Type},
+    types::{ExceptionClass, PyTrait, Type},
     value::Value,
 };
 
@@ -71,29 +72,91 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         let this = self;
         defer_drop!(exc_value, this);
 
-        let simple_exc = match exc_value {
-            // Exception instance on heap
-            Value::Ref(heap_id) => {
-                if let HeapData::Exception(exc) = this.heap.get(*heap_id) {
-                    // Clone the exception (guard handles cleanup at scope exit)
-                    exc.clone()
-                } else {
-                    // Not an exception type
-                    SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException")
+        let simple_exc = this.value_to_exception(exc_value).unwrap_or_else(|| {
+            SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException")
+        });
+        this.raise_exception(simple_exc, is_raise)
+    }
+
+    /// Creates a RunError for `raise exc from cause`.
+    ///
+    /// Takes ownership of both values and drops them properly. `cause` may be `None`,
+    /// in which case the exception is raised without a cause.
+    pub(super) fn make_exception_from(&mut self, exc_value: Value, cause_value: Value) -> RunError {
+        let this = self;
+        defer_drop!(exc_value, this);
+        defer_drop!(cause_value, this);
+
+        let Some(simple_exc) = this.value_to_exception(exc_value) else {
+            let exc = SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException");
+            return this.raise_exception(exc, true);
+        };
+        let cause = match cause_value {
+            Value::None => None,
+            cause_value => match this.value_to_exception(cause_value) {
+                Some(cause) => Some(cause),
+                None => {
+                    let exc =
+                        SimpleException::new_msg(ExcType::TypeError, "exception causes must derive from BaseException");
+                    return this.raise_exception(exc, true);
                 }
-            }
+            },
+        };
+        this.raise_exception(simple_exc.with_cause(cause), true)
+    }
+
+    /// Creates the `AssertionError` for a failed `assert left <op> right` without a message.
+    ///
+    /// Takes ownership of both operands and drops them properly. The message shows the
+    /// operands' reprs around the operator of the `compare` opcode, e.g. `assert 1 == 2`.
+    /// If either repr would exceed the `int_max_str_digits` limit, that `ValueError` is
+    /// returned instead.
+    pub(super) fn make_assert_compare_error(&mut self, left: Value, right: Value, compare: u8) -> RunError {
+        let this = self;
+        defer_drop!(left, this);
+        defer_drop!(right, this);
+
+        let symbol = Opcode::from_repr(compare)
+            .and_then(Opcode::compare_symbol)
+            .unwrap_or("?");
+        let reprs = (
+            left.py_repr_checked(this.heap, this.interns),
+            right.py_repr_checked(this.heap, this.interns),
+        );
+        let msg = match reprs {
+            (Ok(left), Ok(right)) => format!("assert {left} {symbol} {right}"),
+            // An operand past the `int_max_str_digits` limit has no repr to show
+            (Err(e), _) | (_, Err(e)) => return e,
+        };
+        this.raise_exception(SimpleException::new_msg(ExcType::AssertionError, msg), true)
+    }
+
+    /// Converts an exception instance or exception type to a `SimpleException`.
+    ///
+    /// Returns `None` if the value is not an exception.
+    fn value_to_exception(&self, value: &Value) -> Option<SimpleException> {
+        match value {
+            // Exception instance on heap
+            Value::Ref(heap_id) => match self.heap.get(*heap_id) {
+                HeapData::Exception(exc) => Some(exc.clone()),
+                // Exception class defined in the script (e.g., `raise MyError`)
+                HeapData::Class(class) => class.exception().map(ExceptionClass::new_none),
+                _ => None,
+            },
             // Exception type (e.g., `raise ValueError` instead of `raise ValueError()`)
             // Instantiate with no message
-            Value::Builtin(Builtins::ExcType(exc_type)) => SimpleException::new_none(*exc_type),
-            // Invalid exception value
-            _ => SimpleException::new_msg(ExcType::TypeError, "exceptions must derive from BaseException"),
-        };
+            Value::Builtin(Builtins::ExcType(exc_type)) => Some(SimpleException::new_none(*exc_type)),
+            _ => None,
+        }
+    }
 
+    /// Wraps `simple_exc` in a RunError with a frame for the current execution point.
+    fn raise_exception(&self, simple_exc: SimpleException, is_raise: bool) -> RunError {
         // Create frame with appropriate hide_caret setting
         let frame = if is_raise {
-            RawStackFrame::from_raise(this.current_position(), this.current_frame_name())
+            RawStackFrame::from_raise(self.current_position(), self.current_frame_name())
         } else {
-            this.make_stack_frame()
+            self.make_stack_frame()
         };
 
         RunError::Exc(ExceptionRaise {
@@ -265,31 +328,47 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
     /// Returns `Ok(true)` if exception matches, `Ok(false)` if not, or `Err` if exc_type is invalid.
     pub(super) fn check_exc_match(&self, exception: &Value, exc_type: &Value) -> Result<bool, RunError> {
         let exc_type_enum = exception.py_type(self.heap);
-        self.check_exc_match_inner(exc_type_enum, exc_type)
+        let simple_exc = match exception {
+            Value::Ref(id) => match self.heap.get(*id) {
+                HeapData::Exception(exc) => Some(exc),
+                _ => None,
+            },
+            _ => None,
+        };
+        self.check_exc_match_inner(exc_type_enum, simple_exc, exc_type)
     }
 
     /// Inner recursive helper for check_exc_match that handles tuples.
-    fn check_exc_match_inner(&self, exc_type_enum: Type, exc_type: &Value) -> Result<bool, RunError> {
+    fn check_exc_match_inner(
+        &self,
+        exc_type_enum: Type,
+        simple_exc: Option<&SimpleException>,
+        exc_type: &Value,
+    ) -> Result<bool, RunError> {
         match exc_type {
             // Valid exception type
             Value::Builtin(Builtins::ExcType(handler_type)) => {
                 // Check if exception is an instance of handler_type
                 Ok(matches!(exc_type_enum, Type::Exception(et) if et.is_subclass_of(*handler_type)))
             }
-            // Tuple of exception types
-            Value::Ref(id) => {
-                if let HeapData::Tuple(tuple) = self.heap.get(*id) {
+            Value::Ref(id) => match self.heap.get(*id) {
+                // Tuple of exception types
+                HeapData::Tuple(tuple) => {
                     for v in tuple.as_slice() {
-                        if self.check_exc_match_inner(exc_type_enum, v)? {
+                        if self.check_exc_match_inner(exc_type_enum, simple_exc, v)? {
                             return Ok(true);
                         }
                     }
                     Ok(false)
-                } else {
-                    // Not a tuple - invalid exception type
-                    Err(ExcType::except_invalid_type_error())
                 }
-            }
+                // Exception class defined in the script
+                HeapData::Class(class) => match class.exception() {
+                    Some(exception) => Ok(simple_exc.is_some_and(|exc| exception.is_class_of(exc))),
+                    None => Err(ExcType::except_invalid_type_error()),
+                },
+                // Not a tuple or exception class - invalid exception type
+                _ => Err(ExcType::except_invalid_type_error()),
+            },
             // Any other type is invalid for except clause
             _ => Err(ExcType::except_invalid_type_error()),
         }
@@ -58,6 +58,7 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         // Format with spec applied to original value type, or convert and format as string
         let formatted = if let Some(spec_value) = format_spec {
             defer_drop!(spec_value, this);
+            value.check_int_str_digits(this.heap)?;
 
             let spec = this.get_format_spec(spec_value, value)?;
 
@@ -66,17 +67,17 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
                 0 => format_with_spec(value, &spec, this.heap, this.interns)?,
                 // !s - convert to str, format as string
                 1 => {
-                    let s = value.py_str(this.heap, this.interns);
+                    let s = value.py_str_checked(this.heap, this.interns)?;
                     format_string(&s, &spec)?
                 }
                 // !r - convert to repr, format as string
                 2 => {
-                    let s = value.py_repr(this.heap, this.interns);
+                    let s = value.py_repr_checked(this.heap, this.interns)?;
                     format_string(&s, &spec)?
                 }
                 // !a - convert to ascii, format as string
                 3 => {
-                    let s = ascii_escape(&value.py_repr(this.heap, this.interns));
+                    let s = ascii_escape(&value.py_repr_checked(this.heap, this.interns)?);
                     format_string(&s, &spec)?
                 }
                 _ => format_with_spec(value, &spec, this.heap, this.interns)?,
@@ -84,11 +85,9 @@ impl<T: ResourceTracker> VM<'_, '_, T> {
         } else {
             // No format spec - just convert based on conversion flag
             match conversion {
-                0 => value.py_str(this.heap, this.interns).into_owned(),
-                1 => value.py_str(this.heap, this.interns).into_owned(),
-                2 => value.py_repr(this.heap, this.interns).into_owned(),
-                3 => ascii_escape(&value.py_repr(this.heap, this.interns)),
-                _ => value.py_str(this.heap, this.interns).into_owned(),
+                2 => value.py_repr_checked(this.heap, this.interns)?.into_owned(),
+                3 => ascii_escape(&value.py_repr_checked(this.heap, this.interns)?),
+                _ => value.py_str_checked(this.heap, this.interns)?.into_owned(),
             }
         };
 
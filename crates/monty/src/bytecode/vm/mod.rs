@@ -7,15 +7,16 @@ mod async_exec;
 mod attr;
 mod binary;
 mod call;
+mod class;
 mod collections;
 mod compare;
 mod exceptions;
 mod format;
 mod scheduler;
-
-use std::cmp::Ordering;
+mod sort;
 
 use call::CallResult;
+use compare::RichCompare;
 use scheduler::Scheduler;
 
 use crate::{
@@ -33,6 +34,7 @@ use crate::{
     os::OsFunction,
     parse::CodeRange,
     resource::ResourceTracker,
+    sorting::PendingSort,
     types::{LongInt, MontyIter, PyTrait, iter::advance_on_heap},
     value::{BitwiseOp, EitherStr, Value},
 };
@@ -169,7 +171,12 @@ macro_rules! handle_call_result {
     ($self:expr, $cached_frame:ident, $result:expr) => {
         match $result {
             Ok(CallResult::Push(result)) => $self.push(result),
-            Ok(CallResult::FramePushed) => reload_cache!($self, $cached_frame),
+            Ok(CallResult::FramePushed) => {
+                // Operators calling special methods don't sync the caller's IP before the call
+                let caller = $self.frames.len() - 2;
+                $self.frames[caller].ip = $cached_frame.ip;
+                reload_cache!($self, $cached_frame);
+            }
             Ok(CallResult::External(ext_id, args)) => {
                 let call_id = $self.allocate_call_id();
                 // Sync cached IP back to frame before snapshot for resume
@@ -312,6 +319,16 @@ pub struct CallFrame<'code> {
     /// When this frame returns (or exits with an exception) the VM should exit the run loop
     /// and return to the caller. Supports `evaluate_function`.
     should_return: bool,
+
+    /// This frame runs a class's `__init__` for a new instance.
+    ///
+    /// The instance sits on the operand stack just below `stack_base`. On return it
+    /// replaces `__init__`'s result (which must be `None`) as the value of the class call;
+    /// if the frame is unwound by an exception it is dropped along with the frame's stack.
+    is_init: bool,
+
+    /// This frame runs `__eq__` on behalf of `!=`, so its result is negated on return.
+    negate_result: bool,
 }
 
 impl<'code> CallFrame<'code> {
@@ -326,6 +343,8 @@ impl<'code> CallFrame<'code> {
             cells: Vec::new(),
             call_position: None,
             should_return: false,
+            is_init: false,
+            negate_result: false,
         }
     }
 
@@ -347,6 +366,8 @@ impl<'code> CallFrame<'code> {
             cells,
             call_position,
             should_return: false,
+            is_init: false,
+            negate_result: false,
         }
     }
 }
@@ -397,6 +418,12 @@ pub struct SerializedFrame {
 
     /// Call site position (for tracebacks).
     call_position: Option<CodeRange>,
+
+    /// Whether this frame runs a class's `__init__` (see `CallFrame::is_init`).
+    is_init: bool,
+
+    /// Whether this frame's result is negated on return (see `CallFrame::negate_result`).
+    negate_result: bool,
 }
 
 impl CallFrame<'_> {
@@ -413,6 +440,8 @@ impl CallFrame<'_> {
             namespace_idx: self.namespace_idx,
             cells: self.cells.clone(),
             call_position: self.call_position,
+            is_init: self.is_init,
+            negate_result: self.negate_result,
         }
     }
 }
@@ -455,6 +484,9 @@ pub struct VMSnapshot {
     /// This enables async execution to be paused and resumed across host calls.
     /// None if no async operations have been performed yet.
     scheduler: Option<Scheduler>,
+
+    /// Sort waiting for the host to compare two dataclasses (see `VM::sort`).
+    pending_sort: Option<PendingSort>,
 }
 
 // ============================================================================
@@ -520,6 +552,12 @@ pub struct VM<'a, 'p, T: ResourceTracker> {
     /// Stored here because the main task's frames have `function_id: None` and
     /// need a reference to the module code when being restored after task switching.
     module_code: Option<&'a Code>,
+
+    /// Sort waiting for the host to compare two dataclasses.
+    ///
+    /// Set when `sort` suspends with a method call, and taken back by `resume` or
+    /// `resume_with_exception` to continue or abandon the sort.
+    pending_sort: Option<PendingSort>,
 }
 
 impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
@@ -542,6 +580,7 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
             next_call_id: 0,
             scheduler: None, // Lazy - no allocation for sync code
             module_code: None,
+            pending_sort: None,
         }
     }
 
@@ -584,6 +623,8 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     cells: sf.cells,
                     call_position: sf.call_position,
                     should_return: false,
+                    is_init: sf.is_init,
+                    negate_result: sf.negate_result,
                 }
             })
             .collect();
@@ -606,6 +647,7 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
             next_call_id: snapshot.next_call_id,
             scheduler: snapshot.scheduler,
             module_code: Some(module_code),
+            pending_sort: snapshot.pending_sort,
         }
     }
     /// Consumes the VM and creates a snapshot for pause/resume if needed.
@@ -642,6 +684,7 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
             instruction_ip: self.instruction_ip,
             next_call_id: self.next_call_id,
             scheduler: self.scheduler,
+            pending_sort: self.pending_sort,
         }
     }
 
@@ -658,6 +701,7 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
     /// This method must be called before the VM goes out of scope to ensure
     /// proper reference counting cleanup for any exception values and scheduler state.
     pub fn cleanup(&mut self) {
+        self.discard_pending_sort();
         // Drop all exceptions in the exception stack
         for exc in self.exception_stack.drain(..) {
             exc.drop_with_heap(self.heap);
@@ -865,10 +909,10 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     self.store_cell(slot);
                 }
                 // Binary Operations - route through exception handling for tracebacks
-                Opcode::BinaryAdd => try_catch_sync!(self, cached_frame, self.binary_add()),
-                Opcode::BinarySub => try_catch_sync!(self, cached_frame, self.binary_sub()),
-                Opcode::BinaryMul => try_catch_sync!(self, cached_frame, self.binary_mult()),
-                Opcode::BinaryDiv => try_catch_sync!(self, cached_frame, self.binary_div()),
+                Opcode::BinaryAdd => handle_call_result!(self, cached_frame, self.binary_add()),
+                Opcode::BinarySub => handle_call_result!(self, cached_frame, self.binary_sub()),
+                Opcode::BinaryMul => handle_call_result!(self, cached_frame, self.binary_mult()),
+                Opcode::BinaryDiv => handle_call_result!(self, cached_frame, self.binary_div()),
                 Opcode::BinaryFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv()),
                 Opcode::BinaryMod => try_catch_sync!(self, cached_frame, self.binary_mod()),
                 Opcode::BinaryPow => try_catch_sync!(self, cached_frame, self.binary_pow()),
@@ -882,14 +926,14 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                 Opcode::BinaryRShift => {
                     try_catch_sync!(self, cached_frame, self.binary_bitwise(BitwiseOp::RShift));
                 }
-                Opcode::BinaryMatMul => try_catch_sync!(self, cached_frame, self.binary_matmul()),
+                Opcode::BinaryMatMul => handle_call_result!(self, cached_frame, self.binary_matmul()),
                 // Comparison Operations
-                Opcode::CompareEq => try_catch_sync!(self, cached_frame, self.compare_eq()),
-                Opcode::CompareNe => try_catch_sync!(self, cached_frame, self.compare_ne()),
-                Opcode::CompareLt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_lt)),
-                Opcode::CompareLe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_le)),
-                Opcode::CompareGt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_gt)),
-                Opcode::CompareGe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_ge)),
+                Opcode::CompareEq => handle_call_result!(self, cached_frame, self.compare_eq()),
+                Opcode::CompareNe => handle_call_result!(self, cached_frame, self.compare_ne()),
+                Opcode::CompareLt => handle_call_result!(self, cached_frame, self.compare_ord(RichCompare::Lt)),
+                Opcode::CompareLe => handle_call_result!(self, cached_frame, self.compare_ord(RichCompare::Le)),
+                Opcode::CompareGt => handle_call_result!(self, cached_frame, self.compare_ord(RichCompare::Gt)),
+                Opcode::CompareGe => handle_call_result!(self, cached_frame, self.compare_ord(RichCompare::Ge)),
                 Opcode::CompareIs => self.compare_is(false),
                 Opcode::CompareIsNot => self.compare_is(true),
                 Opcode::CompareIn => try_catch_sync!(self, cached_frame, self.compare_in(false)),
@@ -998,11 +1042,11 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     }
                 }
                 // In-place Operations - route through exception handling
-                Opcode::InplaceAdd => try_catch_sync!(self, cached_frame, self.inplace_add()),
+                Opcode::InplaceAdd => handle_call_result!(self, cached_frame, self.inplace_add()),
                 // Other in-place ops use the same logic as binary ops for now
-                Opcode::InplaceSub => try_catch_sync!(self, cached_frame, self.binary_sub()),
-                Opcode::InplaceMul => try_catch_sync!(self, cached_frame, self.binary_mult()),
-                Opcode::InplaceDiv => try_catch_sync!(self, cached_frame, self.binary_div()),
+                Opcode::InplaceSub => handle_call_result!(self, cached_frame, self.binary_sub()),
+                Opcode::InplaceMul => handle_call_result!(self, cached_frame, self.binary_mult()),
+                Opcode::InplaceDiv => handle_call_result!(self, cached_frame, self.binary_div()),
                 Opcode::InplaceFloorDiv => try_catch_sync!(self, cached_frame, self.binary_floordiv()),
                 Opcode::InplaceMod => try_catch_sync!(self, cached_frame, self.binary_mod()),
                 Opcode::InplacePow => try_catch_sync!(self, cached_frame, self.binary_pow()),
@@ -1028,6 +1072,18 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     let count = fetch_u16!(cached_frame) as usize;
                     try_catch_sync!(self, cached_frame, self.build_tuple(count));
                 }
+                Opcode::BuildListConst => {
+                    let start = fetch_u16!(cached_frame);
+                    let count = fetch_u16!(cached_frame);
+                    let items = cached_frame.code.constants().range(start, count);
+                    try_catch_sync!(self, cached_frame, self.build_list_const(items));
+                }
+                Opcode::BuildTupleConst => {
+                    let start = fetch_u16!(cached_frame);
+                    let count = fetch_u16!(cached_frame);
+                    let items = cached_frame.code.constants().range(start, count);
+                    try_catch_sync!(self, cached_frame, self.build_tuple_const(items));
+                }
                 Opcode::BuildDict => {
                     let count = fetch_u16!(cached_frame) as usize;
                     try_catch_sync!(self, cached_frame, self.build_dict(count));
@@ -1093,6 +1149,16 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                         catch_sync!(self, cached_frame, e);
                     }
                 }
+                Opcode::DeleteSubscr => {
+                    // Stack order: obj, index (TOS)
+                    let index = self.pop();
+                    let mut obj = self.pop();
+                    let result = obj.py_delitem(index, self.heap, self.interns);
+                    obj.drop_with_heap(self.heap);
+                    if let Err(e) = result {
+                        catch_sync!(self, cached_frame, e);
+                    }
+                }
                 Opcode::LoadAttr => {
                     let name_idx = fetch_u16!(cached_frame);
                     let name_id = StringId::from_index(name_idx);
@@ -1150,12 +1216,10 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                 // Iteration - route through exception handling
                 Opcode::GetIter => {
                     let value = self.pop();
-                    // Create a MontyIter from the value and store on heap
-                    match MontyIter::new(value, self.heap, self.interns) {
-                        Ok(iter) => match self.heap.allocate(HeapData::Iter(iter)) {
-                            Ok(heap_id) => self.push(Value::Ref(heap_id)),
-                            Err(e) => catch_sync!(self, cached_frame, e.into()),
-                        },
+                    // Sync IP before the call (`__iter__` may push frames and run a nested run() loop)
+                    self.current_frame_mut().ip = cached_frame.ip;
+                    match self.get_iter(value) {
+                        Ok(iter) => self.push(iter),
                         Err(e) => catch_sync!(self, cached_frame, e),
                     }
                 }
@@ -1166,9 +1230,25 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                         return Err(RunError::internal("ForIter: expected iterator ref on stack"));
                     };
 
-                    // Use advance_iterator which avoids std::mem::replace overhead
-                    // by using a two-phase approach: read state, get value, update index
-                    match advance_on_heap(self.heap, heap_id, self.interns) {
+                    let next = match self.heap.get(heap_id) {
+                        // An instance returned by `__iter__`, advanced by calling its `__next__`
+                        HeapData::Instance(_) => {
+                            self.current_frame_mut().ip = cached_frame.ip;
+                            let iterator = self.peek().clone_with_heap(self.heap);
+                            let next = self.instance_next(&iterator);
+                            iterator.drop_with_heap(self.heap);
+                            next
+                        }
+                        // `iter(callable, sentinel)`, advanced by calling the callable
+                        HeapData::Iter(iter) if iter.is_callable_iter() => {
+                            self.current_frame_mut().ip = cached_frame.ip;
+                            MontyIter::call_next(self, heap_id)
+                        }
+                        // Use advance_iterator which avoids std::mem::replace overhead
+                        // by using a two-phase approach: read state, get value, update index
+                        _ => advance_on_heap(self.heap, heap_id, self.interns),
+                    };
+                    match next {
                         Ok(Some(value)) => self.push(value),
                         Ok(None) => {
                             // Iterator exhausted - pop it and jump to end
@@ -1199,13 +1279,15 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     let arg_count = fetch_u8!(cached_frame) as usize;
 
                     // Sync IP before call (builtins like map() may call evaluate_function
-                    // which pushes frames and runs a nested run() loop)
+                    // which pushes frames and runs a nested run() loop, and sorted() may
+                    // yield to the host to compare dataclasses)
                     self.current_frame_mut().ip = cached_frame.ip;
 
-                    match self.exec_call_builtin_function(builtin_id, arg_count) {
-                        Ok(result) => self.push(result),
-                        Err(err) => catch_sync!(self, cached_frame, err),
-                    }
+                    handle_call_result!(
+                        self,
+                        cached_frame,
+                        self.exec_call_builtin_function(builtin_id, arg_count)
+                    );
                 }
                 Opcode::CallBuiltinType => {
                     // Fetch operands: type_id (u8) + arg_count (u8)
@@ -1309,6 +1391,11 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                         self.push(Value::Ref(heap_id));
                     }
                 }
+                Opcode::BuildClass => {
+                    let name_id = StringId::from_index(fetch_u16!(cached_frame));
+                    let method_count = fetch_u8!(cached_frame) as usize;
+                    try_catch_sync!(self, cached_frame, self.build_class(name_id, method_count));
+                }
                 Opcode::MakeClosure => {
                     let func_idx = fetch_u16!(cached_frame);
                     let defaults_count = fetch_u8!(cached_frame) as usize;
@@ -1358,6 +1445,19 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                     let error = self.make_exception(exc, true); // is_raise=true, hide caret
                     catch_sync!(self, cached_frame, error);
                 }
+                Opcode::RaiseFrom => {
+                    let cause = self.pop();
+                    let exc = self.pop();
+                    let error = self.make_exception_from(exc, cause);
+                    catch_sync!(self, cached_frame, error);
+                }
+                Opcode::RaiseAssertCompare => {
+                    let compare = fetch_u8!(cached_frame);
+                    let right = self.pop();
+                    let left = self.pop();
+                    let error = self.make_assert_compare_error(left, right, compare);
+                    catch_sync!(self, cached_frame, error);
+                }
                 Opcode::Reraise => {
                     // Pop the current exception from the stack to re-raise it
                     // If caught, handle_exception will push it back
@@ -1417,8 +1517,36 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
                         }
                         continue;
                     }
+                    // `!=` evaluated through `__eq__` returns the negated result
+                    let value = if self.current_frame().negate_result {
+                        let is_equal = value.py_bool(self.heap, self.interns);
+                        value.drop_with_heap(self.heap);
+                        Value::Bool(!is_equal)
+                    } else {
+                        value
+                    };
+                    // An `__init__` frame returns the new instance instead of its own result
+                    let frame = self.current_frame();
+                    let instance = if frame.is_init {
+                        let instance_slot = frame.stack_base - 1;
+                        Some(std::mem::replace(&mut self.stack[instance_slot], Value::None))
+                    } else {
+                        None
+                    };
                     // Pop current frame and push return value
-                    if self.pop_frame() {
+                    let should_return = self.pop_frame();
+                    let value = match instance {
+                        Some(instance) => match self.finish_init(instance, value) {
+                            Ok(instance) => instance,
+                            Err(e) if should_return => return Err(e),
+                            Err(e) => {
+                                catch_sync!(self, cached_frame, e);
+                                continue;
+                            }
+                        },
+                        None => value,
+                    };
+                    if should_return {
                         // This frame indicated evaluation should stop - return to host with value
                         // e.g. `evaluate_function`
                         return Ok(FrameExit::Return(value));
@@ -1501,15 +1629,39 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
         let value = obj
             .to_value(self.heap, self.interns)
             .map_err(|e| SimpleException::new(ExcType::RuntimeError, Some(format!("invalid return type: {e}"))))?;
+        if let Some(sort) = self.pending_sort.take() {
+            return self.resume_sort(sort, value);
+        }
         self.push(value);
         self.run()
     }
 
+    /// Resumes execution after the host chose to resolve an external call later.
+    ///
+    /// Pushes an `ExternalFuture` for `call_id` that the code can await, and continues
+    /// execution. A sort needs the result of a dataclass comparison straight away, so it
+    /// fails with a `RuntimeError` instead.
+    pub fn resume_with_future(&mut self, call_id: CallId) -> Result<FrameExit, RunError> {
+        if self.pending_sort.is_some() {
+            let error = SimpleException::new_msg(
+                ExcType::RuntimeError,
+                "dataclass comparisons made by a sort can't be resolved as futures",
+            );
+            return self.resume_with_exception(error.into());
+        }
+        // Store pending call data in the scheduler so we can track the creator task
+        // and ignore results if the task is cancelled
+        self.add_pending_call(call_id);
+        self.push(Value::ExternalFuture(call_id));
+        self.run()
+    }
+
     /// Resumes execution after an external call raised an exception.
     ///
     /// Uses the exception handling mechanism to try to catch the exception.
     /// If caught, continues execution at the handler. If not, propagates the error.
     pub fn resume_with_exception(&mut self, error: RunError) -> Result<FrameExit, RunError> {
+        self.discard_pending_sort();
         // Use the normal exception handling mechanism
         // handle_exception returns None if caught, Some(error) if not caught
         if let Some(uncaught_error) = self.handle_exception(error) {
@@ -1519,6 +1671,14 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
         self.run()
     }
 
+    /// Abandons the sort waiting for the host, if any, leaving a list being sorted unchanged.
+    pub(super) fn discard_pending_sort(&mut self) {
+        if let Some(sort) = self.pending_sort.take() {
+            // An abandoned sort doesn't check whether the list was modified, so can't fail
+            let _ = sort.finish(self.heap, false);
+        }
+    }
+
     // ========================================================================
     // Stack Operations
     // ========================================================================
@@ -1608,9 +1768,9 @@ impl<'a, 'p, T: ResourceTracker> VM<'a, 'p, T> {
     }
 
     fn cleanup_frame_state(&mut self, frame: &CallFrame<'_>) {
-        // Clean up frame's stack region
+        // Clean up frame's stack region, including the instance below it for `__init__` frames
         self.stack
-            .drain(frame.stack_base..)
+            .drain(frame.stack_base - usize::from(frame.is_init)..)
             .for_each(|value| value.drop_with_heap(self.heap));
 
         // Clean up the namespace (but not the global namespace)
@@ -101,6 +101,10 @@ pub(crate) struct SerializedTaskFrame {
     pub cells: Vec<HeapId>,
     /// Call site position (for tracebacks).
     pub call_position: Option<CodeRange>,
+    /// Whether this frame runs a class's `__init__` (see `CallFrame::is_init`).
+    pub is_init: bool,
+    /// Whether this frame's result is negated on return (see `CallFrame::negate_result`).
+    pub negate_result: bool,
 }
 
 impl Task {
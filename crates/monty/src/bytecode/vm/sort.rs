@@ -0,0 +1,56 @@
+//! Sorting for `sorted()` and `list.sort()`, which may suspend while the host compares dataclasses.
+
+use super::{FrameExit, VM, compare::RichCompare};
+use crate::{
+    exception_private::RunError,
+    resource::ResourceTracker,
+    sorting::{PendingSort, py_lt},
+    types::{AttrCallResult, PyTrait},
+    value::Value,
+};
+
+impl<T: ResourceTracker> VM<'_, '_, T> {
+    /// Runs `sort` until it's complete or needs the host to compare two dataclasses.
+    ///
+    /// Returns the result of the sort, or a method call asking the host for the dataclass's
+    /// `__lt__` (or reflected `__gt__`), in which case the sort is kept in `pending_sort` until
+    /// the host's answer is passed to `resume` (see `resume_sort`). On error the sort is
+    /// abandoned, leaving a list being sorted unchanged.
+    pub(crate) fn sort(&mut self, mut sort: PendingSort) -> Result<AttrCallResult, RunError> {
+        while let Some((a, b)) = sort.next_comparison() {
+            let (left, right) = (&sort.values()[a], &sort.values()[b]);
+            if let Some((method_name, args)) = self.dataclass_compare(RichCompare::Lt, left, right) {
+                self.pending_sort = Some(sort);
+                return Ok(AttrCallResult::MethodCall(method_name, args));
+            }
+            match py_lt(left, right, self) {
+                Ok(is_less) => sort.answer(is_less),
+                Err(e) => {
+                    sort.finish(self.heap, false)?;
+                    return Err(e);
+                }
+            }
+        }
+        sort.finish(self.heap, true).map(AttrCallResult::Value)
+    }
+
+    /// Continues the suspended sort with the host's answer to a dataclass comparison.
+    pub(super) fn resume_sort(&mut self, mut sort: PendingSort, answer: Value) -> Result<FrameExit, RunError> {
+        let is_less = answer.py_bool(self.heap, self.interns);
+        answer.drop_with_heap(self.heap);
+        sort.answer(is_less);
+        match self.sort(sort) {
+            Ok(AttrCallResult::Value(result)) => {
+                self.push(result);
+                self.run()
+            }
+            Ok(AttrCallResult::MethodCall(method_name, args)) => Ok(FrameExit::MethodCall {
+                method_name,
+                args,
+                call_id: self.allocate_call_id(),
+            }),
+            Ok(_) => unreachable!("sorting only suspends for dataclass comparisons"),
+            Err(e) => self.resume_with_exception(e),
+        }
+    }
+}
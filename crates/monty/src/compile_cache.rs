@@ -0,0 +1,117 @@
+//! Process-wide LRU cache of compiled programs, used by [`MontyRun::with_options`] when
+//! `PrepareOptions::compile_cache` is set.
+//!
+//! Parsing, preparing and compiling code is much more expensive than cloning the
+//! result, so hosts constructing the same program repeatedly can skip that work.
+//! Programs are keyed by everything that affects compilation, and only successfully
+//! compiled programs are cached.
+
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+
+use indexmap::IndexMap;
+
+use crate::{MontyRun, PrepareOptions};
+
+/// Default maximum number of programs kept in the compile cache.
+pub const DEFAULT_COMPILE_CACHE_CAPACITY: usize = 128;
+
+static CACHE: LazyLock<Mutex<CompileCache>> = LazyLock::new(|| Mutex::new(CompileCache::default()));
+
+/// The inputs to compiling a program, which identify it in the cache.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    pub code: String,
+    pub script_name: String,
+    pub input_names: Vec<String>,
+    pub external_functions: Vec<String>,
+    pub options: PrepareOptions,
+}
+
+/// Counters describing the compile cache, see [`MontyRun::compile_cache_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompileCacheStats {
+    /// Number of cached `MontyRun::with_options` calls which reused a cached program.
+    pub hits: u64,
+    /// Number of cached `MontyRun::with_options` calls which had to compile the code.
+    pub misses: u64,
+    /// Number of programs currently cached.
+    pub len: usize,
+    /// Maximum number of programs cached before the least recently used is evicted.
+    pub capacity: usize,
+}
+
+#[derive(Debug)]
+struct CompileCache {
+    /// Cached programs, ordered from least to most recently used.
+    entries: IndexMap<CacheKey, MontyRun>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for CompileCache {
+    fn default() -> Self {
+        Self {
+            entries: IndexMap::new(),
+            capacity: DEFAULT_COMPILE_CACHE_CAPACITY,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+fn cache() -> MutexGuard<'static, CompileCache> {
+    CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns a clone of the cached program for `key`, marking it as most recently used.
+pub(crate) fn get(key: &CacheKey) -> Option<MontyRun> {
+    let mut cache = cache();
+    let Some(index) = cache.entries.get_index_of(key) else {
+        cache.misses += 1;
+        return None;
+    };
+    cache.hits += 1;
+    let last = cache.entries.len() - 1;
+    cache.entries.move_index(index, last);
+    Some(cache.entries[last].clone())
+}
+
+/// Caches `runner` for `key`, evicting the least recently used program if the cache is full.
+pub(crate) fn insert(key: CacheKey, runner: MontyRun) {
+    let mut cache = cache();
+    if cache.capacity == 0 {
+        return;
+    }
+    if cache.entries.len() >= cache.capacity && !cache.entries.contains_key(&key) {
+        cache.entries.shift_remove_index(0);
+    }
+    cache.entries.insert(key, runner);
+}
+
+/// Removes all cached programs and resets the hit and miss counters.
+pub(crate) fn clear() {
+    let mut cache = cache();
+    cache.entries.clear();
+    cache.hits = 0;
+    cache.misses = 0;
+}
+
+/// Sets the maximum number of cached programs, evicting the least recently used ones over it.
+pub(crate) fn set_capacity(capacity: usize) {
+    let mut cache = cache();
+    cache.capacity = capacity;
+    while cache.entries.len() > capacity {
+        cache.entries.shift_remove_index(0);
+    }
+}
+
+pub(crate) fn stats() -> CompileCacheStats {
+    let cache = cache();
+    CompileCacheStats {
+        hits: cache.hits,
+        misses: cache.misses,
+        len: cache.entries.len(),
+        capacity: cache.capacity,
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     parse::CodeRange,
     resource::ResourceTracker,
     types::{
-        AttrCallResult, PyTrait, Str, Type, allocate_tuple,
+        AttrCallResult, ClassId, PyTrait, Str, Type, allocate_tuple,
         str::{StringRepr, string_repr_fmt},
     },
     value::{EitherStr, Value},
@@ -163,8 +163,20 @@ impl ExcType {
         args: ArgValues,
         interns: &Interns,
     ) -> RunResult<Value> {
+        let exc = self.new_exception(heap, args, interns)?;
+        let heap_id = heap.allocate(HeapData::Exception(exc))?;
+        Ok(Value::Ref(heap_id))
+    }
+
+    /// Creates an exception of this type from constructor arguments, see `call`.
+    pub(crate) fn new_exception(
+        self,
+        heap: &mut Heap<impl ResourceTracker>,
+        args: ArgValues,
+        interns: &Interns,
+    ) -> RunResult<SimpleException> {
         defer_drop!(args, heap);
-        let exc = match args {
+        match args {
             ArgValues::Empty => Ok(SimpleException::new_none(self)),
             ArgValues::One(value) => match value {
                 Value::InternString(string_id) => {
@@ -186,9 +198,7 @@ impl ExcType {
             _ => Err(RunError::internal(
                 "exceptions can only be called with zero or one string argument",
             )),
-        }?;
-        let heap_id = heap.allocate(HeapData::Exception(exc))?;
-        Ok(Value::Ref(heap_id))
+        }
     }
 
     /// Creates an AttributeError for when an attribute is not found (GET operation).
@@ -236,6 +246,34 @@ impl ExcType {
         })
     }
 
+    /// Creates an AttributeError for a missing attribute on a class object.
+    ///
+    /// Matches CPython's format: `AttributeError: type object 'Point' has no attribute 'attr'`
+    #[must_use]
+    pub(crate) fn attribute_error_type_object(class_name: &str, attr_name: &str) -> RunError {
+        let exc = SimpleException::new_msg(
+            Self::AttributeError,
+            format!("type object '{class_name}' has no attribute '{attr_name}'"),
+        );
+        RunError::Exc(ExceptionRaise {
+            exc,
+            frame: None,
+            hide_caret: true, // CPython doesn't show carets for attribute GET errors
+        })
+    }
+
+    /// Creates an AttributeError for assigning to a property that has no setter.
+    ///
+    /// Matches CPython's format: `AttributeError: property 'area' of 'Rect' object has no setter`
+    #[must_use]
+    pub(crate) fn attribute_error_property_no_setter(attr_name: &str, class_name: &str) -> RunError {
+        SimpleException::new_msg(
+            Self::AttributeError,
+            format!("property '{attr_name}' of '{class_name}' object has no setter"),
+        )
+        .into()
+    }
+
     /// Creates a FrozenInstanceError for assigning to a frozen dataclass.
     ///
     /// Matches CPython's `dataclasses.FrozenInstanceError` which is a subclass of `AttributeError`.
@@ -249,6 +287,14 @@ impl ExcType {
         .into()
     }
 
+    /// Creates a TypeError for `vars()` called on a value without a `__dict__`.
+    ///
+    /// Matches CPython's format: `TypeError: vars() argument must have __dict__ attribute`
+    #[must_use]
+    pub(crate) fn type_error_vars_no_dict() -> RunError {
+        SimpleException::new_msg(Self::TypeError, "vars() argument must have __dict__ attribute").into()
+    }
+
     #[must_use]
     pub(crate) fn type_error_not_sub(type_: Type) -> RunError {
         SimpleException::new_msg(Self::TypeError, format!("'{type_}' object is not subscriptable")).into()
@@ -274,6 +320,18 @@ impl ExcType {
         .into()
     }
 
+    /// Creates a TypeError for item deletion on types that don't support it.
+    ///
+    /// Matches CPython's format: `TypeError: '{type}' object doesn't support item deletion`
+    #[must_use]
+    pub(crate) fn type_error_not_sub_deletion(type_: Type) -> RunError {
+        SimpleException::new_msg(
+            Self::TypeError,
+            format!("'{type_}' object doesn't support item deletion"),
+        )
+        .into()
+    }
+
     /// Creates a TypeError for unhashable types when calling `hash()`.
     ///
     /// This matches Python 3.14's error message: `TypeError: unhashable type: 'list'`
@@ -310,11 +368,15 @@ impl ExcType {
 
     /// Creates a KeyError for a missing dict key.
     ///
-    /// For string keys, uses the raw string value without extra quoting.
+    /// For string keys, uses the raw string value without extra quoting. A key that can't
+    /// be converted to a string because of the `int_max_str_digits` limit gives that
+    /// `ValueError` instead.
     #[must_use]
     pub(crate) fn key_error(key: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunError {
-        let key_str = key.py_str(heap, interns).into_owned();
-        SimpleException::new_msg(Self::KeyError, key_str).into()
+        match key.py_str_checked(heap, interns) {
+            Ok(key_str) => SimpleException::new_msg(Self::KeyError, key_str.into_owned()).into(),
+            Err(e) => e,
+        }
     }
 
     /// Creates a KeyError for popping from an empty set.
@@ -562,6 +624,12 @@ impl ExcType {
         SimpleException::new_msg(Self::TypeError, msg).into()
     }
 
+    /// Creates a simple ValueError with a custom message.
+    #[must_use]
+    pub(crate) fn value_error(msg: impl fmt::Display) -> RunError {
+        SimpleException::new_msg(Self::ValueError, msg).into()
+    }
+
     /// Creates a TypeError for bytes() constructor with invalid type.
     ///
     /// Matches CPython's format: `TypeError: cannot convert '{type}' object to bytes`
@@ -849,6 +917,38 @@ impl ExcType {
         SimpleException::new_msg(Self::OverflowError, "cannot fit 'int' into an index-sized integer")
     }
 
+    /// Creates an OverflowError for a string repetition whose length overflows `usize`.
+    ///
+    /// Matches CPython's format: `OverflowError('repeated string is too long')`
+    #[must_use]
+    pub(crate) fn overflow_repeated_str() -> SimpleException {
+        SimpleException::new_msg(Self::OverflowError, "repeated string is too long")
+    }
+
+    /// Creates an OverflowError for a bytes repetition whose length overflows `usize`.
+    ///
+    /// Matches CPython's format: `OverflowError('repeated bytes are too long')`
+    #[must_use]
+    pub(crate) fn overflow_repeated_bytes() -> SimpleException {
+        SimpleException::new_msg(Self::OverflowError, "repeated bytes are too long")
+    }
+
+    /// Creates an OverflowError for an `itertools.count()` which has passed the range of `i64`.
+    ///
+    /// CPython switches to arbitrary precision ints instead, this is Monty-specific.
+    #[must_use]
+    pub(crate) fn overflow_error_count() -> RunError {
+        SimpleException::new_msg(Self::OverflowError, "count() exceeded the supported integer range").into()
+    }
+
+    /// Creates a MemoryError for materializing an infinite iterator, e.g. `list(itertools.count())`.
+    ///
+    /// CPython would run until memory runs out, Monty fails immediately.
+    #[must_use]
+    pub(crate) fn memory_error_infinite_iterator() -> RunError {
+        SimpleException::new_msg(Self::MemoryError, "cannot materialize an infinite iterator").into()
+    }
+
     /// Creates an IndexError for when an integer index is too large to fit in i64.
     ///
     /// Matches CPython's format: `IndexError: cannot fit 'int' into an index-sized integer`
@@ -893,6 +993,32 @@ impl ExcType {
         SimpleException::new_msg(Self::OverflowError, "Python int too large to convert to C ssize_t").into()
     }
 
+    /// Creates a ValueError for formatting an int with more decimal digits than allowed.
+    ///
+    /// Matches CPython's format, minus the hint about `sys.set_int_max_str_digits()`:
+    /// `ValueError: Exceeds the limit (4300 digits) for integer string conversion`
+    #[must_use]
+    pub(crate) fn value_error_int_str_digits(limit: usize) -> RunError {
+        SimpleException::new_msg(
+            Self::ValueError,
+            format!("Exceeds the limit ({limit} digits) for integer string conversion"),
+        )
+        .into()
+    }
+
+    /// Creates a ValueError for parsing an int from a string with more digits than allowed.
+    ///
+    /// Matches CPython's format, minus the hint about `sys.set_int_max_str_digits()`:
+    /// `ValueError: Exceeds the limit (4300 digits) for integer string conversion: value has 5000 digits`
+    #[must_use]
+    pub(crate) fn value_error_int_parse_digits(limit: usize, digits: usize) -> RunError {
+        SimpleException::new_msg(
+            Self::ValueError,
+            format!("Exceeds the limit ({limit} digits) for integer string conversion: value has {digits} digits"),
+        )
+        .into()
+    }
+
     /// Creates a TypeError for unsupported binary operations.
     ///
     /// For `+` or `+=` with str/list on the left side, uses CPython's special format:
@@ -902,10 +1028,22 @@ impl ExcType {
     /// `unsupported operand type(s) for {op}: '{left}' and '{right}'`
     #[must_use]
     pub(crate) fn binary_type_error(op: &str, lhs_type: Type, rhs_type: Type) -> RunError {
+        Self::binary_type_error_named(op, lhs_type, lhs_type, rhs_type)
+    }
+
+    /// Like `binary_type_error`, but with the operand type names given separately from
+    /// `lhs_type`, so class instances can be named by their class rather than `object`.
+    #[must_use]
+    pub(crate) fn binary_type_error_named(
+        op: &str,
+        lhs_type: Type,
+        lhs_name: impl Display,
+        rhs_name: impl Display,
+    ) -> RunError {
         let message = if (op == "+" || op == "+=") && (lhs_type == Type::Str || lhs_type == Type::List) {
-            format!("can only concatenate {lhs_type} (not \"{rhs_type}\") to {lhs_type}")
+            format!("can only concatenate {lhs_name} (not \"{rhs_name}\") to {lhs_name}")
         } else {
-            format!("unsupported operand type(s) for {op}: '{lhs_type}' and '{rhs_type}'")
+            format!("unsupported operand type(s) for {op}: '{lhs_name}' and '{rhs_name}'")
         };
         SimpleException::new_msg(Self::TypeError, message).into()
     }
@@ -1097,6 +1235,12 @@ impl ExcType {
     }
 }
 
+/// Maximum length of the `raise ... from cause` chain kept on an exception.
+///
+/// Causes are owned copies which are cloned whenever the exception is raised again, so
+/// a chain built up in a loop is cut off beyond this depth rather than growing without bound.
+const MAX_CAUSE_DEPTH: usize = 100;
+
 /// Simple lightweight representation of an exception.
 ///
 /// This is used for performance reasons for common exception patterns.
@@ -1105,19 +1249,24 @@ impl ExcType {
 pub(crate) struct SimpleException {
     exc_type: ExcType,
     arg: Option<String>,
+    /// The exception this one was raised from with `raise ... from cause`.
+    cause: Option<Box<Self>>,
+    /// For instances of exception classes defined in the script, the class name.
+    class_name: Option<StringId>,
+    /// For instances of exception classes defined in the script, the ids of the class and
+    /// its script-defined bases, most derived first (see `ExceptionClass`). Empty otherwise.
+    class_ids: Vec<ClassId>,
 }
 
 impl fmt::Display for SimpleException {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.py_repr_fmt(f)
+        self.repr_fmt(self.exc_type.into(), f)
     }
 }
 impl From<MontyException> for SimpleException {
     fn from(exc: MontyException) -> Self {
-        Self {
-            exc_type: exc.exc_type(),
-            arg: exc.into_message(),
-        }
+        let cause = exc.cause().map(|cause| cause.clone().into());
+        Self::new(exc.exc_type(), exc.into_message()).with_cause(cause)
     }
 }
 
@@ -1125,7 +1274,13 @@ impl SimpleException {
     /// Creates a new exception with the given type and optional argument message.
     #[must_use]
     pub fn new(exc_type: ExcType, arg: Option<String>) -> Self {
-        Self { exc_type, arg }
+        Self {
+            exc_type,
+            arg,
+            cause: None,
+            class_name: None,
+            class_ids: Vec::new(),
+        }
     }
 
     /// Creates a new exception with the given type and argument message.
@@ -1134,13 +1289,59 @@ impl SimpleException {
         Self {
             exc_type,
             arg: Some(arg.to_string()),
+            cause: None,
+            class_name: None,
+            class_ids: Vec::new(),
         }
     }
 
     /// Creates a new exception with the given type and no argument message.
     #[must_use]
     pub fn new_none(exc_type: ExcType) -> Self {
-        Self { exc_type, arg: None }
+        Self {
+            exc_type,
+            arg: None,
+            cause: None,
+            class_name: None,
+            class_ids: Vec::new(),
+        }
+    }
+
+    /// Sets the exception this one is raised from, as `raise ... from cause` does.
+    ///
+    /// The chain of causes is cut off after `MAX_CAUSE_DEPTH` exceptions.
+    #[must_use]
+    pub fn with_cause(mut self, cause: Option<Self>) -> Self {
+        self.cause = cause.map(|mut cause| {
+            cause.truncate_causes(MAX_CAUSE_DEPTH - 1);
+            Box::new(cause)
+        });
+        self
+    }
+
+    /// Drops the causes beyond the first `depth` of the chain.
+    fn truncate_causes(&mut self, depth: usize) {
+        let mut exc = self;
+        for _ in 0..depth {
+            let Some(cause) = exc.cause.as_deref_mut() else {
+                return;
+            };
+            exc = cause;
+        }
+        exc.cause = None;
+    }
+
+    /// Estimates the memory used by this exception, including its chain of causes.
+    pub fn estimate_size(&self) -> usize {
+        let mut size = 0;
+        let mut exc = Some(self);
+        while let Some(e) = exc {
+            size += std::mem::size_of::<Self>()
+                + e.arg.as_ref().map_or(0, String::len)
+                + e.class_ids.len() * std::mem::size_of::<ClassId>();
+            exc = e.cause();
+        }
+        size
     }
 
     #[must_use]
@@ -1153,6 +1354,47 @@ impl SimpleException {
         self.arg.as_ref()
     }
 
+    #[must_use]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+
+    /// Converts this exception and its cause to a `MontyException` with the given traceback.
+    pub(crate) fn to_monty_exception(&self, traceback: Vec<StackFrame>, interns: &Interns) -> MontyException {
+        let mut exc = MontyException::new_full(self.exc_type, self.arg.clone(), traceback);
+        if let Some(name) = self.class_name {
+            exc = exc.with_type_name(interns.get_str(name).to_owned());
+        }
+        if let Some(cause) = &self.cause {
+            exc = exc.with_cause(cause.to_monty_exception(Vec::new(), interns));
+        }
+        exc
+    }
+
+    /// Marks this exception as an instance of the exception class `name` defined in the script,
+    /// whose id and the ids of its script-defined bases are `class_ids`.
+    #[must_use]
+    pub fn with_class(mut self, name: StringId, class_ids: Vec<ClassId>) -> Self {
+        self.class_name = Some(name);
+        self.class_ids = class_ids;
+        self
+    }
+
+    #[must_use]
+    pub fn class_ids(&self) -> &[ClassId] {
+        &self.class_ids
+    }
+
+    /// Returns the name of the exception's type, the script class name for exception classes
+    /// defined in the script.
+    #[must_use]
+    pub fn type_name<'a>(&self, interns: &'a Interns) -> &'a str {
+        match self.class_name {
+            Some(name) => interns.get_str(name),
+            None => self.exc_type.into(),
+        }
+    }
+
     /// str() for an exception
     #[must_use]
     pub fn py_str(&self) -> String {
@@ -1169,8 +1411,11 @@ impl SimpleException {
     }
 
     /// Returns the exception formatted as Python would repr it.
-    pub fn py_repr_fmt(&self, f: &mut impl Write) -> std::fmt::Result {
-        let type_str: &'static str = self.exc_type.into();
+    pub fn py_repr_fmt(&self, f: &mut impl Write, interns: &Interns) -> std::fmt::Result {
+        self.repr_fmt(self.type_name(interns), f)
+    }
+
+    fn repr_fmt(&self, type_str: &str, f: &mut impl Write) -> std::fmt::Result {
         write!(f, "{type_str}(")?;
 
         if let Some(arg) = &self.arg {
@@ -1198,8 +1443,9 @@ impl SimpleException {
 
     /// Gets an attribute from this exception.
     ///
-    /// Handles the `.args` attribute by allocating a tuple containing the message.
-    /// Returns `Err(AttributeError)` for all other attributes.
+    /// Handles the `.args` attribute by allocating a tuple containing the message,
+    /// and `.__cause__` by allocating a copy of the cause exception, or `None`.
+    /// Returns `Ok(None)` for all other attributes.
     pub fn py_getattr(
         &self,
         attr: &EitherStr,
@@ -1220,6 +1466,12 @@ impl SimpleException {
                 smallvec![]
             };
             Ok(Some(AttrCallResult::Value(allocate_tuple(elements, heap)?)))
+        } else if attr.static_string() == Some(StaticStrings::DunderCause) {
+            let cause = match &self.cause {
+                Some(cause) => Value::Ref(heap.allocate(HeapData::Exception((**cause).clone()))?),
+                None => Value::None,
+            };
+            Ok(Some(AttrCallResult::Value(cause)))
         } else {
             Ok(None)
         }
@@ -1322,7 +1574,7 @@ impl ExceptionRaise {
             })
             .unwrap_or_default();
 
-        MontyException::new_full(self.exc.exc_type(), self.exc.arg().cloned(), traceback)
+        self.exc.to_monty_exception(traceback, interns)
     }
 }
 
@@ -1386,7 +1638,7 @@ pub(crate) enum RunError {
     Internal(Cow<'static, str>),
     /// Catchable Python exception (e.g., ValueError, TypeError).
     Exc(ExceptionRaise),
-    /// Uncatchable Python exception from resource limits (MemoryError, TimeoutError).
+    /// Uncatchable Python exception from resource limits (MemoryError, TimeoutError, ...).
     ///
     /// These exceptions display with proper tracebacks like normal Python exceptions,
     /// but cannot be caught by try/except blocks. This prevents untrusted code from
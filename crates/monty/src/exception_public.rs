@@ -16,6 +16,10 @@ pub struct MontyException {
     message: Option<String>,
     /// Stack trace of the exception, first is the outermost frame shown first in the traceback
     traceback: Vec<StackFrame>,
+    /// The exception this one was raised from with `raise ... from cause`
+    cause: Option<Box<Self>>,
+    /// Name of the exception class defined in the script, if `exc_type` is its builtin base
+    type_name: Option<String>,
 }
 
 /// Number of identical consecutive frames to show before collapsing.
@@ -26,6 +30,14 @@ const REPEAT_FRAMES_SHOWN: usize = 3;
 /// Display implementation for MontyException should exactly match python traceback format.
 impl fmt::Display for MontyException {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like CPython, the cause is printed first
+        if let Some(cause) = &self.cause {
+            write!(
+                f,
+                "{cause}\n\nThe above exception was the direct cause of the following exception:\n\n"
+            )?;
+        }
+
         // Print the traceback header if we have frames
         if !self.traceback.is_empty() {
             writeln!(f, "Traceback (most recent call last):")?;
@@ -62,9 +74,9 @@ impl fmt::Display for MontyException {
         }
 
         if let Some(msg) = &self.message {
-            write!(f, "{}: {}", self.exc_type, msg)
+            write!(f, "{}: {}", self.type_name(), msg)
         } else {
-            write!(f, "{}", self.exc_type)
+            write!(f, "{}", self.type_name())
         }
     }
 }
@@ -81,15 +93,40 @@ impl MontyException {
             exc_type,
             message,
             traceback: vec![],
+            cause: None,
+            type_name: None,
         }
     }
 
+    /// Sets the exception this one was raised from, as `raise ... from cause` does.
+    #[must_use]
+    pub fn with_cause(mut self, cause: Self) -> Self {
+        self.cause = Some(Box::new(cause));
+        self
+    }
+
+    pub(crate) fn with_type_name(mut self, type_name: String) -> Self {
+        self.type_name = Some(type_name);
+        self
+    }
+
     /// The exception type raised.
+    ///
+    /// For instances of exception classes defined in the script, this is the builtin
+    /// exception type the class derives from, see [`Self::type_name`].
     #[must_use]
     pub fn exc_type(&self) -> ExcType {
         self.exc_type
     }
 
+    /// Name of the exception type raised, e.g. `ValueError`.
+    ///
+    /// For instances of exception classes defined in the script, this is the class name.
+    #[must_use]
+    pub fn type_name(&self) -> &str {
+        self.type_name.as_deref().unwrap_or_else(|| self.exc_type.into())
+    }
+
     /// Optional exception message explaining what went wrong.
     ///
     /// Equivalent of python's `exc.args[0]`
@@ -114,6 +151,14 @@ impl MontyException {
         &self.traceback
     }
 
+    /// The exception this one was raised from with `raise ... from cause`, if any.
+    ///
+    /// Equivalent of python's `exc.__cause__`
+    #[must_use]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+
     /// Returns a compact summary of the exception.
     ///
     /// Format: `ExceptionType: message` (e.g., `NotImplementedError: feature not supported`)
@@ -121,9 +166,9 @@ impl MontyException {
     #[must_use]
     pub fn summary(&self) -> String {
         if let Some(msg) = &self.message {
-            format!("{}: {}", self.exc_type, msg)
+            format!("{}: {}", self.type_name(), msg)
         } else {
-            self.exc_type.to_string()
+            self.type_name().to_owned()
         }
     }
 
@@ -133,7 +178,7 @@ impl MontyException {
     /// Uses appropriate quoting for messages containing quotes.
     #[must_use]
     pub fn py_repr(&self) -> String {
-        let type_str: &'static str = self.exc_type.into();
+        let type_str = self.type_name();
         if let Some(msg) = &self.message {
             format!("{}({})", type_str, StringRepr(msg))
         } else {
@@ -146,6 +191,8 @@ impl MontyException {
             exc_type,
             message,
             traceback,
+            cause: None,
+            type_name: None,
         }
     }
 
@@ -154,6 +201,8 @@ impl MontyException {
             exc_type: ExcType::RuntimeError,
             message: Some(err.to_string()),
             traceback: vec![],
+            cause: None,
+            type_name: None,
         }
     }
 }
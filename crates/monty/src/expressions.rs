@@ -4,7 +4,7 @@ use crate::{
     fstring::FStringPart,
     intern::{BytesId, LongIntId, StringId},
     namespace::NamespaceId,
-    parse::{CodeRange, ParsedSignature, Try},
+    parse::{ClassDef, CodeRange, ParsedSignature, Try},
     signature::Signature,
     value::{EitherStr, Marker, Value},
 };
@@ -165,6 +165,15 @@ pub enum Expr {
         /// Sequence of (operator, operand) pairs: `[(op1, b), (op2, c), ...]`
         comparisons: Vec<(CmpOperator, ExprLoc)>,
     },
+    /// Boolean operation chain: `a and b and c` or `a or b or c`
+    ///
+    /// Kept as one node rather than nested operations, so a long chain doesn't make the
+    /// tree deeper. `op` is always `Operator::And` or `Operator::Or`.
+    BoolOp {
+        op: Operator,
+        /// The operands, evaluated in order until one short-circuits the chain.
+        values: Vec<ExprLoc>,
+    },
     List(Vec<ExprLoc>),
     Tuple(Vec<ExprLoc>),
     Subscript {
@@ -337,7 +346,7 @@ pub enum Literal {
     None,
     Bool(bool),
     Int(i64),
-    Float(f64),
+    Float(#[serde(with = "crate::serialize::float")] f64),
     /// An interned string literal. The StringId references the string in the Interns table.
     Str(StringId),
     /// An interned bytes literal. The BytesId references the bytes in the Interns table.
@@ -397,7 +406,11 @@ pub enum Node<F> {
     Expr(ExprLoc),
     Return(ExprLoc),
     ReturnNone,
-    Raise(Option<ExprLoc>),
+    /// `raise exc from cause`, `exc` is `None` for a bare `raise` which re-raises.
+    Raise {
+        exc: Option<ExprLoc>,
+        cause: Option<ExprLoc>,
+    },
     Assert {
         test: ExprLoc,
         msg: Option<ExprLoc>,
@@ -429,6 +442,13 @@ pub enum Node<F> {
         /// Position of the subscript expression (e.g., `lst[10]`) for traceback carets.
         target_position: CodeRange,
     },
+    /// Subscript deletion (e.g., `del lst[0]` or `del lst[1:3]`).
+    DeleteSubscript {
+        target: Identifier,
+        index: ExprLoc,
+        /// Position of the subscript expression (e.g., `lst[10]`) for traceback carets.
+        target_position: CodeRange,
+    },
     /// Attribute assignment (e.g., `point.x = 5` or `a.b.c = 5`).
     ///
     /// Assigns a value to an attribute on an object. For mutable dataclasses,
@@ -476,6 +496,8 @@ pub enum Node<F> {
         or_else: Vec<Self>,
     },
     FunctionDef(F),
+    /// Class definition: builds a class object from its methods and binds it to the class name.
+    ClassDef(ClassDef<F>),
     /// Global variable declaration. Only present in parsed form, consumed during prepare.
     ///
     /// Declares that the listed names refer to module-level (global) variables,
@@ -253,7 +253,7 @@ impl std::fmt::Display for FormatError {
 ///
 /// Dispatches to the appropriate formatting function based on the value type and format spec:
 /// - Integers: `format_int`, `format_int_base`, `format_char`
-/// - Floats: `format_float_f`, `format_float_e`, `format_float_g`, `format_float_percent`
+/// - Floats: `format_float_repr`, `format_float_f`, `format_float_e`, `format_float_g`, `format_float_percent`
 /// - Strings: `format_string`
 ///
 /// Returns a `ValueError` if the format type character is incompatible with the value type.
@@ -275,6 +275,7 @@ pub fn format_with_spec(
         (Value::Int(n), Some('c')) => Ok(format_char(*n, spec)?),
 
         // Float formatting
+        (Value::Float(f), None) if spec.precision.is_none() => Ok(format_float_repr(*f, spec)),
         (Value::Float(f), None | Some('g' | 'G')) => Ok(format_float_g(*f, spec)),
         (Value::Float(f), Some('f' | 'F')) => Ok(format_float_f(*f, spec)),
         (Value::Float(f), Some('e')) => Ok(format_float_e(*f, spec, false)),
@@ -299,7 +300,7 @@ pub fn format_with_spec(
 
         // No type specifier: convert to string and format
         (_, None) => {
-            let s = value.py_str(heap, interns);
+            let s = value.py_str_checked(heap, interns)?;
             Ok(format_string(&s, spec)?)
         }
 
@@ -468,7 +469,7 @@ pub fn format_string(value: &str, spec: &ParsedFormatSpec) -> Result<String, For
 /// - Alignment: Right-aligned by default for numbers, pads to `width` with `fill` character
 pub fn format_int(n: i64, spec: &ParsedFormatSpec) -> String {
     let is_negative = n < 0;
-    let abs_str = n.abs().to_string();
+    let abs_str = n.unsigned_abs().to_string();
 
     // Build the sign prefix
     let sign = if is_negative {
@@ -666,6 +667,68 @@ pub fn format_float_g(f: f64, spec: &ParsedFormatSpec) -> String {
     pad_string(&value, spec.width, align, spec.fill)
 }
 
+/// Formats a float with no format type or precision, as in `f'{x:>10}'`.
+///
+/// Uses the same digits as `repr()`, with the sign option and padding from `spec` applied.
+pub fn format_float_repr(f: f64, spec: &ParsedFormatSpec) -> String {
+    let repr = float_repr(f);
+    let value = match spec.sign {
+        Some(sign @ ('+' | ' ')) if !repr.starts_with('-') => format!("{sign}{repr}"),
+        _ => repr,
+    };
+    let align = spec.align.unwrap_or('>');
+    pad_string(&value, spec.width, align, spec.fill)
+}
+
+/// Returns Python's `repr()` of a float: the shortest string that round-trips to `f`.
+///
+/// Matches CPython's choice of notation: fixed-point (always with a fractional part, as in
+/// `1.0`) when the decimal exponent is in `-4..16`, and scientific notation with a signed,
+/// at least two digit exponent (`1e+16`, `2.5e-07`) otherwise.
+pub fn float_repr(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_owned();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_owned();
+    }
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    if f == 0.0 {
+        return format!("{sign}0.0");
+    }
+
+    // Rust's `{:e}` yields the shortest round-tripping digits, e.g. `1.2345e-7`
+    let scientific = format!("{:e}", f.abs());
+    let (mantissa, exp) = scientific
+        .split_once('e')
+        .expect("exponent formatting always contains 'e'");
+    let exp: i32 = exp.parse().expect("exponent is a valid integer");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+
+    if !(-4..16).contains(&exp) {
+        let (first, rest) = digits.split_at(1);
+        let exp_sign = if exp < 0 { '-' } else { '+' };
+        let abs_exp = exp.unsigned_abs();
+        if rest.is_empty() {
+            format!("{sign}{first}e{exp_sign}{abs_exp:02}")
+        } else {
+            format!("{sign}{first}.{rest}e{exp_sign}{abs_exp:02}")
+        }
+    } else if exp < 0 {
+        let leading_zeros = "0".repeat(exp.unsigned_abs() as usize - 1);
+        format!("{sign}0.{leading_zeros}{digits}")
+    } else {
+        let int_len = exp.unsigned_abs() as usize + 1;
+        if digits.len() <= int_len {
+            let trailing_zeros = "0".repeat(int_len - digits.len());
+            format!("{sign}{digits}{trailing_zeros}.0")
+        } else {
+            let (int_part, frac_part) = digits.split_at(int_len);
+            format!("{sign}{int_part}.{frac_part}")
+        }
+    }
+}
+
 /// Applies ASCII conversion to a string (escapes non-ASCII characters).
 ///
 /// Used for the `!a` conversion flag in f-strings. Takes a string (typically a repr)
@@ -10,6 +10,7 @@ use std::{
 };
 
 use ahash::AHashSet;
+use num_bigint::BigInt;
 use num_integer::Integer;
 use smallvec::SmallVec;
 
@@ -20,10 +21,12 @@ use crate::{
     exception_private::{ExcType, RunResult, SimpleException},
     heap_data::{CellValue, Closure, FunctionDefaults, HeapDataMut},
     intern::Interns,
+    modules::random::Rng,
     resource::{ResourceError, ResourceTracker, check_mult_size, check_repeat_size},
     types::{
-        AttrCallResult, Bytes, Dataclass, Dict, FrozenSet, List, LongInt, Module, MontyIter, NamedTuple, Path, PyTrait,
-        Range, Set, Slice, Str, Tuple, Type, allocate_tuple,
+        AttrCallResult, BoundMethod, Bytes, ClassId, ClassObject, Dataclass, Dict, FrozenSet, HashObject, Instance,
+        List, LongInt, MethodDescriptor, Module, MontyIter, NamedTuple, Path, PropertyObject, PyTrait, Range, Set,
+        Slice, Str, Tuple, Type, allocate_tuple, long_int::exceeds_str_digits,
     },
     value::{EitherStr, Value},
 };
@@ -119,6 +122,26 @@ pub(crate) enum HeapData {
     /// Pure methods (name, parent, etc.) are handled directly by the VM.
     /// I/O methods (exists, read_text, etc.) yield external function calls.
     Path(Path),
+    /// A hash object from `hashlib`, e.g. `hashlib.sha256(b"abc")`.
+    ///
+    /// Holds the data fed to it so far; the digest is computed when requested.
+    Hash(HashObject),
+    /// A class defined by a `class` statement in the script.
+    ///
+    /// Holds the class name and its methods; calling it creates an `Instance`.
+    Class(ClassObject),
+    /// An instance of a class defined in the script.
+    ///
+    /// Holds a reference to its class and a dict of instance attributes.
+    Instance(Instance),
+    /// A function bound to a `self` value, e.g. `p.norm` for an instance `p`.
+    ///
+    /// Created when a method is looked up on an instance without being called directly.
+    BoundMethod(BoundMethod),
+    /// A `property` defined in the script, e.g. by decorating a method with `@property`.
+    Property(PropertyObject),
+    /// A function wrapped by `staticmethod` or `classmethod`.
+    MethodDescriptor(MethodDescriptor),
 }
 
 impl HeapData {
@@ -148,6 +171,11 @@ impl HeapData {
                 | Self::Module(_)
                 | Self::Coroutine(_)
                 | Self::GatherFuture(_)
+                | Self::Class(_)
+                | Self::Instance(_)
+                | Self::BoundMethod(_)
+                | Self::Property(_)
+                | Self::MethodDescriptor(_)
         )
     }
 
@@ -191,6 +219,12 @@ impl HeapData {
                         .iter()
                         .any(|r| r.as_ref().is_some_and(|v| matches!(v, Value::Ref(_))))
             }
+            Self::Class(c) => c.has_refs(),
+            // Instances always reference their class
+            Self::Instance(_) => true,
+            Self::BoundMethod(m) => m.has_refs(),
+            Self::Property(p) => p.has_refs(),
+            Self::MethodDescriptor(d) => d.has_refs(),
             // Leaf types cannot have refs
             Self::Str(_)
             | Self::Bytes(_)
@@ -198,7 +232,8 @@ impl HeapData {
             | Self::Slice(_)
             | Self::Exception(_)
             | Self::LongInt(_)
-            | Self::Path(_) => false,
+            | Self::Path(_)
+            | Self::Hash(_) => false,
         }
     }
 
@@ -235,6 +270,12 @@ impl HeapData {
             Self::Coroutine(coro) => HeapDataMut::Coroutine(coro),
             Self::GatherFuture(gather) => HeapDataMut::GatherFuture(gather),
             Self::Path(p) => HeapDataMut::Path(p),
+            Self::Hash(h) => HeapDataMut::Hash(h),
+            Self::Class(c) => HeapDataMut::Class(c),
+            Self::Instance(i) => HeapDataMut::Instance(i),
+            Self::BoundMethod(m) => HeapDataMut::BoundMethod(m),
+            Self::Property(p) => HeapDataMut::Property(p),
+            Self::MethodDescriptor(d) => HeapDataMut::MethodDescriptor(d),
         }
     }
 }
@@ -266,6 +307,12 @@ impl PyTrait for HeapData {
             Self::Module(_) => Type::Module,
             Self::Coroutine(_) | Self::GatherFuture(_) => Type::Coroutine,
             Self::Path(p) => p.py_type(heap),
+            Self::Hash(h) => h.py_type(heap),
+            Self::Class(c) => c.py_type(heap),
+            Self::Instance(i) => i.py_type(heap),
+            Self::BoundMethod(m) => m.py_type(heap),
+            Self::Property(p) => p.py_type(heap),
+            Self::MethodDescriptor(d) => d.py_type(heap),
         }
     }
 
@@ -284,7 +331,7 @@ impl PyTrait for HeapData {
             Self::Cell(cell) => std::mem::size_of::<Value>() + cell.0.py_estimate_size(),
             Self::Range(_) => std::mem::size_of::<Range>(),
             Self::Slice(s) => s.py_estimate_size(),
-            Self::Exception(e) => std::mem::size_of::<SimpleException>() + e.arg().map_or(0, String::len),
+            Self::Exception(e) => e.estimate_size(),
             Self::Dataclass(dc) => dc.py_estimate_size(),
             Self::Iter(_) => std::mem::size_of::<MontyIter>(),
             Self::LongInt(li) => li.estimate_size(),
@@ -301,6 +348,12 @@ impl PyTrait for HeapData {
                     + gather.pending_calls.len() * std::mem::size_of::<crate::asyncio::CallId>()
             }
             Self::Path(p) => p.py_estimate_size(),
+            Self::Hash(h) => h.py_estimate_size(),
+            Self::Class(c) => c.py_estimate_size(),
+            Self::Instance(i) => i.py_estimate_size(),
+            Self::BoundMethod(m) => m.py_estimate_size(),
+            Self::Property(p) => p.py_estimate_size(),
+            Self::MethodDescriptor(d) => d.py_estimate_size(),
         }
     }
 
@@ -327,7 +380,13 @@ impl PyTrait for HeapData {
             | Self::Module(_)
             | Self::Coroutine(_)
             | Self::GatherFuture(_)
-            | Self::Path(_) => None,
+            | Self::Path(_)
+            | Self::Hash(_)
+            | Self::Class(_)
+            | Self::Instance(_)
+            | Self::BoundMethod(_)
+            | Self::Property(_)
+            | Self::MethodDescriptor(_) => None,
         }
     }
 
@@ -372,8 +431,15 @@ impl PyTrait for HeapData {
             (Self::Slice(a), Self::Slice(b)) => a.py_eq(b, heap, interns),
             // Path equality
             (Self::Path(a), Self::Path(b)) => a.py_eq(b, heap, interns),
-            // Cells, Exceptions, Iterators, Modules, and async types compare by identity only (handled at Value level via HeapId comparison)
+            // Bound methods are equal if they bind the same function to the same object
+            (Self::BoundMethod(a), Self::BoundMethod(b)) => a.py_eq(b, heap, interns),
+            // Cells, Exceptions, Iterators, Modules, classes, instances, descriptors, and async types compare by identity only (handled at Value level via HeapId comparison)
             (Self::Cell(_), Self::Cell(_))
+            | (Self::Class(_), Self::Class(_))
+            | (Self::Instance(_), Self::Instance(_))
+            | (Self::Property(_), Self::Property(_))
+            | (Self::MethodDescriptor(_), Self::MethodDescriptor(_))
+            | (Self::Hash(_), Self::Hash(_))
             | (Self::Exception(_), Self::Exception(_))
             | (Self::Iter(_), Self::Iter(_))
             | (Self::Module(_), Self::Module(_))
@@ -431,8 +497,14 @@ impl PyTrait for HeapData {
                     result.py_dec_ref_ids(stack);
                 }
             }
-            // Range, Slice, Exception, LongInt, and Path have no nested heap references
-            Self::Range(_) | Self::Slice(_) | Self::Exception(_) | Self::LongInt(_) | Self::Path(_) => {}
+            Self::Class(c) => c.py_dec_ref_ids(stack),
+            Self::Instance(i) => i.py_dec_ref_ids(stack),
+            Self::BoundMethod(m) => m.py_dec_ref_ids(stack),
+            Self::Property(p) => p.py_dec_ref_ids(stack),
+            Self::MethodDescriptor(d) => d.py_dec_ref_ids(stack),
+            // Range, Slice, Exception, LongInt, Path, and Hash have no nested heap references
+            Self::Range(_) | Self::Slice(_) | Self::Exception(_) | Self::LongInt(_) | Self::Path(_) | Self::Hash(_) => {
+            }
         }
     }
 
@@ -458,6 +530,13 @@ impl PyTrait for HeapData {
             Self::Coroutine(_) => true,    // Coroutines are always truthy
             Self::GatherFuture(_) => true, // GatherFutures are always truthy
             Self::Path(p) => p.py_bool(heap, interns),
+            Self::Hash(h) => h.py_bool(heap, interns),
+            // Objects are always truthy
+            Self::Class(_)
+            | Self::Instance(_)
+            | Self::BoundMethod(_)
+            | Self::Property(_)
+            | Self::MethodDescriptor(_) => true,
         }
     }
 
@@ -483,10 +562,11 @@ impl PyTrait for HeapData {
             Self::Cell(cell) => write!(f, "<cell: {} object>", cell.0.py_type(heap)),
             Self::Range(r) => r.py_repr_fmt(f, heap, heap_ids, interns),
             Self::Slice(s) => s.py_repr_fmt(f, heap, heap_ids, interns),
-            Self::Exception(e) => e.py_repr_fmt(f),
+            Self::Exception(e) => e.py_repr_fmt(f, interns),
             Self::Dataclass(dc) => dc.py_repr_fmt(f, heap, heap_ids, interns),
             Self::Iter(_) => write!(f, "<iterator>"),
-            Self::LongInt(li) => write!(f, "{li}"),
+            Self::LongInt(li) if heap.long_int_str_fits(li.inner()) => write!(f, "{li}"),
+            Self::LongInt(_) => f.write_str("..."),
             Self::Module(m) => write!(f, "<module '{}'>", interns.get_str(m.name())),
             Self::Coroutine(coro) => {
                 let func = interns.get_function(coro.func_id);
@@ -495,6 +575,12 @@ impl PyTrait for HeapData {
             }
             Self::GatherFuture(gather) => write!(f, "<gather({})>", gather.item_count()),
             Self::Path(p) => p.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Hash(h) => h.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Class(c) => c.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Instance(i) => i.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::BoundMethod(m) => m.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Property(p) => p.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::MethodDescriptor(d) => d.py_repr_fmt(f, heap, heap_ids, interns),
         }
     }
 
@@ -503,7 +589,7 @@ impl PyTrait for HeapData {
             // Strings return their value directly without quotes
             Self::Str(s) => s.py_str(heap, interns),
             // LongInt returns its string representation
-            Self::LongInt(li) => Cow::Owned(li.to_string()),
+            Self::LongInt(li) if heap.long_int_str_fits(li.inner()) => Cow::Owned(li.to_string()),
             // Exceptions return just the message (or empty string if no message)
             Self::Exception(e) => Cow::Owned(e.py_str()),
             // Paths return the path string without the PosixPath() wrapper
@@ -630,6 +716,7 @@ impl PyTrait for HeapData {
             Self::FrozenSet(fs) => fs.py_call_attr(heap, attr, args, interns),
             Self::Dataclass(dc) => dc.py_call_attr(heap, attr, args, interns),
             Self::Path(p) => p.py_call_attr(heap, attr, args, interns),
+            Self::Hash(h) => h.py_call_attr(heap, attr, args, interns),
             _ => Err(ExcType::attribute_error(self.py_type(heap), attr.as_str(interns))),
         }
     }
@@ -687,6 +774,17 @@ impl PyTrait for HeapData {
         }
     }
 
+    fn py_delitem(&mut self, key: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        match self {
+            Self::List(l) => l.py_delitem(key, heap, interns),
+            Self::Dict(d) => d.py_delitem(key, heap, interns),
+            _ => {
+                key.drop_with_heap(heap);
+                Err(ExcType::type_error_not_sub_deletion(self.py_type(heap)))
+            }
+        }
+    }
+
     fn py_getattr(
         &self,
         attr: &EitherStr,
@@ -700,6 +798,9 @@ impl PyTrait for HeapData {
             Self::Slice(s) => s.py_getattr(attr, heap, interns),
             Self::Exception(exc) => exc.py_getattr(attr, heap, interns),
             Self::Path(p) => p.py_getattr(attr, heap, interns),
+            Self::Hash(h) => h.py_getattr(attr, heap, interns),
+            Self::Class(c) => c.py_getattr(attr, heap, interns),
+            Self::Instance(i) => i.py_getattr(attr, heap, interns),
             // All other types don't support attribute access via py_getattr
             _ => Ok(None),
         }
@@ -737,6 +838,10 @@ impl HashState {
             | HeapData::Range(_)
             | HeapData::Slice(_)
             | HeapData::LongInt(_) => Self::Unknown,
+            // Classes, instances and descriptors are hashable by identity
+            HeapData::Class(_) | HeapData::Instance(_) | HeapData::Property(_) | HeapData::MethodDescriptor(_) => {
+                Self::Unknown
+            }
             // Dataclass hashability depends on the mutable flag
             HeapData::Dataclass(dc) => {
                 if dc.is_frozen() {
@@ -747,6 +852,8 @@ impl HashState {
             }
             // Path is immutable and hashable
             HeapData::Path(_) => Self::Unknown,
+            // Hash objects are hashable by identity
+            HeapData::Hash(_) => Self::Unknown,
             // Mutable containers, exceptions, iterators, modules, and async types are unhashable
             HeapData::List(_)
             | HeapData::Dict(_)
@@ -755,7 +862,8 @@ impl HashState {
             | HeapData::Iter(_)
             | HeapData::Module(_)
             | HeapData::Coroutine(_)
-            | HeapData::GatherFuture(_) => Self::Unhashable,
+            | HeapData::GatherFuture(_)
+            | HeapData::BoundMethod(_) => Self::Unhashable,
         }
     }
 }
@@ -783,13 +891,10 @@ pub struct HeapValue {
 /// Zero-size token returned by [`Heap::incr_recursion_depth`].
 ///
 /// Represents one level of recursion depth that must be released when the
-/// recursive operation completes. There are two ways to release the token:
+/// recursive operation completes, via `DropWithHeap` (e.g. in `py_eq`). Compatible
+/// with `defer_drop!` and `HeapGuard` for automatic cleanup on all code paths.
 ///
-/// - **`DropWithHeap`** — for `&mut Heap` paths (e.g., `py_eq`). Compatible with
-///   `defer_drop!` and `HeapGuard` for automatic cleanup on all code paths.
-/// - **`DropWithImmutableHeap`** — for `&Heap` paths (e.g., `py_repr_fmt`) where
-///   only shared access is available. Compatible with `defer_drop_immutable_heap!`
-///   and `ImmutableHeapGuard`.
+/// Repr paths, which only have `&Heap`, use [`ReprDepthToken`] instead.
 #[derive(Debug)]
 pub(crate) struct RecursionToken(());
 
@@ -800,6 +905,30 @@ impl DropWithHeap for RecursionToken {
     }
 }
 
+/// Zero-size token returned by [`Heap::incr_repr_depth`].
+///
+/// Released via `DropWithImmutableHeap`, typically with `defer_drop_immutable_heap!`,
+/// since repr paths only have shared access to the heap.
+#[derive(Debug)]
+pub(crate) struct ReprDepthToken(());
+
+/// Maximum nesting depth for repr-like traversals (`repr()`, `str()`, conversion to `MontyObject`).
+///
+/// Independent of the tracker's recursion limit, but still bounded because each level
+/// recurses on the Rust stack. Past this depth the nested value is written as `...`.
+pub(crate) const MAX_REPR_DEPTH: usize = 500;
+
+/// Heap storage kept between runs so short evaluations don't reallocate it each time.
+///
+/// Pass the same pool to repeated [`MontyRun::run_reusing`](crate::MontyRun::run_reusing)
+/// calls. Every run still starts from an empty heap, but the slot and free-list vectors
+/// keep the capacity grown by earlier runs. A pool holds no values between runs.
+#[derive(Debug, Default)]
+pub struct HeapPool {
+    entries: Vec<Option<HeapValue>>,
+    free_list: Vec<HeapId>,
+}
+
 /// Reference-counted arena that backs all heap-only runtime values.
 ///
 /// Uses a free list to reuse slots from freed values, keeping memory usage
@@ -829,17 +958,35 @@ pub(crate) struct Heap<T: ResourceTracker> {
     /// Uses `Cell` for interior mutability so that methods with only `&Heap`
     /// (like `py_repr_fmt`) can still increment/decrement the depth counter.
     recursion_depth: Cell<usize>,
+    /// Current nesting depth of repr-like traversals, see [`Heap::incr_repr_depth`].
+    ///
+    /// Kept apart from `recursion_depth` so a repr isn't truncated just because it
+    /// happens deep in the call stack, or under a low `max_recursion_depth`.
+    repr_depth: Cell<usize>,
+    /// The `int_max_str_digits` limit, once a repr-like traversal has skipped the digits
+    /// of a LongInt exceeding it, see [`Heap::long_int_str_fits`].
+    int_str_digits_exceeded: Cell<Option<usize>>,
+    /// Whether a traversal run by [`Heap::catch_int_str_digits_exceeded`] is in progress.
+    catching_int_str_digits: Cell<bool>,
+    /// Whether text for the host to display is being formatted, see [`Heap::format_for_display`].
+    formatting_for_display: Cell<bool>,
+    /// Generator behind the `random` module, kept here so it survives snapshots.
+    rng: Rng,
+    /// Id given to the next class defined in the script, see [`Heap::new_class_id`].
+    next_class_id: u64,
 }
 
 impl<T: ResourceTracker + serde::Serialize> serde::Serialize for Heap<T> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Heap", 6)?;
+        let mut state = serializer.serialize_struct("Heap", 7)?;
         state.serialize_field("entries", &self.entries)?;
         state.serialize_field("free_list", &self.free_list)?;
         state.serialize_field("tracker", &self.tracker)?;
         state.serialize_field("may_have_cycles", &self.may_have_cycles)?;
         state.serialize_field("allocations_since_gc", &self.allocations_since_gc)?;
+        state.serialize_field("rng", &self.rng)?;
+        state.serialize_field("next_class_id", &self.next_class_id)?;
         state.end()
     }
 }
@@ -853,6 +1000,8 @@ impl<'de, T: ResourceTracker + serde::Deserialize<'de>> serde::Deserialize<'de>
             tracker: T,
             may_have_cycles: bool,
             allocations_since_gc: u32,
+            rng: Rng,
+            next_class_id: u64,
         }
         let fields = HeapFields::<T>::deserialize(deserializer)?;
         Ok(Self {
@@ -862,6 +1011,12 @@ impl<'de, T: ResourceTracker + serde::Deserialize<'de>> serde::Deserialize<'de>
             may_have_cycles: fields.may_have_cycles,
             allocations_since_gc: fields.allocations_since_gc,
             recursion_depth: Cell::new(0),
+            repr_depth: Cell::new(0),
+            int_str_digits_exceeded: Cell::new(None),
+            catching_int_str_digits: Cell::new(false),
+            formatting_for_display: Cell::new(false),
+            rng: fields.rng,
+            next_class_id: fields.next_class_id,
         })
     }
 }
@@ -903,13 +1058,34 @@ impl<T: ResourceTracker> Heap<T> {
     ///
     /// Use this to create heaps with custom resource limits or GC scheduling.
     pub fn new(capacity: usize, tracker: T) -> Self {
+        Self::with_storage(Vec::with_capacity(capacity), Vec::new(), tracker)
+    }
+
+    /// Creates a new heap backed by the allocations held in `pool`, see [`HeapPool`].
+    ///
+    /// The pool is left empty until the heap is handed back with [`Heap::release_into`].
+    pub fn from_pool(pool: &mut HeapPool, capacity: usize, tracker: T) -> Self {
+        let mut entries = std::mem::take(&mut pool.entries);
+        entries.reserve(capacity);
+        Self::with_storage(entries, std::mem::take(&mut pool.free_list), tracker)
+    }
+
+    /// Builds a heap around empty `entries` and `free_list` vectors, allocating the empty tuple.
+    fn with_storage(entries: Vec<Option<HeapValue>>, free_list: Vec<HeapId>, tracker: T) -> Self {
+        debug_assert!(entries.is_empty() && free_list.is_empty());
         let mut this = Self {
-            entries: Vec::with_capacity(capacity),
-            free_list: Vec::new(),
+            entries,
+            free_list,
             tracker,
             may_have_cycles: false,
             allocations_since_gc: 0,
             recursion_depth: Cell::new(0),
+            repr_depth: Cell::new(0),
+            int_str_digits_exceeded: Cell::new(None),
+            catching_int_str_digits: Cell::new(false),
+            formatting_for_display: Cell::new(false),
+            rng: Rng::default(),
+            next_class_id: 0,
         };
         // TBC: should the empty tuple contribute to the resource limits?
         // If not, can just place it in `entries` directly without going through `allocate()`.
@@ -920,6 +1096,20 @@ impl<T: ResourceTracker> Heap<T> {
         this
     }
 
+    /// Frees every remaining value and hands the emptied storage back to `pool`.
+    ///
+    /// The vectors keep their capacity, so the next [`Heap::from_pool`] avoids regrowing them.
+    pub fn release_into(mut self, pool: &mut HeapPool) {
+        #[cfg(feature = "ref-count-panic")]
+        self.mark_all_dereferenced();
+        let mut entries = std::mem::take(&mut self.entries);
+        entries.clear();
+        let mut free_list = std::mem::take(&mut self.free_list);
+        free_list.clear();
+        pool.entries = entries;
+        pool.free_list = free_list;
+    }
+
     /// Returns a reference to the resource tracker.
     pub fn tracker(&self) -> &T {
         &self.tracker
@@ -930,6 +1120,18 @@ impl<T: ResourceTracker> Heap<T> {
         &mut self.tracker
     }
 
+    /// Returns a mutable reference to the generator behind the `random` module.
+    pub fn rng_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    /// Returns a new id for a class defined in the script, distinct from all ids returned before.
+    pub fn new_class_id(&mut self) -> ClassId {
+        let id = ClassId::new(self.next_class_id);
+        self.next_class_id += 1;
+        id
+    }
+
     /// Checks whether the configured time limit has been exceeded.
     ///
     /// Delegates to the resource tracker's `check_time()`. For `NoLimitTracker`,
@@ -944,11 +1146,33 @@ impl<T: ResourceTracker> Heap<T> {
         self.tracker.check_time()
     }
 
+    /// Yields the container items a repr should show.
+    ///
+    /// Each shown item is yielded as `Some`. Within [`Heap::format_for_display`], when the
+    /// container is longer than the tracker's `repr_max_items`, its first `ceil(max / 2)` and
+    /// last `floor(max / 2)` items are shown and a single `None` stands in for the elided
+    /// middle, to be written as `...`. Otherwise every item is shown.
+    pub(crate) fn repr_items<'a, E>(&self, items: &'a [E]) -> impl Iterator<Item = Option<&'a E>> {
+        let max_items = if self.formatting_for_display.get() {
+            self.tracker.repr_max_items()
+        } else {
+            None
+        };
+        let (head, tail, elided) = match max_items {
+            Some(max) if items.len() > max => (max.div_ceil(2), max / 2, true),
+            _ => (items.len(), 0, false),
+        };
+        items[..head]
+            .iter()
+            .map(Some)
+            .chain(elided.then_some(None))
+            .chain(items[items.len() - tail..].iter().map(Some))
+    }
+
     /// Increments the recursion depth and checks the limit via the `ResourceTracker`.
     ///
     /// Returns `Ok(RecursionToken)` if within limits. The caller must ensure the
-    /// token is released on all code paths — either via `defer_drop!`/`HeapGuard`
-    /// (for `&mut Heap` contexts) or via `RecursionToken::release()` (for `&Heap` contexts).
+    /// token is released on all code paths via `defer_drop!`/`HeapGuard`.
     ///
     /// Returns `Err(ResourceError::Recursion)` if the limit would be exceeded.
     #[inline]
@@ -959,14 +1183,82 @@ impl<T: ResourceTracker> Heap<T> {
         Ok(RecursionToken(()))
     }
 
-    /// Increments the recursion depth, returning `Some(RecursionToken)` if within
-    /// limits, or `None` if the limit is exceeded.
+    /// Increments the repr nesting depth, returning `Some(ReprDepthToken)` if within
+    /// `MAX_REPR_DEPTH`, or `None` if the budget is exhausted.
     ///
     /// Use this in repr-like contexts where exceeding the limit should produce
-    /// truncated output (e.g., `[...]`) rather than an error.
+    /// truncated output (e.g., `...`) rather than an error. Self-references are handled
+    /// separately by the `heap_ids` set passed through `py_repr_fmt`, so this budget only
+    /// bounds genuinely deep (non-cyclic) structures.
     #[inline]
-    pub fn incr_recursion_depth_for_repr(&self) -> Option<RecursionToken> {
-        self.incr_recursion_depth().ok()
+    pub fn incr_repr_depth(&self) -> Option<ReprDepthToken> {
+        let depth = self.repr_depth.get();
+        if depth >= MAX_REPR_DEPTH {
+            return None;
+        }
+        self.repr_depth.set(depth + 1);
+        Some(ReprDepthToken(()))
+    }
+
+    /// Returns whether a repr-like traversal may write the decimal digits of `value`, i.e.
+    /// whether they are within the tracker's `int_max_str_digits` limit.
+    ///
+    /// Repr paths can't raise, so when this returns `false` the caller writes a placeholder
+    /// and the limit is recorded for [`Heap::catch_int_str_digits_exceeded`], which turns it
+    /// into the error. This is how `str([10**5000])` raises, not just `str(10**5000)`.
+    ///
+    /// Text the script or the host sees should never hold the placeholder, so every repr or
+    /// str of a value that may hold a LongInt goes through `catch_int_str_digits_exceeded`,
+    /// e.g. via `Value::py_str_checked`. Outside it the placeholder is still written, as the
+    /// decimal digits may take quadratic time, but nothing is recorded.
+    pub fn long_int_str_fits(&self, value: &BigInt) -> bool {
+        match self.tracker.int_max_str_digits() {
+            Some(limit) if exceeds_str_digits(value, limit) => {
+                if self.catching_int_str_digits.get() {
+                    self.int_str_digits_exceeded.set(Some(limit));
+                }
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Runs `format`, which produces text only shown to the host such as `print()` output,
+    /// with long container reprs elided per the tracker's `repr_max_items`.
+    ///
+    /// Text the script can see, like the result of `repr()`, is never elided.
+    pub fn format_for_display<R>(&self, format: impl FnOnce() -> R) -> R {
+        let outer = self.formatting_for_display.replace(true);
+        let result = format();
+        self.formatting_for_display.set(outer);
+        result
+    }
+
+    /// Runs the repr-like traversal `format`, returning its result and, if it skipped the
+    /// digits of a LongInt exceeding `int_max_str_digits`, the limit. The result is not
+    /// to be used in that case, as it has a placeholder in place of the digits.
+    pub fn catch_int_str_digits_exceeded<R>(&self, format: impl FnOnce() -> R) -> (R, Option<usize>) {
+        let outer = self.catching_int_str_digits.replace(true);
+        let result = format();
+        self.catching_int_str_digits.set(outer);
+        // A nested call leaves the limit for the outermost one to report
+        let exceeded = if outer {
+            self.int_str_digits_exceeded.get()
+        } else {
+            self.int_str_digits_exceeded.take()
+        };
+        (result, exceeded)
+    }
+
+    /// Decrements the repr nesting depth.
+    ///
+    /// Called internally by `ReprDepthToken` — prefer releasing the token
+    /// rather than calling this directly.
+    #[inline]
+    fn decr_repr_depth(&self) {
+        let depth = self.repr_depth.get();
+        debug_assert!(depth > 0, "decr_repr_depth called when depth is 0");
+        self.repr_depth.set(depth - 1);
     }
 
     /// Decrements the recursion depth.
@@ -1191,8 +1483,17 @@ impl<T: ResourceTracker> Heap<T> {
             HashState::Unknown => {}
         }
 
-        // Handle Cell specially - uses identity-based hashing (like Python cell objects)
-        if let Some(HeapData::Cell(_)) = &entry.data {
+        // Handle Cell, Class, Instance, descriptors and hash objects specially - they use identity-based hashing
+        // (like Python objects without a `__hash__` override)
+        if let Some(
+            HeapData::Cell(_)
+            | HeapData::Class(_)
+            | HeapData::Instance(_)
+            | HeapData::Property(_)
+            | HeapData::MethodDescriptor(_)
+            | HeapData::Hash(_),
+        ) = &entry.data
+        {
             let mut hasher = DefaultHasher::new();
             id.hash(&mut hasher);
             let hash = hasher.finish();
@@ -1311,12 +1612,13 @@ impl<T: ResourceTracker> Heap<T> {
 
     /// Returns the reference count for the heap entry at the given ID.
     ///
-    /// This is primarily used for testing reference counting behavior.
+    /// Used for testing reference counting behavior, and by in-place string concatenation
+    /// to decide whether a buffer can be extended without other references observing it.
+    /// Still readable while the entry's data is borrowed via `with_entry_mut`.
     ///
     /// # Panics
     /// Panics if the value ID is invalid or the value has already been freed.
     #[must_use]
-    #[cfg(feature = "ref-count-return")]
     pub fn get_refcount(&self, id: HeapId) -> usize {
         self.entries
             .get(id.index())
@@ -1444,18 +1746,29 @@ impl<T: ResourceTracker> Heap<T> {
     pub fn mult_sequence(&mut self, id: HeapId, count: usize) -> RunResult<Option<Value>> {
         match self.get(id) {
             HeapData::Str(s) => {
+                if s.len().checked_mul(count).is_none() {
+                    return Err(ExcType::overflow_repeated_str().into());
+                }
                 check_repeat_size(s.len(), count, &self.tracker)?;
                 Ok(Some(Value::Ref(
                     self.allocate(HeapData::Str(s.as_str().repeat(count).into()))?,
                 )))
             }
             HeapData::Bytes(b) => {
+                if b.len().checked_mul(count).is_none() {
+                    return Err(ExcType::overflow_repeated_bytes().into());
+                }
                 check_repeat_size(b.len(), count, &self.tracker)?;
                 Ok(Some(Value::Ref(
                     self.allocate(HeapData::Bytes(b.as_slice().repeat(count).into()))?,
                 )))
             }
             HeapData::List(list) => {
+                // `[] * n` is always empty - skip the repeat loop, which would otherwise
+                // spin `n` times for an arbitrarily large `n`
+                if list.as_slice().is_empty() || count == 0 {
+                    return Ok(Some(Value::Ref(self.allocate(HeapData::List(List::new(Vec::new())))?)));
+                }
                 check_repeat_size(list.len().saturating_mul(size_of::<Value>()), count, &self.tracker)?;
                 let mut result = Vec::with_capacity(list.as_slice().len() * count);
                 for _ in 0..count {
@@ -1465,7 +1778,7 @@ impl<T: ResourceTracker> Heap<T> {
                 Ok(Some(Value::Ref(self.allocate(HeapData::List(List::new(result)))?)))
             }
             HeapData::Tuple(tuple) => {
-                if count == 0 {
+                if tuple.as_slice().is_empty() || count == 0 {
                     return Ok(Some(self.get_empty_tuple()));
                 }
                 check_repeat_size(
@@ -1585,11 +1898,14 @@ fn i64_to_repeat_count(n: i64) -> RunResult<usize> {
 
 /// Converts a `LongInt` repeat count to `usize` for sequence repetition.
 ///
-/// Returns 0 for negative values (Python treats negative repeat counts as 0).
-/// Returns `OverflowError` if the value exceeds `usize::MAX`.
+/// Returns 0 for negative values that fit in an `i64` (Python treats negative repeat
+/// counts as 0). Returns `OverflowError` if the value is outside the index-sized range
+/// in either direction, matching CPython.
 fn longint_to_repeat_count(li: &LongInt) -> RunResult<usize> {
     if li.is_negative() {
-        Ok(0)
+        li.to_i64()
+            .map(|_| 0)
+            .ok_or_else(|| ExcType::overflow_repeat_count().into())
     } else if let Some(count) = li.to_usize() {
         Ok(count)
     } else {
@@ -1607,7 +1923,8 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
         | HeapData::Exception(_)
         | HeapData::LongInt(_)
         | HeapData::Slice(_)
-        | HeapData::Path(_) => {}
+        | HeapData::Path(_)
+        | HeapData::Hash(_) => {}
         HeapData::List(list) => {
             // Skip iteration if no refs - major GC optimization for lists of primitives
             if !list.contains_refs() {
@@ -1706,6 +2023,46 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
                 }
             }
         }
+        HeapData::Class(class) => {
+            for (k, v) in class.attrs() {
+                if let Value::Ref(id) = k {
+                    work_list.push(*id);
+                }
+                if let Value::Ref(id) = v {
+                    work_list.push(*id);
+                }
+            }
+        }
+        HeapData::Instance(instance) => {
+            // Instances reference their class as well as their attribute values
+            work_list.push(instance.class_id());
+            for (k, v) in instance.attrs() {
+                if let Value::Ref(id) = k {
+                    work_list.push(*id);
+                }
+                if let Value::Ref(id) = v {
+                    work_list.push(*id);
+                }
+            }
+        }
+        HeapData::BoundMethod(method) => {
+            if let Value::Ref(id) = method.func() {
+                work_list.push(*id);
+            }
+            if let Value::Ref(id) = method.self_value() {
+                work_list.push(*id);
+            }
+        }
+        HeapData::Property(property) => {
+            if let Value::Ref(id) = property.getter() {
+                work_list.push(*id);
+            }
+        }
+        HeapData::MethodDescriptor(descriptor) => {
+            if let Value::Ref(id) = descriptor.func() {
+                work_list.push(*id);
+            }
+        }
         HeapData::Iter(iter) => {
             // Iterator holds a reference to the iterable being iterated
             if let Value::Ref(id) = iter.value() {
@@ -1760,7 +2117,14 @@ fn collect_child_ids(data: &HeapData, work_list: &mut Vec<HeapId>) {
 #[cfg(feature = "ref-count-panic")]
 impl<T: ResourceTracker> Drop for Heap<T> {
     fn drop(&mut self) {
-        // Mark all contained Objects as Dereferenced before dropping.
+        self.mark_all_dereferenced();
+    }
+}
+
+#[cfg(feature = "ref-count-panic")]
+impl<T: ResourceTracker> Heap<T> {
+    /// Marks all contained Objects as Dereferenced so the entries can be dropped.
+    fn mark_all_dereferenced(&mut self) {
         // We use py_dec_ref_ids for this since it handles the marking
         // (we ignore the collected IDs since we're dropping everything anyway).
         let mut dummy_stack = Vec::new();
@@ -1860,17 +2224,17 @@ impl<U: DropWithHeap, V: DropWithHeap> DropWithHeap for (U, V) {
 /// This is needed for cleanup in contexts that only have shared access to the heap,
 /// such as `py_repr_fmt` and `py_str` formatting methods.
 ///
-/// Currently implemented for [`RecursionToken`], which decrements the recursion depth
+/// Currently implemented for [`ReprDepthToken`], which decrements the repr depth
 /// counter via interior mutability (`Cell`).
 pub(crate) trait DropWithImmutableHeap {
     /// Consume `self` and perform cleanup using an immutable heap reference.
     fn drop_with_immutable_heap<T: ResourceTracker>(self, heap: &Heap<T>);
 }
 
-impl DropWithImmutableHeap for RecursionToken {
+impl DropWithImmutableHeap for ReprDepthToken {
     #[inline]
     fn drop_with_immutable_heap<T: ResourceTracker>(self, heap: &Heap<T>) {
-        heap.decr_recursion_depth();
+        heap.decr_repr_depth();
     }
 }
 
@@ -2050,7 +2414,7 @@ macro_rules! defer_drop_mut {
 ///
 /// Creates an [`ImmutableHeapGuard`] and immediately rebinds `$value` as `&V` and `$heap`
 /// as `&Heap<T>`. The guard will call [`DropWithImmutableHeap::drop_with_immutable_heap`]
-/// when scope exits. Use this for values like [`RecursionToken`] in contexts that only have
+/// when scope exits. Use this for values like [`ReprDepthToken`] in contexts that only have
 /// shared access to the heap (e.g., `py_repr_fmt` formatting methods).
 #[macro_export]
 macro_rules! defer_drop_immutable_heap {
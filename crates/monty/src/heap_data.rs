@@ -17,8 +17,9 @@ use crate::{
     heap::{Heap, HeapId},
     intern::{FunctionId, Interns},
     types::{
-        AttrCallResult, Bytes, Dataclass, Dict, FrozenSet, List, LongInt, Module, MontyIter, NamedTuple, Path, PyTrait,
-        Range, Set, Slice, Str, Tuple, Type,
+        AttrCallResult, BoundMethod, Bytes, ClassObject, Dataclass, Dict, FrozenSet, HashObject, Instance, List,
+        LongInt, MethodDescriptor, Module, MontyIter, NamedTuple, Path, PropertyObject, PyTrait, Range, Set, Slice,
+        Str, Tuple, Type,
     },
     value::{EitherStr, Value},
 };
@@ -95,6 +96,18 @@ pub(crate) enum HeapDataMut<'a> {
     /// Pure methods (name, parent, etc.) are handled directly by the VM.
     /// I/O methods (exists, read_text, etc.) yield external function calls.
     Path(&'a mut Path),
+    /// A hash object from `hashlib`.
+    Hash(&'a mut HashObject),
+    /// A class defined by a `class` statement in the script.
+    Class(&'a mut ClassObject),
+    /// An instance of a class defined in the script.
+    Instance(&'a mut Instance),
+    /// A function bound to a `self` value, e.g. `p.norm` for an instance `p`.
+    BoundMethod(&'a mut BoundMethod),
+    /// A `property` defined in the script.
+    Property(&'a mut PropertyObject),
+    /// A function wrapped by `staticmethod` or `classmethod`.
+    MethodDescriptor(&'a mut MethodDescriptor),
 }
 
 /// Thin wrapper around `Value` which is used in the `Cell` variant above.
@@ -244,12 +257,18 @@ impl HeapDataMut<'_> {
                 path.as_str().hash(&mut hasher);
                 Ok(Some(hasher.finish()))
             }
-            // Mutable types, exceptions, iterators, modules, and async types cannot be hashed
-            // (Cell is handled specially in get_or_compute_hash)
+            // Mutable types, exceptions, iterators, modules, bound methods, and async types cannot be hashed
+            // (Cell, Class, Instance, descriptors and hash objects are handled specially in get_or_compute_hash)
             Self::List(_)
             | Self::Dict(_)
             | Self::Set(_)
             | Self::Cell(_)
+            | Self::Class(_)
+            | Self::Instance(_)
+            | Self::BoundMethod(_)
+            | Self::Property(_)
+            | Self::MethodDescriptor(_)
+            | Self::Hash(_)
             | Self::Exception(_)
             | Self::Iter(_)
             | Self::Module(_)
@@ -288,6 +307,12 @@ impl PyTrait for HeapDataMut<'_> {
             Self::Module(_) => Type::Module,
             Self::Coroutine(_) | Self::GatherFuture(_) => Type::Coroutine,
             Self::Path(p) => p.py_type(heap),
+            Self::Hash(h) => h.py_type(heap),
+            Self::Class(c) => c.py_type(heap),
+            Self::Instance(i) => i.py_type(heap),
+            Self::BoundMethod(m) => m.py_type(heap),
+            Self::Property(p) => p.py_type(heap),
+            Self::MethodDescriptor(d) => d.py_type(heap),
         }
     }
 
@@ -323,6 +348,12 @@ impl PyTrait for HeapDataMut<'_> {
                     + gather.pending_calls.len() * std::mem::size_of::<crate::asyncio::CallId>()
             }
             Self::Path(p) => p.py_estimate_size(),
+            Self::Hash(h) => h.py_estimate_size(),
+            Self::Class(c) => c.py_estimate_size(),
+            Self::Instance(i) => i.py_estimate_size(),
+            Self::BoundMethod(m) => m.py_estimate_size(),
+            Self::Property(p) => p.py_estimate_size(),
+            Self::MethodDescriptor(d) => d.py_estimate_size(),
         }
     }
 
@@ -336,7 +367,7 @@ impl PyTrait for HeapDataMut<'_> {
             Self::Dict(d) => d.py_len(heap, interns),
             Self::Set(s) => s.py_len(heap, interns),
             Self::FrozenSet(fs) => fs.py_len(heap, interns),
-            Self::Range(r) => Some(r.len()),
+            Self::Range(r) => r.py_len(heap, interns),
             // Cells, Slices, Exceptions, Dataclasses, Iterators, LongInts, Modules, Paths, and async types don't have length
             Self::Cell(_)
             | Self::Closure(_)
@@ -349,7 +380,13 @@ impl PyTrait for HeapDataMut<'_> {
             | Self::Module(_)
             | Self::Coroutine(_)
             | Self::GatherFuture(_)
-            | Self::Path(_) => None,
+            | Self::Path(_)
+            | Self::Hash(_)
+            | Self::Class(_)
+            | Self::Instance(_)
+            | Self::BoundMethod(_)
+            | Self::Property(_)
+            | Self::MethodDescriptor(_) => None,
         }
     }
 
@@ -394,8 +431,15 @@ impl PyTrait for HeapDataMut<'_> {
             (Self::Slice(a), Self::Slice(b)) => a.py_eq(b, heap, interns),
             // Path equality
             (Self::Path(a), Self::Path(b)) => a.py_eq(b, heap, interns),
-            // Cells, Exceptions, Iterators, Modules, and async types compare by identity only (handled at Value level via HeapId comparison)
+            // Bound methods are equal if they bind the same function to the same object
+            (Self::BoundMethod(a), Self::BoundMethod(b)) => a.py_eq(b, heap, interns),
+            // Cells, Exceptions, Iterators, Modules, classes, instances, descriptors, and async types compare by identity only (handled at Value level via HeapId comparison)
             (Self::Cell(_), Self::Cell(_))
+            | (Self::Class(_), Self::Class(_))
+            | (Self::Instance(_), Self::Instance(_))
+            | (Self::Property(_), Self::Property(_))
+            | (Self::MethodDescriptor(_), Self::MethodDescriptor(_))
+            | (Self::Hash(_), Self::Hash(_))
             | (Self::Exception(_), Self::Exception(_))
             | (Self::Iter(_), Self::Iter(_))
             | (Self::Module(_), Self::Module(_))
@@ -453,8 +497,14 @@ impl PyTrait for HeapDataMut<'_> {
                     result.py_dec_ref_ids(stack);
                 }
             }
-            // Range, Slice, Exception, LongInt, and Path have no nested heap references
-            Self::Range(_) | Self::Slice(_) | Self::Exception(_) | Self::LongInt(_) | Self::Path(_) => {}
+            Self::Class(c) => c.py_dec_ref_ids(stack),
+            Self::Instance(i) => i.py_dec_ref_ids(stack),
+            Self::BoundMethod(m) => m.py_dec_ref_ids(stack),
+            Self::Property(p) => p.py_dec_ref_ids(stack),
+            Self::MethodDescriptor(d) => d.py_dec_ref_ids(stack),
+            // Range, Slice, Exception, LongInt, Path, and Hash have no nested heap references
+            Self::Range(_) | Self::Slice(_) | Self::Exception(_) | Self::LongInt(_) | Self::Path(_) | Self::Hash(_) => {
+            }
         }
     }
 
@@ -480,6 +530,13 @@ impl PyTrait for HeapDataMut<'_> {
             Self::Coroutine(_) => true,    // Coroutines are always truthy
             Self::GatherFuture(_) => true, // GatherFutures are always truthy
             Self::Path(p) => p.py_bool(heap, interns),
+            Self::Hash(h) => h.py_bool(heap, interns),
+            // Objects are always truthy
+            Self::Class(_)
+            | Self::Instance(_)
+            | Self::BoundMethod(_)
+            | Self::Property(_)
+            | Self::MethodDescriptor(_) => true,
         }
     }
 
@@ -505,7 +562,7 @@ impl PyTrait for HeapDataMut<'_> {
             Self::Cell(cell) => write!(f, "<cell: {} object>", cell.0.py_type(heap)),
             Self::Range(r) => r.py_repr_fmt(f, heap, heap_ids, interns),
             Self::Slice(s) => s.py_repr_fmt(f, heap, heap_ids, interns),
-            Self::Exception(e) => e.py_repr_fmt(f),
+            Self::Exception(e) => e.py_repr_fmt(f, interns),
             Self::Dataclass(dc) => dc.py_repr_fmt(f, heap, heap_ids, interns),
             Self::Iter(_) => write!(f, "<iterator>"),
             Self::LongInt(li) => write!(f, "{li}"),
@@ -517,6 +574,12 @@ impl PyTrait for HeapDataMut<'_> {
             }
             Self::GatherFuture(gather) => write!(f, "<gather({})>", gather.item_count()),
             Self::Path(p) => p.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Hash(h) => h.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Class(c) => c.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Instance(i) => i.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::BoundMethod(m) => m.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::Property(p) => p.py_repr_fmt(f, heap, heap_ids, interns),
+            Self::MethodDescriptor(d) => d.py_repr_fmt(f, heap, heap_ids, interns),
         }
     }
 
@@ -652,6 +715,7 @@ impl PyTrait for HeapDataMut<'_> {
             Self::FrozenSet(fs) => fs.py_call_attr(heap, attr, args, interns),
             Self::Dataclass(dc) => dc.py_call_attr(heap, attr, args, interns),
             Self::Path(p) => p.py_call_attr(heap, attr, args, interns),
+            Self::Hash(h) => h.py_call_attr(heap, attr, args, interns),
             _ => Err(ExcType::attribute_error(self.py_type(heap), attr.as_str(interns))),
         }
     }
@@ -709,6 +773,17 @@ impl PyTrait for HeapDataMut<'_> {
         }
     }
 
+    fn py_delitem(&mut self, key: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        match self {
+            Self::List(l) => l.py_delitem(key, heap, interns),
+            Self::Dict(d) => d.py_delitem(key, heap, interns),
+            _ => {
+                key.drop_with_heap(heap);
+                Err(ExcType::type_error_not_sub_deletion(self.py_type(heap)))
+            }
+        }
+    }
+
     fn py_getattr(
         &self,
         attr: &EitherStr,
@@ -722,6 +797,9 @@ impl PyTrait for HeapDataMut<'_> {
             Self::Slice(s) => s.py_getattr(attr, heap, interns),
             Self::Exception(exc) => exc.py_getattr(attr, heap, interns),
             Self::Path(p) => p.py_getattr(attr, heap, interns),
+            Self::Hash(h) => h.py_getattr(attr, heap, interns),
+            Self::Class(c) => c.py_getattr(attr, heap, interns),
+            Self::Instance(i) => i.py_getattr(attr, heap, interns),
             // All other types don't support attribute access via py_getattr
             _ => Ok(None),
         }
@@ -172,7 +172,9 @@ pub enum StaticStrings {
     Zfill,
     // Additional string methods
     Encode,
+    Format,
     Isidentifier,
+    Isprintable,
     Istitle,
 
     // ==========================
@@ -290,15 +292,98 @@ pub enum StaticStrings {
     Getenv,
     Environ,
     Default,
+    ReadLine,
+    ReadChunk,
+
+    // ==========================
+    // copy module strings (`copy` itself is shared with the `copy()` methods)
+    Deepcopy,
+
+    // ==========================
+    // time module strings
+    Time,
+    Monotonic,
+    Isoformat,
+    Fromisoformat,
+
+    // ==========================
+    // random module strings
+    Random,
+    Randint,
+    Choice,
+    Shuffle,
+    Seed,
+
+    // ==========================
+    // itertools module strings (`count` is shared with the `count()` methods)
+    Itertools,
+    Cycle,
+    Repeat,
+    Chain,
+    Islice,
+
+    // ==========================
+    // collections module strings
+    Collections,
+    #[strum(serialize = "Counter")]
+    Counter,
+    MostCommon,
+
+    // ==========================
+    // heapq module strings
+    Heapq,
+    Heappush,
+    Heappop,
+    Heapify,
+    Heappushpop,
+
+    // ==========================
+    // bisect module strings
+    Bisect,
+    BisectLeft,
+    BisectRight,
+    InsortLeft,
+    InsortRight,
+
+    // ==========================
+    // textwrap module strings
+    Textwrap,
+    Wrap,
+    Fill,
+
+    // ==========================
+    // base64 module strings
+    #[strum(serialize = "base64")]
+    Base64,
+    #[strum(serialize = "b64encode")]
+    B64encode,
+    #[strum(serialize = "b64decode")]
+    B64decode,
+
+    // ==========================
+    // hashlib module strings
+    Hashlib,
+    #[strum(serialize = "md5")]
+    Md5,
+    #[strum(serialize = "sha256")]
+    Sha256,
+    Digest,
+    Hexdigest,
+    DigestSize,
+    BlockSize,
 
     // ==========================
     // Exception attributes
     Args,
+    #[strum(serialize = "__cause__")]
+    DunderCause,
 
     // ==========================
-    // Type attributes
+    // Type and instance attributes
     #[strum(serialize = "__name__")]
     DunderName,
+    #[strum(serialize = "__dict__")]
+    DunderDict,
 
     // ==========================
     // pathlib module strings
@@ -6,6 +6,7 @@ mod args;
 mod asyncio;
 mod builtins;
 mod bytecode;
+mod compile_cache;
 mod exception_private;
 mod exception_public;
 mod expressions;
@@ -23,6 +24,7 @@ mod prepare;
 mod repl;
 mod resource;
 mod run;
+mod serialize;
 mod signature;
 mod sorting;
 mod types;
@@ -31,11 +33,15 @@ mod value;
 #[cfg(feature = "ref-count-return")]
 pub use crate::run::RefCountOutput;
 pub use crate::{
+    compile_cache::{CompileCacheStats, DEFAULT_COMPILE_CACHE_CAPACITY},
     exception_private::ExcType,
     exception_public::{CodeLoc, MontyException, StackFrame},
+    heap::HeapPool,
     io::{PrintWriter, PrintWriterCallback},
     object::{DictPairs, InvalidInputError, MontyObject},
     os::{OsFunction, dir_stat, file_stat, stat_result, symlink_stat},
+    parse::ParseLimits,
+    prepare::PrepareOptions,
     repl::{
         MontyRepl, ReplContinuationMode, ReplFutureSnapshot, ReplProgress, ReplSnapshot, ReplStartError,
         detect_repl_continuation_mode,
@@ -44,4 +50,8 @@ pub use crate::{
         DEFAULT_MAX_RECURSION_DEPTH, LimitedTracker, NoLimitTracker, ResourceError, ResourceLimits, ResourceTracker,
     },
     run::{ExternalResult, FutureSnapshot, MontyFuture, MontyRun, RunProgress, Snapshot},
+    serialize::{
+        LoadError, SERIALIZATION_FORMAT_VERSION, dump_json_versioned, dump_versioned, load_json_versioned,
+        load_versioned,
+    },
 };
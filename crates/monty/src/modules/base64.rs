@@ -0,0 +1,177 @@
+//! Implementation of the `base64` module.
+//!
+//! Provides standard base64 (RFC 4648, with `+`, `/` and `=` padding):
+//! - `b64encode(s)`: Encodes bytes, returning bytes
+//! - `b64decode(s)`: Decodes bytes or an ASCII string, returning bytes
+//!
+//! Decoding is always strict, as CPython's `b64decode(s, validate=True)` is: characters
+//! outside the alphabet raise `ValueError` instead of being discarded. Errors are plain
+//! `ValueError`s since there is no `binascii.Error`.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Bytes, Module, PyTrait},
+    value::Value,
+};
+
+/// Base64 module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum Base64Functions {
+    B64encode,
+    B64decode,
+}
+
+/// The standard base64 alphabet, indexed by 6-bit value.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Creates the `base64` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Base64);
+
+    for (name, function) in [
+        (StaticStrings::B64encode, Base64Functions::B64encode),
+        (StaticStrings::B64decode, Base64Functions::B64decode),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Base64(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a base64 module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: Base64Functions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let result = match functions {
+        Base64Functions::B64encode => {
+            let data = args.get_one_arg("b64encode", heap)?;
+            defer_drop!(data, heap);
+            let Some(bytes) = bytes_arg(data, heap, interns) else {
+                let type_name = data.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "a bytes-like object is required, not '{type_name}'"
+                )));
+            };
+            encode(bytes)
+        }
+        Base64Functions::B64decode => {
+            let data = args.get_one_arg("b64decode", heap)?;
+            defer_drop!(data, heap);
+            if let Some(string) = data.as_either_str(heap) {
+                let string = string.as_str(interns);
+                if !string.is_ascii() {
+                    return Err(ExcType::value_error(
+                        "string argument should contain only ASCII characters",
+                    ));
+                }
+                decode(string.as_bytes())?
+            } else if let Some(bytes) = bytes_arg(data, heap, interns) {
+                decode(bytes)?
+            } else {
+                let type_name = data.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "argument should be a bytes-like object or ASCII string, not '{type_name}'"
+                )));
+            }
+        }
+    };
+    let heap_id = heap.allocate(HeapData::Bytes(Bytes::new(result)))?;
+    Ok(AttrCallResult::Value(Value::Ref(heap_id)))
+}
+
+/// Returns the contents of a bytes value, or `None` for other types.
+fn bytes_arg<'a>(value: &Value, heap: &'a Heap<impl ResourceTracker>, interns: &'a Interns) -> Option<&'a [u8]> {
+    match value {
+        Value::InternBytes(id) => Some(interns.get_bytes(*id)),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Bytes(bytes) => Some(bytes.as_slice()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Encodes bytes as base64, padding the output to a multiple of 4 with `=`.
+fn encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        // A chunk of k bytes gives k + 1 characters, the rest is padding
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize]);
+            } else {
+                out.push(b'=');
+            }
+        }
+    }
+    out
+}
+
+/// Decodes strict base64, using CPython's `binascii` error messages.
+///
+/// # Errors
+/// Returns `ValueError` for characters outside the alphabet, data after padding,
+/// a data length which can't be decoded, or too little or too much padding.
+fn decode(data: &[u8]) -> RunResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len() / 4 * 3);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut data_chars = 0;
+    let mut padding = 0;
+    for &c in data {
+        if c == b'=' {
+            padding += 1;
+            continue;
+        }
+        let Some(value) = ALPHABET.iter().position(|&a| a == c) else {
+            return Err(ExcType::value_error("Only base64 data is allowed"));
+        };
+        if padding > 0 {
+            return Err(ExcType::value_error("Excess data after padding"));
+        }
+        data_chars += 1;
+        // Only the low bits which haven't been output yet matter, the rest can overflow away
+        acc = (acc << 6) | u32::try_from(value).expect("alphabet index fits in u32");
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push(u8::try_from((acc >> bits) & 0xFF).expect("masked to a byte"));
+        }
+    }
+
+    if data_chars % 4 == 1 {
+        return Err(ExcType::value_error(format!(
+            "Invalid base64-encoded string: number of data characters ({data_chars}) cannot be 1 more than a multiple of 4"
+        )));
+    }
+    let expected_padding = (4 - data_chars % 4) % 4;
+    if padding < expected_padding {
+        return Err(ExcType::value_error("Incorrect padding"));
+    } else if padding > expected_padding {
+        return Err(ExcType::value_error("Excess padding not allowed"));
+    }
+    Ok(out)
+}
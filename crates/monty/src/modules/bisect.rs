@@ -0,0 +1,219 @@
+//! Implementation of the `bisect` module.
+//!
+//! Provides binary search on sorted sequences:
+//! - `bisect_left(a, x, lo=0, hi=len(a))`: First index where `x` could be inserted, before equal items
+//! - `bisect_right(a, x, lo=0, hi=len(a))`: Last index where `x` could be inserted, after equal items
+//! - `insort_left(a, x, lo=0, hi=len(a))`: Inserts `x` into the list at `bisect_left(...)`
+//! - `insort_right(a, x, lo=0, hi=len(a))`: Inserts `x` into the list at `bisect_right(...)`
+//!
+//! `a` can be a list or tuple for the searches, and must be a list for the inserts.
+//! Items are compared with `<` only. There is no `key` argument, and `hi` is clamped
+//! to `len(a)` rather than raising `IndexError` when too large.
+
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
+    heap_data::HeapDataMut,
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    sorting::py_lt,
+    types::{AttrCallResult, Module, PyTrait},
+    value::Value,
+};
+
+/// Bisect module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum BisectFunctions {
+    BisectLeft,
+    BisectRight,
+    InsortLeft,
+    InsortRight,
+}
+
+/// Creates the `bisect` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Bisect);
+
+    for (name, function) in [
+        (StaticStrings::BisectLeft, BisectFunctions::BisectLeft),
+        (StaticStrings::BisectRight, BisectFunctions::BisectRight),
+        (StaticStrings::InsortLeft, BisectFunctions::InsortLeft),
+        (StaticStrings::InsortRight, BisectFunctions::InsortRight),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Bisect(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a bisect module function.
+pub(super) fn call(
+    vm: &mut VM<'_, '_, impl ResourceTracker>,
+    functions: BisectFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let (name, right, insert) = match functions {
+        BisectFunctions::BisectLeft => ("bisect_left", false, false),
+        BisectFunctions::BisectRight => ("bisect_right", true, false),
+        BisectFunctions::InsortLeft => ("insort_left", false, true),
+        BisectFunctions::InsortRight => ("insort_right", true, true),
+    };
+    let (seq, x, lo, hi) = bisect_args(name, args, vm.heap, vm.interns)?;
+    defer_drop!(seq, vm);
+
+    let id = match seq {
+        Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::List(_)) => *id,
+        Value::Ref(id) if !insert && matches!(vm.heap.get(*id), HeapData::Tuple(_)) => *id,
+        _ => {
+            let type_name = seq.py_type(vm.heap);
+            x.drop_with_heap(vm.heap);
+            return Err(if insert {
+                ExcType::attribute_error(type_name, "insert")
+            } else {
+                ExcType::type_error(format!("object of type '{type_name}' has no len()"))
+            });
+        }
+    };
+
+    let index = match search(id, &x, lo, hi, right, vm) {
+        Ok(index) => index,
+        Err(e) => {
+            x.drop_with_heap(vm.heap);
+            return Err(e);
+        }
+    };
+
+    let result = if insert {
+        vm.heap.with_entry_mut(id, |heap, data| {
+            let HeapDataMut::List(list) = data else {
+                unreachable!("heap entry changed type")
+            };
+            list.insert(heap, index, x);
+        });
+        Value::None
+    } else {
+        x.drop_with_heap(vm.heap);
+        Value::Int(i64::try_from(index).expect("index fits in i64"))
+    };
+    Ok(AttrCallResult::Value(result))
+}
+
+/// Returns the index in `seq[lo..hi]` where `x` would be inserted to keep it sorted,
+/// before any equal items, or after them if `right` is true.
+///
+/// Like CPython, each item is looked up as it's compared, so a comparison calling `__lt__`
+/// can use the sequence, and an `IndexError` is raised if it has shrunk meanwhile.
+fn search(
+    seq_id: HeapId,
+    x: &Value,
+    mut lo: usize,
+    hi: Option<usize>,
+    right: bool,
+    vm: &mut VM<'_, '_, impl ResourceTracker>,
+) -> RunResult<usize> {
+    let len = items(vm.heap, seq_id).len();
+    let mut hi = hi.unwrap_or(len).min(len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let Some(item) = items(vm.heap, seq_id).get(mid) else {
+            return Err(ExcType::list_index_error());
+        };
+        let item = item.clone_with_heap(vm.heap);
+        defer_drop!(item, vm);
+        let go_left = if right { py_lt(x, item, vm)? } else { !py_lt(item, x, vm)? };
+        if go_left {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Ok(lo)
+}
+
+/// The items of the list or tuple `seq_id`.
+fn items(heap: &Heap<impl ResourceTracker>, seq_id: HeapId) -> &[Value] {
+    match heap.get(seq_id) {
+        HeapData::List(list) => list.as_slice(),
+        HeapData::Tuple(tuple) => tuple.as_slice(),
+        _ => unreachable!("bisect sequence changed type"),
+    }
+}
+
+/// Parses the `(a, x, lo=0, hi=None)` arguments shared by all bisect functions.
+///
+/// Returns `a` and `x`, which the caller owns, along with the bounds.
+fn bisect_args(
+    name: &str,
+    args: ArgValues,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<(Value, Value, usize, Option<usize>)> {
+    let (pos, kwargs) = args.into_parts();
+    let pos: Vec<Value> = pos.collect();
+    let kwargs: Vec<(Value, Value)> = kwargs.into_iter().collect();
+    let mut args_guard = HeapGuard::new((pos, kwargs), heap);
+    let ((pos, kwargs), heap) = args_guard.as_parts();
+
+    if pos.len() < 2 {
+        return Err(ExcType::type_error_at_least(name, 2, pos.len()));
+    } else if pos.len() > 4 {
+        return Err(ExcType::type_error_at_most(name, 4, pos.len()));
+    }
+    let mut lo = pos.get(2);
+    let mut hi = pos.get(3);
+    for (key, value) in kwargs {
+        let Some(keyword) = key.as_either_str(heap) else {
+            return Err(ExcType::type_error("keywords must be strings"));
+        };
+        let keyword = keyword.as_str(interns);
+        let slot = match keyword {
+            "lo" => &mut lo,
+            "hi" => &mut hi,
+            _ => {
+                return Err(ExcType::type_error(format!(
+                    "'{keyword}' is an invalid keyword argument for {name}()"
+                )));
+            }
+        };
+        if slot.is_some() {
+            return Err(ExcType::type_error(format!(
+                "{name}() got multiple values for argument '{keyword}'"
+            )));
+        }
+        *slot = Some(value);
+    }
+
+    let lo = match lo {
+        Some(lo) => lo.as_int(heap)?,
+        None => 0,
+    };
+    let lo = usize::try_from(lo).map_err(|_| ExcType::value_error("lo must be non-negative"))?;
+    let hi = match hi {
+        None | Some(Value::None) => None,
+        // A negative `hi` gives an empty range, so the result is `lo`
+        Some(hi) => Some(usize::try_from(hi.as_int(heap)?).unwrap_or(0)),
+    };
+
+    let ((mut pos, kwargs), heap) = args_guard.into_parts();
+    kwargs.drop_with_heap(heap);
+    pos.split_off(2).drop_with_heap(heap);
+    let x = pos.pop().expect("argument count checked above");
+    let seq = pos.pop().expect("argument count checked above");
+    Ok((seq, x, lo, hi))
+}
@@ -0,0 +1,108 @@
+//! Implementation of the `collections` module.
+//!
+//! Provides:
+//! - `Counter([iterable_or_mapping])`: A dict counting how often each element occurs
+//!
+//! Counters are dicts flagged with `Dict::is_counter`, see `Dict` for how they differ.
+
+use crate::{
+    args::ArgValues,
+    defer_drop_mut,
+    exception_private::RunResult,
+    heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
+    heap_data::HeapDataMut,
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Dict, Module, MontyIter},
+    value::Value,
+};
+
+/// Collections module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+pub(crate) enum CollectionsFunctions {
+    Counter,
+}
+
+/// Creates the `collections` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Collections);
+
+    module.set_attr(
+        StaticStrings::Counter,
+        Value::ModuleFunction(ModuleFunctions::Collections(CollectionsFunctions::Counter)),
+        heap,
+        interns,
+    );
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a collections module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: CollectionsFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    match functions {
+        CollectionsFunctions::Counter => {
+            let source = args.get_zero_one_arg("Counter", heap)?;
+            let counter = match source {
+                None | Some(Value::None) => {
+                    let mut counter = Dict::new();
+                    counter.set_counter(true);
+                    counter
+                }
+                Some(source) => counter_from(source, heap, interns)?,
+            };
+            let heap_id = heap.allocate(HeapData::Dict(counter))?;
+            Ok(AttrCallResult::Value(Value::Ref(heap_id)))
+        }
+    }
+}
+
+/// Builds a counter from a mapping of elements to counts, or by counting an iterable's items.
+fn counter_from(source: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Dict> {
+    if let Value::Ref(id) = source
+        && matches!(heap.get(id), HeapData::Dict(_))
+    {
+        let counter = heap.with_entry_mut(id, |heap, data| {
+            let HeapDataMut::Dict(mapping) = data else {
+                unreachable!("heap entry changed type")
+            };
+            mapping.shallow_copy(heap, interns)
+        });
+        source.drop_with_heap(heap);
+        let mut counter = counter?;
+        counter.set_counter(true);
+        return Ok(counter);
+    }
+
+    let iter = MontyIter::new(source, heap, interns)?;
+    defer_drop_mut!(iter, heap);
+    let mut counter = Dict::new();
+    counter.set_counter(true);
+    let mut counter_guard = HeapGuard::new(counter, heap);
+    let (counter, heap) = counter_guard.as_parts_mut();
+    while let Some(item) = iter.for_next(heap, interns)? {
+        let count = match counter.get(&item, heap, interns) {
+            Ok(Some(Value::Int(count))) => *count,
+            Ok(_) => 0,
+            Err(e) => {
+                item.drop_with_heap(heap);
+                return Err(e);
+            }
+        };
+        if let Some(old_count) = counter.set(item, Value::Int(count + 1), heap, interns)? {
+            old_count.drop_with_heap(heap);
+        }
+    }
+    Ok(counter_guard.into_inner())
+}
@@ -0,0 +1,281 @@
+//! Implementation of the `copy` module.
+//!
+//! Provides:
+//! - `copy(x)`: Shallow copy - a new container sharing the original's items
+//! - `deepcopy(x)`: Recursive copy of nested containers, preserving shared and
+//!   self references
+//!
+//! As in CPython, immutable values (ints, strings, bytes, frozensets, ...) are returned
+//! as-is rather than copied, and so are tuples whose items all deep-copy to themselves.
+
+use ahash::AHashMap;
+use itertools::Itertools;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::RunResult,
+    heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
+    heap_data::HeapDataMut,
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Dict, List, Module, allocate_tuple},
+    value::Value,
+};
+
+/// Copy module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum CopyFunctions {
+    Copy,
+    Deepcopy,
+}
+
+/// Maps the heap id of each object already deep-copied to the id of its copy.
+///
+/// Consulted before copying any heap object so that an object reachable through several
+/// paths is copied once, and a self-referential structure terminates.
+type Memo = AHashMap<HeapId, HeapId>;
+
+/// Creates the `copy` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Copy);
+
+    module.set_attr(
+        StaticStrings::Copy,
+        Value::ModuleFunction(ModuleFunctions::Copy(CopyFunctions::Copy)),
+        heap,
+        interns,
+    );
+    module.set_attr(
+        StaticStrings::Deepcopy,
+        Value::ModuleFunction(ModuleFunctions::Copy(CopyFunctions::Deepcopy)),
+        heap,
+        interns,
+    );
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a copy module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: CopyFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let result = match functions {
+        CopyFunctions::Copy => {
+            let value = args.get_one_arg("copy.copy", heap)?;
+            defer_drop!(value, heap);
+            shallow_copy(value, heap, interns)?
+        }
+        CopyFunctions::Deepcopy => {
+            let value = args.get_one_arg("copy.deepcopy", heap)?;
+            defer_drop!(value, heap);
+            deep_copy(value, &mut Memo::new(), heap, interns)?
+        }
+    };
+    Ok(AttrCallResult::Value(result))
+}
+
+/// Implementation of `copy.copy(x)`.
+///
+/// Lists, dicts, sets and dataclass instances get a new container whose items are
+/// shared with the original. Everything else is immutable and returned as-is.
+fn shallow_copy(value: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let Value::Ref(id) = value else {
+        return Ok(value.clone_with_heap(heap));
+    };
+    let data = match heap.get(*id) {
+        HeapData::List(list) => {
+            let items = list.as_slice().iter().map(|v| v.clone_with_heap(heap)).collect();
+            HeapData::List(List::new(items))
+        }
+        HeapData::Set(set) => HeapData::Set(set.copy(heap)),
+        HeapData::Dict(dict) => {
+            let is_counter = dict.is_counter();
+            let pairs = clone_pairs(dict, heap);
+            let mut copy = Dict::from_pairs(pairs, heap, interns)?;
+            copy.set_counter(is_counter);
+            HeapData::Dict(copy)
+        }
+        HeapData::Dataclass(dc) => {
+            let pairs = clone_pairs(dc.attrs(), heap);
+            let attrs = Dict::from_pairs(pairs, heap, interns)?;
+            let HeapData::Dataclass(dc) = heap.get(*id) else {
+                unreachable!("heap entry changed type during copy")
+            };
+            HeapData::Dataclass(dc.with_attrs(attrs))
+        }
+        _ => return Ok(value.clone_with_heap(heap)),
+    };
+    Ok(Value::Ref(heap.allocate(data)?))
+}
+
+/// Implementation of `copy.deepcopy(x)`.
+///
+/// Mutable containers are copied recursively. Each one is allocated before its items
+/// are copied and recorded in `memo`, so references back to it (including from itself)
+/// resolve to the copy. Sets are copied shallowly: their elements are hashable and so
+/// immutable, which makes sharing them indistinguishable from copying them.
+fn deep_copy(
+    value: &Value,
+    memo: &mut Memo,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let Value::Ref(id) = value else {
+        return Ok(value.clone_with_heap(heap));
+    };
+    if let Some(&copy_id) = memo.get(id) {
+        heap.inc_ref(copy_id);
+        return Ok(Value::Ref(copy_id));
+    }
+
+    let token = heap.incr_recursion_depth()?;
+    defer_drop!(token, heap);
+
+    match heap.get(*id) {
+        HeapData::List(list) => {
+            let originals: Vec<Value> = list.as_slice().iter().map(|v| v.clone_with_heap(heap)).collect();
+            defer_drop!(originals, heap);
+            let copy_id = heap.allocate(HeapData::List(List::new(Vec::new())))?;
+            memo.insert(*id, copy_id);
+            let mut copy_guard = HeapGuard::new(Value::Ref(copy_id), heap);
+            let heap = copy_guard.heap();
+
+            let items = deep_copy_all(originals, memo, heap, interns)?;
+            heap.with_entry_mut(copy_id, |heap, data| {
+                let HeapDataMut::List(copy) = data else {
+                    unreachable!("deepcopy placeholder is not a list")
+                };
+                for item in items {
+                    copy.append(heap, item);
+                }
+            });
+            Ok(copy_guard.into_inner())
+        }
+        HeapData::Tuple(tuple) => {
+            let originals: Vec<Value> = tuple.as_slice().iter().map(|v| v.clone_with_heap(heap)).collect();
+            defer_drop!(originals, heap);
+            let items = deep_copy_all(originals, memo, heap, interns)?;
+
+            // A cycle back through a mutable item may already have copied this tuple
+            if let Some(&copy_id) = memo.get(id) {
+                items.drop_with_heap(heap);
+                heap.inc_ref(copy_id);
+                return Ok(Value::Ref(copy_id));
+            }
+            if items.iter().zip(originals).all(|(copy, original)| copy.is(original)) {
+                items.drop_with_heap(heap);
+                return Ok(value.clone_with_heap(heap));
+            }
+            let copy = allocate_tuple(items.into(), heap)?;
+            if let Value::Ref(copy_id) = copy {
+                memo.insert(*id, copy_id);
+            }
+            Ok(copy)
+        }
+        HeapData::Dict(dict) => {
+            let is_counter = dict.is_counter();
+            let originals = flatten_pairs(dict, heap);
+            defer_drop!(originals, heap);
+            let copy_id = heap.allocate(HeapData::Dict(Dict::new()))?;
+            memo.insert(*id, copy_id);
+            let mut copy_guard = HeapGuard::new(Value::Ref(copy_id), heap);
+            let heap = copy_guard.heap();
+
+            let mut dict = deep_copy_dict(originals, memo, heap, interns)?;
+            dict.set_counter(is_counter);
+            heap.with_entry_mut(copy_id, |heap, data| {
+                let HeapDataMut::Dict(copy) = data else {
+                    unreachable!("deepcopy placeholder is not a dict")
+                };
+                if dict.has_refs() {
+                    heap.mark_potential_cycle();
+                }
+                *copy = dict;
+            });
+            Ok(copy_guard.into_inner())
+        }
+        HeapData::Dataclass(dc) => {
+            let placeholder = dc.with_attrs(Dict::new());
+            let originals = flatten_pairs(dc.attrs(), heap);
+            defer_drop!(originals, heap);
+            let copy_id = heap.allocate(HeapData::Dataclass(placeholder))?;
+            memo.insert(*id, copy_id);
+            let mut copy_guard = HeapGuard::new(Value::Ref(copy_id), heap);
+            let heap = copy_guard.heap();
+
+            let attrs = deep_copy_dict(originals, memo, heap, interns)?;
+            heap.with_entry_mut(copy_id, |heap, data| {
+                let HeapDataMut::Dataclass(copy) = data else {
+                    unreachable!("deepcopy placeholder is not a dataclass")
+                };
+                if attrs.has_refs() {
+                    heap.mark_potential_cycle();
+                }
+                *copy = copy.with_attrs(attrs);
+            });
+            Ok(copy_guard.into_inner())
+        }
+        HeapData::Set(set) => {
+            let copy = set.copy(heap);
+            let copy_id = heap.allocate(HeapData::Set(copy))?;
+            memo.insert(*id, copy_id);
+            Ok(Value::Ref(copy_id))
+        }
+        _ => Ok(value.clone_with_heap(heap)),
+    }
+}
+
+/// Deep-copies each of `values` in order, dropping the copies made so far if one fails.
+fn deep_copy_all(
+    values: &[Value],
+    memo: &mut Memo,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Vec<Value>> {
+    let mut copies_guard = HeapGuard::new(Vec::with_capacity(values.len()), heap);
+    let (copies, heap) = copies_guard.as_parts_mut();
+    for value in values {
+        heap.check_time()?;
+        copies.push(deep_copy(value, memo, heap, interns)?);
+    }
+    Ok(copies_guard.into_inner())
+}
+
+/// Deep-copies the flattened `[key, value, key, value, ...]` entries of a dict into a new dict.
+///
+/// Keys are copied before their values, matching the order CPython visits them in.
+fn deep_copy_dict(
+    entries: &[Value],
+    memo: &mut Memo,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Dict> {
+    let copies = deep_copy_all(entries, memo, heap, interns)?;
+    Dict::from_pairs(copies.into_iter().tuples().collect(), heap, interns)
+}
+
+/// Clones the entries of `dict` as owned `(key, value)` pairs.
+fn clone_pairs(dict: &Dict, heap: &Heap<impl ResourceTracker>) -> Vec<(Value, Value)> {
+    dict.iter()
+        .map(|(k, v)| (k.clone_with_heap(heap), v.clone_with_heap(heap)))
+        .collect()
+}
+
+/// Clones the entries of `dict` as a flat `[key, value, key, value, ...]` vec.
+fn flatten_pairs(dict: &Dict, heap: &Heap<impl ResourceTracker>) -> Vec<Value> {
+    dict.iter()
+        .flat_map(|(k, v)| [k.clone_with_heap(heap), v.clone_with_heap(heap)])
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Implementation of the `hashlib` module.
+//!
+//! Provides hash object constructors:
+//! - `md5(data=b'')`: An MD5 hash object, optionally fed `data`
+//! - `sha256(data=b'')`: A SHA-256 hash object, optionally fed `data`
+//!
+//! The returned objects support `update()`, `digest()`, `hexdigest()` and `copy()`, see
+//! `HashObject`. Data must be bytes, `str` is rejected as in CPython. The data argument
+//! is positional only.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::RunResult,
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, HashAlgorithm, HashObject, Module, hash::hash_data_arg},
+    value::Value,
+};
+
+/// Hashlib module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum HashlibFunctions {
+    Md5,
+    Sha256,
+}
+
+/// Creates the `hashlib` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Hashlib);
+
+    for (name, function) in [
+        (StaticStrings::Md5, HashlibFunctions::Md5),
+        (StaticStrings::Sha256, HashlibFunctions::Sha256),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Hashlib(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a hashlib module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: HashlibFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let (name, algorithm) = match functions {
+        HashlibFunctions::Md5 => ("md5", HashAlgorithm::Md5),
+        HashlibFunctions::Sha256 => ("sha256", HashAlgorithm::Sha256),
+    };
+    let data = args.get_zero_one_arg(name, heap)?;
+    defer_drop!(data, heap);
+    let mut hash = HashObject::new(algorithm);
+    if let Some(data) = data {
+        hash.update(hash_data_arg(data, heap, interns)?);
+    }
+    let heap_id = heap.allocate(HeapData::Hash(hash))?;
+    Ok(AttrCallResult::Value(Value::Ref(heap_id)))
+}
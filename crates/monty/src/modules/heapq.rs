@@ -0,0 +1,206 @@
+//! Implementation of the `heapq` module.
+//!
+//! Provides heap queue functions operating in place on a list used as a binary min-heap,
+//! where `heap[k] <= heap[2*k+1]` and `heap[k] <= heap[2*k+2]`:
+//! - `heappush(heap, item)`: Pushes `item` onto the heap
+//! - `heappop(heap)`: Pops and returns the smallest item
+//! - `heapify(list)`: Turns a list into a heap
+//! - `heappushpop(heap, item)`: Pushes `item`, then pops and returns the smallest item
+//!
+//! Items are compared with `<` only and the sift algorithms mirror CPython's, so heaps
+//! end up in exactly the same order as in CPython.
+
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    sorting::py_lt,
+    types::{
+        AttrCallResult, Module, PyTrait,
+        list::{restore_list_items, take_list_items},
+    },
+    value::Value,
+};
+
+/// Heapq module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum HeapqFunctions {
+    Heappush,
+    Heappop,
+    Heapify,
+    Heappushpop,
+}
+
+/// Creates the `heapq` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Heapq);
+
+    for (name, function) in [
+        (StaticStrings::Heappush, HeapqFunctions::Heappush),
+        (StaticStrings::Heappop, HeapqFunctions::Heappop),
+        (StaticStrings::Heapify, HeapqFunctions::Heapify),
+        (StaticStrings::Heappushpop, HeapqFunctions::Heappushpop),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Heapq(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a heapq module function.
+pub(super) fn call(
+    vm: &mut VM<'_, '_, impl ResourceTracker>,
+    functions: HeapqFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let result = match functions {
+        HeapqFunctions::Heappush => {
+            let (list, item) = args.get_two_args("heappush", vm.heap)?;
+            defer_drop!(list, vm);
+            with_list_items(list, "heappush() argument 1", item, vm, |items, item, vm| {
+                items.push(item);
+                sift_down(items, 0, items.len() - 1, vm)?;
+                Ok(Value::None)
+            })?
+        }
+        HeapqFunctions::Heappop => {
+            let list = args.get_one_arg("heappop", vm.heap)?;
+            defer_drop!(list, vm);
+            with_list_items(list, "heappop() argument", Value::None, vm, |items, _, vm| {
+                let Some(mut last) = items.pop() else {
+                    return Err(SimpleException::new_msg(ExcType::IndexError, "index out of range").into());
+                };
+                if let Some(first) = items.first_mut() {
+                    std::mem::swap(first, &mut last);
+                    if let Err(e) = sift_up(items, 0, vm) {
+                        last.drop_with_heap(vm.heap);
+                        return Err(e);
+                    }
+                }
+                Ok(last)
+            })?
+        }
+        HeapqFunctions::Heapify => {
+            let list = args.get_one_arg("heapify", vm.heap)?;
+            defer_drop!(list, vm);
+            with_list_items(list, "heapify() argument", Value::None, vm, |items, _, vm| {
+                for pos in (0..items.len() / 2).rev() {
+                    sift_up(items, pos, vm)?;
+                }
+                Ok(Value::None)
+            })?
+        }
+        HeapqFunctions::Heappushpop => {
+            let (list, item) = args.get_two_args("heappushpop", vm.heap)?;
+            defer_drop!(list, vm);
+            with_list_items(list, "heappushpop() argument 1", item, vm, |items, mut item, vm| {
+                // The item is returned straight away if it is no bigger than the smallest item
+                if let Some(first) = items.first() {
+                    let result = py_lt(first, &item, vm).and_then(|less| {
+                        if less {
+                            std::mem::swap(&mut items[0], &mut item);
+                            sift_up(items, 0, vm)?;
+                        }
+                        Ok(())
+                    });
+                    if let Err(e) = result {
+                        item.drop_with_heap(vm.heap);
+                        return Err(e);
+                    }
+                }
+                Ok(item)
+            })?
+        }
+    };
+    Ok(AttrCallResult::Value(result))
+}
+
+/// Runs `f` on the items of the list `value`, which are moved out of the list meanwhile
+/// so comparisons calling `__lt__` can use the list.
+///
+/// `item` is passed through to `f`, which takes ownership of it.
+///
+/// # Errors
+/// Returns `TypeError` naming the argument as `arg` if `value` isn't a list, dropping `item`,
+/// and `RuntimeError` if the list was changed by a comparison, in which case the changes are
+/// dropped in favour of the items.
+fn with_list_items<T: ResourceTracker>(
+    value: &Value,
+    arg: &str,
+    item: Value,
+    vm: &mut VM<'_, '_, T>,
+    f: impl FnOnce(&mut Vec<Value>, Value, &mut VM<'_, '_, T>) -> RunResult<Value>,
+) -> RunResult<Value> {
+    let id = match value {
+        Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::List(_)) => *id,
+        _ => {
+            let type_name = value.py_type(vm.heap);
+            item.drop_with_heap(vm.heap);
+            return Err(ExcType::type_error(format!("{arg} must be list, not {type_name}")));
+        }
+    };
+    let mut items = take_list_items(vm.heap, id);
+    let result = f(&mut items, item, vm);
+    if restore_list_items(vm.heap, id, items) {
+        result
+    } else {
+        result?.drop_with_heap(vm.heap);
+        Err(SimpleException::new_msg(ExcType::RuntimeError, "list changed size during iteration").into())
+    }
+}
+
+/// Moves the item at `pos` up towards `start` until its parent is no bigger,
+/// like CPython's `heapq._siftdown`.
+fn sift_down(
+    items: &mut [Value],
+    start: usize,
+    mut pos: usize,
+    vm: &mut VM<'_, '_, impl ResourceTracker>,
+) -> RunResult<()> {
+    while pos > start {
+        let parent = (pos - 1) / 2;
+        if !py_lt(&items[pos], &items[parent], vm)? {
+            break;
+        }
+        items.swap(pos, parent);
+        pos = parent;
+    }
+    Ok(())
+}
+
+/// Moves the item at `pos` down to a leaf along the path of smaller children, then back up
+/// to its place, like CPython's `heapq._siftup`.
+///
+/// This takes fewer comparisons than stopping as soon as both children are bigger, since
+/// items sifted down this way usually came from the bottom of the heap.
+fn sift_up(items: &mut [Value], mut pos: usize, vm: &mut VM<'_, '_, impl ResourceTracker>) -> RunResult<()> {
+    let start = pos;
+    let mut child = 2 * pos + 1;
+    while child < items.len() {
+        let right = child + 1;
+        if right < items.len() && !py_lt(&items[child], &items[right], vm)? {
+            child = right;
+        }
+        items.swap(pos, child);
+        pos = child;
+        child = 2 * pos + 1;
+    }
+    sift_down(items, start, pos, vm)
+}
@@ -0,0 +1,207 @@
+//! Implementation of the `itertools` module.
+//!
+//! Provides lazy iterators built on `MontyIter`:
+//! - `count(start=0, step=1)`: `start`, `start + step`, ... forever (ints only)
+//! - `cycle(iterable)`: The items of `iterable` over and over
+//! - `repeat(obj[, times])`: `obj`, `times` times or forever
+//! - `chain(*iterables)`: The items of each iterable in turn
+//! - `islice(iterable, stop)` / `islice(iterable, start, stop[, step])`: A slice of `iterable`
+//!
+//! Arguments are positional only. Collecting an infinite iterator, e.g. `list(count())`,
+//! raises `MemoryError` rather than running until memory runs out.
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, List, Module, MontyIter, allocate_tuple},
+    value::Value,
+};
+
+/// Itertools module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum ItertoolsFunctions {
+    Count,
+    Cycle,
+    Repeat,
+    Chain,
+    Islice,
+}
+
+/// Creates the `itertools` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Itertools);
+
+    for (name, function) in [
+        (StaticStrings::Count, ItertoolsFunctions::Count),
+        (StaticStrings::Cycle, ItertoolsFunctions::Cycle),
+        (StaticStrings::Repeat, ItertoolsFunctions::Repeat),
+        (StaticStrings::Chain, ItertoolsFunctions::Chain),
+        (StaticStrings::Islice, ItertoolsFunctions::Islice),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Itertools(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to an itertools module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: ItertoolsFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let iter = match functions {
+        ItertoolsFunctions::Count => {
+            let (start, step) = args.get_zero_one_two_args("count", heap)?;
+            defer_drop!(start, heap);
+            defer_drop!(step, heap);
+            let start = start.as_ref().map_or(Ok(0), |start| count_arg(start, heap))?;
+            let step = step.as_ref().map_or(Ok(1), |step| count_arg(step, heap))?;
+            MontyIter::new_count(start, step)
+        }
+        ItertoolsFunctions::Cycle => {
+            let iterable = args.get_one_arg("cycle", heap)?;
+            let items: Vec<Value> = MontyIter::new(iterable, heap, interns)?.collect(heap, interns)?;
+            let list = Value::Ref(heap.allocate(HeapData::List(List::new(items)))?);
+            MontyIter::new_cycle(list)?
+        }
+        ItertoolsFunctions::Repeat => {
+            let (value, times) = args.get_one_two_args("repeat", heap)?;
+            let times = match times {
+                None => None,
+                Some(times) => {
+                    defer_drop!(times, heap);
+                    match times.as_int(heap) {
+                        // A negative count repeats nothing, as in CPython
+                        Ok(times) => Some(usize::try_from(times.max(0)).unwrap_or(usize::MAX)),
+                        Err(e) => {
+                            value.drop_with_heap(heap);
+                            return Err(e);
+                        }
+                    }
+                }
+            };
+            MontyIter::new_repeat(value, times)
+        }
+        ItertoolsFunctions::Chain => {
+            let iterables = args.into_pos_only("chain", heap)?;
+            defer_drop_mut!(iterables, heap);
+            let mut iterators = Vec::with_capacity(iterables.len());
+            for iterable in iterables {
+                match to_iterator(iterable, heap, interns) {
+                    Ok(iterator) => iterators.push(iterator),
+                    Err(e) => {
+                        iterators.drop_with_heap(heap);
+                        return Err(e);
+                    }
+                }
+            }
+            MontyIter::new_chain(allocate_tuple(iterators.into(), heap)?)?
+        }
+        ItertoolsFunctions::Islice => {
+            let mut args: Vec<Value> = args.into_pos_only("islice", heap)?.collect();
+            if !(2..=4).contains(&args.len()) {
+                let count = args.len();
+                args.drop_with_heap(heap);
+                return Err(if count < 2 {
+                    ExcType::type_error_at_least("islice", 2, count)
+                } else {
+                    ExcType::type_error_at_most("islice", 4, count)
+                });
+            }
+            let iterable = args.remove(0);
+            let bounds = islice_bounds(&args);
+            args.drop_with_heap(heap);
+            let (start, stop, step) = match bounds {
+                Ok(bounds) => bounds,
+                Err(e) => {
+                    iterable.drop_with_heap(heap);
+                    return Err(e);
+                }
+            };
+            MontyIter::new_islice(to_iterator(iterable, heap, interns)?, start, stop, step)?
+        }
+    };
+    let heap_id = heap.allocate(HeapData::Iter(iter))?;
+    Ok(AttrCallResult::Value(Value::Ref(heap_id)))
+}
+
+/// Returns `value` if it is already an iterator, otherwise a new iterator over it.
+fn to_iterator(value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    if let Value::Ref(id) = value
+        && matches!(heap.get(id), HeapData::Iter(_))
+    {
+        return Ok(value);
+    }
+    let iter = MontyIter::new(value, heap, interns)?;
+    Ok(Value::Ref(heap.allocate(HeapData::Iter(iter))?))
+}
+
+/// Extracts a `count()` start or step, which unlike CPython must be an int.
+fn count_arg(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<i64> {
+    match value {
+        Value::Int(i) => Ok(*i),
+        Value::Bool(b) => Ok(i64::from(*b)),
+        Value::Float(_) => Err(ExcType::type_error("count() only supports int arguments")),
+        _ => value
+            .as_int(heap)
+            .map_err(|_| ExcType::type_error("a number is required")),
+    }
+}
+
+/// Parses the `stop` or `start, stop[, step]` arguments of `islice()`.
+fn islice_bounds(bounds: &[Value]) -> RunResult<(usize, Option<usize>, usize)> {
+    const INDICES: &str = "Indices for islice() must be None or an integer: 0 <= x <= sys.maxsize.";
+    const STOP: &str = "Stop argument for islice() must be None or an integer: 0 <= x <= sys.maxsize.";
+    const STEP: &str = "Step for islice() must be a positive integer or None.";
+
+    match bounds {
+        [stop] => Ok((0, islice_index(stop, STOP)?, 1)),
+        [start, stop, step @ ..] => {
+            let start = islice_index(start, INDICES)?.unwrap_or(0);
+            let stop = islice_index(stop, STOP)?;
+            let step = match step.first() {
+                Some(step) => islice_index(step, STEP)?.unwrap_or(1),
+                None => 1,
+            };
+            if step == 0 {
+                return Err(ExcType::value_error(STEP));
+            }
+            Ok((start, stop, step))
+        }
+        [] => unreachable!("islice() argument count is checked by the caller"),
+    }
+}
+
+/// Converts an `islice()` bound to an index, `None` for `None`, or returns `ValueError(msg)`.
+fn islice_index(value: &Value, msg: &str) -> RunResult<Option<usize>> {
+    match value {
+        Value::None => Ok(None),
+        Value::Bool(b) => Ok(Some(usize::from(*b))),
+        // Bounded by `sys.maxsize` as in CPython, whatever the width of `i64` vs `usize`
+        Value::Int(i) => isize::try_from(*i)
+            .ok()
+            .and_then(|i| usize::try_from(i).ok())
+            .map(Some)
+            .ok_or_else(|| ExcType::value_error(msg)),
+        _ => Err(ExcType::value_error(msg)),
+    }
+}
@@ -1,7 +1,7 @@
 //! Built-in module implementations.
 //!
 //! This module provides implementations for Python built-in modules like `sys`, `typing`,
-//! and `asyncio`. These are created on-demand when import statements are executed.
+//! `asyncio`, `copy`, `time`, `random`, `itertools`, `collections`, `heapq`, `bisect`, `textwrap`, `base64` and `hashlib`. These are created on-demand when import statements are executed.
 
 use std::fmt::{self, Write};
 
@@ -9,6 +9,7 @@ use strum::FromRepr;
 
 use crate::{
     args::ArgValues,
+    bytecode::VM,
     exception_private::RunResult,
     heap::{Heap, HeapId},
     intern::{Interns, StaticStrings, StringId},
@@ -17,9 +18,19 @@ use crate::{
 };
 
 pub(crate) mod asyncio;
+pub(crate) mod base64;
+pub(crate) mod bisect;
+pub(crate) mod collections;
+pub(crate) mod copy;
+pub(crate) mod hashlib;
+pub(crate) mod heapq;
+pub(crate) mod itertools;
 pub(crate) mod os;
 pub(crate) mod pathlib;
+pub(crate) mod random;
 pub(crate) mod sys;
+pub(crate) mod textwrap;
+pub(crate) mod time;
 pub(crate) mod typing;
 
 /// Built-in modules that can be imported.
@@ -36,6 +47,26 @@ pub(crate) enum BuiltinModule {
     Pathlib,
     /// The `os` module providing operating system interface (only `getenv()` implemented).
     Os,
+    /// The `copy` module providing shallow and deep copies (`copy()` and `deepcopy()`).
+    Copy,
+    /// The `time` module providing the host's clocks (`time()` and `monotonic()`).
+    Time,
+    /// The `random` module providing an in-core seedable generator.
+    Random,
+    /// The `itertools` module providing lazy iterators (`count()`, `cycle()`, `repeat()`, `chain()` and `islice()`).
+    Itertools,
+    /// The `collections` module providing container types (only `Counter` implemented).
+    Collections,
+    /// The `heapq` module providing min-heap functions on lists.
+    Heapq,
+    /// The `bisect` module providing binary search on sorted lists.
+    Bisect,
+    /// The `textwrap` module providing greedy word wrapping (`wrap()` and `fill()`).
+    Textwrap,
+    /// The `base64` module providing standard base64 encoding (`b64encode()` and `b64decode()`).
+    Base64,
+    /// The `hashlib` module providing `md5()` and `sha256()` hash objects.
+    Hashlib,
 }
 
 impl BuiltinModule {
@@ -47,6 +78,16 @@ impl BuiltinModule {
             StaticStrings::Asyncio => Some(Self::Asyncio),
             StaticStrings::Pathlib => Some(Self::Pathlib),
             StaticStrings::Os => Some(Self::Os),
+            StaticStrings::Copy => Some(Self::Copy),
+            StaticStrings::Time => Some(Self::Time),
+            StaticStrings::Random => Some(Self::Random),
+            StaticStrings::Itertools => Some(Self::Itertools),
+            StaticStrings::Collections => Some(Self::Collections),
+            StaticStrings::Heapq => Some(Self::Heapq),
+            StaticStrings::Bisect => Some(Self::Bisect),
+            StaticStrings::Textwrap => Some(Self::Textwrap),
+            StaticStrings::Base64 => Some(Self::Base64),
+            StaticStrings::Hashlib => Some(Self::Hashlib),
             _ => None,
         }
     }
@@ -65,6 +106,16 @@ impl BuiltinModule {
             Self::Asyncio => asyncio::create_module(heap, interns),
             Self::Pathlib => pathlib::create_module(heap, interns),
             Self::Os => os::create_module(heap, interns),
+            Self::Copy => copy::create_module(heap, interns),
+            Self::Time => time::create_module(heap, interns),
+            Self::Random => random::create_module(heap, interns),
+            Self::Itertools => itertools::create_module(heap, interns),
+            Self::Collections => collections::create_module(heap, interns),
+            Self::Heapq => heapq::create_module(heap, interns),
+            Self::Bisect => bisect::create_module(heap, interns),
+            Self::Textwrap => textwrap::create_module(heap, interns),
+            Self::Base64 => base64::create_module(heap, interns),
+            Self::Hashlib => hashlib::create_module(heap, interns),
         }
     }
 }
@@ -74,6 +125,16 @@ impl BuiltinModule {
 pub(crate) enum ModuleFunctions {
     Asyncio(asyncio::AsyncioFunctions),
     Os(os::OsFunctions),
+    Copy(copy::CopyFunctions),
+    Time(time::TimeFunctions),
+    Random(random::RandomFunctions),
+    Itertools(itertools::ItertoolsFunctions),
+    Collections(collections::CollectionsFunctions),
+    Heapq(heapq::HeapqFunctions),
+    Bisect(bisect::BisectFunctions),
+    Textwrap(textwrap::TextwrapFunctions),
+    Base64(base64::Base64Functions),
+    Hashlib(hashlib::HashlibFunctions),
 }
 
 impl fmt::Display for ModuleFunctions {
@@ -81,6 +142,16 @@ impl fmt::Display for ModuleFunctions {
         match self {
             Self::Asyncio(func) => write!(f, "{func}"),
             Self::Os(func) => write!(f, "{func}"),
+            Self::Copy(func) => write!(f, "{func}"),
+            Self::Time(func) => write!(f, "{func}"),
+            Self::Random(func) => write!(f, "{func}"),
+            Self::Itertools(func) => write!(f, "{func}"),
+            Self::Collections(func) => write!(f, "{func}"),
+            Self::Heapq(func) => write!(f, "{func}"),
+            Self::Bisect(func) => write!(f, "{func}"),
+            Self::Textwrap(func) => write!(f, "{func}"),
+            Self::Base64(func) => write!(f, "{func}"),
+            Self::Hashlib(func) => write!(f, "{func}"),
         }
     }
 }
@@ -90,10 +161,22 @@ impl ModuleFunctions {
     ///
     /// Returns `AttrCallResult` to support both immediate values and OS calls that
     /// require host involvement (e.g., `os.getenv()` needs the host to provide environment variables).
-    pub fn call(self, heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
+    /// The `heapq` and `bisect` functions need the VM to call `__lt__` on class instances.
+    pub fn call(self, vm: &mut VM<'_, '_, impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
+        let (heap, interns) = (&mut *vm.heap, vm.interns);
         match self {
             Self::Asyncio(functions) => asyncio::call(heap, functions, args),
             Self::Os(functions) => os::call(heap, functions, args),
+            Self::Copy(functions) => copy::call(heap, interns, functions, args),
+            Self::Time(functions) => time::call(heap, interns, functions, args),
+            Self::Random(functions) => random::call(heap, interns, functions, args),
+            Self::Itertools(functions) => itertools::call(heap, interns, functions, args),
+            Self::Collections(functions) => collections::call(heap, interns, functions, args),
+            Self::Heapq(functions) => heapq::call(vm, functions, args),
+            Self::Bisect(functions) => bisect::call(vm, functions, args),
+            Self::Textwrap(functions) => textwrap::call(heap, interns, functions, args),
+            Self::Base64(functions) => base64::call(heap, interns, functions, args),
+            Self::Hashlib(functions) => hashlib::call(heap, interns, functions, args),
         }
     }
 
@@ -4,6 +4,11 @@
 //! - `getenv(key, default=None)`: Get a single environment variable
 //! - `environ`: Property that returns the entire environment as a dict
 //!
+//! It also has two Monty-specific functions for streaming host input, so large inputs
+//! never have to be materialized in the sandbox at once:
+//! - `read_line()`: The next line of host input, `''` once the input is exhausted
+//! - `read_chunk(size)`: The next chunk of at most `size` characters, `''` at the end
+//!
 //! Other os functions are not implemented. OS operations require host involvement
 //! via the `OsFunction` callback mechanism - Monty yields control to the host
 //! which executes the operation and returns the result.
@@ -25,6 +30,10 @@ use crate::{
 #[strum(serialize_all = "lowercase")]
 pub(crate) enum OsFunctions {
     Getenv,
+    #[strum(serialize = "read_line")]
+    ReadLine,
+    #[strum(serialize = "read_chunk")]
+    ReadChunk,
 }
 
 /// Creates the `os` module and allocates it on the heap.
@@ -33,7 +42,9 @@ pub(crate) enum OsFunctions {
 /// - `getenv(key, default=None)`: Get a single environment variable
 /// - `environ`: Property that returns the entire environment as a dict
 ///
-/// Both operations yield to the host via `OsFunction` callbacks.
+/// - `read_line()` / `read_chunk(size)`: Stream host input incrementally
+///
+/// All of these yield to the host via `OsFunction` callbacks.
 ///
 /// # Returns
 /// A HeapId pointing to the newly allocated module.
@@ -51,6 +62,19 @@ pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -
         interns,
     );
 
+    // os.read_line / os.read_chunk - stream input from the host
+    for (name, function) in [
+        (StaticStrings::ReadLine, OsFunctions::ReadLine),
+        (StaticStrings::ReadChunk, OsFunctions::ReadChunk),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Os(function)),
+            heap,
+            interns,
+        );
+    }
+
     // os.environ - property that returns the entire environment as a dict
     module.set_attr(
         StaticStrings::Environ,
@@ -73,6 +97,11 @@ pub(super) fn call(
 ) -> RunResult<AttrCallResult> {
     match functions {
         OsFunctions::Getenv => getenv(heap, args),
+        OsFunctions::ReadLine => {
+            args.check_zero_args("os.read_line", heap)?;
+            Ok(AttrCallResult::OsCall(OsFunction::ReadLine, ArgValues::Empty))
+        }
+        OsFunctions::ReadChunk => read_chunk(heap, args),
     }
 }
 
@@ -115,3 +144,26 @@ fn getenv(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<A
         Err(ExcType::type_error(format!("str expected, not {type_name}")))
     }
 }
+
+/// Implementation of `os.read_chunk(size)`.
+///
+/// Asks the host for the next chunk of input with at most `size` characters. The host
+/// answers with a string, where `''` signals that the input is exhausted.
+///
+/// # Errors
+/// Returns `TypeError` if `size` is not an int, or `ValueError` if it is not positive.
+fn read_chunk(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<AttrCallResult> {
+    let size = args.get_one_arg("os.read_chunk", heap)?;
+    match size {
+        Value::Int(n) if n > 0 => Ok(AttrCallResult::OsCall(OsFunction::ReadChunk, ArgValues::One(size))),
+        Value::Int(_) => Err(ExcType::value_error("read_chunk size must be positive")),
+        Value::Bool(_) => Err(ExcType::type_error("read_chunk size must be int, not bool")),
+        _ => {
+            let type_name = size.py_type(heap);
+            size.drop_with_heap(heap);
+            Err(ExcType::type_error(format!(
+                "read_chunk size must be int, not {type_name}"
+            )))
+        }
+    }
+}
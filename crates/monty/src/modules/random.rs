@@ -0,0 +1,253 @@
+//! Implementation of the `random` module.
+//!
+//! Provides a minimal implementation of Python's `random` module with:
+//! - `random()`: A float in the range `[0.0, 1.0)`
+//! - `randint(a, b)`: An int in the range `[a, b]`, including both end points
+//! - `choice(seq)`: A random item from a non-empty sequence
+//! - `shuffle(list)`: Shuffles a list in place
+//! - `seed(n)`: Reseeds the generator
+//!
+//! Unlike CPython, numbers come from an in-core xorshift generator rather than a Mersenne
+//! Twister seeded by the OS, so no host callback is needed. The generator lives on the heap
+//! and starts from a fixed seed, so every run produces the same sequence unless reseeded.
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
+    heap_data::HeapDataMut,
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Module, PyTrait},
+    value::Value,
+};
+
+/// Random module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum RandomFunctions {
+    Random,
+    Randint,
+    Choice,
+    Shuffle,
+    Seed,
+}
+
+/// Seed used by a fresh heap and by `random.seed(None)`.
+const DEFAULT_SEED: u64 = 0;
+
+/// Deterministic pseudo-random number generator backing the `random` module.
+///
+/// Uses xorshift64* with the seed scrambled through splitmix64, so that small or
+/// similar seeds still give unrelated sequences and the state is never zero.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl Rng {
+    /// Creates a generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // xorshift gets stuck at zero, so replace it with an arbitrary non-zero state
+        Self {
+            state: if z == 0 { 0x2545_F491_4F6C_DD1D } else { z },
+        }
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a float in `[0.0, 1.0)` with 53 random bits, as CPython's `random()` does.
+    pub fn next_f64(&mut self) -> f64 {
+        #[expect(clippy::cast_precision_loss, reason = "53 bits fit exactly in an f64")]
+        let (bits, scale) = ((self.next_u64() >> 11) as f64, (1u64 << 53) as f64);
+        bits / scale
+    }
+
+    /// Returns a uniformly distributed integer in `[0, n)`.
+    ///
+    /// Uses rejection sampling to avoid the bias a plain modulo would have.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below: empty range");
+        // Largest multiple of `n` which fits in a u64, values at or above it are rejected
+        let zone = u64::MAX - (u64::MAX - n + 1) % n;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return value % n;
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed integer in `[low, high]`, which must not be empty.
+    fn between(&mut self, low: i64, high: i64) -> i64 {
+        // Width of the range minus one, which can't overflow a u64
+        #[expect(
+            clippy::cast_sign_loss,
+            reason = "high >= low, so the wrapped difference is the width"
+        )]
+        let span = high.wrapping_sub(low) as u64;
+        let offset = match span.checked_add(1) {
+            Some(width) => self.below(width),
+            None => self.next_u64(),
+        };
+        #[expect(clippy::cast_possible_wrap, reason = "wrapping back into [low, high] is intended")]
+        let offset = offset as i64;
+        low.wrapping_add(offset)
+    }
+}
+
+/// Creates the `random` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Random);
+
+    for (name, function) in [
+        (StaticStrings::Random, RandomFunctions::Random),
+        (StaticStrings::Randint, RandomFunctions::Randint),
+        (StaticStrings::Choice, RandomFunctions::Choice),
+        (StaticStrings::Shuffle, RandomFunctions::Shuffle),
+        (StaticStrings::Seed, RandomFunctions::Seed),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Random(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a random module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: RandomFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let result = match functions {
+        RandomFunctions::Random => {
+            args.check_zero_args("random.random", heap)?;
+            Value::Float(heap.rng_mut().next_f64())
+        }
+        RandomFunctions::Randint => {
+            let (low, high) = args.get_two_args("random.randint", heap)?;
+            defer_drop!(low, heap);
+            defer_drop!(high, heap);
+            let low = int_arg(low, heap)?;
+            let high = int_arg(high, heap)?;
+            if low > high {
+                return Err(ExcType::value_error(format!(
+                    "empty range in randrange({low}, {})",
+                    i128::from(high) + 1
+                )));
+            }
+            Value::Int(heap.rng_mut().between(low, high))
+        }
+        RandomFunctions::Choice => {
+            let seq = args.get_one_arg("random.choice", heap)?;
+            defer_drop!(seq, heap);
+            let Some(len) = seq.py_len(heap, interns) else {
+                let type_name = seq.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "object of type '{type_name}' has no len()"
+                )));
+            };
+            if len == 0 {
+                return Err(
+                    SimpleException::new_msg(ExcType::IndexError, "Cannot choose from an empty sequence").into(),
+                );
+            }
+            let index = heap.rng_mut().below(len as u64);
+            let index = Value::Int(i64::try_from(index).expect("sequence length fits in i64"));
+            seq.py_getitem(&index, heap, interns)?
+        }
+        RandomFunctions::Shuffle => {
+            let list = args.get_one_arg("random.shuffle", heap)?;
+            defer_drop!(list, heap);
+            shuffle(list, heap)?;
+            Value::None
+        }
+        RandomFunctions::Seed => {
+            let seed = args.get_zero_one_arg("random.seed", heap)?;
+            let seed = match seed {
+                None | Some(Value::None) => DEFAULT_SEED,
+                Some(seed) => {
+                    defer_drop!(seed, heap);
+                    // Negative seeds are reinterpreted rather than rejected, as long as
+                    // equal seeds give equal sequences it doesn't matter how
+                    let seed = int_arg(seed, heap)?;
+                    u64::from_ne_bytes(seed.to_ne_bytes())
+                }
+            };
+            *heap.rng_mut() = Rng::new(seed);
+            Value::None
+        }
+    };
+    Ok(AttrCallResult::Value(result))
+}
+
+/// Shuffles a list in place with the Fisher-Yates algorithm, drawing indices in the same
+/// order as CPython's `random.shuffle`.
+///
+/// # Errors
+/// Returns `TypeError` if `list` isn't a list, since only lists support item assignment here.
+fn shuffle(list: &Value, heap: &mut Heap<impl ResourceTracker>) -> RunResult<()> {
+    let id = match list {
+        Value::Ref(id) if matches!(heap.get(*id), HeapData::List(_)) => *id,
+        _ => {
+            let type_name = list.py_type(heap);
+            return Err(ExcType::type_error(format!(
+                "'{type_name}' object does not support item assignment"
+            )));
+        }
+    };
+    // The generator is copied out while the list is borrowed mutably, then written back
+    let mut rng = heap.rng_mut().clone();
+    if let HeapDataMut::List(list) = heap.get_mut(id) {
+        let items = list.as_vec_mut();
+        for i in (1..items.len()).rev() {
+            let j = usize::try_from(rng.below(i as u64 + 1)).expect("index is below the list length");
+            items.swap(i, j);
+        }
+    }
+    *heap.rng_mut() = rng;
+    Ok(())
+}
+
+/// Extracts an integer argument, accepting bools as CPython does.
+fn int_arg(value: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<i64> {
+    match value {
+        Value::Bool(b) => Ok(i64::from(*b)),
+        _ => value.as_int(heap),
+    }
+}
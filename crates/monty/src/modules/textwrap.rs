@@ -0,0 +1,175 @@
+//! Implementation of the `textwrap` module.
+//!
+//! Provides greedy word wrapping:
+//! - `wrap(text, width=70)`: The wrapped lines as a list of strings
+//! - `fill(text, width=70)`: The wrapped lines joined with newlines
+//!
+//! Text is split into words on whitespace, and words are packed onto each line until the
+//! next one would make it longer than `width`. Unlike CPython, words longer than `width`
+//! are never broken, they get a line to themselves instead, and runs of whitespace between
+//! words always become a single space. None of CPython's other keyword options are supported.
+
+use crate::{
+    args::ArgValues,
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, List, Module, PyTrait, str::allocate_string},
+    value::Value,
+};
+
+/// Textwrap module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum TextwrapFunctions {
+    Wrap,
+    Fill,
+}
+
+/// Width used when `width` isn't given, as in CPython.
+const DEFAULT_WIDTH: i64 = 70;
+
+/// Creates the `textwrap` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Textwrap);
+
+    for (name, function) in [
+        (StaticStrings::Wrap, TextwrapFunctions::Wrap),
+        (StaticStrings::Fill, TextwrapFunctions::Fill),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Textwrap(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a textwrap module function.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: TextwrapFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    let result = match functions {
+        TextwrapFunctions::Wrap => {
+            let lines = wrap_args("wrap", args, heap, interns)?;
+            let mut items = Vec::with_capacity(lines.len());
+            for line in lines {
+                match allocate_string(line, heap) {
+                    Ok(item) => items.push(item),
+                    Err(e) => {
+                        items.drop_with_heap(heap);
+                        return Err(e);
+                    }
+                }
+            }
+            Value::Ref(heap.allocate(HeapData::List(List::new(items)))?)
+        }
+        TextwrapFunctions::Fill => {
+            let lines = wrap_args("fill", args, heap, interns)?;
+            allocate_string(lines.join("\n"), heap)?
+        }
+    };
+    Ok(AttrCallResult::Value(result))
+}
+
+/// Parses the `(text, width=70)` arguments of `wrap()` and `fill()`, and wraps the text.
+fn wrap_args(
+    name: &str,
+    args: ArgValues,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Vec<String>> {
+    let (pos, kwargs) = args.into_parts();
+    defer_drop_mut!(pos, heap);
+    let kwargs = kwargs.into_iter();
+    defer_drop_mut!(kwargs, heap);
+
+    if pos.len() == 0 {
+        return Err(ExcType::type_error_at_least(name, 1, 0));
+    } else if pos.len() > 2 {
+        return Err(ExcType::type_error_at_most(name, 2, pos.len()));
+    }
+    let text = pos.next().expect("argument count checked above");
+    defer_drop!(text, heap);
+    let width = pos.next();
+    defer_drop_mut!(width, heap);
+
+    for (key, value) in kwargs {
+        defer_drop!(key, heap);
+        let Some(keyword) = key.as_either_str(heap) else {
+            value.drop_with_heap(heap);
+            return Err(ExcType::type_error("keywords must be strings"));
+        };
+        let keyword = keyword.as_str(interns);
+        if keyword != "width" {
+            value.drop_with_heap(heap);
+            return Err(ExcType::type_error(format!(
+                "'{keyword}' is an invalid keyword argument for {name}()"
+            )));
+        }
+        if width.is_some() {
+            value.drop_with_heap(heap);
+            return Err(ExcType::type_error(format!(
+                "{name}() got multiple values for argument 'width'"
+            )));
+        }
+        *width = Some(value);
+    }
+
+    let Some(text) = text.as_either_str(heap) else {
+        let type_name = text.py_type(heap);
+        return Err(ExcType::type_error(format!(
+            "{name}() argument 1 must be str, not {type_name}"
+        )));
+    };
+    let width = match width.as_ref() {
+        Some(width) => width.as_int(heap)?,
+        None => DEFAULT_WIDTH,
+    };
+    let lines = wrap_words(text.as_str(interns), usize::try_from(width).unwrap_or(0));
+    if width <= 0 && !lines.is_empty() {
+        return Err(ExcType::value_error(format!("invalid width {width} (must be > 0)")));
+    }
+    Ok(lines)
+}
+
+/// Greedily packs the whitespace-separated words of `text` into lines of at most `width`
+/// characters, giving words longer than `width` a line of their own.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in text.split_whitespace() {
+        let word_width = word.chars().count();
+        if line.is_empty() {
+            line.push_str(word);
+            line_width = word_width;
+        } else if line_width + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+            line_width += 1 + word_width;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_owned()));
+            line_width = word_width;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
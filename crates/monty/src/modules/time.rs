@@ -0,0 +1,285 @@
+//! Implementation of the `time` module.
+//!
+//! Provides a minimal implementation of Python's `time` module with:
+//! - `time()`: Seconds since the epoch as a float
+//! - `monotonic()`: Seconds from a monotonic clock as a float
+//!
+//! Both yield to the host via `OsFunction` callbacks, since the sandbox has no clock.
+//!
+//! There are no timezones or `struct_time`. Instead the module has two Monty-specific
+//! helpers which work on UTC timestamps entirely inside the sandbox:
+//! - `isoformat(secs)`: Formats a timestamp like `datetime.utcfromtimestamp(secs).isoformat()`
+//! - `fromisoformat(string)`: Parses such a string back to a timestamp
+
+use std::fmt::Write;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    modules::ModuleFunctions,
+    os::OsFunction,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Module, PyTrait},
+    value::Value,
+};
+
+/// Time module functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum TimeFunctions {
+    Time,
+    Monotonic,
+    Isoformat,
+    Fromisoformat,
+}
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+/// Timestamp in microseconds of `0001-01-01T00:00:00`, the earliest supported time.
+const MIN_MICROS: i64 = -62_135_596_800 * MICROS_PER_SECOND;
+/// Timestamp in microseconds of `9999-12-31T23:59:59.999999`, the latest supported time.
+const MAX_MICROS: i64 = 253_402_300_800 * MICROS_PER_SECOND - 1;
+
+/// Creates the `time` module and allocates it on the heap.
+///
+/// # Returns
+/// A HeapId pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Time);
+
+    for (name, function) in [
+        (StaticStrings::Time, TimeFunctions::Time),
+        (StaticStrings::Monotonic, TimeFunctions::Monotonic),
+        (StaticStrings::Isoformat, TimeFunctions::Isoformat),
+        (StaticStrings::Fromisoformat, TimeFunctions::Fromisoformat),
+    ] {
+        module.set_attr(
+            name,
+            Value::ModuleFunction(ModuleFunctions::Time(function)),
+            heap,
+            interns,
+        );
+    }
+
+    heap.allocate(HeapData::Module(module))
+}
+
+/// Dispatches a call to a time module function.
+///
+/// Returns `AttrCallResult::OsCall` for the clocks, which need the host,
+/// or `AttrCallResult::Value` for the helpers computed in the sandbox.
+pub(super) fn call(
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+    functions: TimeFunctions,
+    args: ArgValues,
+) -> RunResult<AttrCallResult> {
+    match functions {
+        TimeFunctions::Time => {
+            args.check_zero_args("time.time", heap)?;
+            Ok(AttrCallResult::OsCall(OsFunction::Time, ArgValues::Empty))
+        }
+        TimeFunctions::Monotonic => {
+            args.check_zero_args("time.monotonic", heap)?;
+            Ok(AttrCallResult::OsCall(OsFunction::MonotonicTime, ArgValues::Empty))
+        }
+        TimeFunctions::Isoformat => {
+            let secs = args.get_one_arg("time.isoformat", heap)?;
+            defer_drop!(secs, heap);
+            let micros = timestamp_micros(secs, heap)?;
+            let formatted = format_iso(micros);
+            let heap_id = heap.allocate(HeapData::Str(formatted.into()))?;
+            Ok(AttrCallResult::Value(Value::Ref(heap_id)))
+        }
+        TimeFunctions::Fromisoformat => {
+            let string = args.get_one_arg("time.fromisoformat", heap)?;
+            defer_drop!(string, heap);
+            let Some(string) = string.as_either_str(heap) else {
+                let type_name = string.py_type(heap);
+                return Err(ExcType::type_error(format!(
+                    "fromisoformat: argument must be str, not {type_name}"
+                )));
+            };
+            let string = string.as_str(interns);
+            let micros = parse_iso(string)
+                .ok_or_else(|| ExcType::value_error(format!("Invalid isoformat string: '{string}'")))?;
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "timestamps are returned as floats, like time()"
+            )]
+            let secs = micros as f64 / MICROS_PER_SECOND as f64;
+            Ok(AttrCallResult::Value(Value::Float(secs)))
+        }
+    }
+}
+
+/// Converts an int or float timestamp in seconds to whole microseconds, rounding to the nearest.
+///
+/// # Errors
+/// Returns `TypeError` for other types, and `ValueError` for timestamps outside years 1 to 9999.
+fn timestamp_micros(secs: &Value, heap: &Heap<impl ResourceTracker>) -> RunResult<i64> {
+    let micros = match secs {
+        Value::Int(secs) => secs.checked_mul(MICROS_PER_SECOND),
+        Value::Bool(secs) => Some(i64::from(*secs) * MICROS_PER_SECOND),
+        Value::Float(secs) => {
+            let micros = (secs * 1e6).round();
+            #[expect(clippy::cast_precision_loss, reason = "bounds only need to be approximate")]
+            let in_range = micros.abs() < i64::MAX as f64;
+            #[expect(clippy::cast_possible_truncation, reason = "only used when in range")]
+            let micros = micros as i64;
+            in_range.then_some(micros)
+        }
+        _ => {
+            let type_name = secs.py_type(heap);
+            return Err(ExcType::type_error(format!("must be real number, not {type_name}")));
+        }
+    };
+    micros
+        .filter(|micros| (MIN_MICROS..=MAX_MICROS).contains(micros))
+        .ok_or_else(|| ExcType::value_error("timestamp out of range for isoformat"))
+}
+
+/// Formats a timestamp in microseconds as `YYYY-MM-DDTHH:MM:SS[.ffffff]` in UTC.
+///
+/// The fraction is omitted when it is zero, as `datetime.isoformat()` does.
+fn format_iso(micros: i64) -> String {
+    let days = micros.div_euclid(MICROS_PER_DAY);
+    let day_micros = micros.rem_euclid(MICROS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let secs = day_micros / MICROS_PER_SECOND;
+    let fraction = day_micros % MICROS_PER_SECOND;
+
+    let mut out = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+    if fraction != 0 {
+        write!(out, ".{fraction:06}").expect("writing to a String cannot fail");
+    }
+    out
+}
+
+/// Parses `YYYY-MM-DD[(T| )HH[:MM[:SS[.fff...]]]][Z]` as a UTC timestamp in microseconds.
+///
+/// Fractions beyond microseconds are truncated. Returns `None` if the string is invalid.
+fn parse_iso(string: &str) -> Option<i64> {
+    let bytes = string.as_bytes();
+    let bytes = bytes.strip_suffix(b"Z").unwrap_or(bytes);
+    let (date, time) = match bytes.get(10) {
+        None => (bytes, None),
+        Some(b'T' | b' ') => (&bytes[..10], Some(&bytes[11..])),
+        Some(_) => return None,
+    };
+
+    let &[y1, y2, y3, y4, b'-', m1, m2, b'-', d1, d2] = date else {
+        return None;
+    };
+    let year = digits(&[y1, y2, y3, y4])?;
+    let month = digits(&[m1, m2])?;
+    let day = digits(&[d1, d2])?;
+    if year == 0 || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let mut day_micros = 0;
+    if let Some(time) = time {
+        let (time, fraction) = match time.iter().position(|&b| b == b'.') {
+            Some(dot) => (&time[..dot], Some(&time[dot + 1..])),
+            None => (time, None),
+        };
+        // Hours, minutes and seconds, each two digits below its limit
+        let limits = [24, 60, 60];
+        let mut part_count = 0;
+        for part in time.split(|&b| b == b':') {
+            let limit = *limits.get(part_count)?;
+            let value = if part.len() == 2 { digits(part)? } else { return None };
+            if value >= limit {
+                return None;
+            }
+            day_micros = day_micros * 60 + value;
+            part_count += 1;
+        }
+        // Scale the parsed hours or minutes up to seconds
+        for _ in part_count..limits.len() {
+            day_micros *= 60;
+        }
+        day_micros *= MICROS_PER_SECOND;
+
+        if let Some(fraction) = fraction {
+            // A fraction is only allowed after the seconds
+            if part_count != limits.len() || fraction.is_empty() {
+                return None;
+            }
+            let mut micros = 0;
+            for (i, &b) in fraction.iter().enumerate() {
+                let digit = digits(&[b])?;
+                if i < 6 {
+                    micros = micros * 10 + digit;
+                }
+            }
+            for _ in fraction.len()..6 {
+                micros *= 10;
+            }
+            day_micros += micros;
+        }
+    }
+
+    Some(days_from_civil(year, month, day) * MICROS_PER_DAY + day_micros)
+}
+
+/// Parses a run of ASCII digits, returning `None` if any byte isn't a digit.
+fn digits(bytes: &[u8]) -> Option<i64> {
+    bytes
+        .iter()
+        .try_fold(0, |acc, &b| b.is_ascii_digit().then(|| acc * 10 + i64::from(b - b'0')))
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days between `1970-01-01` and the given date in the proleptic
+/// Gregorian calendar, using Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`], returning `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -11,6 +11,7 @@ use num_traits::Zero;
 use crate::{
     builtins::{Builtins, BuiltinsFunctions},
     exception_private::{ExcType, SimpleException},
+    fstring::float_repr,
     heap::{Heap, HeapData, HeapId},
     intern::Interns,
     resource::{ResourceError, ResourceTracker},
@@ -306,7 +307,7 @@ impl MontyObject {
     /// a HeapId already in the set, we've found a cycle and return `MontyObject::Cycle`
     /// with an appropriate placeholder string.
     ///
-    /// Recursion depth is tracked via `heap.incr_recursion_depth_for_repr()`.
+    /// Recursion depth is tracked via `heap.incr_repr_depth()`.
     fn from_value_inner(
         object: &Value,
         heap: &Heap<impl ResourceTracker>,
@@ -314,7 +315,7 @@ impl MontyObject {
         interns: &Interns,
     ) -> Self {
         // Check depth limit before processing
-        let Some(token) = heap.incr_recursion_depth_for_repr() else {
+        let Some(token) = heap.incr_repr_depth() else {
             return Self::Repr("<deeply nested>".to_owned());
         };
         crate::defer_drop_immutable_heap!(token, heap);
@@ -338,6 +339,7 @@ impl MontyObject {
             Value::Float(f) => Self::Float(*f),
             Value::InternString(string_id) => Self::String(interns.get_str(*string_id).to_owned()),
             Value::InternBytes(bytes_id) => Self::Bytes(interns.get_bytes(*bytes_id).to_owned()),
+            Value::InternLongInt(long_int_id) => Self::BigInt(interns.get_long_int(*long_int_id).clone()),
             Value::Ref(id) => {
                 // Check for cycle
                 if visited.contains(id) {
@@ -410,9 +412,14 @@ impl MontyObject {
                         // Show the cell's contents
                         Self::from_value_inner(&cell.0, heap, visited, interns)
                     }
-                    HeapData::Closure(..) | HeapData::FunctionDefaults(..) => {
-                        Self::Repr(object.py_repr(heap, interns).into_owned())
-                    }
+                    HeapData::Closure(..)
+                    | HeapData::FunctionDefaults(..)
+                    | HeapData::Class(_)
+                    | HeapData::Instance(_)
+                    | HeapData::BoundMethod(_)
+                    | HeapData::Property(_)
+                    | HeapData::MethodDescriptor(_)
+                    | HeapData::Hash(_) => Self::Repr(object.py_repr(heap, interns).into_owned()),
                     HeapData::Range(range) => {
                         // Represent Range as a repr string since MontyObject doesn't have a Range variant
                         let mut s = String::new();
@@ -504,14 +511,7 @@ impl MontyObject {
             Self::Bool(false) => f.write_str("False"),
             Self::Int(v) => write!(f, "{v}"),
             Self::BigInt(v) => write!(f, "{v}"),
-            Self::Float(v) => {
-                let s = v.to_string();
-                f.write_str(&s)?;
-                if !s.contains('.') {
-                    f.write_str(".0")?;
-                }
-                Ok(())
-            }
+            Self::Float(v) => f.write_str(&float_repr(*v)),
             Self::String(s) => string_repr_fmt(s, f),
             Self::Bytes(b) => f.write_str(&bytes_repr(b)),
             Self::List(l) => {
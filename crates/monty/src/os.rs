@@ -81,6 +81,18 @@ pub enum OsFunction {
     /// Get the entire environment as a dictionary
     #[strum(serialize = "os.environ")]
     GetEnviron,
+    /// Read the next line of host input, `''` at the end of the input
+    #[strum(serialize = "os.read_line")]
+    ReadLine,
+    /// Read the next chunk of host input of at most `size` characters, `''` at the end
+    #[strum(serialize = "os.read_chunk")]
+    ReadChunk,
+    /// Get the current time in seconds since the epoch
+    #[strum(serialize = "time.time")]
+    Time,
+    /// Get the value of a monotonic clock in seconds
+    #[strum(serialize = "time.monotonic")]
+    MonotonicTime,
 }
 
 impl TryFrom<StaticStrings> for OsFunction {
@@ -7,7 +7,7 @@ use ruff_python_ast::{
     name::Name,
 };
 use ruff_python_parser::parse_module;
-use ruff_text_size::{Ranged, TextRange};
+use ruff_text_size::{Ranged, TextRange, TextSize};
 
 use crate::{
     StackFrame,
@@ -25,6 +25,9 @@ use crate::{
 /// Maximum nesting depth for AST structures during parsing.
 /// Matches CPython's limit of ~200 for nested parentheses.
 /// This prevents stack overflow from deeply nested structures like `((((x,),),),)`.
+///
+/// Prepare and the bytecode compiler recurse over the parsed tree without depth checks
+/// of their own, relying on this limit to bound their recursion too.
 #[cfg(not(debug_assertions))]
 pub const MAX_NESTING_DEPTH: u16 = 200;
 /// In debug builds, we use a lower limit because stack frames are much larger
@@ -33,6 +36,55 @@ pub const MAX_NESTING_DEPTH: u16 = 200;
 #[cfg(debug_assertions)]
 pub const MAX_NESTING_DEPTH: u16 = 35;
 
+/// Maximum length in bytes of a string folded from the concatenation of string literals.
+///
+/// Matches the limit CPython's AST optimizer puts on folded constants. Longer concatenations
+/// are left to run as usual, so their memory is accounted for by the resource tracker.
+const MAX_FOLDED_STR_LEN: usize = 4096;
+
+/// Limits on the size of parsed programs, to protect the host from adversarial inputs.
+///
+/// Programs exceeding a limit are rejected with a `SyntaxError` before any code runs.
+/// Use `ParseLimits::default()` for no limits beyond the built-in nesting depth limit,
+/// or build custom limits with the builder pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseLimits {
+    /// Maximum total number of statements and expressions in the program.
+    pub max_nodes: Option<usize>,
+    /// Maximum nesting depth of statements and expressions.
+    ///
+    /// Capped at the built-in limit which protects the native stack (200 in release builds).
+    pub max_nesting_depth: Option<u16>,
+}
+
+impl ParseLimits {
+    /// Creates a new ParseLimits with all limits disabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum total number of statements and expressions.
+    #[must_use]
+    pub fn max_nodes(mut self, limit: usize) -> Self {
+        self.max_nodes = Some(limit);
+        self
+    }
+
+    /// Sets the maximum nesting depth of statements and expressions.
+    #[must_use]
+    pub fn max_nesting_depth(mut self, limit: u16) -> Self {
+        self.max_nesting_depth = Some(limit);
+        self
+    }
+
+    /// Returns the nesting depth limit to enforce, never above `MAX_NESTING_DEPTH`.
+    fn nesting_depth(self) -> u16 {
+        self.max_nesting_depth
+            .map_or(MAX_NESTING_DEPTH, |limit| limit.min(MAX_NESTING_DEPTH))
+    }
+}
+
 /// A parameter in a function signature with optional default value.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ParsedParam {
@@ -108,6 +160,43 @@ pub struct Try<N> {
     pub finally: Vec<N>,
 }
 
+/// A parsed class definition.
+///
+/// Only plain classes and exception subclasses are supported: no keywords, class decorators
+/// or multiple bases, and the body may only contain methods (plus `pass` and docstrings).
+/// A class with a base must subclass an exception type, and may not define methods.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClassDef<F> {
+    /// The class name, bound in the enclosing scope once the class is built.
+    pub name: Identifier,
+    /// The base class expression, if any, which must evaluate to an exception type.
+    pub base: Option<ExprLoc>,
+    /// Methods defined in the class body, in definition order.
+    pub methods: Vec<MethodDef<F>>,
+}
+
+/// A method in a class body.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MethodDef<F> {
+    /// How the method binds, set by its decorator.
+    pub kind: MethodKind,
+    /// The method's function definition.
+    pub func: F,
+}
+
+/// The supported method decorators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MethodKind {
+    /// An undecorated method, bound to the instance it is looked up on.
+    Instance,
+    /// A `@property` getter, called with the instance whenever the attribute is read.
+    Property,
+    /// A `@staticmethod`, never bound.
+    Static,
+    /// A `@classmethod`, bound to the class.
+    Class,
+}
+
 /// A parsed exception handler (except clause).
 ///
 /// Represents `except ExcType as name:` or bare `except:` clauses.
@@ -129,8 +218,8 @@ pub struct ParseResult {
     pub interner: InternerBuilder,
 }
 
-pub(crate) fn parse(code: &str, filename: &str) -> Result<ParseResult, ParseError> {
-    parse_with_interner(code, filename, InternerBuilder::new(code))
+pub(crate) fn parse(code: &str, filename: &str, limits: ParseLimits) -> Result<ParseResult, ParseError> {
+    parse_with_interner(code, filename, InternerBuilder::new(code), limits)
 }
 
 /// Parses code using a caller-provided interner seed.
@@ -141,8 +230,15 @@ pub(crate) fn parse_with_interner(
     code: &str,
     filename: &str,
     interner: InternerBuilder,
+    limits: ParseLimits,
 ) -> Result<ParseResult, ParseError> {
-    let mut parser = Parser::new(code, filename, interner);
+    let mut parser = Parser::new(code, filename, interner, limits);
+    if let Some(range) = find_excessive_bracket_nesting(code, limits.nesting_depth()) {
+        return Err(ParseError::syntax(
+            "too many nested parentheses",
+            parser.convert_range(range),
+        ));
+    }
     let parsed = parse_module(code).map_err(|e| ParseError::syntax(e.to_string(), parser.convert_range(e.range())))?;
     let module = parsed.into_syntax();
     let nodes = parser.parse_statements(module.body)?;
@@ -152,6 +248,60 @@ pub(crate) fn parse_with_interner(
     })
 }
 
+/// Returns the range of the first bracket nested more than `max_depth` deep, if any.
+///
+/// Like CPython's tokenizer, this rejects deeply nested brackets before parsing: ruff's
+/// parser recurses for each level, so input like `((((...))))` thousands deep would overflow
+/// the native stack before `Parser` gets to check the nesting depth of the AST.
+///
+/// Brackets in comments and string literals are skipped. That includes replacement fields
+/// in f-strings, which are still covered by the AST depth check.
+fn find_excessive_bracket_nesting(code: &str, max_depth: u16) -> Option<TextRange> {
+    let bytes = code.as_bytes();
+    let mut depth: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => {
+                depth += 1;
+                if depth > max_depth {
+                    let start = TextSize::try_from(i).ok()?;
+                    return Some(TextRange::at(start, TextSize::from(1)));
+                }
+            }
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            quote @ (b'\'' | b'"') => {
+                let triple = bytes[i..].starts_with(&[quote; 3]);
+                let delimiter_len = if triple { 3 } else { 1 };
+                i += delimiter_len;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        // Skip the escaped character, which may be the quote
+                        b'\\' => i += 2,
+                        b if b == quote && (!triple || bytes[i..].starts_with(&[quote; 3])) => {
+                            i += delimiter_len;
+                            break;
+                        }
+                        // Unterminated single-quoted string, left for ruff to report
+                        b'\n' if !triple => break,
+                        _ => i += 1,
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
 /// Parser for converting ruff AST to Monty's intermediate ParseNode representation.
 ///
 /// Holds references to the source code and owns a string interner for names.
@@ -164,13 +314,17 @@ pub struct Parser<'a> {
     /// String interner for names (variables, functions, etc).
     pub interner: InternerBuilder,
     /// Remaining nesting depth budget for recursive structures.
-    /// Starts at MAX_NESTING_DEPTH and decrements on each nested level.
+    /// Starts at MAX_NESTING_DEPTH (or a lower configured limit) and decrements on each nested level.
     /// When it reaches zero, we return a "too many nested parentheses" error.
     depth_remaining: u16,
+    /// Number of statements and expressions parsed so far, checked against `max_nodes`.
+    node_count: usize,
+    /// Maximum number of statements and expressions, from `ParseLimits::max_nodes`.
+    max_nodes: Option<usize>,
 }
 
 impl<'a> Parser<'a> {
-    fn new(code: &'a str, filename: &'a str, mut interner: InternerBuilder) -> Self {
+    fn new(code: &'a str, filename: &'a str, mut interner: InternerBuilder, limits: ParseLimits) -> Self {
         // Position of each line in the source code, to convert indexes to line number and column number
         let mut line_ends = vec![];
         for (i, c) in code.chars().enumerate() {
@@ -184,7 +338,9 @@ impl<'a> Parser<'a> {
             code,
             filename_id,
             interner,
-            depth_remaining: MAX_NESTING_DEPTH,
+            depth_remaining: limits.nesting_depth(),
+            node_count: 0,
+            max_nodes: limits.max_nodes,
         }
     }
 
@@ -229,6 +385,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_statement(&mut self, statement: Stmt) -> Result<ParseNode, ParseError> {
+        self.count_node(|| statement.range())?;
         self.decr_depth_remaining(|| statement.range())?;
         let result = self.parse_statement_impl(statement);
         self.depth_remaining += 1;
@@ -275,18 +432,12 @@ impl<'a> Parser<'a> {
                     is_async,
                 }))
             }
-            Stmt::ClassDef(c) => Err(ParseError::not_implemented(
-                "class definitions",
-                self.convert_range(c.range),
-            )),
+            Stmt::ClassDef(class) => self.parse_class_def(class),
             Stmt::Return(ast::StmtReturn { value, .. }) => match value {
                 Some(value) => Ok(Node::Return(self.parse_expression(*value)?)),
                 None => Ok(Node::ReturnNone),
             },
-            Stmt::Delete(d) => Err(ParseError::not_implemented(
-                "the 'del' statement",
-                self.convert_range(d.range),
-            )),
+            Stmt::Delete(d) => self.parse_delete(d),
             Stmt::TypeAlias(t) => Err(ParseError::not_implemented("type aliases", self.convert_range(t.range))),
             Stmt::Assign(ast::StmtAssign {
                 targets, value, range, ..
@@ -316,7 +467,7 @@ impl<'a> Parser<'a> {
                     ));
                 }
                 Ok(Node::For {
-                    target: self.parse_unpack_target(*target)?,
+                    target: self.parse_loop_target(*target)?,
                     iter: self.parse_expression(*iter)?,
                     body: self.parse_statements(body)?,
                     or_else: self.parse_statements(orelse)?,
@@ -355,13 +506,16 @@ impl<'a> Parser<'a> {
                 "pattern matching (match statements)",
                 self.convert_range(m.range),
             )),
-            Stmt::Raise(ast::StmtRaise { exc, .. }) => {
-                // TODO add cause to Node::Raise
-                let expr = match exc {
+            Stmt::Raise(ast::StmtRaise { exc, cause, .. }) => {
+                let exc = match exc {
+                    Some(expr) => Some(self.parse_expression(*expr)?),
+                    None => None,
+                };
+                let cause = match cause {
                     Some(expr) => Some(self.parse_expression(*expr)?),
                     None => None,
                 };
-                Ok(Node::Raise(expr))
+                Ok(Node::Raise { exc, cause })
             }
             Stmt::Try(ast::StmtTry {
                 body,
@@ -504,6 +658,26 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `del target[index]`, the only form of `del` supported.
+    fn parse_delete(&mut self, delete: ast::StmtDelete) -> Result<ParseNode, ParseError> {
+        let position = self.convert_range(delete.range);
+        let [target] = <[AstExpr; 1]>::try_from(delete.targets)
+            .map_err(|_| ParseError::not_implemented("the 'del' statement with multiple targets", position))?;
+        match target {
+            AstExpr::Subscript(ast::ExprSubscript {
+                value, slice, range, ..
+            }) => Ok(Node::DeleteSubscript {
+                target: self.parse_identifier(*value)?,
+                index: self.parse_expression(*slice)?,
+                target_position: self.convert_range(range),
+            }),
+            _ => Err(ParseError::not_implemented(
+                "the 'del' statement except for deleting items",
+                position,
+            )),
+        }
+    }
+
     /// `lhs = rhs` -> `lhs, rhs`
     /// Handles simple assignments (x = value), subscript assignments (dict[key] = value),
     /// attribute assignments (obj.attr = value), and tuple unpacking (a, b = value)
@@ -564,6 +738,7 @@ impl<'a> Parser<'a> {
     /// Includes depth tracking to prevent stack overflow from deeply nested structures.
     /// Matches CPython's limit of 200 for nested parentheses.
     fn parse_expression(&mut self, expression: AstExpr) -> Result<ExprLoc, ParseError> {
+        self.count_node(|| expression.range())?;
         self.decr_depth_remaining(|| expression.range())?;
         let result = self.parse_expression_impl(expression);
         self.depth_remaining += 1;
@@ -572,31 +747,7 @@ impl<'a> Parser<'a> {
 
     fn parse_expression_impl(&mut self, expression: AstExpr) -> Result<ExprLoc, ParseError> {
         match expression {
-            AstExpr::BoolOp(ast::ExprBoolOp { op, values, range, .. }) => {
-                // Handle chained boolean operations like `a and b and c` by right-folding
-                // into nested binary operations: `a and (b and c)`
-                let rust_op = convert_bool_op(op);
-                let position = self.convert_range(range);
-                let mut values_iter = values.into_iter().rev();
-
-                // Start with the rightmost value
-                let last_value = values_iter.next().expect("Expected at least one value in boolean op");
-                let mut result = self.parse_expression(last_value)?;
-
-                // Fold from right to left
-                for value in values_iter {
-                    let left = Box::new(self.parse_expression(value)?);
-                    result = ExprLoc::new(
-                        position,
-                        Expr::Op {
-                            left,
-                            op: rust_op.clone(),
-                            right: Box::new(result),
-                        },
-                    );
-                }
-                Ok(result)
-            }
+            AstExpr::BoolOp(ast::ExprBoolOp { op, values, range, .. }) => self.parse_bool_op(op, values, range),
             AstExpr::Named(ast::ExprNamed {
                 target, value, range, ..
             }) => {
@@ -610,18 +761,17 @@ impl<'a> Parser<'a> {
                     },
                 ))
             }
+            AstExpr::BinOp(binop) if binop.op == AstOperator::Add => self.parse_add_chain(binop),
             AstExpr::BinOp(ast::ExprBinOp {
                 left, op, right, range, ..
             }) => {
                 let left = Box::new(self.parse_expression(*left)?);
                 let right = Box::new(self.parse_expression(*right)?);
+                let position = self.convert_range(range);
+                let op = convert_op(op);
                 Ok(ExprLoc {
-                    position: self.convert_range(range),
-                    expr: Expr::Op {
-                        left,
-                        op: convert_op(op),
-                        right,
-                    },
+                    position,
+                    expr: Expr::Op { left, op, right },
                 })
             }
             AstExpr::UnaryOp(ast::ExprUnaryOp { op, operand, range, .. }) => match op {
@@ -1051,6 +1201,120 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses chained boolean operations like `a and b and c` into a single `Expr::BoolOp`.
+    ///
+    /// The chain is kept flat, as in ruff's AST, so however long it is it only takes one
+    /// level of the nesting budget.
+    fn parse_bool_op(&mut self, op: BoolOp, values: Vec<AstExpr>, range: TextRange) -> Result<ExprLoc, ParseError> {
+        let values = values
+            .into_iter()
+            .map(|value| self.parse_expression(value))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ExprLoc::new(
+            self.convert_range(range),
+            Expr::BoolOp {
+                op: convert_bool_op(op),
+                values,
+            },
+        ))
+    }
+
+    /// Parses a chain of additions like `a + b + c`, which ruff parses as `(a + b) + c`.
+    ///
+    /// Concatenation of a leading run of string literals, as in `'a' + 'b' + x`, is folded
+    /// here rather than with the other constant folding in prepare, since only the parser can
+    /// intern new strings. The chain is walked iteratively so the folded string is built once
+    /// and interned once, rather than interning every intermediate result. Folding stops
+    /// before the string would exceed `MAX_FOLDED_STR_LEN`, leaving the rest to run as usual.
+    fn parse_add_chain(&mut self, top: ast::ExprBinOp) -> Result<ExprLoc, ParseError> {
+        // Each addition below the top one is charged to the nesting budget as it would be if
+        // the chain were parsed recursively
+        let mut additions = Vec::new();
+        let mut charged: u16 = 0;
+        let mut node = top;
+        let leftmost = loop {
+            let ast::ExprBinOp { left, right, range, .. } = node;
+            additions.push((range, *right));
+            match *left {
+                AstExpr::BinOp(inner) if inner.op == AstOperator::Add => {
+                    let result = self
+                        .count_node(|| inner.range)
+                        .and_then(|()| self.decr_depth_remaining(|| inner.range));
+                    if let Err(e) = result {
+                        self.depth_remaining += charged;
+                        return Err(e);
+                    }
+                    charged += 1;
+                    node = inner;
+                }
+                left => break left,
+            }
+        };
+        let operands = self.parse_add_operands(leftmost, additions, &mut charged);
+        self.depth_remaining += charged;
+        let (mut operands, positions) = operands?;
+
+        // Join the leading run of string literals
+        let mut concat = String::new();
+        let mut folded = 0;
+        for operand in &operands {
+            let Expr::Literal(Literal::Str(string_id)) = operand.expr else {
+                break;
+            };
+            let s = self.interner.get_str(string_id);
+            if folded > 0 && concat.len() + s.len() > MAX_FOLDED_STR_LEN {
+                break;
+            }
+            concat.push_str(s);
+            folded += 1;
+        }
+
+        let folded = folded.max(1);
+        let rest = operands.split_off(folded);
+        let mut result = if folded > 1 {
+            let string_id = self.interner.intern(&concat);
+            ExprLoc::new(positions[folded - 2], Expr::Literal(Literal::Str(string_id)))
+        } else {
+            operands.pop().expect("an addition has a left operand")
+        };
+        for (right, position) in rest.into_iter().zip(&positions[folded - 1..]) {
+            result = ExprLoc::new(
+                *position,
+                Expr::Op {
+                    left: Box::new(result),
+                    op: Operator::Add,
+                    right: Box::new(right),
+                },
+            );
+        }
+        Ok(result)
+    }
+
+    /// Parses the operands of the additions collected by `parse_add_chain`, top first, returning
+    /// them in evaluation order along with the position of each addition, bottom first.
+    ///
+    /// One level of the nesting budget, counted by `charged`, is given back after each addition,
+    /// so every operand is parsed at the depth it has in the tree.
+    fn parse_add_operands(
+        &mut self,
+        leftmost: AstExpr,
+        additions: Vec<(TextRange, AstExpr)>,
+        charged: &mut u16,
+    ) -> Result<(Vec<ExprLoc>, Vec<CodeRange>), ParseError> {
+        let mut operands = Vec::with_capacity(additions.len() + 1);
+        let mut positions = Vec::with_capacity(additions.len());
+        operands.push(self.parse_expression(leftmost)?);
+        for (range, right) in additions.into_iter().rev() {
+            operands.push(self.parse_expression(right)?);
+            positions.push(self.convert_range(range));
+            if *charged > 0 {
+                self.depth_remaining += 1;
+                *charged -= 1;
+            }
+        }
+        Ok((operands, positions))
+    }
+
     /// Parses a chain comparison expression like `a < b < c < d`.
     ///
     /// Chain comparisons evaluate each intermediate value only once and short-circuit
@@ -1091,6 +1355,20 @@ impl<'a> Parser<'a> {
         result
     }
 
+    /// Parses the target of a `for` loop or comprehension.
+    ///
+    /// Same as `parse_unpack_target`, except that a bare starred name (`for *a in rows`)
+    /// is rejected as in CPython: a starred target is only valid inside a tuple or list.
+    fn parse_loop_target(&mut self, ast: AstExpr) -> Result<UnpackTarget, ParseError> {
+        if let AstExpr::Starred(ast::ExprStarred { range, .. }) = &ast {
+            return Err(ParseError::syntax(
+                "starred assignment target must be in a list or tuple",
+                self.convert_range(*range),
+            ));
+        }
+        self.parse_unpack_target(ast)
+    }
+
     fn parse_unpack_target_impl(&mut self, ast: AstExpr) -> Result<UnpackTarget, ParseError> {
         match ast {
             AstExpr::Name(ast::ExprName { id, range, .. }) => Ok(UnpackTarget::Name(self.identifier(&id, range))),
@@ -1152,6 +1430,93 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses a class definition into its name, base and methods.
+    ///
+    /// Anything beyond a plain class whose body holds methods, `pass` and docstrings, or an
+    /// exception subclass whose body holds only `pass` and docstrings, is rejected with a
+    /// not-implemented error pointing at the offending code.
+    fn parse_class_def(&mut self, class: ast::StmtClassDef) -> Result<ParseNode, ParseError> {
+        if let Some(decorator) = class.decorator_list.first() {
+            return Err(ParseError::not_implemented(
+                "class decorators",
+                self.convert_range(decorator.range),
+            ));
+        }
+        if let Some(type_params) = &class.type_params {
+            return Err(ParseError::not_implemented(
+                "class type parameters",
+                self.convert_range(type_params.range),
+            ));
+        }
+        let mut base = None;
+        if let Some(arguments) = class.arguments {
+            if !arguments.keywords.is_empty() || arguments.args.len() > 1 {
+                return Err(ParseError::not_implemented(
+                    "class keywords and multiple inheritance",
+                    self.convert_range(arguments.range),
+                ));
+            }
+            if let Some(expr) = arguments.args.into_vec().pop() {
+                base = Some(self.parse_expression(expr)?);
+            }
+        }
+
+        let name = self.identifier(&class.name.id, class.name.range);
+        let mut methods = Vec::new();
+        for statement in class.body {
+            match statement {
+                Stmt::FunctionDef(ref function) => {
+                    let kind = self.method_kind(&function.decorator_list)?;
+                    match self.parse_statement(statement)? {
+                        Node::FunctionDef(func) => methods.push(MethodDef { kind, func }),
+                        _ => unreachable!("function definitions always parse to Node::FunctionDef"),
+                    }
+                }
+                Stmt::Pass(_) => {}
+                Stmt::Expr(ast::StmtExpr { value, .. })
+                    if matches!(*value, AstExpr::StringLiteral(_) | AstExpr::EllipsisLiteral(_)) => {}
+                other => {
+                    return Err(ParseError::not_implemented(
+                        "class attributes other than methods",
+                        self.convert_range(other.range()),
+                    ));
+                }
+            }
+        }
+        if base.is_some()
+            && let Some(method) = methods.first()
+        {
+            return Err(ParseError::not_implemented(
+                "methods in subclasses",
+                method.func.name.position,
+            ));
+        }
+        Ok(Node::ClassDef(ClassDef { name, base, methods }))
+    }
+
+    /// Determines how a method binds from its decorators.
+    ///
+    /// Decorators are resolved by name here rather than evaluated, so only a single
+    /// `@property`, `@staticmethod` or `@classmethod` is supported.
+    fn method_kind(&self, decorators: &[ast::Decorator]) -> Result<MethodKind, ParseError> {
+        match decorators {
+            [] => Ok(MethodKind::Instance),
+            [decorator] => match &decorator.expression {
+                AstExpr::Name(ast::ExprName { id, .. }) if id.as_str() == "property" => Ok(MethodKind::Property),
+                AstExpr::Name(ast::ExprName { id, .. }) if id.as_str() == "staticmethod" => Ok(MethodKind::Static),
+                AstExpr::Name(ast::ExprName { id, .. }) if id.as_str() == "classmethod" => Ok(MethodKind::Class),
+                _ => Err(ParseError::not_implemented(
+                    "method decorators other than @property, @staticmethod and @classmethod",
+                    self.convert_range(decorator.range),
+                )),
+            },
+            [_, second, ..] => Err(ParseError::not_implemented(
+                "multiple method decorators",
+                self.convert_range(second.range),
+            )),
+        }
+    }
+
     fn identifier(&mut self, id: &Name, range: TextRange) -> Identifier {
         let string_id = self.interner.intern(id);
         Identifier::new(string_id, self.convert_range(range))
@@ -1194,7 +1559,7 @@ impl<'a> Parser<'a> {
                         self.convert_range(comp.range),
                     ));
                 }
-                let target = self.parse_unpack_target(comp.target)?;
+                let target = self.parse_loop_target(comp.target)?;
                 let iter = self.parse_expression(comp.iter)?;
                 let ifs = comp
                     .ifs
@@ -1295,18 +1660,11 @@ impl<'a> Parser<'a> {
                     let string_id = self.interner.intern(&processed);
                     parts.push(FStringPart::Literal(string_id));
                 }
-                InterpolatedStringElement::Interpolation(interp) => {
+                InterpolatedStringElement::Interpolation(_) => {
                     has_interpolation = true;
-                    let expr = Box::new(self.parse_expression((*interp.expression).clone())?);
-                    let conversion = convert_conversion_flag(interp.conversion);
-                    // Format specs within format specs are not allowed in Python,
-                    // and debug_prefix doesn't apply to nested interpolations
-                    parts.push(FStringPart::Interpolation {
-                        expr,
-                        conversion,
-                        format_spec: None,
-                        debug_prefix: None,
-                    });
+                    // Nested replacement fields are full fields: `f'{x:{w:03}}'` formats `w` with
+                    // its own spec, and `f'{x:{w=}}'` inserts the debug text into the outer spec
+                    parts.push(self.parse_fstring_element(element)?);
                 }
             }
         }
@@ -1367,6 +1725,22 @@ impl<'a> Parser<'a> {
         (self.line_ends.len(), line_start, None)
     }
 
+    /// Counts a parsed statement or expression.
+    /// Returns an error if the program has more nodes than allowed by `ParseLimits::max_nodes`.
+    fn count_node(&mut self, get_range: impl FnOnce() -> TextRange) -> Result<(), ParseError> {
+        self.node_count += 1;
+        match self.max_nodes {
+            Some(max_nodes) if self.node_count > max_nodes => {
+                let position = self.convert_range(get_range());
+                Err(ParseError::syntax(
+                    format!("program too large: more than {max_nodes} statements and expressions"),
+                    position,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Decrements the depth remaining for nested parentheses.
     /// Returns an error if the depth remaining goes to zero.
     fn decr_depth_remaining(&mut self, get_range: impl FnOnce() -> TextRange) -> Result<(), ParseError> {
@@ -1523,6 +1897,16 @@ pub enum ParseError {
         msg: Cow<'static, str>,
         position: CodeRange,
     },
+    /// Binding or mutating construct rejected in frozen mode.
+    Frozen {
+        msg: Cow<'static, str>,
+        position: CodeRange,
+    },
+    /// Reference to a builtin the host denied.
+    Name {
+        msg: Cow<'static, str>,
+        position: CodeRange,
+    },
 }
 
 impl ParseError {
@@ -1553,6 +1937,20 @@ impl ParseError {
             position,
         }
     }
+
+    pub(crate) fn frozen(msg: impl Into<Cow<'static, str>>, position: CodeRange) -> Self {
+        Self::Frozen {
+            msg: msg.into(),
+            position,
+        }
+    }
+
+    pub(crate) fn name(msg: impl Into<Cow<'static, str>>, position: CodeRange) -> Self {
+        Self::Name {
+            msg: msg.into(),
+            position,
+        }
+    }
 }
 
 impl ParseError {
@@ -1578,6 +1976,16 @@ impl ParseError {
                 Some(msg.into_owned()),
                 vec![StackFrame::from_position_no_caret(position, filename, source)],
             ),
+            Self::Frozen { msg, position } => MontyException::new_full(
+                ExcType::RuntimeError,
+                Some(msg.into_owned()),
+                vec![StackFrame::from_position(position, filename, source)],
+            ),
+            Self::Name { msg, position } => MontyException::new_full(
+                ExcType::NameError,
+                Some(msg.into_owned()),
+                vec![StackFrame::from_position(position, filename, source)],
+            ),
         }
     }
 }
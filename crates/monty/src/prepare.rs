@@ -4,7 +4,7 @@ use ahash::{AHashMap, AHashSet};
 
 use crate::{
     args::ArgExprs,
-    builtins::Builtins,
+    builtins::{Builtins, BuiltinsFunctions},
     expressions::{
         Callable, CmpOperator, Comprehension, Expr, ExprLoc, Identifier, Literal, NameScope, Node, Operator,
         PreparedFunctionDef, PreparedNode, UnpackTarget,
@@ -12,10 +12,37 @@ use crate::{
     fstring::{FStringPart, FormatSpec},
     intern::{InternerBuilder, StringId},
     namespace::NamespaceId,
-    parse::{CodeRange, ExceptHandler, ParseError, ParseNode, ParseResult, ParsedSignature, RawFunctionDef, Try},
+    parse::{
+        ClassDef, CodeRange, ExceptHandler, MethodDef, ParseError, ParseLimits, ParseNode, ParseResult,
+        ParsedSignature, RawFunctionDef, Try,
+    },
     signature::Signature,
+    value::{EitherStr, floor_divmod},
 };
 
+/// Names of methods that mutate their receiver in place, rejected in frozen mode.
+///
+/// Covers the in-place methods of `list`, `dict` and `set`. The receiver's type is only known
+/// at runtime, so any method with one of these names is rejected regardless of the object.
+const MUTATING_METHODS: &[&str] = &[
+    "add",
+    "append",
+    "clear",
+    "difference_update",
+    "discard",
+    "extend",
+    "insert",
+    "intersection_update",
+    "pop",
+    "popitem",
+    "remove",
+    "reverse",
+    "setdefault",
+    "sort",
+    "symmetric_difference_update",
+    "update",
+];
+
 /// Result of the prepare phase, containing everything needed to compile and execute code.
 ///
 /// This struct holds the outputs of name resolution and AST transformation:
@@ -39,6 +66,114 @@ pub struct PrepareResult {
     pub interner: InternerBuilder,
 }
 
+/// Options chosen when code is compiled by [`MontyRun::with_options`](crate::MontyRun::with_options).
+///
+/// Restricts what the code may do, sets the module name and the parse limits, and chooses
+/// whether to use the compile cache. All options can be combined. Use `PrepareOptions::default()`
+/// for the behaviour of [`MontyRun::new`](crate::MontyRun::new), or build custom options with
+/// the builder pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PrepareOptions {
+    /// Reject every construct that binds a name or mutates a value with a `RuntimeError`,
+    /// for evaluating pure expressions.
+    ///
+    /// Covers assignments of every form (including `:=`), `for` loops, function definitions,
+    /// imports, `global`/`nonlocal`, `except ... as`, `setattr()`, and in-place container
+    /// methods such as `list.append` or `dict.update` (matched by method name).
+    pub frozen: bool,
+    /// Reject assignments that would rebind a declared input name in the module namespace
+    /// with a `SyntaxError`. Function locals that shadow an input are still allowed.
+    pub read_only_inputs: bool,
+    /// Don't implicitly return the last top-level expression, so the module returns `None`
+    /// as a script would.
+    pub no_implicit_return: bool,
+    /// Names of builtins which aren't resolved, e.g. `["print", "setattr"]`; referencing one
+    /// raises `NameError`.
+    ///
+    /// Names which aren't builtins are ignored, and a denied name can still be bound and
+    /// used as an ordinary variable.
+    pub denied_builtins: Vec<String>,
+    /// Value of the module's `__name__` constant, `"__main__"` if not set.
+    pub module_name: Option<String>,
+    /// Size limits enforced while parsing, to protect the host from adversarial inputs.
+    pub parse_limits: ParseLimits,
+    /// Reuse a process-wide cache of compiled programs, see [`MontyRun::with_options`](crate::MontyRun::with_options).
+    pub compile_cache: bool,
+    /// Keep `x % n == k` as a separate `%` and `==` instead of fusing them into a single
+    /// comparison, so the compiled program, e.g. as written by
+    /// [`MontyRun::dump_json`](crate::MontyRun::dump_json), has the same operations as the
+    /// source. The result of the comparison is the same either way.
+    pub no_mod_eq: bool,
+}
+
+impl PrepareOptions {
+    /// Creates new PrepareOptions with all options disabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether constructs that bind a name or mutate a value are rejected.
+    #[must_use]
+    pub fn frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Sets whether assignments to declared input names are rejected.
+    #[must_use]
+    pub fn read_only_inputs(mut self, read_only_inputs: bool) -> Self {
+        self.read_only_inputs = read_only_inputs;
+        self
+    }
+
+    /// Sets whether the last top-level expression is evaluated as a plain statement
+    /// instead of being returned.
+    #[must_use]
+    pub fn no_implicit_return(mut self, no_implicit_return: bool) -> Self {
+        self.no_implicit_return = no_implicit_return;
+        self
+    }
+
+    /// Sets the names of builtins to disable.
+    #[must_use]
+    pub fn denied_builtins(mut self, denied_builtins: Vec<String>) -> Self {
+        self.denied_builtins = denied_builtins;
+        self
+    }
+
+    /// Sets the value of `__name__`.
+    #[must_use]
+    pub fn module_name(mut self, module_name: impl Into<String>) -> Self {
+        self.module_name = Some(module_name.into());
+        self
+    }
+
+    /// Sets the size limits enforced while parsing.
+    #[must_use]
+    pub fn parse_limits(mut self, parse_limits: ParseLimits) -> Self {
+        self.parse_limits = parse_limits;
+        self
+    }
+
+    /// Sets whether the compile cache is used.
+    #[must_use]
+    pub fn compile_cache(mut self, compile_cache: bool) -> Self {
+        self.compile_cache = compile_cache;
+        self
+    }
+
+    /// Sets whether `x % n == k` is kept as a separate `%` and `==`.
+    #[must_use]
+    pub fn no_mod_eq(mut self, no_mod_eq: bool) -> Self {
+        self.no_mod_eq = no_mod_eq;
+        self
+    }
+}
+
+/// Default value of `__name__`, as for a script run directly by CPython.
+const DEFAULT_MODULE_NAME: &str = "__main__";
+
 /// Prepares parsed nodes for compilation by resolving names and building the initial namespace.
 ///
 /// The namespace will be converted to runtime Objects when execution begins and the heap is available.
@@ -47,15 +182,19 @@ pub(crate) fn prepare(
     parse_result: ParseResult,
     input_names: Vec<String>,
     external_functions: &[String],
+    options: PrepareOptions,
 ) -> Result<PrepareResult, ParseError> {
-    let ParseResult { nodes, interner } = parse_result;
-    let mut p = Prepare::new_module(input_names, external_functions, &interner);
+    let ParseResult { nodes, mut interner } = parse_result;
+    let module_name = interner.intern(options.module_name.as_deref().unwrap_or(DEFAULT_MODULE_NAME));
+    let mut p = Prepare::new_module(input_names, external_functions, &options, module_name, &interner);
+    p.register_function_globals(&nodes);
     let mut prepared_nodes = p.prepare_nodes(nodes)?;
 
     // In the root frame, the last expression is implicitly returned
-    // if it's not None. This matches Python REPL behavior where the last expression
-    // value is displayed/returned.
-    if let Some(Node::Expr(expr_loc)) = prepared_nodes.last()
+    // if it's not None (unless disabled). This matches Python REPL behavior where the last
+    // expression value is displayed/returned.
+    if !options.no_implicit_return
+        && let Some(Node::Expr(expr_loc)) = prepared_nodes.last()
         && !expr_loc.expr.is_none()
     {
         let new_expr_loc = expr_loc.clone();
@@ -79,8 +218,10 @@ pub(crate) fn prepare_with_existing_names(
     parse_result: ParseResult,
     existing_name_map: AHashMap<String, NamespaceId>,
 ) -> Result<PrepareResult, ParseError> {
-    let ParseResult { nodes, interner } = parse_result;
-    let mut p = Prepare::new_module_with_name_map(existing_name_map, &interner);
+    let ParseResult { nodes, mut interner } = parse_result;
+    let module_name = interner.intern(DEFAULT_MODULE_NAME);
+    let mut p = Prepare::new_module_with_name_map(existing_name_map, module_name, &interner);
+    p.register_function_globals(&nodes);
     let mut prepared_nodes = p.prepare_nodes(nodes)?;
 
     // In the root frame, the last expression is implicitly returned to match REPL behavior.
@@ -112,6 +253,7 @@ pub(crate) fn prepare_with_existing_names(
 /// - Which variables are assigned locally (determines local vs global scope)
 /// - Reference to the global name map for resolving global variable references
 /// - Enclosing scope information for closure analysis
+#[derive(Clone)]
 struct Prepare<'i> {
     /// Reference to the string interner for looking up names in error messages.
     interner: &'i InternerBuilder,
@@ -131,6 +273,11 @@ struct Prepare<'i> {
     /// Names that have been assigned so far during the second pass (in order).
     /// Used to produce the correct error message for `global x` when x was assigned before.
     names_assigned_in_order: AHashSet<String>,
+    /// Names declared `global` in some function but never necessarily bound at module level.
+    ///
+    /// They get module namespace slots up front (see [`Prepare::register_function_globals`]),
+    /// and module-level reads of them raise `NameError` until a function assigns them.
+    function_globals: AHashSet<String>,
     /// Copy of the module-level global name map.
     /// Used by functions to resolve global variable references.
     /// None at module level (not needed since all names are global there).
@@ -148,6 +295,27 @@ struct Prepare<'i> {
     /// that are both nonlocal and captured by nested functions), then extended as new
     /// captures are discovered during nested function preparation.
     cell_var_map: AHashMap<String, NamespaceId>,
+    /// Whether binding and mutating constructs are rejected (frozen execution mode).
+    frozen: bool,
+    /// Input names that may not be rebound, empty unless read-only inputs were requested.
+    ///
+    /// Copied into function scopes so that `global x; x = 1` is caught as well.
+    read_only_inputs: AHashSet<String>,
+    /// Builtins which may not be referenced, empty unless some were denied.
+    ///
+    /// Copied into function scopes.
+    denied_builtins: AHashSet<Builtins>,
+    /// Iteration variable names of the comprehensions currently being prepared.
+    /// Per PEP 572, a walrus inside a comprehension may not rebind any of them.
+    comprehension_targets: AHashSet<String>,
+    /// Interned value of `__name__`, which reads of an unbound `__name__` resolve to.
+    ///
+    /// Copied into function scopes.
+    module_name: StringId,
+    /// Whether `x % n == k` is rewritten to `CmpOperator::ModEq`, see `PrepareOptions::no_mod_eq`.
+    ///
+    /// Copied into function scopes.
+    mod_eq: bool,
 }
 
 impl<'i> Prepare<'i> {
@@ -159,15 +327,33 @@ impl<'i> Prepare<'i> {
     /// # Arguments
     /// * `input_names` - Names that should be pre-registered in the namespace (e.g., external variables)
     /// * `external_functions` - Names of external functions to pre-register
+    /// * `options` - Restrictions to enforce while preparing
+    /// * `module_name` - Interned value of `__name__`
     /// * `interner` - Reference to the string interner for looking up names
-    fn new_module(input_names: Vec<String>, external_functions: &[String], interner: &'i InternerBuilder) -> Self {
+    fn new_module(
+        input_names: Vec<String>,
+        external_functions: &[String],
+        options: &PrepareOptions,
+        module_name: StringId,
+        interner: &'i InternerBuilder,
+    ) -> Self {
         let mut name_map = AHashMap::with_capacity(input_names.len() + external_functions.len());
         for (index, name) in external_functions.iter().enumerate() {
             name_map.insert(name.clone(), NamespaceId::new(index));
         }
+        let read_only_inputs = if options.read_only_inputs {
+            input_names.iter().cloned().collect()
+        } else {
+            AHashSet::new()
+        };
         for (index, name) in input_names.into_iter().enumerate() {
             name_map.insert(name, NamespaceId::new(external_functions.len() + index));
         }
+        let denied_builtins = options
+            .denied_builtins
+            .iter()
+            .filter_map(|name| name.parse().ok())
+            .collect();
         let namespace_size = name_map.len();
         Self {
             interner,
@@ -177,17 +363,28 @@ impl<'i> Prepare<'i> {
             global_names: AHashSet::new(),
             assigned_names: AHashSet::new(),
             names_assigned_in_order: AHashSet::new(),
+            function_globals: AHashSet::new(),
             global_name_map: None,
             enclosing_locals: None,
             free_var_map: AHashMap::new(),
             cell_var_map: AHashMap::new(),
+            frozen: options.frozen,
+            read_only_inputs,
+            denied_builtins,
+            comprehension_targets: AHashSet::new(),
+            module_name,
+            mod_eq: !options.no_mod_eq,
         }
     }
 
     /// Creates a module-scope Prepare instance from an existing global name map.
     ///
     /// Used by incremental REPL compilation to keep stable slot assignments across snippets.
-    fn new_module_with_name_map(name_map: AHashMap<String, NamespaceId>, interner: &'i InternerBuilder) -> Self {
+    fn new_module_with_name_map(
+        name_map: AHashMap<String, NamespaceId>,
+        module_name: StringId,
+        interner: &'i InternerBuilder,
+    ) -> Self {
         let namespace_size = name_map
             .values()
             .map(|id| id.index())
@@ -202,10 +399,17 @@ impl<'i> Prepare<'i> {
             global_names: AHashSet::new(),
             assigned_names: AHashSet::new(),
             names_assigned_in_order: AHashSet::new(),
+            function_globals: AHashSet::new(),
             global_name_map: None,
             enclosing_locals: None,
             free_var_map: AHashMap::new(),
             cell_var_map: AHashMap::new(),
+            frozen: false,
+            read_only_inputs: AHashSet::new(),
+            denied_builtins: AHashSet::new(),
+            comprehension_targets: AHashSet::new(),
+            module_name,
+            mod_eq: true,
         }
     }
 
@@ -288,10 +492,34 @@ impl<'i> Prepare<'i> {
             global_names,
             assigned_names,
             names_assigned_in_order: AHashSet::new(),
+            function_globals: AHashSet::new(),
             global_name_map: Some(global_name_map),
             enclosing_locals,
             free_var_map,
             cell_var_map,
+            frozen: false,
+            read_only_inputs: AHashSet::new(),
+            denied_builtins: AHashSet::new(),
+            comprehension_targets: AHashSet::new(),
+            module_name: StringId::default(),
+            mod_eq: true,
+        }
+    }
+
+    /// Allocates module namespace slots for names declared `global` in any function of `nodes`.
+    ///
+    /// Functions resolve globals against a copy of the module name map taken when they are
+    /// defined, so a global that a function creates (`global x; x = 1`) needs a slot before
+    /// any function is prepared, even if the module itself never assigns it.
+    fn register_function_globals(&mut self, nodes: &[ParseNode]) {
+        let mut names = Vec::new();
+        collect_function_global_names(nodes, false, &mut names, self.interner);
+        for name in names {
+            if let Entry::Vacant(e) = self.name_map.entry(name.clone()) {
+                e.insert(NamespaceId::new(self.namespace_size));
+                self.namespace_size += 1;
+                self.function_globals.insert(name);
+            }
         }
     }
 
@@ -309,32 +537,27 @@ impl<'i> Prepare<'i> {
         let nodes_len = nodes.len();
         let mut new_nodes = Vec::with_capacity(nodes_len);
         for node in nodes {
+            if self.frozen {
+                Self::check_frozen_node(&node)?;
+            }
+            if !self.read_only_inputs.is_empty() {
+                self.check_node_keeps_inputs(&node)?;
+            }
             match node {
                 Node::Pass => (),
                 Node::Expr(expr) => new_nodes.push(Node::Expr(self.prepare_expression(expr)?)),
                 Node::Return(expr) => new_nodes.push(Node::Return(self.prepare_expression(expr)?)),
                 Node::ReturnNone => new_nodes.push(Node::ReturnNone),
-                Node::Raise(exc) => {
-                    let expr = match exc {
-                        Some(expr) => {
-                            let prepared = self.prepare_expression(expr)?;
-                            match prepared.expr {
-                                // Handle raising a builtin exception type without instantiation,
-                                // e.g. `raise TypeError`. Transform into `raise TypeError()`
-                                // so the exception is properly instantiated before being raised.
-                                Expr::Builtin(b) => {
-                                    let call_expr = Expr::Call {
-                                        callable: Callable::Builtin(b),
-                                        args: Box::new(ArgExprs::Empty),
-                                    };
-                                    Some(ExprLoc::new(prepared.position, call_expr))
-                                }
-                                _ => Some(prepared),
-                            }
-                        }
+                Node::Raise { exc, cause } => {
+                    let exc = match exc {
+                        Some(expr) => Some(self.prepare_raised_expression(expr)?),
+                        None => None,
+                    };
+                    let cause = match cause {
+                        Some(expr) => Some(self.prepare_raised_expression(expr)?),
                         None => None,
                     };
-                    new_nodes.push(Node::Raise(expr));
+                    new_nodes.push(Node::Raise { exc, cause });
                 }
                 Node::Assert { test, msg } => {
                     let test = self.prepare_expression(test)?;
@@ -394,6 +617,20 @@ impl<'i> Prepare<'i> {
                         target_position,
                     });
                 }
+                Node::DeleteSubscript {
+                    target,
+                    index,
+                    target_position,
+                } => {
+                    // Like SubscriptAssign, this modifies the target rather than unbinding it
+                    let target = self.get_id(target).0;
+                    let index = self.prepare_expression(index)?;
+                    new_nodes.push(Node::DeleteSubscript {
+                        target,
+                        index,
+                        target_position,
+                    });
+                }
                 Node::AttrAssign {
                     object,
                     attr,
@@ -440,9 +677,30 @@ impl<'i> Prepare<'i> {
                 }
                 Node::If { test, body, or_else } => {
                     let test = self.prepare_expression(test)?;
-                    let body = self.prepare_nodes(body)?;
-                    let or_else = self.prepare_nodes(or_else)?;
-                    new_nodes.push(Node::If { test, body, or_else });
+                    let constant = match &test.expr {
+                        Expr::Literal(literal) => self.literal_truthiness(literal),
+                        _ => None,
+                    };
+                    if let Some(taken) = constant {
+                        // Dead code elimination: only the branch that runs is prepared. It stays
+                        // wrapped in `if True:` so a trailing expression in it isn't returned.
+                        let (live, dead) = if taken { (body, or_else) } else { (or_else, body) };
+                        self.check_dead_branch(dead)?;
+                        let test = ExprLoc {
+                            position: test.position,
+                            expr: Expr::Literal(Literal::Bool(true)),
+                        };
+                        let body = self.prepare_nodes(live)?;
+                        new_nodes.push(Node::If {
+                            test,
+                            body,
+                            or_else: Vec::new(),
+                        });
+                    } else {
+                        let body = self.prepare_nodes(body)?;
+                        let or_else = self.prepare_nodes(or_else)?;
+                        new_nodes.push(Node::If { test, body, or_else });
+                    }
                 }
                 Node::FunctionDef(RawFunctionDef {
                     name,
@@ -453,6 +711,10 @@ impl<'i> Prepare<'i> {
                     let func_node = self.prepare_function_def(name, &signature, body, is_async)?;
                     new_nodes.push(func_node);
                 }
+                Node::ClassDef(ClassDef { name, base, methods }) => {
+                    let class_node = self.prepare_class_def(name, base, methods)?;
+                    new_nodes.push(class_node);
+                }
                 Node::Global { names, position } => {
                     // At module level, `global` is a no-op since all variables are already global.
                     // In functions, the global declarations are already collected in the first pass
@@ -575,6 +837,144 @@ impl<'i> Prepare<'i> {
         Ok(new_nodes)
     }
 
+    /// Rejects statements that bind names or mutate values, for frozen mode.
+    ///
+    /// Frozen mode only admits code that computes a value: expressions, control flow,
+    /// `assert`, `raise` and `try`. Assignments of every form, `for` loops (which bind their
+    /// target), function definitions, imports and `global`/`nonlocal` declarations are rejected.
+    /// Mutations inside expressions are caught as expressions are prepared.
+    fn check_frozen_node(node: &ParseNode) -> Result<(), ParseError> {
+        let (what, position) = match node {
+            Node::Assign { target, .. } => ("assignment", target.position),
+            Node::UnpackAssign { targets_position, .. } => ("assignment", *targets_position),
+            Node::OpAssign { target, .. } => ("augmented assignment", target.position),
+            Node::SubscriptAssign { target_position, .. } => ("item assignment", *target_position),
+            Node::DeleteSubscript { target_position, .. } => ("item deletion", *target_position),
+            Node::AttrAssign { target_position, .. } => ("attribute assignment", *target_position),
+            Node::For { target, .. } => {
+                let position = match target {
+                    UnpackTarget::Name(ident) | UnpackTarget::Starred(ident) => ident.position,
+                    UnpackTarget::Tuple { position, .. } => *position,
+                };
+                ("for loop", position)
+            }
+            Node::FunctionDef(RawFunctionDef { name, .. }) => ("function definition", name.position),
+            Node::ClassDef(ClassDef { name, .. }) => ("class definition", name.position),
+            Node::Global { position, .. } => ("global declaration", *position),
+            Node::Nonlocal { position, .. } => ("nonlocal declaration", *position),
+            Node::Import { binding, .. } => ("import", binding.position),
+            Node::ImportFrom { position, .. } => ("import", *position),
+            _ => return Ok(()),
+        };
+        Err(ParseError::frozen(
+            format!("{what} is not allowed in frozen mode"),
+            position,
+        ))
+    }
+
+    /// Rejects a binding or mutating construct found while preparing frozen code.
+    fn check_not_frozen(&self, what: &str, position: CodeRange) -> Result<(), ParseError> {
+        if self.frozen {
+            Err(ParseError::frozen(
+                format!("{what} is not allowed in frozen mode"),
+                position,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rejects references to builtins which the host denied, as if they weren't defined.
+    fn check_builtin_allowed(&self, builtin: Builtins, position: CodeRange) -> Result<(), ParseError> {
+        if self.denied_builtins.contains(&builtin) {
+            let name = match builtin {
+                Builtins::Function(function) => function.to_string(),
+                Builtins::ExcType(exc_type) => exc_type.to_string(),
+                Builtins::Type(t) => t.to_string(),
+            };
+            Err(ParseError::name(format!("name '{name}' is not defined"), position))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rejects references to `setattr()` in frozen mode, since it mutates its first argument.
+    fn check_builtin_not_frozen(&self, builtin: Builtins, position: CodeRange) -> Result<(), ParseError> {
+        if builtin == Builtins::Function(BuiltinsFunctions::Setattr) {
+            self.check_not_frozen("setattr()", position)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rejects calls and lookups of in-place container methods in frozen mode.
+    ///
+    /// This is a check on the method name only, see [`MUTATING_METHODS`]. A method fetched
+    /// dynamically with `getattr()` is not detected.
+    fn check_method_not_frozen(&self, attr: &EitherStr, position: CodeRange) -> Result<(), ParseError> {
+        if !self.frozen {
+            return Ok(());
+        }
+        let name = match attr {
+            EitherStr::Interned(string_id) => self.interner.get_str(*string_id),
+            EitherStr::Heap(name) => name.as_str(),
+        };
+        if MUTATING_METHODS.contains(&name) {
+            self.check_not_frozen(&format!("mutating method '{name}'"), position)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rejects statements that would rebind a read-only input name.
+    ///
+    /// Covers every statement that binds a name. Exception handler (`as e`) and `:=` targets
+    /// are checked where they are prepared.
+    fn check_node_keeps_inputs(&self, node: &ParseNode) -> Result<(), ParseError> {
+        match node {
+            Node::Assign { target, .. } | Node::OpAssign { target, .. } => self.check_input_not_rebound(target),
+            Node::UnpackAssign { targets, .. } => targets
+                .iter()
+                .try_for_each(|target| self.check_unpack_target_keeps_inputs(target)),
+            Node::For { target, .. } => self.check_unpack_target_keeps_inputs(target),
+            Node::FunctionDef(RawFunctionDef { name, .. }) | Node::ClassDef(ClassDef { name, .. }) => {
+                self.check_input_not_rebound(name)
+            }
+            Node::Import { binding, .. } => self.check_input_not_rebound(binding),
+            Node::ImportFrom { names, .. } => names
+                .iter()
+                .try_for_each(|(_, binding)| self.check_input_not_rebound(binding)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects an unpacking target that would rebind a read-only input name.
+    fn check_unpack_target_keeps_inputs(&self, target: &UnpackTarget) -> Result<(), ParseError> {
+        match target {
+            UnpackTarget::Name(ident) | UnpackTarget::Starred(ident) => self.check_input_not_rebound(ident),
+            UnpackTarget::Tuple { targets, .. } => targets
+                .iter()
+                .try_for_each(|target| self.check_unpack_target_keeps_inputs(target)),
+        }
+    }
+
+    /// Raises a `SyntaxError` if binding `ident` here would rebind a read-only input.
+    ///
+    /// Only bindings in the module namespace can rebind an input: at module level, or in a
+    /// function that declared the name `global`. Other function bindings create locals.
+    fn check_input_not_rebound(&self, ident: &Identifier) -> Result<(), ParseError> {
+        let name = self.interner.get_str(ident.name_id);
+        let binds_global = self.is_module_scope || self.global_names.contains(name);
+        if binds_global && self.read_only_inputs.contains(name) {
+            Err(ParseError::syntax(
+                format!("cannot assign to input '{name}'"),
+                ident.position,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Prepares an exception handler by resolving names in the exception type and body.
     ///
     /// The exception variable (if present) is treated as an assigned name in the current scope.
@@ -589,6 +989,8 @@ impl<'i> Prepare<'i> {
         // The exception variable binding (e.g., `as e:`) is an assignment
         let name = match handler.name {
             Some(ident) => {
+                self.check_not_frozen("exception binding", ident.position)?;
+                self.check_input_not_rebound(&ident)?;
                 // Track that this name was assigned
                 self.names_assigned_in_order
                     .insert(self.interner.get_str(ident.name_id).to_string());
@@ -600,6 +1002,25 @@ impl<'i> Prepare<'i> {
         Ok(ExceptHandler { exc_type, name, body })
     }
 
+    /// Prepares the exception or cause expression of a `raise` statement.
+    ///
+    /// Handles raising a builtin exception type without instantiation, e.g. `raise TypeError`,
+    /// by transforming it into `raise TypeError()` so the exception is properly instantiated
+    /// before being raised.
+    fn prepare_raised_expression(&mut self, expr: ExprLoc) -> Result<ExprLoc, ParseError> {
+        let prepared = self.prepare_expression(expr)?;
+        match prepared.expr {
+            Expr::Builtin(b) => {
+                let call_expr = Expr::Call {
+                    callable: Callable::Builtin(b),
+                    args: Box::new(ArgExprs::Empty),
+                };
+                Ok(ExprLoc::new(prepared.position, call_expr))
+            }
+            _ => Ok(prepared),
+        }
+    }
+
     /// Prepares an expression by resolving names, transforming calls, and applying optimizations.
     ///
     /// Key transformations performed:
@@ -607,8 +1028,9 @@ impl<'i> Prepare<'i> {
     /// - Function calls are resolved from identifiers to builtin types
     /// - Attribute calls validate that the object is already defined (not a new name)
     /// - Lists and tuples are recursively prepared
+    /// - Operations on literals only are folded to a literal, see `fold_constant`
     /// - Modulo equality patterns like `x % n == k` (constant right-hand side) are optimized to
-    ///   `CmpOperator::ModEq`
+    ///   `CmpOperator::ModEq`, unless disabled with `PrepareOptions::no_mod_eq`
     ///
     /// # Errors
     /// Returns a NameError if an attribute call references an undefined variable
@@ -617,7 +1039,15 @@ impl<'i> Prepare<'i> {
         let expr = match expr {
             Expr::Literal(object) => Expr::Literal(object),
             Expr::Builtin(callable) => Expr::Builtin(callable),
-            Expr::Name(name) => self.resolve_name_or_builtin(name),
+            Expr::Name(name) if self.is_unbound_module_name(&name) => Expr::Literal(Literal::Str(self.module_name)),
+            Expr::Name(name) => {
+                let expr = self.resolve_name_or_builtin(name);
+                if let Expr::Builtin(builtin) = &expr {
+                    self.check_builtin_allowed(*builtin, position)?;
+                    self.check_builtin_not_frozen(*builtin, position)?;
+                }
+                expr
+            }
             Expr::Op { left, op, right } => Expr::Op {
                 left: Box::new(self.prepare_expression(*left)?),
                 op,
@@ -635,6 +1065,13 @@ impl<'i> Prepare<'i> {
                     .map(|(op, expr)| Ok((op, self.prepare_expression(expr)?)))
                     .collect::<Result<Vec<_>, _>>()?,
             },
+            Expr::BoolOp { op, values } => Expr::BoolOp {
+                op,
+                values: values
+                    .into_iter()
+                    .map(|expr| self.prepare_expression(expr))
+                    .collect::<Result<Vec<_>, _>>()?,
+            },
             Expr::Call { callable, mut args } => {
                 // Prepare the arguments
                 args.prepare_args(|expr| self.prepare_expression(expr))?;
@@ -648,9 +1085,14 @@ impl<'i> Prepare<'i> {
                     },
                     other @ Callable::Builtin(_) => other,
                 };
+                if let Callable::Builtin(builtin) = &callable {
+                    self.check_builtin_allowed(*builtin, position)?;
+                    self.check_builtin_not_frozen(*builtin, position)?;
+                }
                 Expr::Call { callable, args }
             }
             Expr::AttrCall { object, attr, mut args } => {
+                self.check_method_not_frozen(&attr, position)?;
                 // Prepare the object expression (supports chained access like a.b.c.method())
                 let object = Box::new(self.prepare_expression(*object)?);
                 args.prepare_args(|expr| self.prepare_expression(expr))?;
@@ -663,6 +1105,7 @@ impl<'i> Prepare<'i> {
                 Expr::IndirectCall { callable, args }
             }
             Expr::AttrGet { object, attr } => {
+                self.check_method_not_frozen(&attr, position)?;
                 // Prepare the object expression (supports chained access like a.b.c)
                 let object = Box::new(self.prepare_expression(*object)?);
                 Expr::AttrGet { object, attr }
@@ -756,6 +1199,15 @@ impl<'i> Prepare<'i> {
                 step: step.map(|e| self.prepare_expression(*e)).transpose()?.map(Box::new),
             },
             Expr::Named { target, value } => {
+                self.check_not_frozen("assignment expression", position)?;
+                self.check_input_not_rebound(&target)?;
+                let name = self.interner.get_str(target.name_id);
+                if self.comprehension_targets.contains(name) {
+                    return Err(ParseError::syntax(
+                        format!("assignment expression cannot rebind comprehension iteration variable '{name}'"),
+                        position,
+                    ));
+                }
                 let value = Box::new(self.prepare_expression(*value)?);
                 // Register the target as assigned in this scope
                 self.names_assigned_in_order
@@ -769,12 +1221,20 @@ impl<'i> Prepare<'i> {
             Expr::Await(value) => Expr::Await(Box::new(self.prepare_expression(*value)?)),
         };
 
+        if let Some(literal) = fold_constant(&expr, self.interner) {
+            return Ok(ExprLoc {
+                position,
+                expr: Expr::Literal(literal),
+            });
+        }
+
         // Optimization: Transform `(x % n) == value` with any constant right-hand side into a
         // specialized ModEq operator.
         // This is a common pattern in competitive programming (e.g., FizzBuzz checks like `i % 3 == 0`)
         // and can be executed more efficiently with a single modulo operation + comparison
         // instead of separate modulo, then equality check.
-        if let Expr::CmpOp { left, op, right } = &expr
+        if self.mod_eq
+            && let Expr::CmpOp { left, op, right } = &expr
             && op == &CmpOperator::Eq
             && let Expr::Literal(Literal::Int(value)) = right.expr
             && let Expr::Op {
@@ -798,6 +1258,31 @@ impl<'i> Prepare<'i> {
         Ok(ExprLoc { position, expr })
     }
 
+    /// Returns the truthiness of a literal `if` test, or `None` if it isn't known at prepare time.
+    fn literal_truthiness(&self, literal: &Literal) -> Option<bool> {
+        match literal {
+            Literal::Ellipsis | Literal::LongInt(_) => Some(true),
+            Literal::None => Some(false),
+            Literal::Bool(value) => Some(*value),
+            Literal::Int(value) => Some(*value != 0),
+            Literal::Float(value) => Some(*value != 0.0),
+            Literal::Str(string_id) => Some(!self.interner.get_str(*string_id).is_empty()),
+            Literal::Bytes(_) | Literal::Marker(_) => None,
+        }
+    }
+
+    /// Reports syntax errors in a branch that dead code elimination drops.
+    ///
+    /// The branch is prepared on a throwaway copy of this scope, so the names it binds or
+    /// references don't affect the code that runs. Other errors, such as references to denied
+    /// builtins, are ignored since the branch never executes.
+    fn check_dead_branch(&self, nodes: Vec<ParseNode>) -> Result<(), ParseError> {
+        match self.clone().prepare_nodes(nodes) {
+            Err(err @ ParseError::Syntax { .. }) => Err(err),
+            _ => Ok(()),
+        }
+    }
+
     /// Resolves a name to either `Expr::Builtin` or `Expr::Name` with scope-aware builtin detection.
     ///
     /// Python's name resolution follows LEGB order (Local, Enclosing, Global, Builtin).
@@ -809,7 +1294,19 @@ impl<'i> Prepare<'i> {
     /// `global_name_map` for nested functions, causing incorrect resolution.
     fn resolve_name_or_builtin(&mut self, name: Identifier) -> Expr {
         let name_str = self.interner.get_str(name.name_id);
+        if !self.is_name_bound(name_str)
+            && let Ok(builtin) = name_str.parse::<Builtins>()
+        {
+            return Expr::Builtin(builtin);
+        }
+
+        Expr::Name(self.get_id(name).0)
+    }
 
+    /// Whether `name_str` is bound in a scope that shadows builtins.
+    ///
+    /// Names not bound here fall back to builtins and module constants like `__name__`.
+    fn is_name_bound(&self, name_str: &str) -> bool {
         // Check if the name is assigned in the current scope. If so, it shadows
         // any builtin with the same name.
         let is_locally_assigned = if self.is_module_scope {
@@ -821,24 +1318,25 @@ impl<'i> Prepare<'i> {
             self.assigned_names.contains(name_str)
         };
 
-        if !is_locally_assigned {
-            // In function scope, also check if the name is bound by other mechanisms
-            // (global declaration, parameter, closure capture, enclosing/global scope).
-            // Only fall back to builtins if the name is truly unresolved.
-            let is_otherwise_bound = !self.is_module_scope
+        // In function scope, also check if the name is bound by other mechanisms
+        // (global declaration, parameter, closure capture, enclosing/global scope).
+        is_locally_assigned
+            || (!self.is_module_scope
                 && (self.global_names.contains(name_str)
                     || self.free_var_map.contains_key(name_str)
                     || self.cell_var_map.contains_key(name_str)
                     || self.name_map.contains_key(name_str)
                     || self.enclosing_locals.as_ref().is_some_and(|l| l.contains(name_str))
-                    || self.global_name_map.as_ref().is_some_and(|m| m.contains_key(name_str)));
-
-            if !is_otherwise_bound && let Ok(builtin) = name_str.parse::<Builtins>() {
-                return Expr::Builtin(builtin);
-            }
-        }
+                    || self.global_name_map.as_ref().is_some_and(|m| m.contains_key(name_str))))
+    }
 
-        Expr::Name(self.get_id(name).0)
+    /// Whether `name` is a read of `__name__` that the code hasn't bound itself.
+    ///
+    /// Such reads compile to the module name as a string constant, so every module has
+    /// `__name__` without a namespace slot, while `__name__ = ...` still works as usual.
+    fn is_unbound_module_name(&self, name: &Identifier) -> bool {
+        let name_str = self.interner.get_str(name.name_id);
+        name_str == "__name__" && !self.is_name_bound(name_str)
     }
 
     /// Prepares a comprehension with scope isolation for loop variables.
@@ -873,11 +1371,20 @@ impl<'i> Prepare<'i> {
             collect_assigned_names_from_expr(k, &mut walrus_targets, self.interner);
             collect_assigned_names_from_expr(v, &mut walrus_targets, self.interner);
         }
+        let saved_comprehension_targets = self.comprehension_targets.clone();
         for generator in &generators {
-            // Note: we don't scan iter expressions here because walrus in iterable is not allowed
+            let mut iter_targets = AHashSet::new();
+            collect_assigned_names_from_expr(&generator.iter, &mut iter_targets, self.interner);
+            if !iter_targets.is_empty() {
+                return Err(ParseError::syntax(
+                    "assignment expression cannot be used in a comprehension iterable expression",
+                    generator.iter.position,
+                ));
+            }
             for cond in &generator.ifs {
                 collect_assigned_names_from_expr(cond, &mut walrus_targets, self.interner);
             }
+            collect_names_from_unpack_target(&generator.target, &mut self.comprehension_targets, self.interner);
         }
         // Pre-allocate slots for walrus targets in the enclosing scope
         for name in &walrus_targets {
@@ -967,6 +1474,7 @@ impl<'i> Prepare<'i> {
         self.free_var_map = saved_free_var_map;
         self.cell_var_map = saved_cell_var_map;
         self.enclosing_locals = saved_enclosing_locals;
+        self.comprehension_targets = saved_comprehension_targets;
 
         Ok((prepared_generators, prepared_elt, prepared_key_value))
     }
@@ -1149,7 +1657,22 @@ impl<'i> Prepare<'i> {
     ) -> Result<PreparedNode, ParseError> {
         // Register the function name in the current scope
         let (name, _) = self.get_id(name);
+        let func_def = self.prepare_function(name, parsed_sig, body, is_async)?;
+        Ok(Node::FunctionDef(func_def))
+    }
 
+    /// Prepares a function body and signature without binding its name.
+    ///
+    /// `name` must already be resolved by the caller: `prepare_function_def` binds it in
+    /// the current scope, while class methods use a placeholder since they are stored in
+    /// the class rather than in a namespace slot.
+    fn prepare_function(
+        &mut self,
+        name: Identifier,
+        parsed_sig: &ParsedSignature,
+        body: Vec<ParseNode>,
+        is_async: bool,
+    ) -> Result<PreparedFunctionDef, ParseError> {
         // Extract param names from the parsed signature for scope analysis
         let param_names: Vec<StringId> = parsed_sig.param_names().collect();
 
@@ -1172,11 +1695,13 @@ impl<'i> Prepare<'i> {
             AHashSet::new()
         } else {
             // In a function: our params + assigned_names + existing name_map keys
-            // are all potentially available as enclosing locals
+            // are all potentially available as enclosing locals, except names declared
+            // `global` here, which live in the module namespace
             let mut locals = self.assigned_names.clone();
             for key in self.name_map.keys() {
                 locals.insert(key.clone());
             }
+            locals.retain(|name| !self.global_names.contains(name));
             locals
         };
 
@@ -1204,6 +1729,10 @@ impl<'i> Prepare<'i> {
         );
 
         // Prepare the function body
+        inner_prepare.read_only_inputs.clone_from(&self.read_only_inputs);
+        inner_prepare.denied_builtins.clone_from(&self.denied_builtins);
+        inner_prepare.module_name = self.module_name;
+        inner_prepare.mod_eq = self.mod_eq;
         let prepared_body = inner_prepare.prepare_nodes(body)?;
 
         // Mark variables that the inner function captures as our cell_vars
@@ -1325,8 +1854,7 @@ impl<'i> Prepare<'i> {
             }
         }
 
-        // Return the prepared function definition inline in the AST
-        Ok(Node::FunctionDef(PreparedFunctionDef {
+        Ok(PreparedFunctionDef {
             name,
             signature,
             body: prepared_body,
@@ -1336,7 +1864,52 @@ impl<'i> Prepare<'i> {
             cell_param_indices,
             default_exprs,
             is_async,
-        }))
+        })
+    }
+
+    /// Prepares a class definition.
+    ///
+    /// The class name is bound before the methods are prepared so method bodies can refer
+    /// to the class as a global. Methods are prepared as functions whose names are not
+    /// registered in any scope; they become entries in the class's attribute dict.
+    fn prepare_class_def(
+        &mut self,
+        name: Identifier,
+        base: Option<ExprLoc>,
+        methods: Vec<MethodDef<RawFunctionDef>>,
+    ) -> Result<PreparedNode, ParseError> {
+        if !self.is_module_scope {
+            return Err(ParseError::not_implemented(
+                "class definitions inside functions",
+                name.position,
+            ));
+        }
+        // like Python, the base is evaluated before the class name is bound
+        let base = match base {
+            Some(base) => Some(self.prepare_expression(base)?),
+            None => None,
+        };
+        let (name, _) = self.get_id(name);
+        let methods = methods
+            .into_iter()
+            .map(|MethodDef { kind, func }| {
+                let RawFunctionDef {
+                    name: method_name,
+                    signature,
+                    body,
+                    is_async,
+                } = func;
+                let method_name = Identifier::new_with_scope(
+                    method_name.name_id,
+                    method_name.position,
+                    NamespaceId::new(0), // Placeholder, methods live in the class dict
+                    NameScope::Local,
+                );
+                let func = self.prepare_function(method_name, &signature, body, is_async)?;
+                Ok(MethodDef { kind, func })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Node::ClassDef(ClassDef { name, base, methods }))
     }
 
     /// Prepares a lambda expression, converting it into a prepared function definition.
@@ -1418,6 +1991,11 @@ impl<'i> Prepare<'i> {
         );
 
         // Prepare the lambda body
+        inner_prepare.frozen = self.frozen;
+        inner_prepare.read_only_inputs.clone_from(&self.read_only_inputs);
+        inner_prepare.denied_builtins.clone_from(&self.denied_builtins);
+        inner_prepare.module_name = self.module_name;
+        inner_prepare.mod_eq = self.mod_eq;
         let prepared_body = inner_prepare.prepare_nodes(body_nodes)?;
 
         // Mark variables that the inner function captures as our cell_vars
@@ -1567,9 +2145,17 @@ impl<'i> Prepare<'i> {
         if self.is_module_scope {
             return match self.name_map.entry(name_str.to_string()) {
                 Entry::Occupied(e) => {
-                    // Name already exists (from prior assignment or pre-registered)
+                    // Name already exists (from prior assignment or pre-registered). A global only
+                    // created by functions is an undefined reference until the module assigns it.
+                    let scope = if self.function_globals.contains(name_str)
+                        && !self.names_assigned_in_order.contains(name_str)
+                    {
+                        NameScope::LocalUnassigned
+                    } else {
+                        NameScope::Local
+                    };
                     (
-                        Identifier::new_with_scope(ident.name_id, ident.position, *e.get(), NameScope::Local),
+                        Identifier::new_with_scope(ident.name_id, ident.position, *e.get(), scope),
                         false,
                     )
                 }
@@ -1596,36 +2182,17 @@ impl<'i> Prepare<'i> {
         // In a function: determine scope based on global_names, nonlocal_names, assigned_names, global_name_map
 
         // 1. Check if declared `global`
+        // Every such name has a module slot, see `register_function_globals`.
         if self.global_names.contains(name_str) {
-            if let Some(ref global_map) = self.global_name_map
-                && let Some(&global_id) = global_map.get(name_str)
-            {
-                // Name exists in global namespace
-                return (
-                    Identifier::new_with_scope(ident.name_id, ident.position, global_id, NameScope::Global),
-                    false,
-                );
-            }
-            // Declared global but doesn't exist yet - it will be created when assigned
-            // For now, we still need a global index. We'll use a placeholder approach:
-            // allocate in global namespace (this is a simplification - in real Python,
-            // the global would be created at module level when first assigned)
-            // For our implementation, we'll resolve to global but the variable won't exist until assigned.
-            // Return a "new" global - but we can't modify global_name_map here.
-            // For simplicity, we'll resolve to local with Global scope - runtime will handle the lookup.
-            let (id, is_new) = match self.name_map.entry(name_str.to_string()) {
-                Entry::Occupied(e) => (*e.get(), false),
-                Entry::Vacant(e) => {
-                    let id = NamespaceId::new(self.namespace_size);
-                    self.namespace_size += 1;
-                    e.insert(id);
-                    (id, true)
-                }
-            };
-            // Mark as Global scope - runtime will need to handle this specially
+            let global_id = self
+                .global_name_map
+                .as_ref()
+                .and_then(|global_map| global_map.get(name_str))
+                .copied()
+                .expect("global names are registered in the module namespace before functions are prepared");
             return (
-                Identifier::new_with_scope(ident.name_id, ident.position, id, NameScope::Global),
-                is_new,
+                Identifier::new_with_scope(ident.name_id, ident.position, global_id, NameScope::Global),
+                false,
             );
         }
 
@@ -1866,6 +2433,51 @@ fn collect_function_scope_info(
     }
 }
 
+/// Collects names declared `global` inside any function in `nodes`, in source order.
+///
+/// Recurses into compound statements and into every (nested) function body. `global`
+/// statements directly in module code (`in_function` false) are no-ops and are skipped.
+fn collect_function_global_names(
+    nodes: &[ParseNode],
+    in_function: bool,
+    names: &mut Vec<String>,
+    interner: &InternerBuilder,
+) {
+    for node in nodes {
+        match node {
+            Node::Global { names: ids, .. } if in_function => {
+                names.extend(ids.iter().map(|id| interner.get_str(*id).to_string()));
+            }
+            Node::FunctionDef(RawFunctionDef { body, .. }) => {
+                collect_function_global_names(body, true, names, interner);
+            }
+            Node::ClassDef(ClassDef { methods, .. }) => {
+                for method in methods {
+                    collect_function_global_names(&method.func.body, true, names, interner);
+                }
+            }
+            Node::For { body, or_else, .. } | Node::While { body, or_else, .. } | Node::If { body, or_else, .. } => {
+                collect_function_global_names(body, in_function, names, interner);
+                collect_function_global_names(or_else, in_function, names, interner);
+            }
+            Node::Try(Try {
+                body,
+                handlers,
+                or_else,
+                finally,
+            }) => {
+                collect_function_global_names(body, in_function, names, interner);
+                for handler in handlers {
+                    collect_function_global_names(&handler.body, in_function, names, interner);
+                }
+                collect_function_global_names(or_else, in_function, names, interner);
+                collect_function_global_names(finally, in_function, names, interner);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Helper to collect scope info from a single node.
 fn collect_scope_info_from_node(
     node: &ParseNode,
@@ -1909,6 +2521,9 @@ fn collect_scope_info_from_node(
             collect_assigned_names_from_expr(index, assigned_names, interner);
             collect_assigned_names_from_expr(value, assigned_names, interner);
         }
+        Node::DeleteSubscript { index, .. } => {
+            collect_assigned_names_from_expr(index, assigned_names, interner);
+        }
         Node::AttrAssign { object, value, .. } => {
             // Attribute assignment doesn't create a new name, it modifies existing object
             // But scan expressions for walrus operators
@@ -1960,6 +2575,10 @@ fn collect_scope_info_from_node(
             // But we don't recurse into the function body - that's a separate scope
             assigned_names.insert(interner.get_str(name.name_id).to_string());
         }
+        Node::ClassDef(ClassDef { name, .. }) => {
+            // Class definition binds the class name; methods are separate scopes
+            assigned_names.insert(interner.get_str(name.name_id).to_string());
+        }
         Node::Try(Try {
             body,
             handlers,
@@ -2000,8 +2619,10 @@ fn collect_scope_info_from_node(
         Node::Expr(expr) | Node::Return(expr) => {
             collect_assigned_names_from_expr(expr, assigned_names, interner);
         }
-        Node::Raise(Some(expr)) => {
-            collect_assigned_names_from_expr(expr, assigned_names, interner);
+        Node::Raise { exc, cause } => {
+            for expr in [exc, cause].into_iter().flatten() {
+                collect_assigned_names_from_expr(expr, assigned_names, interner);
+            }
         }
         Node::Assert { test, msg } => {
             collect_assigned_names_from_expr(test, assigned_names, interner);
@@ -2010,7 +2631,7 @@ fn collect_scope_info_from_node(
             }
         }
         // These don't create new names
-        Node::Pass | Node::ReturnNone | Node::Raise(None) | Node::Break { .. } | Node::Continue { .. } => {}
+        Node::Pass | Node::ReturnNone | Node::Break { .. } | Node::Continue { .. } => {}
     }
 }
 
@@ -2049,6 +2670,11 @@ fn collect_assigned_names_from_expr(expr: &ExprLoc, assigned_names: &mut AHashSe
                 collect_assigned_names_from_expr(expr, assigned_names, interner);
             }
         }
+        Expr::BoolOp { values, .. } => {
+            for value in values {
+                collect_assigned_names_from_expr(value, assigned_names, interner);
+            }
+        }
         Expr::Not(operand)
         | Expr::UnaryMinus(operand)
         | Expr::UnaryPlus(operand)
@@ -2281,6 +2907,9 @@ fn collect_cell_vars_from_node(
             collect_cell_vars_from_expr(index, our_locals, cell_vars, interner);
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
+        Node::DeleteSubscript { index, .. } => {
+            collect_cell_vars_from_expr(index, our_locals, cell_vars, interner);
+        }
         Node::AttrAssign { object, value, .. } => {
             collect_cell_vars_from_expr(object, our_locals, cell_vars, interner);
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
@@ -2369,6 +2998,11 @@ fn collect_cell_vars_from_expr(
                 collect_cell_vars_from_expr(expr, our_locals, cell_vars, interner);
             }
         }
+        Expr::BoolOp { values, .. } => {
+            for value in values {
+                collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
+            }
+        }
         Expr::Not(operand) | Expr::UnaryMinus(operand) | Expr::UnaryPlus(operand) | Expr::UnaryInvert(operand) => {
             collect_cell_vars_from_expr(operand, our_locals, cell_vars, interner);
         }
@@ -2491,8 +3125,11 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
     match node {
         Node::Expr(expr) => collect_referenced_names_from_expr(expr, referenced, interner),
         Node::Return(expr) => collect_referenced_names_from_expr(expr, referenced, interner),
-        Node::Raise(Some(expr)) => collect_referenced_names_from_expr(expr, referenced, interner),
-        Node::Raise(None) => {}
+        Node::Raise { exc, cause } => {
+            for expr in [exc, cause].into_iter().flatten() {
+                collect_referenced_names_from_expr(expr, referenced, interner);
+            }
+        }
         Node::Assert { test, msg } => {
             collect_referenced_names_from_expr(test, referenced, interner);
             if let Some(m) = msg {
@@ -2517,6 +3154,10 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
             collect_referenced_names_from_expr(index, referenced, interner);
             collect_referenced_names_from_expr(value, referenced, interner);
         }
+        Node::DeleteSubscript { target, index, .. } => {
+            referenced.insert(interner.get_str(target.name_id).to_string());
+            collect_referenced_names_from_expr(index, referenced, interner);
+        }
         Node::AttrAssign { object, value, .. } => {
             collect_referenced_names_from_expr(object, referenced, interner);
             collect_referenced_names_from_expr(value, referenced, interner);
@@ -2550,8 +3191,8 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
                 collect_referenced_names_from_node(n, referenced, interner);
             }
         }
-        Node::FunctionDef(_) => {
-            // Don't recurse into nested function bodies - they have their own scope
+        Node::FunctionDef(_) | Node::ClassDef(_) => {
+            // Don't recurse into nested function or method bodies - they have their own scope
         }
         Node::Try(Try {
             body,
@@ -2623,6 +3264,11 @@ fn collect_referenced_names_from_expr(
                 collect_referenced_names_from_expr(expr, referenced, interner);
             }
         }
+        Expr::BoolOp { values, .. } => {
+            for value in values {
+                collect_referenced_names_from_expr(value, referenced, interner);
+            }
+        }
         Expr::Not(operand) | Expr::UnaryMinus(operand) | Expr::UnaryPlus(operand) | Expr::UnaryInvert(operand) => {
             collect_referenced_names_from_expr(operand, referenced, interner);
         }
@@ -2839,3 +3485,158 @@ fn collect_names_from_unpack_target(target: &UnpackTarget, names: &mut AHashSet<
         }
     }
 }
+
+/// Evaluates `expr` at prepare time if it only operates on literals, returning the result.
+///
+/// Covers arithmetic and bitwise operators on ints and floats, unary minus, and `len()` of
+/// a string literal; string literal concatenation is folded by the parser. Anything that
+/// would raise (division by zero, i64 overflow, unsupported operand types) is left unfolded
+/// so the VM raises it at runtime with the usual traceback.
+fn fold_constant(expr: &Expr, interner: &InternerBuilder) -> Option<Literal> {
+    match expr {
+        Expr::Op { left, op, right } => match (&left.expr, &right.expr) {
+            (Expr::Literal(left), Expr::Literal(right)) => fold_binary_op(left, op, right),
+            _ => None,
+        },
+        Expr::UnaryMinus(operand) => match operand.expr {
+            Expr::Literal(Literal::Int(value)) => value.checked_neg().map(Literal::Int),
+            Expr::Literal(Literal::Float(value)) => Some(Literal::Float(-value)),
+            _ => None,
+        },
+        Expr::Call {
+            callable: Callable::Builtin(Builtins::Function(BuiltinsFunctions::Len)),
+            args: ArgExprs::One(arg),
+        } => match arg.expr {
+            Expr::Literal(Literal::Str(string_id)) => i64::try_from(interner.get_str(string_id).chars().count())
+                .ok()
+                .map(Literal::Int),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Folds `left op right` for numeric literals, mirroring the VM's int and float arithmetic.
+fn fold_binary_op(left: &Literal, op: &Operator, right: &Literal) -> Option<Literal> {
+    match (left, right) {
+        (Literal::Int(a), Literal::Int(b)) => {
+            let (a, b) = (*a, *b);
+            let result = match op {
+                Operator::Add => a.checked_add(b)?,
+                Operator::Sub => a.checked_sub(b)?,
+                Operator::Mult => a.checked_mul(b)?,
+                Operator::FloorDiv => floor_divmod(a, b)?.0,
+                Operator::Mod => floor_divmod(a, b)?.1,
+                Operator::BitAnd => a & b,
+                Operator::BitOr => a | b,
+                Operator::BitXor => a ^ b,
+                Operator::Div if b != 0 => return Some(Literal::Float(a as f64 / b as f64)),
+                _ => return None,
+            };
+            Some(Literal::Int(result))
+        }
+        (Literal::Float(a), Literal::Float(b)) => fold_float_op(*a, op, *b),
+        (Literal::Int(a), Literal::Float(b)) => fold_float_op(*a as f64, op, *b),
+        (Literal::Float(a), Literal::Int(b)) => fold_float_op(*a, op, *b as f64),
+        _ => None,
+    }
+}
+
+/// Folds `a op b` for float operands, leaving division by zero to raise at runtime.
+///
+/// Results which overflow to infinity (or are NaN) are left unfolded too, so folding never
+/// creates a non-finite constant.
+fn fold_float_op(a: f64, op: &Operator, b: f64) -> Option<Literal> {
+    let result = match op {
+        Operator::Add => a + b,
+        Operator::Sub => a - b,
+        Operator::Mult => a * b,
+        Operator::Div if b != 0.0 => a / b,
+        _ => return None,
+    };
+    result.is_finite().then_some(Literal::Float(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse;
+
+    /// Prepares `code` in script mode and returns the expression of its only statement.
+    fn prepare_single_expr(code: &str, no_mod_eq: bool) -> Expr {
+        let parse_result = parse(code, "test.py", ParseLimits::default()).unwrap();
+        let options = PrepareOptions::new().no_implicit_return(true).no_mod_eq(no_mod_eq);
+        let mut prepared = prepare(parse_result, vec!["i".to_owned()], &[], options).unwrap();
+        assert_eq!(prepared.nodes.len(), 1);
+        match prepared.nodes.pop() {
+            Some(Node::Expr(expr_loc)) => expr_loc.expr,
+            _ => panic!("expected an expression statement"),
+        }
+    }
+
+    #[test]
+    fn mod_eq_is_fused_by_default() {
+        let expr = prepare_single_expr("i % 3 == 0", false);
+        assert!(
+            matches!(
+                expr,
+                Expr::CmpOp {
+                    op: CmpOperator::ModEq(0),
+                    ..
+                }
+            ),
+            "expected ModEq, got {expr:?}"
+        );
+    }
+
+    #[test]
+    fn mod_eq_can_be_disabled() {
+        let expr = prepare_single_expr("i % 3 == 0", true);
+        let Expr::CmpOp { left, op, right } = &expr else {
+            panic!("expected CmpOp, got {expr:?}");
+        };
+        assert_eq!(op, &CmpOperator::Eq);
+        assert!(matches!(right.expr, Expr::Literal(Literal::Int(0))));
+        assert!(
+            matches!(left.expr, Expr::Op { op: Operator::Mod, .. }),
+            "expected Mod, got {:?}",
+            left.expr
+        );
+    }
+
+    #[test]
+    fn float_overflow_is_not_folded() {
+        let expr = prepare_single_expr("1e308 * 10", false);
+        assert!(
+            matches!(expr, Expr::Op { op: Operator::Mult, .. }),
+            "expected Mult, got {expr:?}"
+        );
+    }
+
+    #[test]
+    fn leading_string_literals_are_folded() {
+        let expr = prepare_single_expr("'ab' + 'cd' + i + 'ef'", false);
+        let Expr::Op { left, op, .. } = &expr else {
+            panic!("expected Op, got {expr:?}");
+        };
+        assert_eq!(op, &Operator::Add);
+        let Expr::Op { left, .. } = &left.expr else {
+            panic!("expected Op, got {:?}", left.expr);
+        };
+        assert!(
+            matches!(left.expr, Expr::Literal(Literal::Str(_))),
+            "expected a folded literal, got {:?}",
+            left.expr
+        );
+    }
+
+    #[test]
+    fn long_string_concatenation_is_not_folded() {
+        let code = format!("'{}' + '{}'", "a".repeat(4000), "b".repeat(100));
+        let expr = prepare_single_expr(&code, false);
+        assert!(
+            matches!(expr, Expr::Op { op: Operator::Add, .. }),
+            "expected Add, got {expr:?}"
+        );
+    }
+}
@@ -19,10 +19,11 @@ use crate::{
     namespace::{GLOBAL_NS_IDX, NamespaceId, Namespaces},
     object::MontyObject,
     os::OsFunction,
-    parse::{parse, parse_with_interner},
-    prepare::{prepare, prepare_with_existing_names},
+    parse::{ParseLimits, parse, parse_with_interner},
+    prepare::{PrepareOptions, prepare, prepare_with_existing_names},
     resource::ResourceTracker,
     run::{ExternalResult, MontyFuture},
+    serialize::{LoadError, dump_versioned, load_versioned},
     value::Value,
 };
 
@@ -61,9 +62,15 @@ impl ReplExecutor {
         input_names: Vec<String>,
         external_functions: Vec<String>,
     ) -> Result<Self, MontyException> {
-        let parse_result = parse(&code, script_name).map_err(|e| e.into_python_exc(script_name, &code))?;
-        let prepared = prepare(parse_result, input_names, &external_functions)
-            .map_err(|e| e.into_python_exc(script_name, &code))?;
+        let parse_result =
+            parse(&code, script_name, ParseLimits::default()).map_err(|e| e.into_python_exc(script_name, &code))?;
+        let prepared = prepare(
+            parse_result,
+            input_names,
+            &external_functions,
+            PrepareOptions::default(),
+        )
+        .map_err(|e| e.into_python_exc(script_name, &code))?;
 
         let external_function_ids = (0..external_functions.len()).map(ExtFunctionId::new).collect();
 
@@ -98,7 +105,7 @@ impl ReplExecutor {
         existing_interns: &Interns,
     ) -> Result<Self, MontyException> {
         let seeded_interner = InternerBuilder::from_interns(existing_interns, &code);
-        let parse_result = parse_with_interner(&code, script_name, seeded_interner)
+        let parse_result = parse_with_interner(&code, script_name, seeded_interner, ParseLimits::default())
             .map_err(|e| e.into_python_exc(script_name, &code))?;
         let prepared = prepare_with_existing_names(parse_result, existing_name_map)
             .map_err(|e| e.into_python_exc(script_name, &code))?;
@@ -466,7 +473,7 @@ impl<T: ResourceTracker + serde::Serialize> MontyRepl<T> {
     /// # Errors
     /// Returns an error if serialization fails.
     pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
-        postcard::to_allocvec(self)
+        dump_versioned(self)
     }
 }
 
@@ -474,9 +481,10 @@ impl<T: ResourceTracker + serde::de::DeserializeOwned> MontyRepl<T> {
     /// Restores a REPL session from bytes produced by `MontyRepl::dump`.
     ///
     /// # Errors
-    /// Returns an error if deserialization fails.
-    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
-        postcard::from_bytes(bytes)
+    /// Returns an error if the data was written by a different version of Monty,
+    /// or if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, LoadError> {
+        load_versioned(bytes)
     }
 }
 
@@ -601,7 +609,7 @@ impl<T: ResourceTracker + serde::Serialize> ReplProgress<T> {
     /// # Errors
     /// Returns an error if serialization fails.
     pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
-        postcard::to_allocvec(self)
+        dump_versioned(self)
     }
 }
 
@@ -609,9 +617,10 @@ impl<T: ResourceTracker + serde::de::DeserializeOwned> ReplProgress<T> {
     /// Deserializes REPL execution progress from a binary format.
     ///
     /// # Errors
-    /// Returns an error if deserialization fails.
-    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
-        postcard::from_bytes(bytes)
+    /// Returns an error if the data was written by a different version of Monty,
+    /// or if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, LoadError> {
+        load_versioned(bytes)
     }
 }
 
@@ -665,12 +674,7 @@ impl<T: ResourceTracker> ReplSnapshot<T> {
         let vm_result = match ext_result {
             ExternalResult::Return(obj) => vm.resume(obj),
             ExternalResult::Error(exc) => vm.resume_with_exception(exc.into()),
-            ExternalResult::Future => {
-                let call_id = CallId::new(pending_call_id);
-                vm.add_pending_call(call_id);
-                vm.push(Value::ExternalFuture(call_id));
-                vm.run()
-            }
+            ExternalResult::Future => vm.resume_with_future(CallId::new(pending_call_id)),
         };
 
         let vm_state = vm.check_snapshot(&vm_result);
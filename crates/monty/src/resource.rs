@@ -17,6 +17,18 @@ use crate::{
 /// the allocation check can catch them.
 pub const LARGE_RESULT_THRESHOLD: usize = 100_000;
 
+/// Hard ceiling in bytes on the projected size of any single result (512MB).
+///
+/// Enforced by the same pre-checks as `LARGE_RESULT_THRESHOLD`, but independently of the
+/// resource tracker, so it also applies to `NoLimitTracker`. Without it, an expression like
+/// `[0] * 10**9` run without a memory limit would try to grow a multi-gigabyte `Vec` and
+/// abort the host process instead of raising `MemoryError`.
+///
+/// The check happens before the result is built: `Heap::allocate` only sees fully
+/// constructed data, by which point the memory has already been claimed from the
+/// Rust allocator.
+pub const MAX_ALLOCATION_SIZE: usize = 512 * 1024 * 1024;
+
 /// Pre-checks that a sequence repeat won't exceed resource limits before allocating.
 ///
 /// This prevents DoS via expressions like `'x' * 999_999_999` or `b'ab' * huge_int`
@@ -31,6 +43,9 @@ pub fn check_repeat_size(item_len: usize, count: usize, tracker: &impl ResourceT
 /// For bases with 0 or 1 significant bits (0, 1, -1), the result is always
 /// small regardless of exponent, so the check is skipped.
 ///
+/// The integer bit limit is checked against the lower bound `(base_bits - 1) * exp + 1`,
+/// so `2 ** (10**7)` is rejected up front without computing it.
+///
 /// The estimate includes a 4× safety multiplier because `BigInt::pow` uses repeated squaring,
 /// which allocates intermediate values on the Rust heap (not tracked by the resource tracker).
 /// At peak, old/new base and old/new accumulator coexist simultaneously during each
@@ -40,6 +55,8 @@ pub fn check_pow_size(base_bits: u64, exponent: u64, tracker: &impl ResourceTrac
     if base_bits <= 1 {
         return Ok(());
     }
+    // |base| >= 2**(base_bits - 1), so the result has at least this many bits
+    tracker.check_int_bits((base_bits - 1).saturating_mul(exponent).saturating_add(1))?;
     let result_bytes = estimate_bits_to_bytes(base_bits.saturating_mul(exponent));
     // Repeated squaring needs ~4× result size in peak memory (old/new base + old/new accumulator
     // coexist during each multiplication step), and these are Rust heap allocations not tracked
@@ -50,7 +67,13 @@ pub fn check_pow_size(base_bits: u64, exponent: u64, tracker: &impl ResourceTrac
 /// Pre-checks that an integer multiplication won't exceed resource limits.
 ///
 /// The result of multiplying two numbers has at most `a_bits + b_bits` bits.
+/// The integer bit limit is checked against the lower bound `a_bits + b_bits - 1`,
+/// so a product that might still fit is computed and checked exactly afterwards.
 pub fn check_mult_size(a_bits: u64, b_bits: u64, tracker: &impl ResourceTracker) -> Result<(), ResourceError> {
+    if a_bits > 0 && b_bits > 0 {
+        // A nonzero product has at least `a_bits + b_bits - 1` bits
+        tracker.check_int_bits(a_bits.saturating_add(b_bits) - 1)?;
+    }
     check_estimated_size(estimate_bits_to_bytes(a_bits.saturating_add(b_bits)), tracker)
 }
 
@@ -66,6 +89,7 @@ pub fn check_lshift_size(
     if value_bits == 0 {
         return Ok(());
     }
+    tracker.check_int_bits(value_bits.saturating_add(shift_amount))?;
     check_estimated_size(estimate_bits_to_bytes(value_bits.saturating_add(shift_amount)), tracker)
 }
 
@@ -77,13 +101,20 @@ pub fn check_div_size(dividend_bits: u64, tracker: &impl ResourceTracker) -> Res
     check_estimated_size(estimate_bits_to_bytes(dividend_bits), tracker)
 }
 
-/// Checks an estimated result size against the resource tracker.
+/// Checks an estimated result size against the resource tracker and `MAX_ALLOCATION_SIZE`.
 ///
 /// Only calls the tracker when the estimate exceeds `LARGE_RESULT_THRESHOLD`
-/// to avoid overhead on small operations.
+/// to avoid overhead on small operations. The tracker is consulted first so a
+/// configured memory limit is reported in preference to the hard ceiling.
 fn check_estimated_size(estimated_bytes: usize, tracker: &impl ResourceTracker) -> Result<(), ResourceError> {
     if estimated_bytes > LARGE_RESULT_THRESHOLD {
         tracker.check_large_result(estimated_bytes)?;
+        if estimated_bytes > MAX_ALLOCATION_SIZE {
+            return Err(ResourceError::AllocationSize {
+                limit: MAX_ALLOCATION_SIZE,
+                size: estimated_bytes,
+            });
+        }
     }
     Ok(())
 }
@@ -110,6 +141,14 @@ pub enum ResourceError {
     Memory { limit: usize, used: usize },
     /// Maximum recursion depth exceeded.
     Recursion { limit: usize, depth: usize },
+    /// Maximum `print()` output size exceeded.
+    Output { limit: usize, written: usize },
+    /// Maximum number of iteration steps exceeded.
+    Iterations { limit: usize, count: usize },
+    /// A single result would exceed `MAX_ALLOCATION_SIZE`.
+    AllocationSize { limit: usize, size: usize },
+    /// An integer would exceed the maximum number of bits.
+    IntBits { limit: usize, bits: u64 },
     /// Any other error, e.g. when propagating a python exception
     Exception(MontyException),
 }
@@ -129,6 +168,18 @@ impl fmt::Display for ResourceError {
             Self::Recursion { .. } => {
                 write!(f, "maximum recursion depth exceeded")
             }
+            Self::Output { limit, written } => {
+                write!(f, "output limit exceeded: {written} bytes > {limit} bytes")
+            }
+            Self::Iterations { limit, count } => {
+                write!(f, "iteration limit exceeded: {count} > {limit}")
+            }
+            Self::AllocationSize { limit, size } => {
+                write!(f, "allocation size limit exceeded: {size} bytes > {limit} bytes")
+            }
+            Self::IntBits { limit, bits } => {
+                write!(f, "integer bit limit exceeded: {bits} bits > {limit} bits")
+            }
             Self::Exception(exc) => {
                 write!(f, "{exc}")
             }
@@ -146,6 +197,11 @@ impl ResourceError {
     /// - `Memory` → `MemoryError`
     /// - `Time` → `TimeoutError`
     /// - `Recursion` → `RecursionError`
+    /// - `Output` → `OSError`, as for a write to a full device, so hosts can tell it
+    ///   apart from the memory limit
+    /// - `Iterations` → `TimeoutError`
+    /// - `AllocationSize` → `MemoryError`
+    /// - `IntBits` → `ValueError`
     #[must_use]
     pub(crate) fn into_exception(self, frame: Option<RawStackFrame>) -> ExceptionRaise {
         let (exc_type, msg) = match self {
@@ -165,6 +221,24 @@ impl ResourceError {
                 ExcType::RecursionError,
                 Some("maximum recursion depth exceeded".to_string()),
             ),
+            Self::Output { limit, written } => (
+                ExcType::OSError,
+                Some(format!("output limit exceeded: {written} bytes > {limit} bytes")),
+            ),
+            Self::Iterations { limit, count } => (
+                ExcType::TimeoutError,
+                Some(format!("iteration limit exceeded: {count} > {limit}")),
+            ),
+            Self::AllocationSize { limit, size } => (
+                ExcType::MemoryError,
+                Some(format!("allocation size limit exceeded: {size} bytes > {limit} bytes")),
+            ),
+            Self::IntBits { limit, bits } => (
+                ExcType::ValueError,
+                Some(format!(
+                    "Exceeds the limit ({limit} bits) for integer size: value has {bits} bits"
+                )),
+            ),
             Self::Exception(exc) => (exc.exc_type(), exc.into_message()),
         };
         let exc = SimpleException::new(exc_type, msg);
@@ -178,9 +252,11 @@ impl ResourceError {
 impl From<ResourceError> for RunError {
     fn from(err: ResourceError) -> Self {
         // RecursionError is catchable in CPython, so it must be catchable here too.
+        // The integer bit limit mirrors CPython's catchable `ValueError` for oversized
+        // int-to-str conversions, and the offending value is never built.
         // Other resource errors (memory, time, allocation) remain uncatchable to prevent
         // untrusted code from suppressing resource limit violations.
-        if matches!(err, ResourceError::Recursion { .. }) {
+        if matches!(err, ResourceError::Recursion { .. } | ResourceError::IntBits { .. }) {
             Self::Exc(err.into_exception(None))
         } else {
             Self::UncatchableExc(err.into_exception(None))
@@ -243,6 +319,49 @@ pub trait ResourceTracker: fmt::Debug {
     ///
     /// Returns `Ok(())` to allow the operation, or `Err(ResourceError)` to reject.
     fn check_large_result(&self, estimated_bytes: usize) -> Result<(), ResourceError>;
+
+    /// Called before `print()` writes text to the `PrintWriter`.
+    ///
+    /// Output isn't stored on the heap, so it's invisible to the memory limit, yet a
+    /// script printing in a loop can still exhaust host memory (e.g. with
+    /// `PrintWriter::Collect`). This lets trackers cap the total bytes written.
+    ///
+    /// # Arguments
+    /// * `bytes` - Number of UTF-8 bytes about to be written
+    ///
+    /// Returns `Ok(())` to allow the write, or `Err(ResourceError::Output)` to reject it
+    /// before anything is written.
+    fn on_output(&mut self, bytes: usize) -> Result<(), ResourceError>;
+
+    /// Called on every step of a Rust-side iterator, see `MontyIter::for_next`.
+    ///
+    /// Returns `Err(ResourceError::Iterations)` once the total number of steps exceeds
+    /// the limit. This bounds runaway iteration, e.g. over `itertools.count()`,
+    /// independently of the clock.
+    fn on_iteration(&mut self) -> Result<(), ResourceError>;
+
+    /// Called before and after integer arithmetic that may produce a `LongInt`.
+    ///
+    /// The size pre-checks (`check_pow_size`, `check_lshift_size`, `check_mult_size`)
+    /// pass a lower bound on the result's bit length, and `LongInt::into_value` passes
+    /// the exact bit length of every `LongInt` it stores.
+    ///
+    /// Returns `Err(ResourceError::IntBits)` if `bits` exceeds the limit.
+    fn check_int_bits(&self, bits: u64) -> Result<(), ResourceError>;
+
+    /// Maximum number of items shown when `print()` output reprs a list, tuple, dict or set.
+    ///
+    /// Containers longer than this show their first and last few items with `...`
+    /// in between. This only affects text shown to the host, never the container
+    /// itself or the result of `repr()`/`str()` in the script. `None` shows every item.
+    fn repr_max_items(&self) -> Option<usize>;
+
+    /// Maximum number of decimal digits when converting an int to or from a string.
+    ///
+    /// Mirrors CPython's `sys.set_int_max_str_digits()`: decimal conversion of huge
+    /// ints is quadratic, so `str()`, `repr()`, f-strings, `print()` and `int()` parsing
+    /// raise `ValueError` beyond this many digits. `None` allows any size.
+    fn int_max_str_digits(&self) -> Option<usize>;
 }
 
 /// A resource tracker that imposes no limits except default recursion limit.
@@ -287,6 +406,31 @@ impl ResourceTracker for NoLimitTracker {
         // No limit - always allow operations regardless of result size
         Ok(())
     }
+
+    #[inline]
+    fn on_output(&mut self, _bytes: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn on_iteration(&mut self) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn check_int_bits(&self, _bits: u64) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn repr_max_items(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn int_max_str_digits(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Configuration for resource limits.
@@ -306,6 +450,17 @@ pub struct ResourceLimits {
     pub gc_interval: Option<usize>,
     /// Maximum recursion depth (function call stack depth).
     pub max_recursion_depth: Option<usize>,
+    /// Maximum total bytes written by `print()`.
+    pub max_output_bytes: Option<usize>,
+    /// Maximum total number of steps taken by `MontyIter::for_next`, i.e. by builtin
+    /// iterators like `itertools.count()` and by Rust-side loops like `sum()` or `list()`.
+    pub max_iterations: Option<usize>,
+    /// Maximum number of bits in an integer, checked before and after `LongInt` arithmetic.
+    pub max_int_bits: Option<usize>,
+    /// Maximum number of items shown in a container repr in `print()` output before eliding the middle with `...`.
+    pub repr_max_items: Option<usize>,
+    /// Maximum number of decimal digits when converting an int to or from a string.
+    pub int_max_str_digits: Option<usize>,
 }
 
 /// Recommended maximum recursion depth if not otherwise specified.
@@ -355,6 +510,56 @@ impl ResourceLimits {
         self.max_recursion_depth = limit;
         self
     }
+
+    /// Sets the maximum total number of bytes `print()` may write.
+    ///
+    /// A `print()` call that would exceed it raises an uncatchable `OSError`.
+    #[must_use]
+    pub fn max_output_bytes(mut self, limit: usize) -> Self {
+        self.max_output_bytes = Some(limit);
+        self
+    }
+
+    /// Sets the maximum total number of iteration steps, counted over every iterator.
+    ///
+    /// Exceeding it raises an uncatchable `TimeoutError`, so runaway iteration is bounded
+    /// even without a time limit.
+    #[must_use]
+    pub fn max_iterations(mut self, limit: usize) -> Self {
+        self.max_iterations = Some(limit);
+        self
+    }
+
+    /// Sets the maximum number of bits an integer may have.
+    ///
+    /// Exceeding it raises `ValueError`, e.g. for `2 ** (10**7)` with a limit of
+    /// 1,000,000 bits, instead of computing a multi-megabyte integer.
+    #[must_use]
+    pub fn max_int_bits(mut self, limit: usize) -> Self {
+        self.max_int_bits = Some(limit);
+        self
+    }
+
+    /// Sets the maximum number of items shown when `print()` output reprs a container.
+    ///
+    /// Longer containers show their first and last items around a `...` marker,
+    /// e.g. `[0, 1, 2, ..., 97, 98, 99]` for a limit of 6. The script's own `repr()`
+    /// and `str()` results are unaffected.
+    #[must_use]
+    pub fn repr_max_items(mut self, limit: usize) -> Self {
+        self.repr_max_items = Some(limit);
+        self
+    }
+
+    /// Sets the maximum number of decimal digits when converting an int to or from a string.
+    ///
+    /// Like CPython's `sys.set_int_max_str_digits()`, e.g. `str(10**5000)` with a limit
+    /// of 4300 raises `ValueError`. Conversion to binary, octal or hex is not limited.
+    #[must_use]
+    pub fn int_max_str_digits(mut self, limit: usize) -> Self {
+        self.int_max_str_digits = Some(limit);
+        self
+    }
 }
 
 /// How often to actually check `Instant::elapsed()` in `check_time`.
@@ -367,7 +572,7 @@ const TIME_CHECK_INTERVAL: u16 = 10;
 
 /// A resource tracker that enforces configurable limits.
 ///
-/// Tracks allocation count, memory usage, and execution time, returning
+/// Tracks allocation count, memory usage, execution time, output size and iteration steps, returning
 /// errors when limits are exceeded. Also schedules garbage collection
 /// at configurable intervals.
 ///
@@ -384,6 +589,10 @@ pub struct LimitedTracker {
     allocation_count: usize,
     /// Current approximate memory usage in bytes.
     current_memory: usize,
+    /// Total bytes written by `print()` so far.
+    output_bytes: usize,
+    /// Total number of iteration steps taken so far.
+    iteration_count: usize,
     /// Counter for rate-limiting `Instant::elapsed()` calls in `check_time`.
     ///
     /// Uses `AtomicU16` for interior mutability since `check_time` takes `&self`
@@ -403,6 +612,8 @@ impl LimitedTracker {
             start_time: Instant::now(),
             allocation_count: 0,
             current_memory: 0,
+            output_bytes: 0,
+            iteration_count: 0,
             check_counter: AtomicU16::new(0),
         }
     }
@@ -419,6 +630,18 @@ impl LimitedTracker {
         self.current_memory
     }
 
+    /// Returns the total number of bytes written by `print()`.
+    #[must_use]
+    pub fn output_bytes(&self) -> usize {
+        self.output_bytes
+    }
+
+    /// Returns the total number of iteration steps taken.
+    #[must_use]
+    pub fn iteration_count(&self) -> usize {
+        self.iteration_count
+    }
+
     /// Returns the elapsed time since tracker creation.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
@@ -517,4 +740,43 @@ impl ResourceTracker for LimitedTracker {
         }
         Ok(())
     }
+
+    fn on_output(&mut self, bytes: usize) -> Result<(), ResourceError> {
+        let written = self.output_bytes.saturating_add(bytes);
+        if let Some(max) = self.limits.max_output_bytes
+            && written > max
+        {
+            return Err(ResourceError::Output { limit: max, written });
+        }
+        self.output_bytes = written;
+        Ok(())
+    }
+
+    fn on_iteration(&mut self) -> Result<(), ResourceError> {
+        let count = self.iteration_count.saturating_add(1);
+        if let Some(max) = self.limits.max_iterations
+            && count > max
+        {
+            return Err(ResourceError::Iterations { limit: max, count });
+        }
+        self.iteration_count = count;
+        Ok(())
+    }
+
+    fn check_int_bits(&self, bits: u64) -> Result<(), ResourceError> {
+        if let Some(max) = self.limits.max_int_bits
+            && bits > u64::try_from(max).unwrap_or(u64::MAX)
+        {
+            return Err(ResourceError::IntBits { limit: max, bits });
+        }
+        Ok(())
+    }
+
+    fn repr_max_items(&self) -> Option<usize> {
+        self.limits.repr_max_items
+    }
+
+    fn int_max_str_digits(&self) -> Option<usize> {
+        self.limits.int_max_str_digits
+    }
 }
@@ -1,20 +1,25 @@
 //! Public interface for running Monty code.
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use crate::{
     ExcType, MontyException,
     asyncio::CallId,
     bytecode::{Code, Compiler, FrameExit, VM, VMSnapshot},
+    compile_cache::{self, CacheKey, CompileCacheStats},
     exception_private::RunResult,
-    heap::{DropWithHeap, Heap},
+    heap::{DropWithHeap, Heap, HeapPool},
     intern::{ExtFunctionId, Interns},
     io::PrintWriter,
     namespace::Namespaces,
     object::MontyObject,
     os::OsFunction,
     parse::parse,
-    prepare::prepare,
+    prepare::{PrepareOptions, prepare},
     resource::{NoLimitTracker, ResourceTracker},
+    serialize::{LoadError, dump_json_versioned, dump_versioned, load_json_versioned, load_versioned},
     value::Value,
 };
 
@@ -58,7 +63,83 @@ impl MontyRun {
         input_names: Vec<String>,
         external_functions: Vec<String>,
     ) -> Result<Self, MontyException> {
-        Executor::new(code, script_name, input_names, external_functions).map(|executor| Self { executor })
+        Self::with_options(
+            code,
+            script_name,
+            input_names,
+            external_functions,
+            PrepareOptions::default(),
+        )
+    }
+
+    /// Like [`MontyRun::new`], but compiles the code with the given options.
+    ///
+    /// `options` can restrict what the code may do (frozen mode, read-only inputs, denied
+    /// builtins), disable the implicit return of the last expression, set `__name__`,
+    /// limit the size of the program, and keep `x % n == k` from being fused into a single
+    /// comparison (`no_mod_eq`), in any combination.
+    ///
+    /// With `options.compile_cache` set, a process-wide cache of compiled programs is used.
+    /// Programs are cached by their code, script name, input names, external functions and
+    /// options, so constructing the same `MontyRun` again clones the cached one instead of
+    /// parsing and compiling the code. The most recently used programs are kept, up to the
+    /// capacity set by [`MontyRun::set_compile_cache_capacity`]. Errors are not cached.
+    ///
+    /// # Errors
+    /// Returns `MontyException` if the code cannot be parsed, a `SyntaxError` if it exceeds one
+    /// of the parse limits or assigns to a read-only input, a `RuntimeError` pointing at the
+    /// first binding or mutating construct in frozen mode, or a `NameError` pointing at the
+    /// first reference to a denied builtin.
+    pub fn with_options(
+        code: String,
+        script_name: &str,
+        input_names: Vec<String>,
+        external_functions: Vec<String>,
+        options: PrepareOptions,
+    ) -> Result<Self, MontyException> {
+        if !options.compile_cache {
+            let executor = Executor::new(code, script_name, input_names, external_functions, options)?;
+            return Ok(Self { executor });
+        }
+        let key = CacheKey {
+            code,
+            script_name: script_name.to_owned(),
+            input_names,
+            external_functions,
+            options,
+        };
+        if let Some(runner) = compile_cache::get(&key) {
+            return Ok(runner);
+        }
+        let executor = Executor::new(
+            key.code.clone(),
+            script_name,
+            key.input_names.clone(),
+            key.external_functions.clone(),
+            key.options.clone(),
+        )?;
+        let runner = Self { executor };
+        compile_cache::insert(key, runner.clone());
+        Ok(runner)
+    }
+
+    /// Removes all programs from the compile cache and resets its counters.
+    pub fn clear_compile_cache() {
+        compile_cache::clear();
+    }
+
+    /// Sets the maximum number of programs kept in the compile cache, see [`MontyRun::with_options`].
+    ///
+    /// Defaults to [`DEFAULT_COMPILE_CACHE_CAPACITY`](crate::DEFAULT_COMPILE_CACHE_CAPACITY),
+    /// a capacity of zero disables caching.
+    pub fn set_compile_cache_capacity(capacity: usize) {
+        compile_cache::set_capacity(capacity);
+    }
+
+    /// Returns the hit and miss counters and size of the compile cache.
+    #[must_use]
+    pub fn compile_cache_stats() -> CompileCacheStats {
+        compile_cache::stats()
     }
 
     /// Returns the code that was parsed to create this snapshot.
@@ -67,6 +148,26 @@ impl MontyRun {
         &self.executor.code
     }
 
+    /// Returns the declared input names, in the order their values must be passed to `run()`/`start()`.
+    #[must_use]
+    pub fn input_names(&self) -> &[String] {
+        &self.executor.input_names
+    }
+
+    /// Checks a set of input names against the declared `input_names` without running any code.
+    ///
+    /// This lets a host validate its inputs up front and report a useful error, rather than
+    /// failing part way through execution with a `NameError`. Order is not checked, since
+    /// the host is expected to order values by `input_names()` when calling `run()`.
+    ///
+    /// # Errors
+    /// Returns `MontyException` with:
+    /// - `KeyError` if a declared input is missing from `names`
+    /// - `TypeError` if `names` contains a name that wasn't declared, or the same name twice
+    pub fn validate_inputs<S: AsRef<str>>(&self, names: &[S]) -> Result<(), MontyException> {
+        self.executor.validate_inputs(names)
+    }
+
     /// Executes the code and returns both the result and reference count data, used for testing only.
     #[cfg(feature = "ref-count-return")]
     pub fn run_ref_counts(&self, inputs: Vec<MontyObject>) -> Result<RefCountOutput, MontyException> {
@@ -91,6 +192,70 @@ impl MontyRun {
         self.executor.run(inputs, resource_tracker, print)
     }
 
+    /// Executes the code to completion like `run()`, reusing the heap storage held in `pool`.
+    ///
+    /// Intended for evaluating small snippets many times, where setting up and tearing down
+    /// a fresh heap dominates the cost of a run. Each run starts from an empty heap, so
+    /// nothing leaks between runs; only the capacity grown by earlier runs is kept.
+    ///
+    /// # Arguments
+    /// * `pool` - Heap storage reused across runs, start with `HeapPool::default()`
+    /// * `inputs` - Values to fill the first N slots of the namespace
+    /// * `resource_tracker` - Custom resource tracker implementation
+    /// * `print` - print output writer (mutably borrowed so `Collect` data is preserved)
+    pub fn run_reusing(
+        &self,
+        pool: &mut HeapPool,
+        inputs: Vec<MontyObject>,
+        resource_tracker: impl ResourceTracker,
+        print: &mut PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        self.executor.run_reusing(pool, inputs, resource_tracker, print)
+    }
+
+    /// Executes the code to completion, placing each input in its slot by name.
+    ///
+    /// Equivalent to `run()`, except the inputs are matched against the declared `input_names`
+    /// rather than filling the first N slots positionally.
+    ///
+    /// # Arguments
+    /// * `inputs` - Input values keyed by declared input name
+    /// * `resource_tracker` - Custom resource tracker implementation
+    /// * `print` - print output writer (mutably borrowed so `Collect` data is preserved)
+    ///
+    /// # Errors
+    /// Returns `MontyException` with `KeyError` if a declared input is missing, or `TypeError`
+    /// if `inputs` contains a name that wasn't declared; in both cases no code is run.
+    pub fn run_named(
+        &self,
+        inputs: HashMap<String, MontyObject>,
+        resource_tracker: impl ResourceTracker,
+        print: &mut PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        let inputs = self.executor.order_named_inputs(inputs)?;
+        self.executor.run(inputs, resource_tracker, print)
+    }
+
+    /// Executes the code to completion and returns the result along with the final global namespace.
+    ///
+    /// The namespace maps each module-level name that holds a value when execution finishes
+    /// to that value, including inputs. External functions and names that were never
+    /// assigned are left out. Intended for REPL-style and debugging embeddings that need
+    /// to inspect the variables a script defined.
+    ///
+    /// Takes the same arguments as [`MontyRun::run`].
+    ///
+    /// # Errors
+    /// Returns `MontyException` if execution raises, in which case the namespace is discarded.
+    pub fn run_with_namespace(
+        &self,
+        inputs: Vec<MontyObject>,
+        resource_tracker: impl ResourceTracker,
+        print: &mut PrintWriter<'_>,
+    ) -> Result<(MontyObject, HashMap<String, MontyObject>), MontyException> {
+        self.executor.run_with_namespace(inputs, resource_tracker, print)
+    }
+
     /// Executes the code to completion with no resource limits, printing to stdout/stderr.
     pub fn run_no_limits(&self, inputs: Vec<MontyObject>) -> Result<MontyObject, MontyException> {
         self.run(inputs, NoLimitTracker, &mut PrintWriter::Stdout)
@@ -104,7 +269,7 @@ impl MontyRun {
     /// # Errors
     /// Returns an error if serialization fails.
     pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
-        postcard::to_allocvec(self)
+        dump_versioned(self)
     }
 
     /// Deserializes a runner from binary format.
@@ -113,9 +278,33 @@ impl MontyRun {
     /// * `bytes` - The serialized runner data from `dump()`
     ///
     /// # Errors
-    /// Returns an error if deserialization fails.
-    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
-        postcard::from_bytes(bytes)
+    /// Returns an error if the data was written by a different version of Monty,
+    /// or if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, LoadError> {
+        load_versioned(bytes)
+    }
+
+    /// Serializes the runner to JSON, for debugging and interop with other languages.
+    ///
+    /// Like `dump()` but human-readable, and restored with `load_json()`. Bytes are encoded
+    /// as arrays of integers and non-finite floats as the strings `"inf"`, `"-inf"` and `"nan"`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn dump_json(&self) -> Result<String, serde_json::Error> {
+        dump_json_versioned(self)
+    }
+
+    /// Deserializes a runner from JSON.
+    ///
+    /// # Arguments
+    /// * `json` - The serialized runner data from `dump_json()`
+    ///
+    /// # Errors
+    /// Returns an error if the data was written by a different version of Monty,
+    /// or if deserialization fails.
+    pub fn load_json(json: &str) -> Result<Self, LoadError> {
+        load_json_versioned(json)
     }
 
     /// Starts execution with the given inputs and resource tracker, consuming self.
@@ -292,7 +481,7 @@ impl<T: ResourceTracker + serde::Serialize> RunProgress<T> {
     /// # Errors
     /// Returns an error if serialization fails.
     pub fn dump(&self) -> Result<Vec<u8>, postcard::Error> {
-        postcard::to_allocvec(self)
+        dump_versioned(self)
     }
 }
 
@@ -300,9 +489,10 @@ impl<T: ResourceTracker + serde::de::DeserializeOwned> RunProgress<T> {
     /// Deserializes execution state from binary format.
     ///
     /// # Errors
-    /// Returns an error if deserialization fails.
-    pub fn load(bytes: &[u8]) -> Result<Self, postcard::Error> {
-        postcard::from_bytes(bytes)
+    /// Returns an error if the data was written by a different version of Monty,
+    /// or if deserialization fails.
+    pub fn load(bytes: &[u8]) -> Result<Self, LoadError> {
+        load_versioned(bytes)
     }
 }
 
@@ -412,16 +602,8 @@ impl<T: ResourceTracker> Snapshot<T> {
                 // Get the call_id and ext_function_id that were stored when this Snapshot was created
                 let call_id = CallId::new(self.pending_call_id);
 
-                // Store pending call data in the scheduler so we can track the creator task
-                // and ignore results if the task is cancelled
-                vm.add_pending_call(call_id);
-
-                // Push the ExternalFuture value onto the stack
-                // This allows the code to continue and potentially await this future later
-                vm.push(Value::ExternalFuture(call_id));
-
-                // Continue execution
-                vm.run()
+                // Push an ExternalFuture, which the code can await later, and continue execution
+                vm.resume_with_future(call_id)
             }
         };
 
@@ -728,8 +910,11 @@ fn handle_vm_result<T: ResourceTracker>(
 struct Executor {
     /// Number of slots needed in the global namespace.
     namespace_size: usize,
-    /// Maps variable names to their indices in the namespace. Used for ref-count testing.
-    #[cfg(feature = "ref-count-return")]
+    /// Declared input names, in namespace slot order after the external functions.
+    input_names: Vec<String>,
+    /// Maps variable names to their indices in the namespace.
+    ///
+    /// Used to read back the final namespace, see `run_with_namespace` and `run_ref_counts`.
     name_map: ahash::AHashMap<String, crate::namespace::NamespaceId>,
     /// Compiled bytecode for the module.
     module_code: Code,
@@ -748,7 +933,7 @@ impl Clone for Executor {
     fn clone(&self) -> Self {
         Self {
             namespace_size: self.namespace_size,
-            #[cfg(feature = "ref-count-return")]
+            input_names: self.input_names.clone(),
             name_map: self.name_map.clone(),
             module_code: self.module_code.clone(),
             interns: self.interns.clone(),
@@ -761,14 +946,19 @@ impl Clone for Executor {
 
 impl Executor {
     /// Creates a new executor with the given code, filename, input names, and external functions.
+    ///
+    /// `options` selects the size limits enforced during parsing, and the restrictions
+    /// (frozen mode, read-only inputs) enforced during prepare.
     fn new(
         code: String,
         script_name: &str,
         input_names: Vec<String>,
         external_functions: Vec<String>,
+        options: PrepareOptions,
     ) -> Result<Self, MontyException> {
-        let parse_result = parse(&code, script_name).map_err(|e| e.into_python_exc(script_name, &code))?;
-        let prepared = prepare(parse_result, input_names, &external_functions)
+        let parse_result =
+            parse(&code, script_name, options.parse_limits).map_err(|e| e.into_python_exc(script_name, &code))?;
+        let prepared = prepare(parse_result, input_names.clone(), &external_functions, options)
             .map_err(|e| e.into_python_exc(script_name, &code))?;
 
         // Incrementing order matches the indexes used in intern::Interns::get_external_function_name
@@ -787,7 +977,7 @@ impl Executor {
 
         Ok(Self {
             namespace_size: prepared.namespace_size,
-            #[cfg(feature = "ref-count-return")]
+            input_names,
             name_map: prepared.name_map,
             module_code: compile_result.code,
             interns,
@@ -797,6 +987,51 @@ impl Executor {
         })
     }
 
+    /// Checks `names` against the declared input names, see [`MontyRun::validate_inputs`].
+    fn validate_inputs<S: AsRef<str>>(&self, names: &[S]) -> Result<(), MontyException> {
+        let mut seen = ahash::AHashSet::with_capacity(names.len());
+        for name in names {
+            let name = name.as_ref();
+            if !self.input_names.iter().any(|n| n == name) {
+                return Err(MontyException::new(
+                    ExcType::TypeError,
+                    Some(format!("Unexpected input: '{name}'")),
+                ));
+            }
+            if !seen.insert(name) {
+                return Err(MontyException::new(
+                    ExcType::TypeError,
+                    Some(format!("Duplicate input: '{name}'")),
+                ));
+            }
+        }
+        if let Some(missing) = self.input_names.iter().find(|n| !seen.contains(n.as_str())) {
+            return Err(MontyException::new(
+                ExcType::KeyError,
+                Some(format!("Missing required input: '{missing}'")),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Orders named inputs by the declared input names, ready to fill the namespace positionally.
+    fn order_named_inputs(&self, mut inputs: HashMap<String, MontyObject>) -> Result<Vec<MontyObject>, MontyException> {
+        if let Some(name) = inputs.keys().find(|k| !self.input_names.contains(*k)) {
+            return Err(MontyException::new(
+                ExcType::TypeError,
+                Some(format!("Unexpected input: '{name}'")),
+            ));
+        }
+        self.input_names
+            .iter()
+            .map(|name| {
+                inputs.remove(name).ok_or_else(|| {
+                    MontyException::new(ExcType::KeyError, Some(format!("Missing required input: '{name}'")))
+                })
+            })
+            .collect()
+    }
+
     /// Executes the code with a custom resource tracker.
     ///
     /// This provides full control over resource tracking and garbage collection
@@ -812,13 +1047,39 @@ impl Executor {
         inputs: Vec<MontyObject>,
         resource_tracker: impl ResourceTracker,
         print: &mut PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        let mut heap = Heap::new(self.heap_capacity.load(Ordering::Relaxed), resource_tracker);
+        self.run_in_heap(inputs, &mut heap, print)
+    }
+
+    /// Executes the code on a heap built from `pool`, handing its storage back afterwards.
+    ///
+    /// See [`MontyRun::run_reusing`].
+    fn run_reusing(
+        &self,
+        pool: &mut HeapPool,
+        inputs: Vec<MontyObject>,
+        resource_tracker: impl ResourceTracker,
+        print: &mut PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        let mut heap = Heap::from_pool(pool, self.heap_capacity.load(Ordering::Relaxed), resource_tracker);
+        let result = self.run_in_heap(inputs, &mut heap, print);
+        heap.release_into(pool);
+        result
+    }
+
+    /// Runs the module on `heap`, which must be freshly created, and converts the result.
+    fn run_in_heap(
+        &self,
+        inputs: Vec<MontyObject>,
+        heap: &mut Heap<impl ResourceTracker>,
+        print: &mut PrintWriter<'_>,
     ) -> Result<MontyObject, MontyException> {
         let heap_capacity = self.heap_capacity.load(Ordering::Relaxed);
-        let mut heap = Heap::new(heap_capacity, resource_tracker);
-        let mut namespaces = self.prepare_namespaces(inputs, &mut heap)?;
+        let mut namespaces = self.prepare_namespaces(inputs, heap)?;
 
         // Create and run VM
-        let mut vm = VM::new(&mut heap, &mut namespaces, &self.interns, print);
+        let mut vm = VM::new(heap, &mut namespaces, &self.interns, print);
         let frame_exit_result = vm.run_module(&self.module_code);
 
         // Clean up VM state before it goes out of scope
@@ -830,12 +1091,54 @@ impl Executor {
 
         // Clean up the global namespace before returning (only needed with ref-count-panic)
         #[cfg(feature = "ref-count-panic")]
-        namespaces.drop_global_with_heap(&mut heap);
+        namespaces.drop_global_with_heap(heap);
 
-        frame_exit_to_object(frame_exit_result, &mut heap, &self.interns)
+        frame_exit_to_object(frame_exit_result, heap, &self.interns)
             .map_err(|e| e.into_python_exception(&self.interns, &self.code))
     }
 
+    /// Executes the code and returns the result along with the final global namespace.
+    ///
+    /// See [`MontyRun::run_with_namespace`].
+    fn run_with_namespace(
+        &self,
+        inputs: Vec<MontyObject>,
+        resource_tracker: impl ResourceTracker,
+        print: &mut PrintWriter<'_>,
+    ) -> Result<(MontyObject, HashMap<String, MontyObject>), MontyException> {
+        let mut heap = Heap::new(self.heap_capacity.load(Ordering::Relaxed), resource_tracker);
+        let mut namespaces = self.prepare_namespaces(inputs, &mut heap)?;
+
+        let mut vm = VM::new(&mut heap, &mut namespaces, &self.interns, print);
+        let frame_exit_result = vm.run_module(&self.module_code);
+        vm.cleanup();
+
+        // Convert the namespace values before consuming the return value, external
+        // functions occupy the first slots and are skipped
+        let final_namespace = namespaces.into_global();
+        let first_defined = self.external_function_ids.len();
+        let mut variables = HashMap::new();
+        for (name, &namespace_id) in &self.name_map {
+            if namespace_id.index() < first_defined {
+                continue;
+            }
+            match final_namespace.get_opt(namespace_id) {
+                Some(Value::Undefined) | None => {}
+                Some(value) => {
+                    let value = value.clone_with_heap(&heap);
+                    variables.insert(name.clone(), MontyObject::new(value, &mut heap, &self.interns));
+                }
+            }
+        }
+        for obj in final_namespace {
+            obj.drop_with_heap(&mut heap);
+        }
+
+        let py_object = frame_exit_to_object(frame_exit_result, &mut heap, &self.interns)
+            .map_err(|e| e.into_python_exception(&self.interns, &self.code))?;
+        Ok((py_object, variables))
+    }
+
     /// Executes the code and returns both the result and reference count data, used for testing only.
     ///
     /// This is used for testing reference counting behavior. Returns:
@@ -0,0 +1,206 @@
+//! Versioned binary serialization used by the `dump()` and `load()` methods.
+//!
+//! Serialized data starts with a header of the format version byte and the Monty crate
+//! version, followed by the postcard-encoded value. Postcard isn't self-describing, so data
+//! written by a different version of Monty could otherwise be silently misinterpreted.
+//! Loading data with a different header fails with a [`LoadError`] instead.
+//!
+//! The JSON format used by the `dump_json()` methods carries the same versions as fields of
+//! a wrapper object, see [`dump_json_versioned`].
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+
+/// Version of the header layout of serialized data, the first byte of the data.
+pub const SERIALIZATION_FORMAT_VERSION: u8 = 1;
+
+/// Version of the Monty crate, serialized data must be loaded by the same version.
+const MONTY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Error loading data serialized by a `dump()` method.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The data doesn't start with the current format version byte.
+    FormatVersion(Option<u8>),
+    /// The data was written by a different version of Monty.
+    MontyVersion(String),
+    /// The data couldn't be decoded.
+    Postcard(postcard::Error),
+    /// The JSON data couldn't be decoded.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FormatVersion(Some(version)) => write!(
+                f,
+                "serialized data has format version {version}, expected {SERIALIZATION_FORMAT_VERSION}"
+            ),
+            Self::FormatVersion(None) => f.write_str("serialized data is empty"),
+            Self::MontyVersion(version) => write!(
+                f,
+                "serialized data was written by Monty {version}, but this is Monty {MONTY_VERSION}"
+            ),
+            Self::Postcard(err) => write!(f, "{err}"),
+            Self::Json(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<postcard::Error> for LoadError {
+    fn from(err: postcard::Error) -> Self {
+        Self::Postcard(err)
+    }
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+/// Serializes `value` with postcard, prefixed by the format and Monty version header.
+///
+/// Used by the `dump()` methods, and by bindings serializing their own wrappers of Monty types.
+///
+/// # Errors
+/// Returns an error if serialization fails.
+pub fn dump_versioned<T: Serialize>(value: &T) -> Result<Vec<u8>, postcard::Error> {
+    postcard::to_extend(&(MONTY_VERSION, value), vec![SERIALIZATION_FORMAT_VERSION])
+}
+
+/// Deserializes a value written by [`dump_versioned`], checking its header first.
+///
+/// # Errors
+/// Returns an error if the data was written by a different format or Monty version,
+/// or if deserialization fails.
+pub fn load_versioned<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, LoadError> {
+    let Some((&format_version, rest)) = bytes.split_first() else {
+        return Err(LoadError::FormatVersion(None));
+    };
+    if format_version != SERIALIZATION_FORMAT_VERSION {
+        return Err(LoadError::FormatVersion(Some(format_version)));
+    }
+    let (version, rest): (&str, _) = postcard::take_from_bytes(rest)?;
+    if version != MONTY_VERSION {
+        return Err(LoadError::MontyVersion(version.to_owned()));
+    }
+    Ok(postcard::from_bytes(rest)?)
+}
+
+/// Wrapper object of JSON serialized data, holding the versions next to the value.
+#[derive(Serialize, Deserialize)]
+struct JsonDump<T> {
+    format_version: u8,
+    monty_version: String,
+    data: T,
+}
+
+/// The version fields of [`JsonDump`], decoded before the value itself.
+#[derive(Deserialize)]
+struct JsonHeader {
+    format_version: u8,
+    monty_version: String,
+}
+
+/// Serializes `value` to JSON, wrapped in an object with the format and Monty versions.
+///
+/// Used by the `dump_json()` methods, and by bindings serializing their own wrappers of Monty types.
+///
+/// # Errors
+/// Returns an error if serialization fails.
+pub fn dump_json_versioned<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&JsonDump {
+        format_version: SERIALIZATION_FORMAT_VERSION,
+        monty_version: MONTY_VERSION.to_owned(),
+        data: value,
+    })
+}
+
+/// Deserializes a value written by [`dump_json_versioned`], checking its versions first.
+///
+/// # Errors
+/// Returns an error if the data was written by a different format or Monty version,
+/// or if deserialization fails.
+pub fn load_json_versioned<T: DeserializeOwned>(json: &str) -> Result<T, LoadError> {
+    let header: JsonHeader = serde_json::from_str(json)?;
+    if header.format_version != SERIALIZATION_FORMAT_VERSION {
+        return Err(LoadError::FormatVersion(Some(header.format_version)));
+    }
+    if header.monty_version != MONTY_VERSION {
+        return Err(LoadError::MontyVersion(header.monty_version));
+    }
+    let dump: JsonDump<T> = serde_json::from_str(json)?;
+    Ok(dump.data)
+}
+
+/// Serde helpers for `f64` fields that may hold infinities or NaN, used with `#[serde(with)]`.
+///
+/// JSON has no representation for non-finite numbers and `serde_json` writes them as `null`,
+/// which can't be read back as a float. In human-readable formats these are written as the
+/// strings `"inf"`, `"-inf"` and `"nan"` instead. Binary formats store the float unchanged.
+pub(crate) mod float {
+    use std::fmt;
+
+    use serde::de::{self, Visitor};
+
+    use super::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() || value.is_finite() {
+            serializer.serialize_f64(*value)
+        } else if value.is_nan() {
+            serializer.serialize_str("nan")
+        } else if *value > 0.0 {
+            serializer.serialize_str("inf")
+        } else {
+            serializer.serialize_str("-inf")
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FloatVisitor)
+        } else {
+            deserializer.deserialize_f64(FloatVisitor)
+        }
+    }
+
+    /// Accepts a number, or one of the strings written for non-finite floats.
+    struct FloatVisitor;
+
+    impl Visitor<'_> for FloatVisitor {
+        type Value = f64;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a float, \"inf\", \"-inf\" or \"nan\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+            Ok(value)
+        }
+
+        #[expect(clippy::cast_precision_loss)]
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        #[expect(clippy::cast_precision_loss)]
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<f64, E> {
+            match value {
+                "inf" => Ok(f64::INFINITY),
+                "-inf" => Ok(f64::NEG_INFINITY),
+                "nan" => Ok(f64::NAN),
+                _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
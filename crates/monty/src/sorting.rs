@@ -1,75 +1,286 @@
-//! Shared sorting utilities for `sorted()` and `list.sort()`.
+//! Shared sorting utilities for `sorted()`, `list.sort()` and the `heapq` and `bisect` modules.
 //!
-//! Both `sorted()` and `list.sort()` use index-based sorting: they build
-//! a vector of indices `[0, 1, 2, ...]`, sort the indices by comparing the
-//! corresponding items (or key values), then rearrange items according to
-//! the sorted indices.
+//! Both `sorted()` and `list.sort()` use index-based sorting: they sort a
+//! vector of indices `[0, 1, 2, ...]` by comparing the corresponding items
+//! (or key values), then rearrange items according to the sorted indices.
 //!
-//! This module provides [`sort_indices`] for the comparison step and
-//! [`apply_permutation`] for the in-place rearrangement step.
+//! The comparisons are driven by the VM (see `VM::sort`) rather than a callback,
+//! because comparing two dataclasses means asking the host for their `__lt__`,
+//! which suspends execution. [`PendingSort`] holds everything a sort needs so it can
+//! be kept in the VM snapshot while the host answers. [`py_lt`] is the single `<`
+//! comparison used for everything else, including the heap and binary search functions.
 
 use std::cmp::Ordering;
 
+use itertools::Itertools;
+
 use crate::{
-    exception_private::{ExcType, RunError},
-    heap::Heap,
-    intern::Interns,
+    args::ArgValues,
+    bytecode::VM,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapGuard},
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{List, PyTrait, list::restore_list_items},
     value::Value,
 };
 
-/// Sorts a vector of indices by comparing items at those positions.
+/// Length of the runs sorted by insertion before `MergeSort` starts merging them.
+const INSERTION_SORT_RUN: usize = 16;
+
+/// A `sorted()` or `list.sort()` call in progress.
 ///
-/// Compares `values[a]` vs `values[b]` using `py_cmp`, optionally reversing
-/// the ordering. If any comparison fails (type error or runtime error), the
-/// sort finishes early and the error is returned.
+/// Owns the items, the key values if there is a key function and, for `list.sort()`,
+/// a reference to the list. The list's items are moved out of it for the duration of
+/// the sort, so code run by a comparison sees an empty list, as in CPython, instead of
+/// finding it borrowed.
 ///
-/// The `values` slice is typically either the items themselves (no key function)
-/// or the pre-computed key values.
-pub fn sort_indices(
-    indices: &mut [usize],
-    values: &[Value],
+/// The VM keeps this in its snapshot while the host compares two dataclasses, so
+/// it must be finished with `finish` rather than dropped.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct PendingSort {
+    sort: MergeSort,
+    items: Vec<Value>,
+    keys: Option<Vec<Value>>,
     reverse: bool,
-    heap: &mut Heap<impl ResourceTracker>,
-    interns: &Interns,
-) -> Result<(), RunError> {
-    let mut sort_error: Option<RunError> = None;
-
-    indices.sort_by(|&a, &b| {
-        if sort_error.is_some() {
-            return Ordering::Equal;
+    /// The list being sorted by `list.sort()`, `None` for `sorted()`.
+    list: Option<Value>,
+}
+
+impl PendingSort {
+    /// Starts a sort of `items`, by `keys` if given.
+    ///
+    /// `list` is the list `items` were taken from by `list.sort()`, or `None` for `sorted()`.
+    pub fn new(items: Vec<Value>, keys: Option<Vec<Value>>, reverse: bool, list: Option<Value>) -> Self {
+        Self {
+            sort: MergeSort::new(items.len()),
+            items,
+            keys,
+            reverse,
+            list,
         }
-        if let Err(e) = heap.check_time() {
-            sort_error = Some(e.into());
-            return Ordering::Equal;
+    }
+
+    /// Returns the indices into `values()` of the next pair to compare with `<`,
+    /// or `None` once the sort is complete.
+    pub fn next_comparison(&mut self) -> Option<(usize, usize)> {
+        let (a, b) = self.sort.next_comparison()?;
+        Some(if self.reverse { (b, a) } else { (a, b) })
+    }
+
+    /// Feeds the answer to the comparison returned by `next_comparison` into the sort.
+    pub fn answer(&mut self, is_less: bool) {
+        self.sort.answer(is_less);
+    }
+
+    /// The values being compared: the keys if there is a key function, otherwise the items.
+    pub fn values(&self) -> &[Value] {
+        self.keys.as_deref().unwrap_or(&self.items)
+    }
+
+    /// Ends the sort, returning the sorted list for `sorted()` or `None` for `list.sort()`.
+    ///
+    /// If `complete` is false the sort failed part way through: `list.sort()` puts the
+    /// items back in their original order, `sorted()` drops them, and `None` is returned.
+    ///
+    /// # Errors
+    /// Returns `ValueError` if `list.sort()` completed but the list was changed meanwhile,
+    /// in which case the changes are dropped in favour of the sorted items.
+    pub fn finish(self, heap: &mut Heap<impl ResourceTracker>, complete: bool) -> RunResult<Value> {
+        let Self {
+            sort,
+            mut items,
+            keys,
+            list,
+            ..
+        } = self;
+        keys.drop_with_heap(heap);
+        if complete {
+            apply_permutation(&mut items, &mut sort.into_indices());
         }
-        match values[a].py_cmp(&values[b], heap, interns) {
-            Ok(Some(ord)) => {
-                if reverse {
-                    ord.reverse()
+        match list {
+            Some(list) => {
+                let list_id = list.ref_id().expect("list.sort target is a heap list");
+                let unchanged = restore_list_items(heap, list_id, items);
+                list.drop_with_heap(heap);
+                if complete && !unchanged {
+                    Err(ExcType::value_error("list modified during sort"))
                 } else {
-                    ord
+                    Ok(Value::None)
+                }
+            }
+            None if complete => Ok(Value::Ref(heap.allocate(HeapData::List(List::new(items)))?)),
+            None => {
+                items.drop_with_heap(heap);
+                Ok(Value::None)
+            }
+        }
+    }
+}
+
+/// Computes `key_fn(item)` for each item, for a sort with a key function.
+///
+/// Keys computed before an error are dropped.
+pub fn sort_keys(items: &[Value], key_fn: &Value, vm: &mut VM<'_, '_, impl ResourceTracker>) -> RunResult<Vec<Value>> {
+    let mut keys_guard = HeapGuard::new(Vec::with_capacity(items.len()), vm);
+    let (keys, vm) = keys_guard.as_parts_mut();
+    items
+        .iter()
+        .map(|item| {
+            let item = item.clone_with_heap(vm.heap);
+            vm.evaluate_function("sorted() key argument", key_fn, ArgValues::One(item))
+        })
+        .process_results(|keys_iter| keys.extend(keys_iter))?;
+    Ok(keys_guard.into_inner())
+}
+
+/// Stable merge sort of indices, which stops at each comparison until it is answered.
+///
+/// `next_comparison` returns the pair of indices `(a, b)` for which the sort needs to know
+/// whether `a` sorts before `b`, and `answer` continues the sort with the answer. This lets
+/// the VM suspend in the middle of a sort while the host compares two dataclasses.
+///
+/// `slice::sort_by` can't be used because it needs all answers synchronously, and may panic
+/// when the comparison is not a total order, which a user-defined `__lt__` doesn't have to
+/// be. This sort only relies on the answers to pick the next index, so inconsistent answers
+/// can't break it. As in CPython, the later item is always `a`, which determines the operand
+/// order in the error for incomparable types.
+///
+/// Runs of `INSERTION_SORT_RUN` items are sorted by insertion, then merged bottom up.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct MergeSort {
+    indices: Vec<usize>,
+    /// Copy of the runs being merged, so merged indices can be written straight into `indices`.
+    buffer: Vec<usize>,
+    step: SortStep,
+}
+
+/// Where a `MergeSort` has got to.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+enum SortStep {
+    /// Inserting item `i` of the run starting at `run_start`, which has moved left to position `j`.
+    Insert { run_start: usize, i: usize, j: usize },
+    /// Merging the runs `[start, start + width)` and `[start + width, start + 2 * width)`, having
+    /// taken `i` indices from the left run and `j` from the right.
+    Merge {
+        width: usize,
+        start: usize,
+        i: usize,
+        j: usize,
+    },
+    Done,
+}
+
+impl MergeSort {
+    fn new(len: usize) -> Self {
+        Self {
+            indices: (0..len).collect(),
+            buffer: Vec::new(),
+            step: SortStep::Insert {
+                run_start: 0,
+                i: 1,
+                j: 1,
+            },
+        }
+    }
+
+    /// Advances to the next comparison, returning the pair of indices to compare,
+    /// or `None` once sorted.
+    fn next_comparison(&mut self) -> Option<(usize, usize)> {
+        let Self { indices, buffer, step } = self;
+        let len = indices.len();
+        loop {
+            match step {
+                SortStep::Insert { run_start, i, j } => {
+                    if *run_start >= len {
+                        *buffer = vec![0; len];
+                        *step = SortStep::Merge {
+                            width: INSERTION_SORT_RUN,
+                            start: 0,
+                            i: 0,
+                            j: 0,
+                        };
+                    } else if *i >= INSERTION_SORT_RUN.min(len - *run_start) {
+                        *run_start += INSERTION_SORT_RUN;
+                        *i = 1;
+                        *j = 1;
+                    } else if *j == 0 {
+                        *i += 1;
+                        *j = *i;
+                    } else {
+                        let pos = *run_start + *j;
+                        return Some((indices[pos], indices[pos - 1]));
+                    }
                 }
+                SortStep::Merge { width, start, i, j } => {
+                    if *width >= len {
+                        *step = SortStep::Done;
+                        continue;
+                    }
+                    let mid = *start + *width;
+                    if mid >= len {
+                        *width *= 2;
+                        *start = 0;
+                        continue;
+                    }
+                    let end = (mid + *width).min(len);
+                    if *i + *j == end - *start {
+                        *start += 2 * *width;
+                        *i = 0;
+                        *j = 0;
+                        continue;
+                    }
+                    if *i == 0 && *j == 0 {
+                        buffer[*start..end].copy_from_slice(&indices[*start..end]);
+                    }
+                    // Once either run is used up, the rest of the other is taken in order
+                    if *i == *width {
+                        indices[*start + *i + *j] = buffer[mid + *j];
+                        *j += 1;
+                    } else if *j == end - mid {
+                        indices[*start + *i + *j] = buffer[*start + *i];
+                        *i += 1;
+                    } else {
+                        return Some((buffer[mid + *j], buffer[*start + *i]));
+                    }
+                }
+                SortStep::Done => return None,
             }
-            Ok(None) => {
-                sort_error = Some(ExcType::type_error(format!(
-                    "'<' not supported between instances of '{}' and '{}'",
-                    values[a].py_type(heap),
-                    values[b].py_type(heap)
-                )));
-                Ordering::Equal
+        }
+    }
+
+    /// Continues the sort with whether the first index returned by `next_comparison`
+    /// sorts before the second.
+    fn answer(&mut self, is_less: bool) {
+        match &mut self.step {
+            SortStep::Insert { run_start, i, j } => {
+                if is_less {
+                    let pos = *run_start + *j;
+                    self.indices.swap(pos, pos - 1);
+                    *j -= 1;
+                } else {
+                    *i += 1;
+                    *j = *i;
+                }
             }
-            Err(e) => {
-                sort_error = Some(e.into());
-                Ordering::Equal
+            SortStep::Merge { width, start, i, j } => {
+                let mid = *start + *width;
+                // Ties take from the left run, which keeps the sort stable
+                if is_less {
+                    self.indices[*start + *i + *j] = self.buffer[mid + *j];
+                    *j += 1;
+                } else {
+                    self.indices[*start + *i + *j] = self.buffer[*start + *i];
+                    *i += 1;
+                }
             }
+            SortStep::Done => unreachable!("answer given to a finished sort"),
         }
-    });
+    }
 
-    match sort_error {
-        Some(err) => Err(err),
-        None => Ok(()),
+    /// The sorted permutation of indices.
+    fn into_indices(self) -> Vec<usize> {
+        debug_assert!(matches!(self.step, SortStep::Done), "sort is not finished");
+        self.indices
     }
 }
 
@@ -83,7 +294,7 @@ pub fn sort_indices(
 /// Each element is moved at most twice (one swap = two moves), so the total
 /// work is O(n) moves. This is at most 2x the moves of building a fresh
 /// `Vec`, but avoids allocating a second buffer.
-pub fn apply_permutation(items: &mut [Value], indices: &mut [usize]) {
+fn apply_permutation(items: &mut [Value], indices: &mut [usize]) {
     for i in 0..items.len() {
         if indices[i] == i {
             continue;
@@ -100,3 +311,26 @@ pub fn apply_permutation(items: &mut [Value], indices: &mut [usize]) {
         }
     }
 }
+
+/// Returns whether `left < right`, checking the time limit first.
+///
+/// Instances of classes defining `__lt__` (or `__gt__`) are compared by calling
+/// that method, which is why the VM is needed. Only `<` is ever asked for, as in
+/// CPython's sort, heapq and bisect.
+///
+/// # Errors
+/// Returns `TypeError` if the values can't be ordered.
+pub fn py_lt(left: &Value, right: &Value, vm: &mut VM<'_, '_, impl ResourceTracker>) -> RunResult<bool> {
+    vm.heap.check_time()?;
+    if let Some(result) = vm.instance_lt(left, right) {
+        return result;
+    }
+    match left.py_cmp(right, vm.heap, vm.interns)? {
+        Some(ordering) => Ok(ordering == Ordering::Less),
+        None => Err(ExcType::type_error(format!(
+            "'<' not supported between instances of '{}' and '{}'",
+            left.py_type(vm.heap),
+            right.py_type(vm.heap)
+        ))),
+    }
+}
@@ -77,7 +77,7 @@ use crate::{
     exception_private::{ExcType, RunResult, SimpleException},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
     intern::{Interns, StaticStrings, StringId},
-    resource::{ResourceError, ResourceTracker},
+    resource::{ResourceError, ResourceTracker, check_repeat_size},
     types::List,
     value::{EitherStr, Value},
 };
@@ -112,6 +112,30 @@ pub fn get_byte_at_index(bytes: &[u8], index: i64) -> Option<u8> {
     Some(bytes[idx])
 }
 
+/// Implements Python's `in` operator for bytes: `item in bytes`.
+///
+/// An int operand tests for a single byte value and must be in `range(0, 256)`.
+/// A bytes operand tests for a contiguous subsequence; the empty bytes is always found.
+pub(crate) fn bytes_contains(
+    bytes: &[u8],
+    item: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let byte = match item {
+        Value::Int(i) => *i,
+        Value::Bool(b) => i64::from(*b),
+        _ => {
+            let sub = extract_bytes_only(item, heap, interns)?;
+            return Ok(sub.is_empty() || find_subsequence(bytes, sub).is_some());
+        }
+    };
+    match u8::try_from(byte) {
+        Ok(b) => Ok(bytes.contains(&b)),
+        Err(_) => Err(SimpleException::new_msg(ExcType::ValueError, "byte must be in range(0, 256)").into()),
+    }
+}
+
 /// Extracts a slice of a byte array.
 ///
 /// Handles both positive and negative step values. For negative step,
@@ -1942,7 +1966,9 @@ fn bytes_center(
         bytes.to_vec()
     } else {
         let total_pad = width - len;
-        let left_pad = total_pad / 2;
+        check_repeat_size(1, total_pad, heap.tracker())?;
+        // Odd padding goes on the left when width is odd, matching CPython
+        let left_pad = total_pad / 2 + (total_pad & width & 1);
         let right_pad = total_pad - left_pad;
         let mut result = Vec::with_capacity(width);
         for _ in 0..left_pad {
@@ -1974,6 +2000,7 @@ fn bytes_ljust(
         bytes.to_vec()
     } else {
         let pad = width - len;
+        check_repeat_size(1, pad, heap.tracker())?;
         let mut result = Vec::with_capacity(width);
         result.extend_from_slice(bytes);
         for _ in 0..pad {
@@ -2001,6 +2028,7 @@ fn bytes_rjust(
         bytes.to_vec()
     } else {
         let pad = width - len;
+        check_repeat_size(1, pad, heap.tracker())?;
         let mut result = Vec::with_capacity(width);
         for _ in 0..pad {
             result.push(fillbyte);
@@ -2069,6 +2097,7 @@ fn bytes_zfill(bytes: &[u8], args: ArgValues, heap: &mut Heap<impl ResourceTrack
         bytes.to_vec()
     } else {
         let pad = width - len;
+        check_repeat_size(1, pad, heap.tracker())?;
         let mut result = Vec::with_capacity(width);
 
         // Handle sign prefix
@@ -0,0 +1,651 @@
+//! Classes defined in the script, their instances and bound methods.
+//!
+//! A `class` statement builds a `ClassObject` holding its methods. Calling the class
+//! allocates an `Instance` and runs `__init__` on it. Looking a method up on an instance
+//! binds it to that instance as a `BoundMethod`, while `staticmethod` and `classmethod`
+//! wrap a method in a `MethodDescriptor` to bind it to nothing or to the class instead.
+//! Inheritance is only supported from exception types: calling such a class creates an
+//! exception rather than an `Instance`, see `ExceptionClass`. Otherwise attribute lookup
+//! only ever checks the instance and then its class.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings, StringId},
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Dict, PyTrait, Type},
+    value::{EitherStr, Value},
+};
+
+/// A class defined by a `class` statement in the script.
+///
+/// The class attributes are its methods, keyed by method name. The class is itself
+/// callable: calling it creates an `Instance` (see `VM::call_class`).
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ClassObject {
+    /// The class name (e.g., "Point").
+    name: StringId,
+    /// Class attributes: method name -> function.
+    attrs: Dict,
+    /// Set if the class derives from an exception type.
+    exception: Option<ExceptionClass>,
+}
+
+/// Identifies a class defined in the script, unique among the classes created on a heap.
+///
+/// Unlike its name, which two classes made by a class factory can share, no two classes
+/// have the same id, see `Heap::new_class_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ClassId(u64);
+
+impl ClassId {
+    #[must_use]
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+/// The exception type a class defined in the script derives from, e.g. `class MyError(ValueError)`.
+///
+/// Calling such a class creates a `SimpleException` of the builtin base type, tagged with
+/// the class name and `class_ids` so that `except MyError` and `isinstance(e, MyError)` can
+/// match it. Classes are matched by id, since exceptions don't hold references to their class.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ExceptionClass {
+    /// The builtin exception type at the root of the class's bases.
+    exc_type: ExcType,
+    /// The class name, which the exception's type is displayed as.
+    name: StringId,
+    /// The ids of the class and its bases defined in the script, most derived first.
+    class_ids: Vec<ClassId>,
+}
+
+impl ExceptionClass {
+    /// Creates the exception info of class `name` deriving directly from `exc_type`.
+    #[must_use]
+    pub fn new(name: StringId, id: ClassId, exc_type: ExcType) -> Self {
+        Self {
+            exc_type,
+            name,
+            class_ids: vec![id],
+        }
+    }
+
+    /// Creates the exception info of class `name` deriving from the class with this info.
+    #[must_use]
+    pub fn subclass(&self, name: StringId, id: ClassId) -> Self {
+        let mut class_ids = Vec::with_capacity(self.class_ids.len() + 1);
+        class_ids.push(id);
+        class_ids.extend_from_slice(&self.class_ids);
+        Self {
+            exc_type: self.exc_type,
+            name,
+            class_ids,
+        }
+    }
+
+    /// Creates an exception of this class from the call arguments.
+    pub fn call(&self, heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
+        let exc = self
+            .exc_type
+            .new_exception(heap, args, interns)?
+            .with_class(self.name, self.class_ids.clone());
+        Ok(Value::Ref(heap.allocate(HeapData::Exception(exc))?))
+    }
+
+    /// Creates an exception of this class without arguments, as raised by `raise MyError`.
+    #[must_use]
+    pub fn new_none(&self) -> SimpleException {
+        SimpleException::new_none(self.exc_type).with_class(self.name, self.class_ids.clone())
+    }
+
+    /// Returns whether `exc` is an instance of this class or one of its subclasses.
+    #[must_use]
+    pub fn is_class_of(&self, exc: &SimpleException) -> bool {
+        exc.class_ids().contains(&self.class_ids[0])
+    }
+}
+
+impl ClassObject {
+    /// Creates a class from its name and attribute dict (ownership of `attrs` is transferred).
+    #[must_use]
+    pub fn new(name: StringId, attrs: Dict, exception: Option<ExceptionClass>) -> Self {
+        Self { name, attrs, exception }
+    }
+
+    /// Returns the class name.
+    #[must_use]
+    pub fn name<'a>(&self, interns: &'a Interns) -> &'a str {
+        interns.get_str(self.name)
+    }
+
+    /// Returns a reference to the class attributes.
+    #[must_use]
+    pub fn attrs(&self) -> &Dict {
+        &self.attrs
+    }
+
+    /// Returns the exception type the class derives from, if any.
+    #[must_use]
+    pub fn exception(&self) -> Option<&ExceptionClass> {
+        self.exception.as_ref()
+    }
+
+    /// Returns whether the class attributes contain any heap references.
+    #[inline]
+    #[must_use]
+    pub fn has_refs(&self) -> bool {
+        self.attrs.has_refs()
+    }
+
+    /// Looks up an attribute on the class `self_id`.
+    ///
+    /// Plain functions and properties are returned as they are, while classmethods are
+    /// returned as `InstanceAttr::Method` bound to the class.
+    pub fn lookup(
+        self_id: HeapId,
+        attr: &EitherStr,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<InstanceAttr> {
+        let HeapData::Class(class) = heap.get(self_id) else {
+            unreachable!("ClassObject::lookup called on a non-class")
+        };
+        let attr_name = attr.as_str(interns);
+        class
+            .resolve(self_id, attr_name, None, heap, interns)
+            .ok_or_else(|| ExcType::attribute_error_type_object(class.name(interns), attr_name))
+    }
+
+    /// Finds `attr_name` among the class attributes and binds it as Python's descriptors would.
+    ///
+    /// `instance` is the instance the lookup started from, if any. Functions are bound to it
+    /// and properties are evaluated on it, but on the class itself both are returned as is.
+    fn resolve(
+        &self,
+        self_id: HeapId,
+        attr_name: &str,
+        instance: Option<HeapId>,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Option<InstanceAttr> {
+        let value = self.attrs.get_by_str(attr_name, heap, interns)?;
+        if let Value::Ref(id) = value {
+            match heap.get(*id) {
+                HeapData::Property(property) if instance.is_some() => {
+                    return Some(InstanceAttr::Property(property.getter().clone_with_heap(heap)));
+                }
+                HeapData::MethodDescriptor(descriptor) => {
+                    let func = descriptor.func.clone_with_heap(heap);
+                    return Some(match descriptor.kind {
+                        MethodDescriptorKind::Static => InstanceAttr::Value(func),
+                        MethodDescriptorKind::Class => {
+                            heap.inc_ref(self_id);
+                            InstanceAttr::Method {
+                                func,
+                                self_value: Value::Ref(self_id),
+                            }
+                        }
+                    });
+                }
+                _ => {}
+            }
+        }
+        Some(match instance {
+            Some(instance_id) if is_function(value, heap) => {
+                heap.inc_ref(instance_id);
+                InstanceAttr::Method {
+                    func: value.clone_with_heap(heap),
+                    self_value: Value::Ref(instance_id),
+                }
+            }
+            _ => InstanceAttr::Value(value.clone_with_heap(heap)),
+        })
+    }
+}
+
+impl PyTrait for ClassObject {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::Type
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.attrs.py_estimate_size()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Classes compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.attrs.py_dec_ref_ids(stack);
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "<class '__main__.{}'>", self.name(interns))
+    }
+
+    /// Handles `__name__`.
+    ///
+    /// Returns `Ok(None)` for anything else so `Value::py_getattr` can fall back to
+    /// `ClassObject::lookup`, which needs the class's own id to bind classmethods.
+    fn py_getattr(
+        &self,
+        attr: &EitherStr,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> RunResult<Option<AttrCallResult>> {
+        if attr.static_string() == Some(StaticStrings::DunderName) {
+            return Ok(Some(AttrCallResult::Value(Value::InternString(self.name))));
+        }
+        Ok(None)
+    }
+}
+
+/// An instance of a `ClassObject`.
+///
+/// Holds a strong reference to its class and a dict of instance attributes, which
+/// `__init__` and the methods populate through `self.attr = value`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Instance {
+    /// The class this is an instance of; the instance owns a reference to it.
+    class_id: HeapId,
+    /// Instance attributes set via attribute assignment.
+    attrs: Dict,
+}
+
+/// Result of looking up an attribute with `Instance::lookup` or `ClassObject::lookup`.
+pub(crate) enum InstanceAttr {
+    /// A plain value, e.g. an instance attribute or a function looked up on the class.
+    Value(Value),
+    /// A function which must be called with `self_value` as its first argument: a method
+    /// looked up on an instance, or a classmethod bound to its class.
+    Method { func: Value, self_value: Value },
+    /// The getter of a property found on the class, which must be called with the instance.
+    Property(Value),
+}
+
+impl Instance {
+    /// Creates an instance with no attributes.
+    ///
+    /// The caller must have incremented the refcount of `class_id` on behalf of the instance.
+    #[must_use]
+    pub fn new(class_id: HeapId) -> Self {
+        Self {
+            class_id,
+            attrs: Dict::new(),
+        }
+    }
+
+    /// Returns the id of the instance's class.
+    #[must_use]
+    pub fn class_id(&self) -> HeapId {
+        self.class_id
+    }
+
+    /// Returns a reference to the instance attributes.
+    #[must_use]
+    pub fn attrs(&self) -> &Dict {
+        &self.attrs
+    }
+
+    /// Returns the name of the instance's class.
+    #[must_use]
+    pub fn class_name<'a>(&self, heap: &Heap<impl ResourceTracker>, interns: &'a Interns) -> &'a str {
+        match heap.get(self.class_id) {
+            HeapData::Class(class) => class.name(interns),
+            _ => unreachable!("instance class must be a class object"),
+        }
+    }
+
+    /// Returns a new `dict` mapping attribute names to values, backing `vars(obj)` and `obj.__dict__`.
+    ///
+    /// Like `Dataclass::vars_dict`, this is a shallow copy rather than the live namespace.
+    pub fn vars_dict(&self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+        let dict = self.attrs.shallow_copy(heap, interns)?;
+        Ok(Value::Ref(heap.allocate(HeapData::Dict(dict))?))
+    }
+
+    /// Sets an instance attribute.
+    ///
+    /// The caller transfers ownership of `value`. Returns the old value if the
+    /// attribute existed (caller must drop it), or None if this is a new attribute.
+    pub fn set_attr(
+        &mut self,
+        attr: &EitherStr,
+        value: Value,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<Value>> {
+        let HeapData::Class(class) = heap.get(self.class_id) else {
+            unreachable!("instance class must be a class object")
+        };
+        let attr_name = attr.as_str(interns);
+        if let Some(class_attr) = class.attrs.get_by_str(attr_name, heap, interns)
+            && property_getter(class_attr, heap).is_some()
+        {
+            let class_name = class.name(interns);
+            value.drop_with_heap(heap);
+            return Err(ExcType::attribute_error_property_no_setter(attr_name, class_name));
+        }
+        let name = match attr.to_value(heap) {
+            Ok(name) => name,
+            Err(e) => {
+                value.drop_with_heap(heap);
+                return Err(e.into());
+            }
+        };
+        self.attrs.set(name, value, heap, interns)
+    }
+
+    /// Looks up an attribute on the instance `self_id`, falling back to its class.
+    ///
+    /// Functions found on the class are returned as `InstanceAttr::Method` so the caller
+    /// can either bind them (attribute access) or call them with `self` prepended
+    /// (method calls) without allocating a `BoundMethod`. Properties are returned as
+    /// `InstanceAttr::Property` since calling their getter needs the VM. Static and
+    /// class methods bind the same way as when looked up on the class.
+    pub fn lookup(
+        self_id: HeapId,
+        attr: &EitherStr,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<InstanceAttr> {
+        let HeapData::Instance(instance) = heap.get(self_id) else {
+            unreachable!("Instance::lookup called on a non-instance")
+        };
+        let attr_name = attr.as_str(interns);
+        if let Some(value) = instance.attrs.get_by_attr(attr, heap, interns) {
+            return Ok(InstanceAttr::Value(value.clone_with_heap(heap)));
+        }
+        let HeapData::Class(class) = heap.get(instance.class_id) else {
+            unreachable!("instance class must be a class object")
+        };
+        class
+            .resolve(instance.class_id, attr_name, Some(self_id), heap, interns)
+            .ok_or_else(|| ExcType::attribute_error(class.name(interns), attr_name))
+    }
+}
+
+impl PyTrait for Instance {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::Instance
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.attrs.py_estimate_size()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Instances compare by identity, which is handled at the Value level. `__eq__` is only
+        // called by the comparison opcodes, see `VM::compare_eq`
+        Ok(false)
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        stack.push(self.class_id);
+        self.attrs.py_dec_ref_ids(stack);
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "<__main__.{} object>", self.class_name(heap, interns))
+    }
+
+    /// Handles `__dict__` and instance attributes.
+    ///
+    /// Returns `Ok(None)` for anything else so `Value::py_getattr` can fall back to the
+    /// class via `Instance::lookup`, which needs the instance's own id to bind methods.
+    fn py_getattr(
+        &self,
+        attr: &EitherStr,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<AttrCallResult>> {
+        if attr.static_string() == Some(StaticStrings::DunderDict) {
+            return Ok(Some(AttrCallResult::Value(self.vars_dict(heap, interns)?)));
+        }
+        Ok(self
+            .attrs
+            .get_by_attr(attr, heap, interns)
+            .map(|value| AttrCallResult::Value(value.clone_with_heap(heap))))
+    }
+}
+
+/// A function bound to a `self` value, e.g. `p.norm` for an instance `p`.
+///
+/// Calling it calls `func` with `self_value` prepended to the arguments.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct BoundMethod {
+    /// The underlying function (`Value::DefFunction` or a closure/defaults `Value::Ref`).
+    func: Value,
+    /// The value passed as the first argument.
+    self_value: Value,
+}
+
+impl BoundMethod {
+    /// Creates a bound method, taking ownership of both values.
+    #[must_use]
+    pub fn new(func: Value, self_value: Value) -> Self {
+        Self { func, self_value }
+    }
+
+    /// Returns the underlying function.
+    #[must_use]
+    pub fn func(&self) -> &Value {
+        &self.func
+    }
+
+    /// Returns the value bound as `self`.
+    #[must_use]
+    pub fn self_value(&self) -> &Value {
+        &self.self_value
+    }
+
+    /// Returns whether either the function or `self` is a heap reference.
+    #[must_use]
+    pub fn has_refs(&self) -> bool {
+        matches!(self.func, Value::Ref(_)) || matches!(self.self_value, Value::Ref(_))
+    }
+}
+
+impl PyTrait for BoundMethod {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::Method
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Bound methods are equal if they bind the same function to the same object
+        Ok(self.self_value.is(&other.self_value) && self.func.py_eq(&other.func, heap, interns)?)
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.func.py_dec_ref_ids(stack);
+        self.self_value.py_dec_ref_ids(stack);
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        heap: &Heap<impl ResourceTracker>,
+        heap_ids: &mut AHashSet<HeapId>,
+        interns: &Interns,
+    ) -> std::fmt::Result {
+        let func_id = match &self.func {
+            Value::DefFunction(func_id) => Some(*func_id),
+            Value::Ref(id) => match heap.get(*id) {
+                HeapData::Closure(closure) => Some(closure.func_id),
+                HeapData::FunctionDefaults(fd) => Some(fd.func_id),
+                _ => None,
+            },
+            _ => None,
+        };
+        f.write_str("<bound method ")?;
+        match func_id {
+            Some(func_id) => f.write_str(interns.get_str(interns.get_function(func_id).name.name_id))?,
+            None => self.func.py_repr_fmt(f, heap, heap_ids, interns)?,
+        }
+        f.write_str(" of ")?;
+        self.self_value.py_repr_fmt(f, heap, heap_ids, interns)?;
+        f.write_char('>')
+    }
+}
+
+/// Which of `staticmethod` or `classmethod` created a `MethodDescriptor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) enum MethodDescriptorKind {
+    /// `staticmethod`: the function is called without binding it.
+    Static,
+    /// `classmethod`: the function is bound to the class.
+    Class,
+}
+
+/// A function wrapped by `staticmethod` or `classmethod`, e.g. via `@staticmethod`.
+///
+/// Only meaningful as a class attribute, where it changes what the function is bound to
+/// when looked up on the class or its instances (see `ClassObject::lookup`).
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct MethodDescriptor {
+    /// Whether this is a staticmethod or a classmethod.
+    kind: MethodDescriptorKind,
+    /// The wrapped function.
+    func: Value,
+}
+
+impl MethodDescriptor {
+    /// Creates a descriptor from `staticmethod(func)` or `classmethod(func)`.
+    pub fn init(
+        kind: MethodDescriptorKind,
+        heap: &mut Heap<impl ResourceTracker>,
+        args: ArgValues,
+    ) -> RunResult<Value> {
+        let name = match kind {
+            MethodDescriptorKind::Static => "staticmethod",
+            MethodDescriptorKind::Class => "classmethod",
+        };
+        let func = args.get_one_arg(name, heap)?;
+        let descriptor = Self { kind, func };
+        Ok(Value::Ref(heap.allocate(HeapData::MethodDescriptor(descriptor))?))
+    }
+
+    /// Returns the wrapped function.
+    #[must_use]
+    pub fn func(&self) -> &Value {
+        &self.func
+    }
+
+    /// Returns whether the wrapped function is a heap reference.
+    #[must_use]
+    pub fn has_refs(&self) -> bool {
+        matches!(self.func, Value::Ref(_))
+    }
+}
+
+impl PyTrait for MethodDescriptor {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        match self.kind {
+            MethodDescriptorKind::Static => Type::StaticMethod,
+            MethodDescriptorKind::Class => Type::ClassMethod,
+        }
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Descriptors compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.func.py_dec_ref_ids(stack);
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "<{} object>", self.py_type(heap))
+    }
+}
+
+/// Returns the getter if `value` is a property defined in the script.
+fn property_getter<'h>(value: &Value, heap: &'h Heap<impl ResourceTracker>) -> Option<&'h Value> {
+    match value {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Property(property) => Some(property.getter()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether `value` is a function defined in the script, which instances bind as a method.
+fn is_function(value: &Value, heap: &Heap<impl ResourceTracker>) -> bool {
+    match value {
+        Value::DefFunction(_) => true,
+        Value::Ref(id) => matches!(heap.get(*id), HeapData::Closure(_) | HeapData::FunctionDefaults(_)),
+        _ => false,
+    }
+}
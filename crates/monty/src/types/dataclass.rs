@@ -8,8 +8,8 @@ use crate::{
     bytecode::VM,
     defer_drop,
     exception_private::{ExcType, RunResult},
-    heap::{Heap, HeapId},
-    intern::Interns,
+    heap::{Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
     resource::{ResourceError, ResourceTracker},
     types::{AttrCallResult, Type},
     value::{EitherStr, Value},
@@ -40,7 +40,7 @@ use crate::{
 /// all attribute values when the dataclass instance is freed.
 ///
 /// # Attribute Access
-/// - Getting: Looks up the attribute name in the attrs Dict
+/// - Getting: Looks up the attribute name in the attrs Dict (`__dict__` returns a copy of it)
 /// - Setting: Updates or adds the attribute in attrs (only if not frozen)
 /// - Method calls: If the attribute is a public name not found in attrs, dispatched to host
 /// - repr: Only shows declared fields (from field_names), not extra attributes
@@ -111,6 +111,32 @@ impl Dataclass {
         &self.attrs
     }
 
+    /// Returns a new `dict` mapping attribute names to values, backing `vars(obj)` and `obj.__dict__`.
+    ///
+    /// Unlike CPython, where `__dict__` is the live instance namespace, this is a shallow
+    /// copy: adding or removing keys in the returned dict doesn't change the instance,
+    /// which keeps frozen dataclasses immutable. Mutable values (e.g. lists) are still
+    /// shared with the instance. Includes dynamically added attributes, not just
+    /// declared fields.
+    pub fn vars_dict(&self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+        let dict = self.attrs.shallow_copy(heap, interns)?;
+        Ok(Value::Ref(heap.allocate(HeapData::Dict(dict))?))
+    }
+
+    /// Creates another instance of the same dataclass holding `attrs`.
+    ///
+    /// Used by `copy.copy()` and `copy.deepcopy()`. The caller transfers ownership of `attrs`.
+    #[must_use]
+    pub fn with_attrs(&self, attrs: Dict) -> Self {
+        Self::new(
+            self.name.clone(),
+            self.type_id,
+            self.field_names.clone(),
+            attrs,
+            self.frozen,
+        )
+    }
+
     /// Returns whether this dataclass instance is frozen (immutable).
     #[must_use]
     pub fn is_frozen(&self) -> bool {
@@ -119,29 +145,31 @@ impl Dataclass {
 
     /// Sets an attribute value.
     ///
-    /// The caller transfers ownership of both `name` and `value`. Returns the
-    /// old value if the attribute existed (caller must drop it), or None if this
-    /// is a new attribute.
+    /// The caller transfers ownership of `value`. Returns the old value if the
+    /// attribute existed (caller must drop it), or None if this is a new attribute.
+    ///
+    /// Names that aren't interned (e.g. from `setattr(obj, name, value)`) are copied
+    /// into a heap `str` to use as the attrs key.
     ///
     /// Returns `FrozenInstanceError` if the dataclass is frozen.
     pub fn set_attr(
         &mut self,
-        name: Value,
+        attr: &EitherStr,
         value: Value,
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<Option<Value>> {
         if self.frozen {
-            // Get attribute name for error message
-            let attr_name = match &name {
-                Value::InternString(id) => interns.get_str(*id).to_string(),
-                _ => "<unknown>".to_string(),
-            };
-            // Drop the values we were given ownership of
-            name.drop_with_heap(heap);
             value.drop_with_heap(heap);
-            return Err(ExcType::frozen_instance_error(&attr_name));
+            return Err(ExcType::frozen_instance_error(attr.as_str(interns)));
         }
+        let name = match attr.to_value(heap) {
+            Ok(name) => name,
+            Err(e) => {
+                value.drop_with_heap(heap);
+                return Err(e.into());
+            }
+        };
         self.attrs.set(name, value, heap, interns)
     }
 
@@ -229,7 +257,7 @@ impl PyTrait for Dataclass {
         interns: &Interns,
     ) -> std::fmt::Result {
         // Check depth limit before recursing
-        let Some(token) = heap.incr_recursion_depth_for_repr() else {
+        let Some(token) = heap.incr_repr_depth() else {
             return f.write_str("...");
         };
         crate::defer_drop_immutable_heap!(token, heap);
@@ -321,6 +349,9 @@ impl PyTrait for Dataclass {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<Option<AttrCallResult>> {
+        if attr.static_string() == Some(StaticStrings::DunderDict) {
+            return Ok(Some(AttrCallResult::Value(self.vars_dict(heap, interns)?)));
+        }
         let attr_name = attr.as_str(interns);
         match self.attrs.get_by_str(attr_name, heap, interns) {
             Some(value) => Ok(Some(AttrCallResult::Value(value.clone_with_heap(heap)))),
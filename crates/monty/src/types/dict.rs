@@ -1,4 +1,5 @@
 use std::{
+    borrow::Borrow,
     collections::hash_map::DefaultHasher,
     fmt::Write,
     hash::{Hash, Hasher},
@@ -14,7 +15,7 @@ use crate::{
     defer_drop, defer_drop_mut,
     exception_private::{ExcType, RunResult},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
-    intern::{Interns, StaticStrings},
+    intern::{Interns, StaticStrings, StringId},
     resource::{ResourceError, ResourceTracker},
     types::Type,
     value::{EitherStr, Value},
@@ -40,6 +41,11 @@ use crate::{
 ///
 /// All dict methods from Python's builtins are implemented.
 ///
+/// # Counters
+/// `collections.Counter` is a dict with `is_counter` set. Counters report missing keys
+/// as 0, have a `most_common([n])` method and repr as `Counter({...})` with the most
+/// common elements first. Otherwise they behave exactly like dicts.
+///
 /// # Storage Strategy
 /// Uses a `HashTable<usize>` for hash lookups combined with a dense `Vec<DictEntry>`
 /// to preserve insertion order (matching Python 3.7+ behavior). The hash table maps
@@ -66,16 +72,29 @@ pub(crate) struct Dict {
     /// in `collect_child_ids` and `py_dec_ref_ids` when no refs are present.
     /// Only transitions from false to true (never back) since tracking removals would be O(n).
     contains_refs: bool,
+    /// True if this dict is a `collections.Counter`.
+    is_counter: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct DictEntry {
     key: Value,
     value: Value,
-    /// the hash is needed here for correct use of insert_unique
+    /// the table hash (see [`table_hash`]), needed here for correct use of insert_unique
     hash: u64,
 }
 
+/// Mixes a Python hash into the hash used for the `indices` table of dicts and sets.
+///
+/// Python hashes are not uniformly distributed: small ints hash to themselves, so their
+/// top bits (which hashbrown uses as the control byte) are all zero, and keys like
+/// `i << 32` share their low bits (which pick the bucket). A multiply-xorshift spreads
+/// every input bit across the whole word before the hash reaches hashbrown.
+pub(crate) fn table_hash(hash: u64) -> u64 {
+    let mixed = hash.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    mixed ^ (mixed >> 32)
+}
+
 impl Dict {
     /// Creates a new empty dict.
     #[must_use]
@@ -88,9 +107,21 @@ impl Dict {
             indices: HashTable::with_capacity(capacity),
             entries: Vec::with_capacity(capacity),
             contains_refs: false,
+            is_counter: false,
         }
     }
 
+    /// Returns whether this dict is a `collections.Counter`.
+    #[must_use]
+    pub fn is_counter(&self) -> bool {
+        self.is_counter
+    }
+
+    /// Turns this dict into a `collections.Counter`, or back into a plain dict.
+    pub fn set_counter(&mut self, is_counter: bool) {
+        self.is_counter = is_counter;
+    }
+
     /// Returns whether this dict contains any heap references (`Value::Ref`).
     ///
     /// Used during allocation to determine if this container could create cycles,
@@ -130,6 +161,19 @@ impl Dict {
         Ok(dict_guard.into_inner())
     }
 
+    /// Creates a shallow copy of this dict.
+    ///
+    /// Keys and values are shared with the original (their refcounts are incremented),
+    /// so mutating the copy's entries never affects this dict. Used by `dict.copy()` and
+    /// by views like `vars(dataclass)` that hand out a snapshot of internal storage.
+    pub fn shallow_copy(&self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Self> {
+        let pairs: Vec<(Value, Value)> = self
+            .iter()
+            .map(|(k, v)| (k.clone_with_heap(heap), v.clone_with_heap(heap)))
+            .collect();
+        Self::from_pairs(pairs, heap, interns)
+    }
+
     /// Gets a value from the dict by key.
     ///
     /// Returns Ok(Some(value)) if key exists, Ok(None) if key doesn't exist.
@@ -155,7 +199,7 @@ impl Dict {
         // Compute hash for the string key
         let mut hasher = DefaultHasher::new();
         key_str.hash(&mut hasher);
-        let hash = hasher.finish();
+        let hash = table_hash(hasher.finish());
 
         // Find entry with matching hash and key
         self.indices
@@ -176,6 +220,44 @@ impl Dict {
             .map(|&idx| &self.entries[idx].value)
     }
 
+    /// Gets a value from the dict by interned string key (immutable lookup).
+    ///
+    /// Like `get_by_str`, but interned keys are matched by `StringId` without comparing
+    /// their text: interning deduplicates strings, so equal interned strings share an id.
+    /// Heap `Str` keys are still compared by text.
+    pub fn get_by_string_id(
+        &self,
+        string_id: StringId,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Option<&Value> {
+        let key_str = interns.get_str(string_id);
+        let mut hasher = DefaultHasher::new();
+        key_str.hash(&mut hasher);
+        let hash = table_hash(hasher.finish());
+
+        self.indices
+            .find(hash, |&idx| match &self.entries[idx].key {
+                Value::InternString(id) => *id == string_id,
+                Value::Ref(id) => matches!(heap.get(*id), HeapData::Str(s) if s.as_str() == key_str),
+                _ => false,
+            })
+            .map(|&idx| &self.entries[idx].value)
+    }
+
+    /// Gets a value from the dict by attribute name, using the `StringId` fast path when interned.
+    pub fn get_by_attr(
+        &self,
+        attr: &EitherStr,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Option<&Value> {
+        match attr {
+            EitherStr::Interned(string_id) => self.get_by_string_id(*string_id, heap, interns),
+            EitherStr::Heap(_) => self.get_by_str(attr.as_str(interns), heap, interns),
+        }
+    }
+
     /// Sets a key-value pair in the dict.
     ///
     /// The caller transfers ownership of `key` and `value` to the dict. Their refcounts
@@ -183,7 +265,8 @@ impl Dict {
     /// were already incremented (e.g., via `clone_with_heap` or `evaluate_use`).
     ///
     /// If the key already exists, replaces the old value and returns it (caller now
-    /// owns the old value and is responsible for its refcount).
+    /// owns the old value and is responsible for its refcount). The existing key is kept
+    /// and the new, equal key is dropped.
     /// Returns Err if key is unhashable.
     pub fn set(
         &mut self,
@@ -208,19 +291,16 @@ impl Dict {
             }
         };
 
-        let entry = DictEntry { key, value, hash };
         if let Some(index) = opt_index {
-            // Key exists, replace in place to preserve insertion order
-            let old_entry = std::mem::replace(&mut self.entries[index], entry);
-
-            // Decrement refcount for old key (we're discarding it)
-            old_entry.key.drop_with_heap(heap);
+            // Key exists: as in CPython, keep the original key and only replace the value,
+            // so `d[-0.0] = v` on a dict keyed by `0.0` (or `d[1.0]` on `1`) leaves the key as is
+            key.drop_with_heap(heap);
             // Transfer ownership of the old value to caller (no clone needed)
-            Ok(Some(old_entry.value))
+            Ok(Some(std::mem::replace(&mut self.entries[index].value, value)))
         } else {
             // Key doesn't exist, add new pair to indices and entries
             let index = self.entries.len();
-            self.entries.push(entry);
+            self.entries.push(DictEntry { key, value, hash });
             self.indices
                 .insert_unique(hash, index, |index| self.entries[*index].hash);
             Ok(None)
@@ -240,13 +320,14 @@ impl Dict {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<Option<(Value, Value)>> {
-        let hash = key
-            .py_hash(heap, interns)?
-            .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?;
+        let hash = table_hash(
+            key.py_hash(heap, interns)?
+                .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?,
+        );
 
         let entry = self.indices.entry(
             hash,
-            |v| key.py_eq(&self.entries[*v].key, heap, interns).unwrap_or(false),
+            |v| key.py_eq_or_is(&self.entries[*v].key, heap, interns).unwrap_or(false),
             |index| self.entries[*index].hash,
         );
 
@@ -318,6 +399,21 @@ impl Dict {
         self.entries.get(index).map(|e| &e.key)
     }
 
+    /// Returns the entries of a counter ordered by count, highest first.
+    ///
+    /// The sort is stable, so equal counts keep their insertion order as in CPython.
+    /// Counts which aren't ints (only possible if set explicitly) are ordered as zero.
+    fn most_common_entries(&self) -> Vec<&DictEntry> {
+        let count = |entry: &DictEntry| match entry.value {
+            Value::Int(count) => count,
+            Value::Bool(count) => i64::from(count),
+            _ => 0,
+        };
+        let mut entries: Vec<&DictEntry> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(count(entry)));
+        entries
+    }
+
     /// Creates a dict from the `dict([mapping_or_pairs], **kwargs)` constructor call.
     ///
     /// Supported forms:
@@ -365,9 +461,10 @@ impl Dict {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<(Option<usize>, u64)> {
-        let hash = key
-            .py_hash(heap, interns)?
-            .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?;
+        let hash = table_hash(
+            key.py_hash(heap, interns)?
+                .ok_or_else(|| ExcType::type_error_unhashable_dict_key(key.py_type(heap)))?,
+        );
 
         // Dict keys are typically shallow (strings, ints, tuples of primitives),
         // so recursion errors are unlikely. If one occurs, treat it as "not equal" -
@@ -375,7 +472,7 @@ impl Dict {
         let opt_index = self
             .indices
             .find(hash, |v| {
-                key.py_eq(&self.entries[*v].key, heap, interns).unwrap_or(false)
+                key.py_eq_or_is(&self.entries[*v].key, heap, interns).unwrap_or(false)
             })
             .copied();
         Ok((opt_index, hash))
@@ -427,7 +524,7 @@ impl IntoIterator for Dict {
 
 impl PyTrait for Dict {
     fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
-        Type::Dict
+        if self.is_counter { Type::Counter } else { Type::Dict }
     }
 
     fn py_estimate_size(&self) -> usize {
@@ -455,7 +552,7 @@ impl PyTrait for Dict {
         for entry in &self.entries {
             heap.check_time()?;
             if let Ok(Some(other_v)) = other.get(&entry.key, heap, interns) {
-                if !entry.value.py_eq(other_v, heap, interns)? {
+                if !entry.value.py_eq_or_is(other_v, heap, interns)? {
                     return Ok(false);
                 }
             } else {
@@ -495,39 +592,23 @@ impl PyTrait for Dict {
         heap_ids: &mut AHashSet<HeapId>,
         interns: &Interns,
     ) -> std::fmt::Result {
-        if self.is_empty() {
-            return f.write_str("{}");
-        }
-
-        // Check depth limit before recursing
-        let Some(token) = heap.incr_recursion_depth_for_repr() else {
-            return f.write_str("{...}");
-        };
-        crate::defer_drop_immutable_heap!(token, heap);
-
-        f.write_char('{')?;
-        let mut first = true;
-        for entry in &self.entries {
-            if !first {
-                if heap.check_time().is_err() {
-                    f.write_str(", ...[timeout]")?;
-                    break;
-                }
-                f.write_str(", ")?;
+        if self.is_counter {
+            if self.is_empty() {
+                return f.write_str("Counter()");
             }
-            first = false;
-            entry.key.py_repr_fmt(f, heap, heap_ids, interns)?;
-            f.write_str(": ")?;
-            entry.value.py_repr_fmt(f, heap, heap_ids, interns)?;
+            f.write_str("Counter(")?;
+            repr_entries_fmt(&self.most_common_entries(), f, heap, heap_ids, interns)?;
+            f.write_char(')')
+        } else {
+            repr_entries_fmt(&self.entries, f, heap, heap_ids, interns)
         }
-        f.write_char('}')?;
-
-        Ok(())
     }
 
     fn py_getitem(&self, key: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
         match self.get(key, heap, interns)? {
             Some(value) => Ok(value.clone_with_heap(heap)),
+            // Counters count missing elements as zero, without inserting them
+            None if self.is_counter => Ok(Value::Int(0)),
             None => Err(ExcType::key_error(key, heap, interns)),
         }
     }
@@ -546,6 +627,17 @@ impl PyTrait for Dict {
         Ok(())
     }
 
+    fn py_delitem(&mut self, key: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        defer_drop!(key, heap);
+        match self.pop(key, heap, interns)? {
+            Some(pair) => {
+                pair.drop_with_heap(heap);
+                Ok(())
+            }
+            None => Err(ExcType::key_error(key, heap, interns)),
+        }
+    }
+
     fn py_call_attr(
         &mut self,
         heap: &mut Heap<impl ResourceTracker>,
@@ -555,10 +647,11 @@ impl PyTrait for Dict {
     ) -> RunResult<Value> {
         let Some(method) = attr.static_string() else {
             args.drop_with_heap(heap);
-            return Err(ExcType::attribute_error(Type::Dict, attr.as_str(interns)));
+            return Err(ExcType::attribute_error(self.py_type(heap), attr.as_str(interns)));
         };
 
         match method {
+            StaticStrings::MostCommon if self.is_counter => counter_most_common(self, args, heap),
             StaticStrings::Get => {
                 // dict.get() accepts 1 or 2 arguments
                 let (key, default) = args.get_one_two_args("get", heap)?;
@@ -636,7 +729,7 @@ impl PyTrait for Dict {
             StaticStrings::Fromkeys => dict_fromkeys(args, heap, interns),
             _ => {
                 args.drop_with_heap(heap);
-                Err(ExcType::attribute_error(Type::Dict, attr.as_str(interns)))
+                Err(ExcType::attribute_error(self.py_type(heap), attr.as_str(interns)))
             }
         }
     }
@@ -665,15 +758,10 @@ fn dict_clear(dict: &mut Dict, heap: &mut Heap<impl ResourceTracker>) {
 
 /// Implements Python's `dict.copy()` method.
 ///
-/// Returns a shallow copy of the dict.
+/// Returns a shallow copy of the dict, which is a counter if the original is.
 fn dict_copy(dict: &Dict, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    // Copy all key-value pairs (incrementing refcounts)
-    let pairs: Vec<(Value, Value)> = dict
-        .iter()
-        .map(|(k, v)| (k.clone_with_heap(heap), v.clone_with_heap(heap)))
-        .collect();
-
-    let new_dict = Dict::from_pairs(pairs, heap, interns)?;
+    let mut new_dict = dict.shallow_copy(heap, interns)?;
+    new_dict.is_counter = dict.is_counter;
     let heap_id = heap.allocate(HeapData::Dict(new_dict))?;
     Ok(Value::Ref(heap_id))
 }
@@ -882,14 +970,87 @@ fn dict_popitem(dict: &mut Dict, heap: &mut Heap<impl ResourceTracker>) -> RunRe
     Ok(allocate_tuple(smallvec![entry.key, entry.value], heap)?)
 }
 
+/// Implements `Counter.most_common([n])`.
+///
+/// Returns a list of the `n` most common `(element, count)` tuples, or all of them if `n`
+/// is omitted or None. Elements with equal counts keep their insertion order.
+fn counter_most_common(dict: &Dict, args: ArgValues, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
+    let n = args.get_zero_one_arg("most_common", heap)?;
+    let n = match n {
+        None | Some(Value::None) => dict.len(),
+        Some(n) => {
+            defer_drop!(n, heap);
+            // Like CPython, a negative `n` gives an empty list
+            usize::try_from(n.as_int(heap)?).unwrap_or(0)
+        }
+    };
+    let tuples = dict
+        .most_common_entries()
+        .into_iter()
+        .take(n)
+        .map(|entry| {
+            allocate_tuple(
+                smallvec![entry.key.clone_with_heap(heap), entry.value.clone_with_heap(heap)],
+                heap,
+            )
+        })
+        .collect::<Result<_, _>>()?;
+    let list_id = heap.allocate(HeapData::List(List::new(tuples)))?;
+    Ok(Value::Ref(list_id))
+}
+
+/// Writes the `{key: value, ...}` repr of dict entries, shared by dicts and counters.
+fn repr_entries_fmt<E: Borrow<DictEntry>>(
+    entries: &[E],
+    f: &mut impl Write,
+    heap: &Heap<impl ResourceTracker>,
+    heap_ids: &mut AHashSet<HeapId>,
+    interns: &Interns,
+) -> std::fmt::Result {
+    if entries.is_empty() {
+        return f.write_str("{}");
+    }
+
+    // Check depth limit before recursing
+    let Some(token) = heap.incr_repr_depth() else {
+        return f.write_str("{...}");
+    };
+    crate::defer_drop_immutable_heap!(token, heap);
+
+    f.write_char('{')?;
+    let mut first = true;
+    for entry in heap.repr_items(entries) {
+        if !first {
+            if heap.check_time().is_err() {
+                f.write_str(", ...[timeout]")?;
+                break;
+            }
+            f.write_str(", ")?;
+        }
+        first = false;
+        let Some(entry) = entry else {
+            f.write_str("...")?;
+            continue;
+        };
+        let entry = <E as Borrow<DictEntry>>::borrow(entry);
+        entry.key.py_repr_fmt(f, heap, heap_ids, interns)?;
+        f.write_str(": ")?;
+        entry.value.py_repr_fmt(f, heap, heap_ids, interns)?;
+    }
+    f.write_char('}')?;
+
+    Ok(())
+}
+
 // Custom serde implementation for Dict.
-// Serializes entries and contains_refs; rebuilds the indices hash table on deserialize.
+// Serializes entries, contains_refs and is_counter; rebuilds the indices hash table on deserialize.
 impl serde::Serialize for Dict {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Dict", 2)?;
+        let mut state = serializer.serialize_struct("Dict", 3)?;
         state.serialize_field("entries", &self.entries)?;
         state.serialize_field("contains_refs", &self.contains_refs)?;
+        state.serialize_field("is_counter", &self.is_counter)?;
         state.end()
     }
 }
@@ -900,6 +1061,7 @@ impl<'de> serde::Deserialize<'de> for Dict {
         struct DictFields {
             entries: Vec<DictEntry>,
             contains_refs: bool,
+            is_counter: bool,
         }
         let fields = DictFields::deserialize(deserializer)?;
         // Rebuild the indices hash table from the entries
@@ -911,6 +1073,7 @@ impl<'de> serde::Deserialize<'de> for Dict {
             indices,
             entries: fields.entries,
             contains_refs: fields.contains_refs,
+            is_counter: fields.is_counter,
         })
     }
 }
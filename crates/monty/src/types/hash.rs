@@ -0,0 +1,501 @@
+//! Python hash object type from the `hashlib` module.
+//!
+//! `hashlib.md5()` and `hashlib.sha256()` return a `HashObject`, which feeds data passed to
+//! `update()` into a fixed-size incremental state and finalizes a copy of it on demand.
+//! Both algorithms are implemented here rather than pulled in as dependencies since they
+//! are small and only need to be correct, not fast.
+
+use std::fmt::Write;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    heap::{DropWithHeap, Heap, HeapData, HeapId},
+    intern::{Interns, StaticStrings},
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, Bytes, PyTrait, Type, str::allocate_string},
+    value::{EitherStr, Value},
+};
+
+/// A hash algorithm supported by `hashlib`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) enum HashAlgorithm {
+    Md5,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// The algorithm's name, as reported by the `name` attribute.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Md5 => "md5",
+            Self::Sha256 => "sha256",
+        }
+    }
+
+    /// Size of the digest in bytes.
+    fn digest_size(self) -> i64 {
+        match self {
+            Self::Md5 => 16,
+            Self::Sha256 => 32,
+        }
+    }
+
+    /// The chaining state before any data has been processed.
+    ///
+    /// MD5 only uses the first four words, the rest stay zero.
+    fn initial_state(self) -> [u32; 8] {
+        match self {
+            Self::Md5 => [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476, 0, 0, 0, 0],
+            Self::Sha256 => SHA256_INIT,
+        }
+    }
+
+    /// Mixes one 64 byte block into the chaining state.
+    fn compress(self, state: &mut [u32; 8], block: &[u8]) {
+        match self {
+            Self::Md5 => {
+                let md5_state: &mut [u32; 4] = (&mut state[..4]).try_into().expect("state has 8 words");
+                md5_compress(md5_state, block);
+            }
+            Self::Sha256 => sha256_compress(state, block),
+        }
+    }
+}
+
+/// Python hash object, e.g. the result of `hashlib.sha256(b"data")`.
+///
+/// Data passed to `update()` is compressed block by block as it arrives, so the object stays
+/// a fixed size however much is hashed. `digest()`, `hexdigest()` and `copy()` work on a clone
+/// of the state, leaving this object open for further updates.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct HashObject {
+    algorithm: HashAlgorithm,
+    /// Chaining state after every complete block seen so far.
+    state: [u32; 8],
+    /// Trailing bytes which don't yet fill a 64 byte block.
+    pending: Vec<u8>,
+    /// Total number of bytes fed in, needed for the final padding.
+    length: u64,
+}
+
+impl HashObject {
+    /// Creates a hash object which has not been fed any data.
+    #[must_use]
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        Self {
+            algorithm,
+            state: algorithm.initial_state(),
+            pending: Vec::with_capacity(BLOCK_SIZE),
+            length: 0,
+        }
+    }
+
+    /// Feeds `data` into the hash state.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        if !self.pending.is_empty() {
+            let take = (BLOCK_SIZE - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if self.pending.len() < BLOCK_SIZE {
+                return;
+            }
+            self.algorithm.compress(&mut self.state, &self.pending);
+            self.pending.clear();
+        }
+        let mut blocks = data.chunks_exact(BLOCK_SIZE);
+        for block in &mut blocks {
+            self.algorithm.compress(&mut self.state, block);
+        }
+        self.pending.extend_from_slice(blocks.remainder());
+    }
+
+    /// Computes the digest of everything fed in so far, without changing this object.
+    ///
+    /// Pads the message as both MD5 and SHA-256 do: a `0x80` byte, zeros, then the message
+    /// length in bits as a 64-bit integer, big-endian for SHA-256 and little-endian for MD5.
+    fn digest(&self) -> Vec<u8> {
+        let mut state = self.state;
+        let mut tail = self.pending.clone();
+        tail.push(0x80);
+        while tail.len() % BLOCK_SIZE != 56 {
+            tail.push(0);
+        }
+        let bit_len = self.length.wrapping_mul(8);
+        match self.algorithm {
+            HashAlgorithm::Md5 => tail.extend_from_slice(&bit_len.to_le_bytes()),
+            HashAlgorithm::Sha256 => tail.extend_from_slice(&bit_len.to_be_bytes()),
+        }
+        for block in tail.chunks_exact(BLOCK_SIZE) {
+            self.algorithm.compress(&mut state, block);
+        }
+        match self.algorithm {
+            HashAlgorithm::Md5 => state[..4].iter().flat_map(|s| s.to_le_bytes()).collect(),
+            HashAlgorithm::Sha256 => state.iter().flat_map(|s| s.to_be_bytes()).collect(),
+        }
+    }
+
+    /// Returns the attribute named by a static string, or `None` if there is no such attribute.
+    fn getattr_by_static(&self, attr: StaticStrings) -> Option<Value> {
+        match attr {
+            StaticStrings::DigestSize => Some(Value::Int(self.algorithm.digest_size())),
+            // Both algorithms work on 64 byte blocks
+            StaticStrings::BlockSize => Some(Value::Int(64)),
+            _ => None,
+        }
+    }
+}
+
+/// Extracts the data argument of `update()` or a hashlib constructor.
+///
+/// # Errors
+/// Returns `TypeError` for `str`, which must be encoded first, and for other non-bytes types.
+pub(crate) fn hash_data_arg<'a>(
+    value: &Value,
+    heap: &'a Heap<impl ResourceTracker>,
+    interns: &'a Interns,
+) -> RunResult<&'a [u8]> {
+    match value {
+        Value::InternBytes(id) => Ok(interns.get_bytes(*id)),
+        Value::InternString(_) => Err(ExcType::type_error("Strings must be encoded before hashing")),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Bytes(bytes) => Ok(bytes.as_slice()),
+            HeapData::Str(_) => Err(ExcType::type_error("Strings must be encoded before hashing")),
+            _ => Err(ExcType::type_error("object supporting the buffer API required")),
+        },
+        _ => Err(ExcType::type_error("object supporting the buffer API required")),
+    }
+}
+
+impl PyTrait for HashObject {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::Hash
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.pending.capacity()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Hash objects compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_bool(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        write!(f, "<{} _hashlib.HASH object>", self.algorithm.name())
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // Hash objects don't contain heap references, nothing to do
+    }
+
+    fn py_call_attr(
+        &mut self,
+        heap: &mut Heap<impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+        interns: &Interns,
+    ) -> RunResult<Value> {
+        let Some(method) = attr.static_string() else {
+            args.drop_with_heap(heap);
+            return Err(ExcType::attribute_error(Type::Hash, attr.as_str(interns)));
+        };
+
+        match method {
+            StaticStrings::Update => {
+                let data = args.get_one_arg("update", heap)?;
+                defer_drop!(data, heap);
+                self.update(hash_data_arg(data, heap, interns)?);
+                Ok(Value::None)
+            }
+            StaticStrings::Digest => {
+                args.check_zero_args("digest", heap)?;
+                let digest = self.digest();
+                Ok(Value::Ref(heap.allocate(HeapData::Bytes(Bytes::new(digest)))?))
+            }
+            StaticStrings::Hexdigest => {
+                args.check_zero_args("hexdigest", heap)?;
+                let mut hex = String::new();
+                for byte in self.digest() {
+                    write!(hex, "{byte:02x}").expect("writing to a String cannot fail");
+                }
+                allocate_string(hex, heap)
+            }
+            StaticStrings::Copy => {
+                args.check_zero_args("copy", heap)?;
+                Ok(Value::Ref(heap.allocate(HeapData::Hash(self.clone()))?))
+            }
+            _ => {
+                args.drop_with_heap(heap);
+                Err(ExcType::attribute_error(Type::Hash, attr.as_str(interns)))
+            }
+        }
+    }
+
+    fn py_getattr(
+        &self,
+        attr: &EitherStr,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Option<AttrCallResult>> {
+        // Fast path: interned strings can be matched by ID without string comparison
+        let attr = match attr.static_string() {
+            Some(ss) => ss,
+            None => match attr.as_str(interns) {
+                "name" => StaticStrings::Name,
+                "digest_size" => StaticStrings::DigestSize,
+                "block_size" => StaticStrings::BlockSize,
+                _ => return Ok(None),
+            },
+        };
+        if attr == StaticStrings::Name {
+            let name = allocate_string(self.algorithm.name().to_owned(), heap)?;
+            return Ok(Some(AttrCallResult::Value(name)));
+        }
+        Ok(self.getattr_by_static(attr).map(AttrCallResult::Value))
+    }
+}
+
+/// Block size in bytes of both MD5 and SHA-256.
+const BLOCK_SIZE: usize = 64;
+
+/// SHA-256 initial hash values, the first 32 bits of the fractional parts of the square roots of the first 8 primes.
+const SHA256_INIT: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+/// SHA-256 round constants, the first 32 bits of the fractional parts of the cube roots of the first 64 primes.
+const SHA256_K: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+/// MD5 per-round shift amounts.
+const MD5_S: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15,
+    21,
+];
+
+/// MD5 round constants, `floor(abs(sin(i + 1)) * 2^32)`.
+const MD5_K: [u32; 64] = [
+    0xd76a_a478,
+    0xe8c7_b756,
+    0x2420_70db,
+    0xc1bd_ceee,
+    0xf57c_0faf,
+    0x4787_c62a,
+    0xa830_4613,
+    0xfd46_9501,
+    0x6980_98d8,
+    0x8b44_f7af,
+    0xffff_5bb1,
+    0x895c_d7be,
+    0x6b90_1122,
+    0xfd98_7193,
+    0xa679_438e,
+    0x49b4_0821,
+    0xf61e_2562,
+    0xc040_b340,
+    0x265e_5a51,
+    0xe9b6_c7aa,
+    0xd62f_105d,
+    0x0244_1453,
+    0xd8a1_e681,
+    0xe7d3_fbc8,
+    0x21e1_cde6,
+    0xc337_07d6,
+    0xf4d5_0d87,
+    0x455a_14ed,
+    0xa9e3_e905,
+    0xfcef_a3f8,
+    0x676f_02d9,
+    0x8d2a_4c8a,
+    0xfffa_3942,
+    0x8771_f681,
+    0x6d9d_6122,
+    0xfde5_380c,
+    0xa4be_ea44,
+    0x4bde_cfa9,
+    0xf6bb_4b60,
+    0xbebf_bc70,
+    0x289b_7ec6,
+    0xeaa1_27fa,
+    0xd4ef_3085,
+    0x0488_1d05,
+    0xd9d4_d039,
+    0xe6db_99e5,
+    0x1fa2_7cf8,
+    0xc4ac_5665,
+    0xf429_2244,
+    0x432a_ff97,
+    0xab94_23a7,
+    0xfc93_a039,
+    0x655b_59c3,
+    0x8f0c_cc92,
+    0xffef_f47d,
+    0x8584_5dd1,
+    0x6fa8_7e4f,
+    0xfe2c_e6e0,
+    0xa301_4314,
+    0x4e08_11a1,
+    0xf753_7e82,
+    0xbd3a_f235,
+    0x2ad7_d2bb,
+    0xeb86_d391,
+];
+
+/// Mixes one 64 byte block into a SHA-256 state (FIPS 180-4).
+#[expect(clippy::many_single_char_names, reason = "names follow the specification")]
+fn sha256_compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().expect("chunk is 4 bytes"));
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (k, w) in SHA256_K.iter().zip(w) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(w);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+/// Mixes one 64 byte block into an MD5 state (RFC 1321).
+#[expect(clippy::many_single_char_names, reason = "names follow the specification")]
+fn md5_compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut m = [0u32; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().expect("chunk is 4 bytes"));
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+    for (i, (k, s)) in MD5_K.iter().zip(MD5_S).enumerate() {
+        let (f, g) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), (7 * i) % 16),
+        };
+        let rotated = a.wrapping_add(f).wrapping_add(*k).wrapping_add(m[g]).rotate_left(s);
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(rotated);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d]) {
+        *s = s.wrapping_add(v);
+    }
+}
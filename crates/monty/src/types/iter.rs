@@ -26,18 +26,27 @@
 //! This allows `advance_on_heap()` to coordinate access without extracting
 //! the iterator from the heap (avoiding `std::mem::replace` overhead).
 //!
+//! **Lazy iterators** (Count, Repeat, Cycle, Chain, Islice and iterators wrapping another
+//! iterator) may need to advance other iterators on the heap, so they are temporarily taken
+//! out of the heap with `with_entry_mut()` and advanced with `for_next()`.
+//!
 //! ## Builtin Support
 //!
 //! The `iterator_next()` helper implements the `next()` builtin.
+//!
+//! `iter(callable, sentinel)` iterators call back into the script, which needs the VM, so
+//! for loops and `next()` advance them with `MontyIter::call_next()` instead.
 
 use crate::{
     args::ArgValues,
-    exception_private::{ExcType, RunResult},
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
     heap_data::HeapDataMut,
     intern::{BytesId, Interns, StringId},
-    resource::ResourceTracker,
-    types::{PyTrait, Range, str::allocate_char},
+    resource::{ResourceError, ResourceTracker},
+    types::{PyTrait, Range, allocate_tuple, str::allocate_char},
     value::Value,
 };
 
@@ -62,30 +71,96 @@ impl MontyIter {
     ///
     /// - `iter(iterable)` - Returns an iterator for the iterable. If the argument is
     ///   already an iterator, returns the same object.
-    /// - `iter(callable, sentinel)` - Not yet supported.
-    pub fn init(heap: &mut Heap<impl ResourceTracker>, args: ArgValues, interns: &Interns) -> RunResult<Value> {
-        let (iterable, sentinel) = args.get_one_two_args("iter", heap)?;
-
-        if let Some(s) = sentinel {
-            // Two-argument form: iter(callable, sentinel)
-            // This is the sentinel iteration protocol, not yet supported
-            iterable.drop_with_heap(heap);
-            s.drop_with_heap(heap);
-            return Err(ExcType::type_error("iter(callable, sentinel) is not yet supported"));
+    /// - `iter(callable, sentinel)` - Returns an iterator over the values returned by
+    ///   calling `callable` with no arguments until it returns `sentinel`.
+    pub fn init(vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let (iterable, sentinel) = args.get_one_two_args("iter", vm.heap)?;
+
+        if let Some(sentinel) = sentinel {
+            return Self::init_sentinel(vm, iterable, sentinel);
         }
 
         // Check if already an iterator - return self
         if let Value::Ref(id) = &iterable
-            && matches!(heap.get(*id), HeapData::Iter(_))
+            && matches!(vm.heap.get(*id), HeapData::Iter(_))
         {
             // Already an iterator - return it (refcount already correct from caller)
             return Ok(iterable);
         }
 
-        // Create new iterator
-        let iter = Self::new(iterable, heap, interns)?;
-        let id = heap.allocate(HeapData::Iter(iter))?;
-        Ok(Value::Ref(id))
+        // Create new iterator, calling `__iter__` for instances of classes defining it
+        vm.get_iter(iterable)
+    }
+
+    /// Implements `iter(callable, sentinel)`, holding the callable and the sentinel in a tuple.
+    ///
+    /// The callable is only called as the iterator is advanced, see `call_next`.
+    fn init_sentinel(vm: &mut VM<impl ResourceTracker>, callable: Value, sentinel: Value) -> RunResult<Value> {
+        let pair = allocate_tuple([callable, sentinel].into_iter().collect(), vm.heap)?;
+        let Value::Ref(heap_id) = pair else {
+            return Err(RunError::internal("iter(): expected a heap tuple"));
+        };
+        let iter = Self {
+            index: 0,
+            iter_value: IterValue::Callable {
+                heap_id,
+                exhausted: false,
+            },
+            value: pair,
+        };
+        Ok(Value::Ref(vm.heap.allocate(HeapData::Iter(iter))?))
+    }
+
+    /// Returns whether this is an `iter(callable, sentinel)` iterator, which only the VM
+    /// can advance, with `call_next`.
+    #[must_use]
+    pub fn is_callable_iter(&self) -> bool {
+        matches!(self.iter_value, IterValue::Callable { .. })
+    }
+
+    /// Advances the `iter(callable, sentinel)` iterator `iter_id` by calling its callable,
+    /// returning `None` once it returns the sentinel, and from then on.
+    pub fn call_next(vm: &mut VM<impl ResourceTracker>, iter_id: HeapId) -> RunResult<Option<Value>> {
+        let HeapData::Iter(Self {
+            iter_value: IterValue::Callable { heap_id, exhausted },
+            ..
+        }) = vm.heap.get(iter_id)
+        else {
+            return Err(RunError::internal(
+                "call_next: expected an iter(callable, sentinel) iterator",
+            ));
+        };
+        if *exhausted {
+            return Ok(None);
+        }
+        let HeapData::Tuple(pair) = vm.heap.get(*heap_id) else {
+            return Err(RunError::internal("call_next: expected a (callable, sentinel) tuple"));
+        };
+        let [callable, sentinel] = pair.as_slice() else {
+            return Err(RunError::internal("call_next: expected a (callable, sentinel) tuple"));
+        };
+        let callable = callable.clone_with_heap(vm.heap);
+        let sentinel = sentinel.clone_with_heap(vm.heap);
+        defer_drop!(callable, vm);
+        defer_drop!(sentinel, vm);
+
+        let item = vm.evaluate_function("iter()", callable, ArgValues::Empty)?;
+        match item.py_eq(sentinel, vm.heap, vm.interns) {
+            Ok(false) => Ok(Some(item)),
+            Ok(true) => {
+                item.drop_with_heap(vm.heap);
+                if let HeapDataMut::Iter(iter) = vm.heap.get_mut(iter_id)
+                    && let IterValue::Callable { exhausted, .. } = &mut iter.iter_value
+                {
+                    *exhausted = true;
+                }
+                Ok(None)
+            }
+            Err(e) => {
+                item.drop_with_heap(vm.heap);
+                Err(e.into())
+            }
+        }
     }
 
     /// Creates a new MontyIter from a Value.
@@ -115,6 +190,73 @@ impl MontyIter {
         }
     }
 
+    /// Creates an infinite iterator yielding `start`, `start + step`, ... for `itertools.count()`.
+    pub fn new_count(start: i64, step: i64) -> Self {
+        Self {
+            index: 0,
+            iter_value: IterValue::Count {
+                next: Some(start),
+                step,
+            },
+            value: Value::None,
+        }
+    }
+
+    /// Creates an iterator yielding `value` over and over for `itertools.repeat()`,
+    /// `times` times or forever if `None`.
+    pub fn new_repeat(value: Value, times: Option<usize>) -> Self {
+        Self {
+            index: 0,
+            iter_value: IterValue::Repeat { times },
+            value,
+        }
+    }
+
+    /// Creates an iterator cycling through the items of `list` for `itertools.cycle()`.
+    ///
+    /// `list` must be a list owned by the iterator, since it is assumed not to change.
+    pub fn new_cycle(list: Value) -> RunResult<Self> {
+        let Value::Ref(heap_id) = list else {
+            return Err(RunError::internal("MontyIter::new_cycle: expected a list"));
+        };
+        Ok(Self {
+            index: 0,
+            iter_value: IterValue::Cycle { heap_id },
+            value: list,
+        })
+    }
+
+    /// Creates an iterator yielding the items of each iterator in `iterators`, a tuple of
+    /// heap iterators, in turn for `itertools.chain()`.
+    pub fn new_chain(iterators: Value) -> RunResult<Self> {
+        let Value::Ref(heap_id) = iterators else {
+            return Err(RunError::internal("MontyIter::new_chain: expected a tuple"));
+        };
+        Ok(Self {
+            index: 0,
+            iter_value: IterValue::Chain { heap_id },
+            value: iterators,
+        })
+    }
+
+    /// Creates an iterator yielding the items of the heap iterator `iterator` at indices
+    /// `start`, `start + step`, ... below `stop` for `itertools.islice()`.
+    pub fn new_islice(iterator: Value, start: usize, stop: Option<usize>, step: usize) -> RunResult<Self> {
+        let Value::Ref(heap_id) = iterator else {
+            return Err(RunError::internal("MontyIter::new_islice: expected an iterator"));
+        };
+        Ok(Self {
+            index: 0,
+            iter_value: IterValue::Islice {
+                heap_id,
+                next: start,
+                stop,
+                step,
+            },
+            value: iterator,
+        })
+    }
+
     /// Drops the iterator and its held value properly.
     pub fn drop_with_heap(self, heap: &mut Heap<impl ResourceTracker>) {
         self.value.drop_with_heap(heap);
@@ -150,10 +292,16 @@ impl MontyIter {
     /// Returns `None` if the iterator is exhausted.
     fn iter_state(&self) -> Option<IterState> {
         match &self.iter_value {
-            // Range, InternBytes, and ASCII IterStr are handled by try_advance_simple() fast path
-            IterValue::Range { .. } | IterValue::InternBytes { .. } => {
-                unreachable!("Range and InternBytes use fast path, not iter_state")
+            // Range, InternBytes, Count and ASCII IterStr are handled by try_advance_simple() fast path
+            IterValue::Range { .. } | IterValue::InternBytes { .. } | IterValue::Count { .. } => {
+                unreachable!("Range, InternBytes and Count use fast path, not iter_state")
             }
+            IterValue::Repeat { .. }
+            | IterValue::Cycle { .. }
+            | IterValue::Chain { .. }
+            | IterValue::Islice { .. }
+            | IterValue::Callable { .. }
+            | IterValue::Iterator { .. } => Some(IterState::Lazy),
             IterValue::IterStr {
                 string,
                 byte_offset,
@@ -215,8 +363,8 @@ impl MontyIter {
 
     /// Attempts to advance simple iterator types that don't need additional heap access.
     ///
-    /// Returns `Some(result)` if handled (Range, InternBytes, ASCII IterStr),
-    /// `None` if caller should use the multi-phase approach (non-ASCII IterStr, HeapRef).
+    /// Returns `Some(result)` if handled (Range, InternBytes, Count, ASCII IterStr),
+    /// `None` if caller should use the multi-phase approach (non-ASCII IterStr, HeapRef, lazy iterators).
     ///
     /// This optimization avoids two heap lookups for iterator types that can compute
     /// their next value without accessing other heap objects.
@@ -260,7 +408,14 @@ impl MontyIter {
                     Some(Ok(Some(Value::Int(i64::from(bytes[i])))))
                 }
             }
-            IterValue::HeapRef { .. } => None,
+            IterValue::Count { next, step } => Some(count_next(next, *step)),
+            IterValue::HeapRef { .. }
+            | IterValue::Repeat { .. }
+            | IterValue::Cycle { .. }
+            | IterValue::Chain { .. }
+            | IterValue::Islice { .. }
+            | IterValue::Callable { .. }
+            | IterValue::Iterator { .. } => None,
         }
     }
 
@@ -270,11 +425,11 @@ impl MontyIter {
     /// Returns `Err` if allocation fails (for string character iteration) or if
     /// a dict/set changes size during iteration (RuntimeError).
     pub fn for_next(&mut self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Option<Value>> {
-        // Check timeout on every iteration step. For NoLimitTracker this is
-        // inlined as a no-op. For LimitTracker it ensures that Rust-side loops
+        // Check timeout and the iteration budget on every iteration step. For NoLimitTracker
+        // these are inlined as no-ops. For LimitTracker it ensures that Rust-side loops
         // (sum, sorted, min, max, etc.) cannot bypass the VM's per-instruction
         // timeout check by running entirely within a single bytecode instruction.
-        heap.check_time()?;
+        tick(heap)?;
         match &mut self.iter_value {
             IterValue::Range { next, step, len } => {
                 if self.index >= *len {
@@ -339,6 +494,99 @@ impl MontyIter {
                 self.index += 1;
                 Ok(Some(item))
             }
+            IterValue::Count { next, step } => count_next(next, *step),
+            IterValue::Repeat { times } => {
+                if times.is_some_and(|times| self.index >= times) {
+                    return Ok(None);
+                }
+                self.index += 1;
+                Ok(Some(self.value.clone_with_heap(heap)))
+            }
+            IterValue::Cycle { heap_id } => {
+                let HeapData::List(list) = heap.get(*heap_id) else {
+                    panic!("Cycle iterator should hold a list")
+                };
+                if list.len() == 0 {
+                    return Ok(None);
+                }
+                let item = list.as_slice()[self.index].clone_with_heap(heap);
+                self.index = (self.index + 1) % list.len();
+                Ok(Some(item))
+            }
+            IterValue::Chain { heap_id } => loop {
+                // `index` is the position of the iterator currently being drained
+                let HeapData::Tuple(iterators) = heap.get(*heap_id) else {
+                    panic!("Chain iterator should hold a tuple")
+                };
+                let Some(&Value::Ref(iter_id)) = iterators.as_slice().get(self.index) else {
+                    return Ok(None);
+                };
+                if let Some(item) = advance_on_heap(heap, iter_id, interns)? {
+                    return Ok(Some(item));
+                }
+                self.index += 1;
+                tick(heap)?;
+            },
+            IterValue::Islice {
+                heap_id,
+                next,
+                stop,
+                step,
+            } => {
+                // `index` is the number of items taken from the source iterator so far,
+                // which is never advanced past `stop`
+                loop {
+                    if stop.is_some_and(|stop| self.index >= stop) {
+                        return Ok(None);
+                    }
+                    let Some(item) = advance_on_heap(heap, *heap_id, interns)? else {
+                        return Ok(None);
+                    };
+                    self.index += 1;
+                    if self.index > *next {
+                        *next = next.saturating_add(*step);
+                        return Ok(Some(item));
+                    }
+                    item.drop_with_heap(heap);
+                    // Skipped items count as iteration steps, since `advance_on_heap` doesn't
+                    tick(heap)?;
+                }
+            }
+            IterValue::Callable { .. } => Err(ExcType::type_error(
+                "iter(callable, sentinel) iterators can only be advanced by for loops and next()",
+            )),
+            IterValue::Iterator { heap_id } => advance_on_heap(heap, *heap_id, interns),
+        }
+    }
+
+    /// Returns whether the iterator would never be exhausted, so that collecting it would
+    /// run until memory runs out.
+    ///
+    /// This is a best effort check, e.g. a chain is infinite if any remaining part is.
+    fn is_infinite(&self, heap: &Heap<impl ResourceTracker>) -> bool {
+        let iter_is_infinite = |heap_id: HeapId| match heap.get(heap_id) {
+            HeapData::Iter(iter) => iter.is_infinite(heap),
+            _ => false,
+        };
+        match &self.iter_value {
+            IterValue::Count { .. } => true,
+            IterValue::Repeat { times } => times.is_none(),
+            IterValue::Cycle { heap_id } => matches!(heap.get(*heap_id), HeapData::List(list) if list.len() > 0),
+            IterValue::Chain { heap_id } => {
+                let HeapData::Tuple(iterators) = heap.get(*heap_id) else {
+                    panic!("Chain iterator should hold a tuple")
+                };
+                iterators.as_slice()[self.index.min(iterators.as_slice().len())..]
+                    .iter()
+                    .any(|iter| matches!(iter, Value::Ref(id) if iter_is_infinite(*id)))
+            }
+            IterValue::Islice { heap_id, stop, .. } => stop.is_none() && iter_is_infinite(*heap_id),
+            IterValue::Iterator { heap_id } => iter_is_infinite(*heap_id),
+            IterValue::Range { .. }
+            | IterValue::IterStr { .. }
+            | IterValue::InternBytes { .. }
+            | IterValue::HeapRef { .. }
+            | IterValue::Callable { .. } => false,
         }
     }
 
@@ -347,9 +595,17 @@ impl MontyIter {
     /// For immutable types (Range, Tuple, Str, Bytes, FrozenSet), returns the exact remaining count.
     /// For List, returns current length minus index (may change if list is mutated).
     /// For Dict and Set, returns the captured length minus index (used for size-change detection).
+    /// For lazy iterators other than a bounded repeat, the size is unknown and 0 is returned.
     pub fn size_hint(&self, heap: &Heap<impl ResourceTracker>) -> usize {
         let len = match &self.iter_value {
             IterValue::Range { len, .. } | IterValue::IterStr { len, .. } | IterValue::InternBytes { len, .. } => *len,
+            IterValue::Repeat { times } => times.unwrap_or(0),
+            IterValue::Count { .. }
+            | IterValue::Cycle { .. }
+            | IterValue::Chain { .. }
+            | IterValue::Islice { .. }
+            | IterValue::Callable { .. }
+            | IterValue::Iterator { .. } => 0,
             IterValue::HeapRef { heap_id, len, .. } => {
                 // For List (len=None), check current length dynamically
                 len.unwrap_or_else(|| {
@@ -369,11 +625,18 @@ impl MontyIter {
     /// and similar constructors that need to materialize all items.
     ///
     /// Pre-allocates capacity based on `size_hint()` for better performance.
+    ///
+    /// # Errors
+    /// Returns `MemoryError` without consuming anything if the iterator is infinite.
     pub fn collect<T: FromIterator<Value>>(
         self,
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<T> {
+        if self.is_infinite(heap) {
+            self.drop_with_heap(heap);
+            return Err(ExcType::memory_error_infinite_iterator());
+        }
         let mut guard = HeapGuard::new(self, heap);
         let (this, heap) = guard.as_parts_mut();
         HeapedMontyIter(this, heap, interns).collect()
@@ -390,11 +653,16 @@ impl<T: ResourceTracker> Iterator for HeapedMontyIter<'_, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.0.size_hint(self.1);
-        (remaining, Some(remaining))
+        (self.0.size_hint(self.1), None)
     }
 }
 
+/// Checks the timeout and the iteration budget for one step of a Rust-side iterator.
+fn tick(heap: &mut Heap<impl ResourceTracker>) -> Result<(), ResourceError> {
+    heap.check_time()?;
+    heap.tracker_mut().on_iteration()
+}
+
 /// Advances an iterator stored on the heap and returns the next value.
 ///
 /// Uses a fast path for simple iterators (Range, InternBytes, ASCII IterStr) that don't need
@@ -438,6 +706,16 @@ pub(crate) fn advance_on_heap(
 
     // Phase 2: Based on state, get the value and determine char_len for strings
     let (value, string_char_len) = match state {
+        IterState::Lazy => {
+            // Lazy iterators advance themselves, taking the iterator out of the heap while they
+            // access it, including to advance the iterators they wrap
+            return heap.with_entry_mut(iter_id, |heap, data| {
+                let HeapDataMut::Iter(iter) = data else {
+                    panic!("advance_on_heap: expected Iterator on heap");
+                };
+                iter.for_next(heap, interns)
+            });
+        }
         IterState::IterStr { char, char_len } => {
             let value = allocate_char(char, heap)?;
             (value, Some(char_len))
@@ -465,6 +743,17 @@ pub(crate) fn advance_on_heap(
     Ok(Some(value))
 }
 
+/// Yields the next value of a `count()` iterator and advances it by `step`.
+///
+/// Once the count passes the range of `i64`, `next` becomes `None` and an `OverflowError` is raised.
+fn count_next(next: &mut Option<i64>, step: i64) -> RunResult<Option<Value>> {
+    let Some(value) = *next else {
+        return Err(ExcType::overflow_error_count());
+    };
+    *next = value.checked_add(step);
+    Ok(Some(Value::Int(value)))
+}
+
 /// Gets an item from a heap-allocated container at the given index.
 ///
 /// Returns `Ok(None)` if the index is out of bounds (for lists that shrunk during iteration).
@@ -601,6 +890,8 @@ enum IterState {
         index: usize,
         expected_len: Option<usize>,
     },
+    /// Lazy iterator which needs full heap access to advance, see `IterValue::Repeat` onwards.
+    Lazy,
 }
 
 /// Type-specific iteration data for different Python iterable types.
@@ -647,6 +938,31 @@ enum IterValue {
         len: Option<usize>,
         checks_mutation: bool,
     },
+    /// `itertools.count()`, yields `Value::Int` forever.
+    ///
+    /// `next` becomes `None` once the count overflows `i64`.
+    Count { next: Option<i64>, step: i64 },
+    /// `itertools.repeat()`, yields the held value `times` times, or forever if `None`.
+    Repeat { times: Option<usize> },
+    /// `itertools.cycle()`, yields the items of the held list over and over.
+    ///
+    /// The list is built from the source iterable and owned by the iterator, so it never changes.
+    Cycle { heap_id: HeapId },
+    /// `itertools.chain()`, yields the items of each iterator in the held tuple in turn.
+    Chain { heap_id: HeapId },
+    /// `itertools.islice()`, yields the items of the held iterator at index `next`,
+    /// `next + step`, ... while below `stop`.
+    Islice {
+        heap_id: HeapId,
+        next: usize,
+        stop: Option<usize>,
+        step: usize,
+    },
+    /// `iter(callable, sentinel)`, yields the results of calling the first item of the held
+    /// `(callable, sentinel)` tuple until it returns the second, see `MontyIter::call_next`.
+    Callable { heap_id: HeapId, exhausted: bool },
+    /// Iterating over an iterator, e.g. `list(iter(x))`, advances the held iterator.
+    Iterator { heap_id: HeapId },
 }
 
 impl IterValue {
@@ -732,24 +1048,31 @@ impl IterValue {
                 len: Some(set.len()),
                 checks_mutation: true,
             }),
+            // Iterator: advanced in step with this one
+            HeapData::Iter(_) => Some(Self::Iterator { heap_id }),
             // String: copy content for iteration
             HeapData::Str(s) => Some(Self::from_str(s.as_str())),
             // Range: copy values for iteration
             HeapData::Range(range) => Some(Self::from_range(range)),
-            // Closures, FunctionDefaults, Cells, Exceptions, Dataclasses, Iterators, LongInts, Slices, Modules,
-            // Paths, and async types are not iterable
+            // Closures, FunctionDefaults, Cells, Exceptions, Dataclasses, LongInts, Slices, Modules,
+            // Paths, hash objects, classes, instances, bound methods, and async types are not iterable
             HeapData::Closure(_)
             | HeapData::FunctionDefaults(_)
             | HeapData::Cell(_)
             | HeapData::Exception(_)
             | HeapData::Dataclass(_)
-            | HeapData::Iter(_)
             | HeapData::LongInt(_)
             | HeapData::Slice(_)
             | HeapData::Module(_)
             | HeapData::Path(_)
+            | HeapData::Hash(_)
             | HeapData::Coroutine(_)
-            | HeapData::GatherFuture(_) => None,
+            | HeapData::GatherFuture(_)
+            | HeapData::Class(_)
+            | HeapData::Instance(_)
+            | HeapData::BoundMethod(_)
+            | HeapData::Property(_)
+            | HeapData::MethodDescriptor(_) => None,
         }
     }
 }
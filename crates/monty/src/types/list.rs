@@ -1,7 +1,6 @@
 use std::fmt::Write;
 
 use ahash::AHashSet;
-use itertools::Itertools;
 use smallvec::SmallVec;
 
 use super::{AttrCallResult, MontyIter, PyTrait};
@@ -9,11 +8,12 @@ use crate::{
     args::ArgValues,
     bytecode::VM,
     defer_drop, defer_drop_mut,
-    exception_private::{ExcType, RunError, RunResult},
+    exception_private::{ExcType, RunResult},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
+    heap_data::HeapDataMut,
     intern::{Interns, StaticStrings},
     resource::{ResourceError, ResourceTracker},
-    sorting::{apply_permutation, sort_indices},
+    sorting::{PendingSort, sort_keys},
     types::Type,
     value::{EitherStr, Value},
 };
@@ -36,9 +36,8 @@ use crate::{
 /// - `reverse()` - Reverse in place
 /// - `sort([key][, reverse])` - Sort in place
 ///
-/// Note: `sort(key=...)` supports builtin key functions (len, abs, etc.)
-/// but not user-defined functions. This is handled at VM level for access
-/// to function calling machinery.
+/// Note: `sort` is handled at VM level (see `list_sort`) for access to function
+/// calling machinery and so it can suspend while the host compares dataclasses.
 ///
 /// All list methods from Python's builtins are implemented.
 ///
@@ -124,6 +123,9 @@ impl List {
     /// is NOT incremented here - the caller is responsible for ensuring the refcount
     /// was already incremented (e.g., via `clone_with_heap` or `evaluate_use`).
     ///
+    /// The list is borrowed in place from the heap, so appending never copies it, and
+    /// `Vec::push` keeps growth amortised O(1).
+    ///
     /// Returns `Value::None`, matching Python's behavior where `list.append()` returns None.
     pub fn append(&mut self, heap: &mut Heap<impl ResourceTracker>, item: Value) {
         // Track if we're adding a reference and mark potential cycle
@@ -188,10 +190,68 @@ impl List {
             .indices(self.items.len())
             .map_err(|()| ExcType::value_error_slice_step_zero())?;
 
+        // Contiguous slices of primitive-only lists are a plain copy: there are no refcounts
+        // to bump and the result doesn't need scanning for `contains_refs`.
+        if step == 1 && !self.contains_refs {
+            let stop = stop.min(self.items.len());
+            let items = self
+                .items
+                .get(start..stop)
+                .unwrap_or_default()
+                .iter()
+                .map(Value::clone_immediate)
+                .collect();
+            let heap_id = heap.allocate(HeapData::List(Self {
+                items,
+                contains_refs: false,
+            }))?;
+            return Ok(Value::Ref(heap_id));
+        }
+
         let items = get_slice_items(&self.items, start, stop, step, heap)?;
         let heap_id = heap.allocate(HeapData::List(Self::new(items)))?;
         Ok(Value::Ref(heap_id))
     }
+
+    /// Handles slice-based deletion for lists, e.g. `del lst[1:3]` or `del lst[::2]`.
+    ///
+    /// Removes the selected elements, keeping the rest in order.
+    fn delitem_slice(&mut self, slice: &crate::types::Slice, heap: &mut Heap<impl ResourceTracker>) -> RunResult<()> {
+        let len = self.items.len();
+        let (start, stop, step) = slice
+            .indices(len)
+            .map_err(|()| ExcType::value_error_slice_step_zero())?;
+
+        let mut deleted = vec![false; len];
+        if let Ok(step) = usize::try_from(step) {
+            for i in (start..stop.min(len)).step_by(step) {
+                deleted[i] = true;
+            }
+        } else {
+            // Negative step: walk down from start, stopping after stop
+            // (stop > len means "go to the beginning", as in get_slice_items)
+            let step = usize::try_from(-step).expect("step is negative so -step is positive");
+            let lowest = if stop > len { 0 } else { stop + 1 };
+            let mut i = start;
+            while i >= lowest && i < len {
+                deleted[i] = true;
+                let Some(next) = i.checked_sub(step) else { break };
+                i = next;
+            }
+        }
+
+        let items = std::mem::take(&mut self.items);
+        let mut removed = Vec::new();
+        for (item, deleted) in items.into_iter().zip(deleted) {
+            if deleted {
+                removed.push(item);
+            } else {
+                self.items.push(item);
+            }
+        }
+        removed.drop_with_heap(heap);
+        Ok(())
+    }
 }
 
 impl From<List> for Vec<Value> {
@@ -301,6 +361,36 @@ impl PyTrait for List {
         Ok(())
     }
 
+    fn py_delitem(&mut self, key: Value, heap: &mut Heap<impl ResourceTracker>, _interns: &Interns) -> RunResult<()> {
+        defer_drop!(key, heap);
+
+        if let Value::Ref(id) = key
+            && let HeapData::Slice(slice) = heap.get(*id)
+        {
+            let slice = slice.clone();
+            return self.delitem_slice(&slice, heap);
+        }
+
+        let is_int = match key {
+            Value::Int(_) | Value::Bool(_) => true,
+            Value::Ref(id) => matches!(heap.get(*id), HeapData::LongInt(_)),
+            _ => false,
+        };
+        if !is_int {
+            return Err(ExcType::type_error_list_assignment_indices(key.py_type(heap)));
+        }
+        let index = key.as_index(heap, Type::List)?;
+
+        let len = i64::try_from(self.items.len()).expect("list length exceeds i64::MAX");
+        let normalized_index = if index < 0 { index + len } else { index };
+        if normalized_index < 0 || normalized_index >= len {
+            return Err(ExcType::list_assignment_index_error());
+        }
+        let idx = usize::try_from(normalized_index).expect("index validated non-negative");
+        self.items.remove(idx).drop_with_heap(heap);
+        Ok(())
+    }
+
     fn py_eq(
         &self,
         other: &Self,
@@ -315,7 +405,7 @@ impl PyTrait for List {
 
         for (i1, i2) in self.items.iter().zip(&other.items) {
             heap.check_time()?;
-            if !i1.py_eq(i2, heap, interns)? {
+            if !i1.py_eq_or_is(i2, heap, interns)? {
                 return Ok(false);
             }
         }
@@ -429,18 +519,20 @@ impl PyTrait for List {
         call_list_method(self, method, args, heap, interns)
     }
 
-    /// Intercepts `sort` to call `do_list_sort` (which needs `PrintWriter` for key functions),
-    /// and delegates all other methods to `py_call_attr`.
+    /// Intercepts `extend` (which needs `self_id` to handle `a.extend(a)`), and delegates all
+    /// other methods to `py_call_attr`.
     fn py_call_attr_raw(
         &mut self,
-        _self_id: HeapId,
+        self_id: HeapId,
         vm: &mut VM<'_, '_, impl ResourceTracker>,
         attr: &EitherStr,
         args: ArgValues,
     ) -> RunResult<AttrCallResult> {
-        if attr.static_string() == Some(StaticStrings::Sort) {
-            do_list_sort(self, args, vm)?;
-            return Ok(AttrCallResult::Value(Value::None));
+        match attr.static_string() {
+            Some(StaticStrings::Extend) => {
+                return list_extend(self, args, Some(self_id), vm.heap, vm.interns).map(AttrCallResult::Value);
+            }
+            _ => {}
         }
         self.py_call_attr(vm.heap, attr, args, vm.interns)
             .map(AttrCallResult::Value)
@@ -482,7 +574,7 @@ fn call_list_method(
             args.check_zero_args("list.copy", heap)?;
             Ok(list_copy(list, heap)?)
         }
-        StaticStrings::Extend => list_extend(list, args, heap, interns),
+        StaticStrings::Extend => list_extend(list, args, None, heap, interns),
         StaticStrings::Index => list_index(list, args, heap, interns),
         StaticStrings::Count => list_count(list, args, heap, interns),
         StaticStrings::Reverse => {
@@ -490,7 +582,7 @@ fn call_list_method(
             list.items.reverse();
             Ok(Value::None)
         }
-        // Note: list.sort is handled by py_call_attr_raw which intercepts it
+        // Note: list.sort is handled by the VM, see `list_sort`
         _ => {
             args.drop_with_heap(heap);
             Err(ExcType::attribute_error(Type::List, method.into()))
@@ -512,7 +604,7 @@ fn list_insert(list: &mut List, args: ArgValues, heap: &mut Heap<impl ResourceTr
     let len_i64 = i64::try_from(len).expect("list length exceeds i64::MAX");
     let index = if index_i64 < 0 {
         // Negative index: add length, clamp to 0 if still negative
-        let adjusted = index_i64 + len_i64;
+        let adjusted = index_i64.saturating_add(len_i64);
         usize::try_from(adjusted).unwrap_or(0)
     } else {
         // Positive index: clamp to len if too large
@@ -576,7 +668,7 @@ fn list_remove(
     let mut found_idx = None;
     for (i, item) in list.items.iter().enumerate() {
         heap.check_time()?;
-        if value.py_eq(item, heap, interns)? {
+        if value.py_eq_or_is(item, heap, interns)? {
             found_idx = Some(i);
             break;
         }
@@ -615,14 +707,25 @@ fn list_copy(list: &List, heap: &mut Heap<impl ResourceTracker>) -> Result<Value
 /// Implements Python's `list.extend(iterable)` method.
 ///
 /// Extends the list by appending all items from the iterable.
+///
+/// `self_id` is the list's own heap id when known. The list's data is taken out of the
+/// heap for the duration of the call, so `a.extend(a)` must copy the items directly
+/// rather than iterating `a` through the heap.
 fn list_extend(
     list: &mut List,
     args: ArgValues,
+    self_id: Option<HeapId>,
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
 ) -> RunResult<Value> {
     let iterable = args.get_one_arg("list.extend", heap)?;
-    let items: SmallVec<[_; 2]> = MontyIter::new(iterable, heap, interns)?.collect(heap, interns)?;
+    let items: SmallVec<[_; 2]> = if matches!(iterable, Value::Ref(id) if Some(id) == self_id) {
+        let items = list.items.iter().map(|v| v.clone_with_heap(heap)).collect();
+        iterable.drop_with_heap(heap);
+        items
+    } else {
+        MontyIter::new(iterable, heap, interns)?.collect(heap, interns)?
+    };
 
     // Add each item to the list
     for item in items {
@@ -650,12 +753,12 @@ fn list_index(
         [] => return Err(ExcType::type_error_at_least("list.index", 1, 0)),
         [value] => (value, 0, len),
         [value, start_arg] => {
-            let start = normalize_list_index(start_arg.as_int(heap)?, len);
+            let start = search_bound(start_arg, len, heap)?;
             (value, start, len)
         }
         [value, start_arg, end_arg] => {
-            let start = normalize_list_index(start_arg.as_int(heap)?, len);
-            let end = normalize_list_index(end_arg.as_int(heap)?, len).max(start);
+            let start = search_bound(start_arg, len, heap)?;
+            let end = search_bound(end_arg, len, heap)?.max(start);
             (value, start, end)
         }
         other => return Err(ExcType::type_error_at_most("list.index", 3, other.len())),
@@ -664,7 +767,7 @@ fn list_index(
     // Search for the value in the specified range
     for (i, item) in list.items[start..end].iter().enumerate() {
         heap.check_time()?;
-        if value.py_eq(item, heap, interns)? {
+        if value.py_eq_or_is(item, heap, interns)? {
             let idx = i64::try_from(start + i).expect("index exceeds i64::MAX");
             return Ok(Value::Int(idx));
         }
@@ -688,7 +791,7 @@ fn list_count(
     let mut count: usize = 0;
     for item in &list.items {
         heap.check_time()?;
-        if value.py_eq(item, heap, interns)? {
+        if value.py_eq_or_is(item, heap, interns)? {
             count += 1;
         }
     }
@@ -697,18 +800,41 @@ fn list_count(
     Ok(Value::Int(count_i64))
 }
 
+/// Converts a `start`/`end` argument of `list.index` or `tuple.index` into a position
+/// in range [0, len].
+///
+/// Like CPython's slice index conversion, ints too large for i64 clamp to the
+/// nearest end of the sequence rather than raising `OverflowError`.
+pub(crate) fn search_bound(value: &Value, len: usize, heap: &Heap<impl ResourceTracker>) -> RunResult<usize> {
+    if let Value::Ref(id) = value
+        && let HeapData::LongInt(li) = heap.get(*id)
+    {
+        return Ok(if li.is_negative() { 0 } else { len });
+    }
+    Ok(normalize_list_index(value.as_int(heap)?, len))
+}
+
 /// Normalizes a Python-style list index to a valid index in range [0, len].
 fn normalize_list_index(index: i64, len: usize) -> usize {
     if index < 0 {
-        let abs_index = usize::try_from(-index).unwrap_or(usize::MAX);
+        let abs_index = usize::try_from(index.unsigned_abs()).unwrap_or(usize::MAX);
         len.saturating_sub(abs_index)
     } else {
         usize::try_from(index).unwrap_or(len).min(len)
     }
 }
 
-/// Performs an in-place sort on a list with optional key function and reverse flag.
-fn do_list_sort(list: &mut List, args: ArgValues, vm: &mut VM<impl ResourceTracker>) -> Result<(), RunError> {
+/// Implements `list.sort(*, key=None, reverse=False)` on `list`.
+///
+/// The items are moved out of the list while sorting, as CPython does, so a key function
+/// or `__lt__` that looks at the list sees it empty, and changing the list raises a
+/// `ValueError` once the sort is done (see `PendingSort::finish`). The sort is run by the
+/// VM, since it may suspend to let the host compare dataclasses.
+pub(crate) fn list_sort(
+    list: &Value,
+    args: ArgValues,
+    vm: &mut VM<'_, '_, impl ResourceTracker>,
+) -> RunResult<AttrCallResult> {
     // Parse keyword-only arguments: key and reverse
     let (key_arg, reverse_arg) = args.extract_two_kwargs_only("list.sort", "key", "reverse", vm.heap, vm.interns)?;
 
@@ -731,38 +857,47 @@ fn do_list_sort(list: &mut List, args: ArgValues, vm: &mut VM<impl ResourceTrack
     };
     defer_drop!(key_fn, vm);
 
-    // Step 1: Borrow from the list for in-place sorting
-    let items = list.as_vec_mut();
-
-    // 2. Compute key values if a key function was provided, otherwise we'll sort by the items themselves
-    let mut keys_guard;
-    let (compare_values, vm) = if let Some(f) = key_fn {
-        let keys: Vec<Value> = Vec::with_capacity(items.len());
-        // Use a HeapGuard to ensure that if key function evaluation fails partway through,
-        // we clean up any keys that were successfully computed
-        keys_guard = HeapGuard::new(keys, vm);
-        let (keys, vm) = keys_guard.as_parts_mut();
-        items
-            .iter()
-            .map(|item| {
-                let item = item.clone_with_heap(vm.heap);
-                vm.evaluate_function("sorted() key argument", f, ArgValues::One(item))
-            })
-            .process_results(|keys_iter| keys.extend(keys_iter))?;
-        keys_guard.as_parts()
-    } else {
-        (&*items, vm)
+    let list_id = list.ref_id().expect("list.sort called on a heap list");
+    let items = take_list_items(vm.heap, list_id);
+    let keys = match key_fn {
+        Some(f) => match sort_keys(&items, f, vm) {
+            Ok(keys) => Some(keys),
+            Err(e) => {
+                restore_list_items(vm.heap, list_id, items);
+                return Err(e);
+            }
+        },
+        None => None,
     };
+    let list = list.clone_with_heap(vm.heap);
+    vm.sort(PendingSort::new(items, keys, reverse, Some(list)))
+}
 
-    // 3. Sort indices by comparing key values (or items themselves if no key)
-    let len = compare_values.len();
-    let mut indices: Vec<usize> = (0..len).collect();
-
-    sort_indices(&mut indices, compare_values, reverse, vm.heap, vm.interns)?;
+/// Moves all items out of the list `list_id`, leaving it empty.
+pub(crate) fn take_list_items(heap: &mut Heap<impl ResourceTracker>, list_id: HeapId) -> Vec<Value> {
+    let HeapDataMut::List(list) = heap.get_mut(list_id) else {
+        unreachable!("take_list_items called on a non-list")
+    };
+    std::mem::take(&mut list.items)
+}
 
-    // 4. Rearrange items in-place according to the sorted permutation
-    apply_permutation(items, &mut indices);
-    Ok(())
+/// Puts `items` taken by `take_list_items` back into the list `list_id`.
+///
+/// Anything added to the list in the meantime is dropped. Returns whether the list
+/// was still empty.
+pub(crate) fn restore_list_items(heap: &mut Heap<impl ResourceTracker>, list_id: HeapId, items: Vec<Value>) -> bool {
+    let HeapDataMut::List(list) = heap.get_mut(list_id) else {
+        unreachable!("restore_list_items called on a non-list")
+    };
+    let contains_refs = items.iter().any(|v| matches!(v, Value::Ref(_)));
+    list.contains_refs |= contains_refs;
+    let added = std::mem::replace(&mut list.items, items);
+    if contains_refs {
+        heap.mark_potential_cycle();
+    }
+    let unchanged = added.is_empty();
+    added.drop_with_heap(heap);
+    unchanged
 }
 
 /// Writes a formatted sequence of values to a formatter.
@@ -788,22 +923,22 @@ pub(crate) fn repr_sequence_fmt(
     interns: &Interns,
 ) -> std::fmt::Result {
     // Check depth limit before recursing
-    let Some(token) = heap.incr_recursion_depth_for_repr() else {
+    let Some(token) = heap.incr_repr_depth() else {
         return f.write_str("...");
     };
     crate::defer_drop_immutable_heap!(token, heap);
 
     f.write_char(start)?;
-    let mut iter = items.iter();
+    let mut iter = heap.repr_items(items);
     if let Some(first) = iter.next() {
-        first.py_repr_fmt(f, heap, heap_ids, interns)?;
+        write_repr_item(first, f, heap, heap_ids, interns)?;
         for item in iter {
             if heap.check_time().is_err() {
                 f.write_str(", ...[timeout]")?;
                 break;
             }
             f.write_str(", ")?;
-            item.py_repr_fmt(f, heap, heap_ids, interns)?;
+            write_repr_item(item, f, heap, heap_ids, interns)?;
         }
     }
     f.write_char(end)?;
@@ -811,6 +946,20 @@ pub(crate) fn repr_sequence_fmt(
     Ok(())
 }
 
+/// Writes one item yielded by `Heap::repr_items`, with `None` standing for the elided middle.
+fn write_repr_item(
+    item: Option<&Value>,
+    f: &mut impl Write,
+    heap: &Heap<impl ResourceTracker>,
+    heap_ids: &mut AHashSet<HeapId>,
+    interns: &Interns,
+) -> std::fmt::Result {
+    match item {
+        Some(value) => value.py_repr_fmt(f, heap, heap_ids, interns),
+        None => f.write_str("..."),
+    }
+}
+
 /// Helper to extract items from a slice for list/tuple slicing.
 ///
 /// Handles both positive and negative step values. For negative step,
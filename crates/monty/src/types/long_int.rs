@@ -9,9 +9,7 @@
 //! having freestanding functions scattered across the codebase.
 
 use std::{
-    collections::hash_map::DefaultHasher,
     fmt::{self, Display},
-    hash::{Hash, Hasher},
     ops::{Add, Mul, Neg, Sub},
 };
 
@@ -19,6 +17,7 @@ use num_bigint::BigInt;
 use num_traits::{Signed, ToPrimitive, Zero};
 
 use crate::{
+    exception_private::{ExcType, RunResult},
     heap::{Heap, HeapData},
     resource::{ResourceError, ResourceTracker},
     value::Value,
@@ -47,38 +46,25 @@ impl LongInt {
     /// For performance, we want to keep values as `Value::Int(i64)` whenever possible.
     /// This method checks if the value fits in an i64 and returns `Value::Int` if so,
     /// otherwise allocates a `HeapData::LongInt` on the heap.
+    ///
+    /// Returns `ResourceError::IntBits` if the value exceeds the tracker's integer bit limit.
     pub fn into_value(self, heap: &mut Heap<impl ResourceTracker>) -> Result<Value, ResourceError> {
         // Try to demote back to i64 for performance
         if let Some(i) = self.0.to_i64() {
             Ok(Value::Int(i))
         } else {
+            heap.tracker().check_int_bits(self.0.bits())?;
             let heap_id = heap.allocate(HeapData::LongInt(self))?;
             Ok(Value::Ref(heap_id))
         }
     }
 
-    /// Computes a hash consistent with i64 hashing.
+    /// Computes Python's hash of the integer, see [`bigint_hash`].
     ///
-    /// Critical: For values that fit in i64, this must return the same hash as
-    /// hashing the i64 directly. This ensures dict key consistency - e.g.,
-    /// `hash(5)` must equal `hash(LongInt(5))`.
+    /// For values that fit in i64 this equals [`int_hash`], so `hash(5)` matches
+    /// `hash(LongInt(5))` and dict keys stay consistent.
     pub fn hash(&self) -> u64 {
-        // If the LongInt fits in i64, hash as i64 for consistency
-        if let Some(i) = self.0.to_i64() {
-            let mut hasher = DefaultHasher::new();
-            // Hash the i64 discriminant and value to match Value::Int hashing
-            std::mem::discriminant(&Value::Int(0)).hash(&mut hasher);
-            i.hash(&mut hasher);
-            hasher.finish()
-        } else {
-            // For LongInts outside i64 range, use byte representation
-            let mut hasher = DefaultHasher::new();
-            // Use a unique discriminant for LongInt (we use the LongInt's sign and bytes)
-            let (sign, bytes) = self.0.to_bytes_le();
-            sign.hash(&mut hasher);
-            bytes.hash(&mut hasher);
-            hasher.finish()
-        }
+        bigint_hash(&self.0)
     }
 
     /// Estimates memory size in bytes.
@@ -156,6 +142,36 @@ impl LongInt {
     pub fn bits(&self) -> u64 {
         self.0.bits()
     }
+
+    /// Checks that this LongInt's decimal representation fits the tracker's
+    /// `int_max_str_digits` limit, raising `ValueError` like CPython otherwise.
+    pub fn check_str_digits(&self, tracker: &impl ResourceTracker) -> RunResult<()> {
+        match tracker.int_max_str_digits() {
+            Some(limit) if exceeds_str_digits(&self.0, limit) => Err(ExcType::value_error_int_str_digits(limit)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns whether the decimal representation of `value` has more than `limit` digits.
+///
+/// The digit count is bracketed from the bit length, so the decimal string is only
+/// built when the count lands right at the limit. Values too large by a wide margin
+/// are rejected without the quadratic-time conversion the limit exists to prevent.
+pub(crate) fn exceeds_str_digits(value: &BigInt, limit: usize) -> bool {
+    let max_digits = u64::try_from(limit).unwrap_or(u64::MAX);
+    let bits = value.bits();
+    // A value of `bits` bits lies in [2**(bits - 1), 2**bits), so it has between
+    // (bits - 1) * log10(2) + 1 and bits * log10(2) + 1 digits
+    let min_digits = bits.saturating_sub(1).saturating_mul(30_102_999) / 100_000_000 + 1;
+    let max_possible_digits = bits.saturating_mul(30_103) / 100_000 + 1;
+    if min_digits > max_digits {
+        true
+    } else if max_possible_digits <= max_digits {
+        false
+    } else {
+        value.magnitude().to_string().len() > limit
+    }
 }
 
 // === Trait Implementations ===
@@ -209,3 +225,66 @@ impl Display for LongInt {
         write!(f, "{}", self.0)
     }
 }
+
+/// Modulus of Python's numeric hash, the Mersenne prime `2**61 - 1`.
+const HASH_MODULUS: u64 = (1 << 61) - 1;
+
+/// Computes Python's hash of an int: `abs(x) mod (2**61 - 1)`, carrying the sign of `x`.
+///
+/// Together with [`bigint_hash`] and [`float_hash`], this makes numerically equal ints,
+/// floats and bools hash equally, as dict keys and set elements require
+/// (`hash(1) == hash(1.0) == hash(True)`).
+pub(crate) fn int_hash(i: i64) -> u64 {
+    signed_hash(i < 0, i.unsigned_abs() % HASH_MODULUS)
+}
+
+/// Computes Python's hash of an arbitrary precision int, consistent with [`int_hash`].
+pub(crate) fn bigint_hash(bi: &BigInt) -> u64 {
+    let reduced = (bi.magnitude() % HASH_MODULUS)
+        .to_u64()
+        .expect("value reduced below hash modulus");
+    signed_hash(bi.is_negative(), reduced)
+}
+
+/// Computes Python's hash of a float, consistent with [`int_hash`] for integral values.
+///
+/// A finite float is exactly `m * 2**e` for integers `m` and `e`, and hashes as that value
+/// modulo `2**61 - 1`, where `2**61` is 1 so `2**e` reduces to `2**(e mod 61)`. Infinities
+/// hash to `314159` and `-314159` as in CPython. NaN hashes to 0: CPython hashes it by
+/// identity, which Monty floats don't have, and NaN never compares equal anyway.
+pub(crate) fn float_hash(f: f64) -> u64 {
+    if f.is_nan() {
+        return 0;
+    }
+    if f.is_infinite() {
+        return signed_hash(f < 0.0, 314_159);
+    }
+    let bits = f.abs().to_bits();
+    let biased_exp = i32::try_from(bits >> 52).expect("exponent bits fit in i32");
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exp) = if biased_exp == 0 {
+        // Subnormal: no implicit leading bit
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exp - 1075)
+    };
+    let shift = exp.rem_euclid(61).unsigned_abs();
+    let reduced = (u128::from(mantissa) << shift) % u128::from(HASH_MODULUS);
+    signed_hash(
+        f < 0.0,
+        u64::try_from(reduced).expect("value reduced below hash modulus"),
+    )
+}
+
+/// Applies the sign to a reduced hash magnitude and returns the bits of the signed result.
+///
+/// As in CPython, a result of -1 becomes -2, so `hash(-1) == hash(-2)`.
+fn signed_hash(negative: bool, magnitude: u64) -> u64 {
+    let magnitude = i64::try_from(magnitude).expect("hash magnitude below 2**61");
+    let hash = match (negative, magnitude) {
+        (true, 1) => -2,
+        (true, _) => -magnitude,
+        (false, _) => magnitude,
+    };
+    u64::from_ne_bytes(hash.to_ne_bytes())
+}
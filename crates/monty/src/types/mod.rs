@@ -6,8 +6,10 @@
 /// The `AbstractValue` trait provides a common interface for all heap-allocated
 /// types, enabling efficient dispatch via `enum_dispatch`.
 pub mod bytes;
+pub mod class;
 pub mod dataclass;
 pub mod dict;
+pub mod hash;
 pub mod iter;
 pub mod list;
 pub mod long_int;
@@ -24,15 +26,19 @@ pub mod tuple;
 pub mod r#type;
 
 pub(crate) use bytes::Bytes;
+pub(crate) use class::{
+    BoundMethod, ClassId, ClassObject, ExceptionClass, Instance, InstanceAttr, MethodDescriptor, MethodDescriptorKind,
+};
 pub(crate) use dataclass::Dataclass;
 pub(crate) use dict::Dict;
+pub(crate) use hash::{HashAlgorithm, HashObject};
 pub(crate) use iter::MontyIter;
 pub(crate) use list::List;
 pub(crate) use long_int::LongInt;
 pub(crate) use module::Module;
 pub(crate) use namedtuple::NamedTuple;
 pub(crate) use path::Path;
-pub(crate) use property::Property;
+pub(crate) use property::{Property, PropertyObject};
 pub(crate) use py_trait::{AttrCallResult, PyTrait};
 pub(crate) use range::Range;
 pub(crate) use set::{FrozenSet, Set};
@@ -108,7 +108,7 @@ impl Module {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> Option<AttrCallResult> {
-        let value = self.attrs.get_by_str(attr.as_str(interns), heap, interns)?;
+        let value = self.attrs.get_by_attr(attr, heap, interns)?;
 
         // If the value is a Property, invoke its getter to compute the actual value
         if let Value::Property(prop) = *value {
@@ -121,10 +121,7 @@ impl Module {
     /// Calls an attribute as a function on this module.
     ///
     /// Modules don't have methods - they have callable attributes. This looks up
-    /// the attribute and calls it if it's a `ModuleFunction`.
-    ///
-    /// Returns `AttrCallResult` because module functions may need OS operations
-    /// (e.g., `os.getenv()`) that require host involvement.
+    /// the attribute and returns it to be called if it's a `ModuleFunction`.
     pub fn py_call_attr_raw(
         &self,
         _self_id: HeapId,
@@ -145,10 +142,9 @@ impl Module {
         };
 
         match self.get_attr(&attr_key, args_guard.heap(), interns) {
-            Some(Value::ModuleFunction(mf)) => {
-                let (args, heap) = args_guard.into_parts();
-                mf.call(heap, args)
-            }
+            // The VM makes the call once this module is back in the heap, since `heapq` and
+            // `bisect` functions may run script code that uses it
+            Some(func @ Value::ModuleFunction(_)) => Ok(AttrCallResult::CallFunction(func, args_guard.into_inner())),
             Some(func) => {
                 // Found attribute but it's not callable
                 func.drop_with_heap(args_guard.heap());
@@ -193,7 +193,7 @@ impl PyTrait for NamedTuple {
         let token = heap.incr_recursion_depth()?;
         defer_drop!(token, heap);
         for (i1, i2) in self.items.iter().zip(&other.items) {
-            if !i1.py_eq(i2, heap, interns)? {
+            if !i1.py_eq_or_is(i2, heap, interns)? {
                 return Ok(false);
             }
         }
@@ -230,7 +230,7 @@ impl PyTrait for NamedTuple {
         interns: &Interns,
     ) -> std::fmt::Result {
         // Check depth limit before recursing
-        let Some(token) = heap.incr_recursion_depth_for_repr() else {
+        let Some(token) = heap.incr_repr_depth() else {
             return f.write_str("...");
         };
         crate::defer_drop_immutable_heap!(token, heap);
@@ -3,8 +3,24 @@
 //! Properties are descriptors whose value is computed when accessed.
 //! When a Property is retrieved via `py_getattr`, its getter is invoked
 //! rather than returning the Property itself.
+//!
+//! `Property` covers properties built into the interpreter, while `PropertyObject`
+//! holds the getter of a `@property` defined on a class in the script.
+
+use std::fmt::Write;
 
-use crate::{args::ArgValues, os::OsFunction, types::AttrCallResult};
+use ahash::AHashSet;
+
+use crate::{
+    args::ArgValues,
+    exception_private::RunResult,
+    heap::{Heap, HeapData, HeapId},
+    intern::Interns,
+    os::OsFunction,
+    resource::{ResourceError, ResourceTracker},
+    types::{AttrCallResult, PyTrait, Type},
+    value::Value,
+};
 
 /// Property descriptor for computed attributes.
 ///
@@ -33,3 +49,72 @@ impl Property {
         }
     }
 }
+
+/// A `property` object created in the script, e.g. by `@property` on a method.
+///
+/// Only getters are supported, so these properties are read-only. When looked up on an
+/// instance, the getter is called with the instance (see `Instance::lookup`).
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct PropertyObject {
+    /// The getter function, called with the instance as its only argument.
+    getter: Value,
+}
+
+impl PropertyObject {
+    /// Creates a property from `property(fget)`.
+    pub fn init(heap: &mut Heap<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let getter = args.get_one_arg("property", heap)?;
+        let property = Self { getter };
+        Ok(Value::Ref(heap.allocate(HeapData::Property(property))?))
+    }
+
+    /// Returns the getter function.
+    #[must_use]
+    pub fn getter(&self) -> &Value {
+        &self.getter
+    }
+
+    /// Returns whether the getter is a heap reference.
+    #[must_use]
+    pub fn has_refs(&self) -> bool {
+        matches!(self.getter, Value::Ref(_))
+    }
+}
+
+impl PyTrait for PropertyObject {
+    fn py_type(&self, _heap: &Heap<impl ResourceTracker>) -> Type {
+        Type::Property
+    }
+
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    fn py_len(&self, _heap: &Heap<impl ResourceTracker>, _interns: &Interns) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(
+        &self,
+        _other: &Self,
+        _heap: &mut Heap<impl ResourceTracker>,
+        _interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        // Properties compare by identity, which is handled at the Value level
+        Ok(false)
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.getter.py_dec_ref_ids(stack);
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _heap: &Heap<impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+        _interns: &Interns,
+    ) -> std::fmt::Result {
+        f.write_str("<property object>")
+    }
+}
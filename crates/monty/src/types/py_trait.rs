@@ -17,7 +17,7 @@ use crate::{
     args::ArgValues,
     bytecode::VM,
     exception_private::{ExcType, RunResult, SimpleException},
-    heap::{Heap, HeapId},
+    heap::{DropWithHeap, Heap, HeapId},
     intern::{ExtFunctionId, Interns},
     os::OsFunction,
     resource::ResourceTracker,
@@ -70,6 +70,26 @@ pub enum AttrCallResult {
     /// Used by `asyncio.run()` to execute a coroutine without an explicit `await`.
     /// The VM will push the value onto the stack and execute `exec_get_awaitable`.
     AwaitValue(Value),
+    /// The attribute is computed by calling a function defined in the script.
+    ///
+    /// Used for `@property` getters on script classes, which may push a frame, so the
+    /// VM calls the function itself rather than the attribute lookup.
+    CallFunction(Value, ArgValues),
+}
+
+impl DropWithHeap for AttrCallResult {
+    fn drop_with_heap<T: ResourceTracker>(self, heap: &mut Heap<T>) {
+        match self {
+            Self::Value(value) | Self::AwaitValue(value) => value.drop_with_heap(heap),
+            Self::OsCall(_, args) | Self::ExternalCall(_, args) | Self::MethodCall(_, args) => {
+                args.drop_with_heap(heap);
+            }
+            Self::CallFunction(func, args) => {
+                func.drop_with_heap(heap);
+                args.drop_with_heap(heap);
+            }
+        }
+    }
 }
 
 /// Common operations for heap-allocated Python values.
@@ -162,7 +182,7 @@ pub trait PyTrait {
     /// visited heap IDs. When a cycle is detected (ID already in `heap_ids`), implementations
     /// should write an ellipsis (e.g., `[...]` for lists, `{...}` for dicts).
     ///
-    /// Recursion depth is tracked via `heap.incr_recursion_depth_for_repr()`.
+    /// Recursion depth is tracked via `heap.incr_repr_depth()`.
     ///
     /// # Arguments
     /// * `f` - The formatter to write to
@@ -389,6 +409,17 @@ pub trait PyTrait {
         .into())
     }
 
+    /// Python subscript delete operation (`__delitem__`), e.g., `del d[key]`.
+    ///
+    /// Removes the item at the key, or returns an error if the key is invalid
+    /// or the type doesn't support item deletion.
+    ///
+    /// Default implementation returns TypeError.
+    fn py_delitem(&mut self, key: Value, heap: &mut Heap<impl ResourceTracker>, _interns: &Interns) -> RunResult<()> {
+        key.drop_with_heap(heap);
+        Err(ExcType::type_error_not_sub_deletion(self.py_type(heap)))
+    }
+
     /// Python attribute get operation (`__getattr__`), e.g., `obj.attr`.
     ///
     /// Returns the value associated with the attribute (owned), or `Ok(None)` if the type
@@ -64,24 +64,20 @@ impl Range {
     }
 
     /// Returns the length of the range (number of elements it will yield).
+    ///
+    /// Computed arithmetically in `i128` so extreme bounds like `range(-2**63, 2**63 - 1)`
+    /// can't overflow; the result always fits in a `u64`, but may exceed `i64::MAX`.
     #[must_use]
     pub fn len(&self) -> usize {
-        if self.step > 0 {
-            if self.stop > self.start {
-                let len_i64 = (self.stop - self.start - 1) / self.step + 1;
-                usize::try_from(len_i64).expect("range length guaranteed non-negative")
-            } else {
-                0
-            }
+        let (start, stop, step) = (i128::from(self.start), i128::from(self.stop), i128::from(self.step));
+        let len = if step > 0 && stop > start {
+            (stop - start - 1) / step + 1
+        } else if step < 0 && start > stop {
+            (start - stop - 1) / (-step) + 1
         } else {
-            // step < 0
-            if self.start > self.stop {
-                let len_i64 = (self.start - self.stop - 1) / (-self.step) + 1;
-                usize::try_from(len_i64).expect("range length guaranteed non-negative")
-            } else {
-                0
-            }
-        }
+            0
+        };
+        usize::try_from(len).expect("range length guaranteed to fit in usize")
     }
 
     #[must_use]
@@ -106,8 +102,8 @@ impl Range {
                 return false;
             }
         }
-        // Check if n is on the step grid
-        (n - self.start) % self.step == 0
+        // Check if n is on the step grid (widened since `n - start` can overflow i64)
+        (i128::from(n) - i128::from(self.start)) % i128::from(self.step) == 0
     }
 
     /// Creates a range from the `range()` constructor call.
@@ -3,7 +3,7 @@ use std::fmt::Write;
 use ahash::AHashSet;
 use hashbrown::HashTable;
 
-use super::{MontyIter, PyTrait};
+use super::{MontyIter, PyTrait, dict::table_hash};
 use crate::{
     args::ArgValues,
     defer_drop, defer_drop_mut,
@@ -15,7 +15,7 @@ use crate::{
     value::{EitherStr, Value},
 };
 
-/// Entry in the set storage, containing a value and its cached hash.
+/// Entry in the set storage, containing a value and its cached table hash.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct SetEntry {
     pub(crate) value: Value,
@@ -107,7 +107,7 @@ impl SetStorage {
     /// the set, it will be dropped.
     fn add(&mut self, value: Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
         let hash = match value.py_hash(heap, interns) {
-            Ok(Some(h)) => h,
+            Ok(Some(h)) => table_hash(h),
             Ok(None) => {
                 let err = ExcType::type_error_unhashable_set_element(value.py_type(heap));
                 value.drop_with_heap(heap);
@@ -121,7 +121,9 @@ impl SetStorage {
 
         // Check if value already exists.
         let existing = self.indices.find(hash, |&idx| {
-            value.py_eq(&self.entries[idx].value, heap, interns).unwrap_or(false)
+            value
+                .py_eq_or_is(&self.entries[idx].value, heap, interns)
+                .unwrap_or(false)
         });
 
         if existing.is_some() {
@@ -142,13 +144,19 @@ impl SetStorage {
     /// Returns `Ok(true)` if the element was removed, `Ok(false)` if not found.
     /// Returns `Err` if the key is unhashable.
     fn remove(&mut self, value: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
-        let hash = value
-            .py_hash(heap, interns)?
-            .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?;
+        let hash = table_hash(
+            value
+                .py_hash(heap, interns)?
+                .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?,
+        );
 
         let entry = self.indices.entry(
             hash,
-            |&idx| value.py_eq(&self.entries[idx].value, heap, interns).unwrap_or(false),
+            |&idx| {
+                value
+                    .py_eq_or_is(&self.entries[idx].value, heap, interns)
+                    .unwrap_or(false)
+            },
             |&idx| self.entries[idx].hash,
         );
 
@@ -225,16 +233,20 @@ impl SetStorage {
 
     /// Checks if the set contains a value.
     pub fn contains(&self, value: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
-        let hash = value
-            .py_hash(heap, interns)?
-            .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?;
+        let hash = table_hash(
+            value
+                .py_hash(heap, interns)?
+                .ok_or_else(|| ExcType::type_error_unhashable_set_element(value.py_type(heap)))?,
+        );
 
         // Set values are typically shallow (strings, ints, tuples of primitives),
         // so recursion errors are unlikely. If one occurs, treat it as "not equal".
         Ok(self
             .indices
             .find(hash, |&idx| {
-                value.py_eq(&self.entries[idx].value, heap, interns).unwrap_or(false)
+                value
+                    .py_eq_or_is(&self.entries[idx].value, heap, interns)
+                    .unwrap_or(false)
             })
             .is_some())
     }
@@ -411,7 +423,7 @@ impl SetStorage {
         }
 
         // Check depth limit before recursing
-        let Some(token) = heap.incr_recursion_depth_for_repr() else {
+        let Some(token) = heap.incr_repr_depth() else {
             return f.write_str("{...}");
         };
         crate::defer_drop_immutable_heap!(token, heap);
@@ -424,7 +436,7 @@ impl SetStorage {
 
         f.write_char('{')?;
         let mut first = true;
-        for entry in &self.entries {
+        for entry in heap.repr_items(&self.entries) {
             if !first {
                 if heap.check_time().is_err() {
                     f.write_str(", ...[timeout]")?;
@@ -433,7 +445,10 @@ impl SetStorage {
                 f.write_str(", ")?;
             }
             first = false;
-            entry.value.py_repr_fmt(f, heap, heap_ids, interns)?;
+            match entry {
+                Some(entry) => entry.value.py_repr_fmt(f, heap, heap_ids, interns)?,
+                None => f.write_str("...")?,
+            }
         }
         f.write_char('}')?;
 
@@ -541,7 +556,7 @@ impl Set {
 
     /// Returns a shallow copy of the set.
     #[must_use]
-    pub fn copy(&self, heap: &mut Heap<impl ResourceTracker>) -> Self {
+    pub fn copy(&self, heap: &Heap<impl ResourceTracker>) -> Self {
         Self(self.0.clone_with_heap(heap))
     }
 
@@ -996,7 +1011,7 @@ impl FrozenSet {
 
     /// Returns a shallow copy of the frozenset.
     #[must_use]
-    pub fn copy(&self, heap: &mut Heap<impl ResourceTracker>) -> Self {
+    pub fn copy(&self, heap: &Heap<impl ResourceTracker>) -> Self {
         Self(self.0.clone_with_heap(heap))
     }
 
@@ -97,7 +97,8 @@ impl Slice {
     /// - start defaults to length-1, stop defaults to -1 (before beginning)
     /// - start is clamped to [-1, length-1], stop to [-1, length-1]
     pub fn indices(&self, length: usize) -> Result<(usize, usize, i64), ()> {
-        let step = self.step.unwrap_or(1);
+        // Clamp like CPython so `-step` can't overflow for a step of `i64::MIN`
+        let step = self.step.unwrap_or(1).max(-i64::MAX);
         if step == 0 {
             return Err(());
         }
@@ -7,16 +7,18 @@ use std::{borrow::Cow, fmt};
 
 use ahash::AHashSet;
 use smallvec::smallvec;
+use unicode_properties::{GeneralCategoryGroup, UnicodeGeneralCategory};
 
 use super::{Bytes, MontyIter, PyTrait};
 use crate::{
     args::ArgValues,
     defer_drop, defer_drop_mut,
-    exception_private::{ExcType, RunResult},
+    exception_private::{ExcType, RunResult, SimpleException},
+    fstring::{ParsedFormatSpec, ascii_escape, format_string, format_with_spec},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId},
     intern::{Interns, StaticStrings, StringId},
-    resource::{ResourceError, ResourceTracker},
-    types::Type,
+    resource::{ResourceError, ResourceTracker, check_repeat_size},
+    types::{AttrCallResult, Type},
     value::{EitherStr, Value},
 };
 
@@ -55,7 +57,7 @@ impl Str {
             None => Ok(Value::InternString(StaticStrings::EmptyString.into())),
             Some(v) => {
                 defer_drop!(v, heap);
-                let s = v.py_str(heap, interns).into_owned();
+                let s = v.py_str_checked(heap, interns)?.into_owned();
                 allocate_string(s, heap)
             }
         }
@@ -65,12 +67,7 @@ impl Str {
     ///
     /// Returns a new string containing the selected characters (Unicode-aware).
     fn getitem_slice(&self, slice: &crate::types::Slice, heap: &mut Heap<impl ResourceTracker>) -> RunResult<Value> {
-        let char_count = self.0.chars().count();
-        let (start, stop, step) = slice
-            .indices(char_count)
-            .map_err(|()| ExcType::value_error_slice_step_zero())?;
-
-        let result_str = get_str_slice(&self.0, start, stop, step);
+        let result_str = str_getitem_slice(&self.0, slice)?;
         let heap_id = heap.allocate(HeapData::Str(Self::from(result_str)))?;
         Ok(Value::Ref(heap_id))
     }
@@ -152,6 +149,18 @@ pub fn get_char_at_index(s: &str, index: i64) -> Option<char> {
     s.chars().nth(idx)
 }
 
+/// Slices a string by codepoints, shared by heap and interned strings.
+///
+/// Resolves the slice bounds against the codepoint count, then extracts the result
+/// with `get_str_slice`.
+pub(crate) fn str_getitem_slice(s: &str, slice: &crate::types::Slice) -> RunResult<String> {
+    let char_count = if s.is_ascii() { s.len() } else { s.chars().count() };
+    let (start, stop, step) = slice
+        .indices(char_count)
+        .map_err(|()| ExcType::value_error_slice_step_zero())?;
+    Ok(get_str_slice(s, start, stop, step))
+}
+
 /// Extracts a slice of a string (Unicode-aware).
 ///
 /// Handles both positive and negative step values. For negative step,
@@ -159,43 +168,72 @@ pub fn get_char_at_index(s: &str, index: i64) -> Option<char> {
 /// The `stop` parameter uses a sentinel value of `len + 1` for negative
 /// step to indicate "go to the beginning".
 ///
+/// Indices are codepoint positions. ASCII strings index bytes directly, and contiguous
+/// forward slices copy a single substring between char boundaries; otherwise a table of
+/// char boundaries is built once so each selected char is found without rescanning.
+///
 /// Note: step must be non-zero (callers should validate this via `slice.indices()`).
-pub(crate) fn get_str_slice(s: &str, start: usize, stop: usize, step: i64) -> String {
-    let chars: Vec<char> = s.chars().collect();
-    let mut result = String::new();
+fn get_str_slice(s: &str, start: usize, stop: usize, step: i64) -> String {
+    if s.is_ascii() {
+        let bytes = s.as_bytes();
+        return slice_positions(bytes.len(), start, stop, step)
+            .map(|i| char::from(bytes[i]))
+            .collect();
+    }
+
+    // Byte offset of each char, plus a trailing `s.len()` so `boundaries[i + 1]` ends char `i`
+    let boundaries: Vec<usize> = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect();
+    let char_count = boundaries.len() - 1;
+
+    if step == 1 {
+        let stop = stop.min(char_count);
+        return if start < stop {
+            s[boundaries[start]..boundaries[stop]].to_owned()
+        } else {
+            String::new()
+        };
+    }
+
+    slice_positions(char_count, start, stop, step)
+        .map(|i| &s[boundaries[i]..boundaries[i + 1]])
+        .collect()
+}
 
+/// Yields the codepoint positions selected by resolved slice indices, see `get_str_slice`.
+fn slice_positions(len: usize, start: usize, stop: usize, step: i64) -> impl Iterator<Item = usize> {
     // try_from succeeds for non-negative step; step==0 rejected upstream by slice.indices()
-    if let Ok(step_usize) = usize::try_from(step) {
-        // Positive step: iterate forward
-        let mut i = start;
-        while i < stop && i < chars.len() {
-            result.push(chars[i]);
-            i += step_usize;
+    let (forward, step_abs) = match usize::try_from(step) {
+        Ok(step_usize) => (true, step_usize),
+        Err(_) => (false, usize::try_from(step.unsigned_abs()).unwrap_or(usize::MAX)),
+    };
+    // stop > len is the sentinel meaning "go to the beginning" for negative steps
+    let count = if forward {
+        let stop = stop.min(len);
+        if start < stop {
+            (stop - start - 1) / step_abs + 1
+        } else {
+            0
         }
+    } else if start >= len {
+        0
+    } else if stop > len {
+        start / step_abs + 1
+    } else if start > stop {
+        (start - stop - 1) / step_abs + 1
     } else {
-        // Negative step: iterate backward
-        // start is the highest index, stop is the sentinel
-        // stop > chars.len() means "go to the beginning"
-        let step_abs = usize::try_from(-step).expect("step is negative so -step is positive");
-        let step_abs_i64 = i64::try_from(step_abs).expect("step magnitude fits in i64");
-        let mut i = i64::try_from(start).expect("start index fits in i64");
-        // stop > chars.len() is sentinel meaning "go to beginning", use -1
-        let stop_i64 = if stop > chars.len() {
-            -1
+        0
+    };
+    (0..count).map(move |n| {
+        if forward {
+            start + n * step_abs
         } else {
-            i64::try_from(stop).expect("stop bounded by chars.len() fits in i64")
-        };
-
-        while let Ok(i_usize) = usize::try_from(i) {
-            if i_usize >= chars.len() || i <= stop_i64 {
-                break;
-            }
-            result.push(chars[i_usize]);
-            i -= step_abs_i64;
+            start - n * step_abs
         }
-    }
-
-    result
+    })
 }
 
 impl std::ops::Deref for Str {
@@ -363,8 +401,6 @@ pub fn call_str_method(
 /// - `maketrans()` / `translate()` - Character translation tables; moderate complexity,
 ///   requires building and applying Unicode translation maps.
 /// - `expandtabs(tabsize=8)` - Tab expansion; simple but rarely used in practice.
-/// - `isprintable()` - Checks if all characters are printable; requires accurate Unicode
-///   category data for the "printable" property.
 fn call_str_method_impl(
     s: &str,
     method: StaticStrings,
@@ -463,10 +499,15 @@ fn call_str_method_impl(
         StaticStrings::Zfill => str_zfill(s, args, heap),
         // Additional methods
         StaticStrings::Encode => str_encode(s, args, heap, interns),
+        StaticStrings::Format => str_format(s, args, heap, interns),
         StaticStrings::Isidentifier => {
             args.check_zero_args("str.isidentifier", heap)?;
             Ok(Value::Bool(str_isidentifier(s)))
         }
+        StaticStrings::Isprintable => {
+            args.check_zero_args("str.isprintable", heap)?;
+            Ok(Value::Bool(str_isprintable(s)))
+        }
         StaticStrings::Istitle => {
             args.check_zero_args("str.istitle", heap)?;
             Ok(Value::Bool(str_istitle(s)))
@@ -933,16 +974,8 @@ fn is_unicode_digit(c: char) -> bool {
 /// Returns the lowest index in the string where substring sub is found within
 /// the slice s[start:end]. Returns -1 if sub is not found.
 fn str_find(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    let (sub, start, end) = parse_search_args("str.find", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    let result = match slice.find(&sub) {
-        Some(pos) => {
-            // Convert byte offset to char offset, then add start offset
-            let char_pos = slice[..pos].chars().count();
-            i64::try_from(start + char_pos).unwrap_or(i64::MAX)
-        }
-        None => -1,
-    };
+    let (sub, bounds) = parse_search_args("find", s, args, heap, interns)?;
+    let result = find_codepoint(s, &sub, bounds, false).map_or(-1, |index| i64::try_from(index).unwrap_or(i64::MAX));
     Ok(Value::Int(result))
 }
 
@@ -951,16 +984,8 @@ fn str_find(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, int
 /// Returns the highest index in the string where substring sub is found within
 /// the slice s[start:end]. Returns -1 if sub is not found.
 fn str_rfind(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    let (sub, start, end) = parse_search_args("str.rfind", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    let result = match slice.rfind(&sub) {
-        Some(pos) => {
-            // Convert byte offset to char offset, then add start offset
-            let char_pos = slice[..pos].chars().count();
-            i64::try_from(start + char_pos).unwrap_or(i64::MAX)
-        }
-        None => -1,
-    };
+    let (sub, bounds) = parse_search_args("rfind", s, args, heap, interns)?;
+    let result = find_codepoint(s, &sub, bounds, true).map_or(-1, |index| i64::try_from(index).unwrap_or(i64::MAX));
     Ok(Value::Int(result))
 }
 
@@ -968,14 +993,9 @@ fn str_rfind(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
 ///
 /// Like find(), but raises ValueError when the substring is not found.
 fn str_index(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    let (sub, start, end) = parse_search_args("str.index", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    match slice.find(&sub) {
-        Some(pos) => {
-            let char_pos = slice[..pos].chars().count();
-            let result = i64::try_from(start + char_pos).unwrap_or(i64::MAX);
-            Ok(Value::Int(result))
-        }
+    let (sub, bounds) = parse_search_args("index", s, args, heap, interns)?;
+    match find_codepoint(s, &sub, bounds, false) {
+        Some(index) => Ok(Value::Int(i64::try_from(index).unwrap_or(i64::MAX))),
         None => Err(ExcType::value_error_substring_not_found()),
     }
 }
@@ -984,14 +1004,9 @@ fn str_index(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
 ///
 /// Like rfind(), but raises ValueError when the substring is not found.
 fn str_rindex(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    let (sub, start, end) = parse_search_args("str.rindex", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    match slice.rfind(&sub) {
-        Some(pos) => {
-            let char_pos = slice[..pos].chars().count();
-            let result = i64::try_from(start + char_pos).unwrap_or(i64::MAX);
-            Ok(Value::Int(result))
-        }
+    let (sub, bounds) = parse_search_args("rindex", s, args, heap, interns)?;
+    match find_codepoint(s, &sub, bounds, true) {
+        Some(index) => Ok(Value::Int(i64::try_from(index).unwrap_or(i64::MAX))),
         None => Err(ExcType::value_error_substring_not_found()),
     }
 }
@@ -1001,7 +1016,10 @@ fn str_rindex(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, i
 /// Returns the number of non-overlapping occurrences of substring sub in
 /// the string s[start:end].
 fn str_count(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
-    let (sub, start, end) = parse_search_args("str.count", s, args, heap, interns)?;
+    let (sub, bounds) = parse_search_args("count", s, args, heap, interns)?;
+    let Some((start, end)) = bounds else {
+        return Ok(Value::Int(0));
+    };
     let slice = slice_string(s, start, end);
     let count = if sub.is_empty() {
         // Empty string matches between every character, plus start and end
@@ -1013,6 +1031,16 @@ fn str_count(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
     Ok(Value::Int(result))
 }
 
+/// Returns the codepoint index of the first (or last, if `reverse`) occurrence of `sub` in `s`
+/// within `bounds`, as resolved by [`search_bounds`].
+fn find_codepoint(s: &str, sub: &str, bounds: Option<(usize, usize)>, reverse: bool) -> Option<usize> {
+    let (start, end) = bounds?;
+    let slice = slice_string(s, start, end);
+    let byte_pos = if reverse { slice.rfind(sub) } else { slice.find(sub) }?;
+    // Convert byte offset to char offset, then add start offset
+    Some(start + slice[..byte_pos].chars().count())
+}
+
 /// Implements Python's `str.startswith(prefix, start?, end?)` method.
 ///
 /// Returns True if the string starts with the prefix, otherwise returns False.
@@ -1023,10 +1051,9 @@ fn str_startswith(
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
 ) -> RunResult<Value> {
-    let (prefixes, start, end) = parse_prefix_suffix_args("str.startswith", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    let result = prefixes.iter().any(|prefix| slice.starts_with(prefix));
-    Ok(Value::Bool(result))
+    str_affix_match("startswith", s, args, heap, interns, |slice, prefix| {
+        slice.starts_with(prefix)
+    })
 }
 
 /// Implements Python's `str.endswith(suffix, start?, end?)` method.
@@ -1039,109 +1066,137 @@ fn str_endswith(
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
 ) -> RunResult<Value> {
-    let (suffixes, start, end) = parse_prefix_suffix_args("str.endswith", s, args, heap, interns)?;
-    let slice = slice_string(s, start, end);
-    let result = suffixes.iter().any(|suffix| slice.ends_with(suffix));
-    Ok(Value::Bool(result))
+    str_affix_match("endswith", s, args, heap, interns, |slice, suffix| {
+        slice.ends_with(suffix)
+    })
 }
 
-/// Parses arguments for search methods (find, rfind, index, rindex, count, startswith, endswith).
+/// Shared implementation of `str.startswith` and `str.endswith`.
 ///
-/// Returns (substring, start, end) where start and end are character indices.
-fn parse_search_args(
-    method: &str,
+/// Matches `is_match(slice, affix)` against `s[start:end]` for the affix, or for each
+/// item of a tuple of affixes. As in CPython, tuple items are type-checked lazily, so items
+/// after the first match are never inspected. Empty bounds (see [`search_bounds`]) match
+/// nothing, not even `''`.
+fn str_affix_match(
+    name: &str,
     s: &str,
     args: ArgValues,
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
-) -> RunResult<(String, usize, usize)> {
-    let pos = args.into_pos_only(method, heap)?;
+    is_match: impl Fn(&str, &str) -> bool,
+) -> RunResult<Value> {
+    let method = format!("str.{name}");
+    let pos = args.into_pos_only(&method, heap)?;
     defer_drop!(pos, heap);
 
-    let str_len = s.chars().count();
-    match pos.as_slice() {
-        [sub_value] => {
-            let sub = extract_string_arg(sub_value, heap, interns)?;
-            Ok((sub, 0, str_len))
-        }
-        [sub_value, start_value] => {
-            let sub = extract_string_arg(sub_value, heap, interns)?;
-            let start = optional_index(start_value, 0, str_len, heap)?;
-            Ok((sub, start, str_len))
-        }
-        [sub_value, start_value, end_value] => {
-            let sub = extract_string_arg(sub_value, heap, interns)?;
-            let start = optional_index(start_value, 0, str_len, heap)?;
-            let end = optional_index(end_value, str_len, str_len, heap)?;
-            Ok((sub, start, end))
+    let (affix, start_value, end_value) = match pos.as_slice() {
+        [affix] => (affix, None, None),
+        [affix, start] => (affix, Some(start), None),
+        [affix, start, end] => (affix, Some(start), Some(end)),
+        [] => return Err(ExcType::type_error_at_least(&method, 1, 0)),
+        _ => return Err(ExcType::type_error_at_most(&method, 3, pos.len())),
+    };
+
+    let bounds = search_bounds(start_value, end_value, s.chars().count(), heap)?;
+    let slice = bounds.map(|(start, end)| slice_string(s, start, end));
+    let matches = |affix: &str| slice.is_some_and(|slice| is_match(slice, affix));
+
+    if let Some(affix) = str_value(affix, heap, interns) {
+        return Ok(Value::Bool(matches(affix)));
+    }
+    let tuple = match affix {
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Tuple(tuple) => Some(tuple),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(tuple) = tuple {
+        for item in tuple.as_slice() {
+            let Some(item_str) = str_value(item, heap, interns) else {
+                return Err(ExcType::type_error(format!(
+                    "tuple for {name} must only contain str, not {}",
+                    item.py_type(heap)
+                )));
+            };
+            if matches(item_str) {
+                return Ok(Value::Bool(true));
+            }
         }
-        [] => Err(ExcType::type_error_at_least(method, 1, 0)),
-        _ => Err(ExcType::type_error_at_most(method, 3, pos.len())),
+        return Ok(Value::Bool(false));
+    }
+    Err(ExcType::type_error(format!(
+        "{name} first arg must be str or a tuple of str, not {}",
+        affix.py_type(heap)
+    )))
+}
+
+/// Returns the contents of `value` if it is a str, borrowing from the heap or interns.
+fn str_value<'a>(value: &Value, heap: &'a Heap<impl ResourceTracker>, interns: &'a Interns) -> Option<&'a str> {
+    match value {
+        Value::InternString(id) => Some(interns.get_str(*id)),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Str(s) => Some(s.as_str()),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
-/// Parses arguments for startswith/endswith methods.
+/// Parses arguments for search methods (find, rfind, index, rindex, count).
 ///
-/// Returns (prefixes/suffixes as Vec, start, end) where start and end are character indices.
-/// The first argument can be either a string or a tuple of strings.
-fn parse_prefix_suffix_args(
-    method: &str,
+/// Returns the substring and its codepoint bounds, as resolved by [`search_bounds`].
+fn parse_search_args(
+    name: &str,
     s: &str,
     args: ArgValues,
     heap: &mut Heap<impl ResourceTracker>,
     interns: &Interns,
-) -> RunResult<(Vec<String>, usize, usize)> {
-    let pos = args.into_pos_only(method, heap)?;
+) -> RunResult<(String, Option<(usize, usize)>)> {
+    let method = format!("str.{name}");
+    let pos = args.into_pos_only(&method, heap)?;
     defer_drop!(pos, heap);
 
-    let str_len = s.chars().count();
-    match pos.as_slice() {
-        [prefix_value] => {
-            let prefixes = extract_str_or_tuple_of_str(prefix_value, heap, interns)?;
-            Ok((prefixes, 0, str_len))
-        }
-        [prefix_value, start_value] => {
-            let prefixes = extract_str_or_tuple_of_str(prefix_value, heap, interns)?;
-            let start = optional_index(start_value, 0, str_len, heap)?;
-            Ok((prefixes, start, str_len))
-        }
-        [prefix_value, start_value, end_value] => {
-            let prefixes = extract_str_or_tuple_of_str(prefix_value, heap, interns)?;
-            let start = optional_index(start_value, 0, str_len, heap)?;
-            let end = optional_index(end_value, str_len, str_len, heap)?;
-            Ok((prefixes, start, end))
-        }
-        [] => Err(ExcType::type_error_at_least(method, 1, 0)),
-        _ => Err(ExcType::type_error_at_most(method, 3, pos.len())),
-    }
+    let (sub_value, start_value, end_value) = match pos.as_slice() {
+        [sub] => (sub, None, None),
+        [sub, start] => (sub, Some(start), None),
+        [sub, start, end] => (sub, Some(start), Some(end)),
+        [] => return Err(ExcType::type_error_at_least(&method, 1, 0)),
+        _ => return Err(ExcType::type_error_at_most(&method, 3, pos.len())),
+    };
+    let Some(sub) = str_value(sub_value, heap, interns) else {
+        return Err(ExcType::type_error(format!(
+            "{name}() argument 1 must be str, not {}",
+            sub_value.py_type(heap)
+        )));
+    };
+    let sub = sub.to_owned();
+    let bounds = search_bounds(start_value, end_value, s.chars().count(), heap)?;
+    Ok((sub, bounds))
 }
 
-/// Extracts a string or tuple of strings from a Value.
+/// Resolves the optional `start`/`end` arguments of a search method to codepoint bounds
+/// within a string of `str_len` codepoints, with `None` meaning the default.
 ///
-/// Returns a Vec of strings - a single-element Vec if given a string,
-/// or multiple elements if given a tuple of strings.
-fn extract_str_or_tuple_of_str(
-    value: &Value,
+/// Returns `None` when `start` lies past the end of the string or `end` before `start`:
+/// CPython finds nothing in such bounds, not even the empty string.
+fn search_bounds(
+    start_value: Option<&Value>,
+    end_value: Option<&Value>,
+    str_len: usize,
     heap: &Heap<impl ResourceTracker>,
-    interns: &Interns,
-) -> RunResult<Vec<String>> {
-    match value {
-        Value::InternString(id) => Ok(vec![interns.get_str(*id).to_owned()]),
-        Value::Ref(heap_id) => match heap.get(*heap_id) {
-            HeapData::Str(s) => Ok(vec![s.as_str().to_owned()]),
-            HeapData::Tuple(tuple) => {
-                let items = tuple.as_slice();
-                let mut strings = Vec::with_capacity(items.len());
-                for item in items {
-                    let s = extract_string_arg(item, heap, interns)?;
-                    strings.push(s);
-                }
-                Ok(strings)
-            }
-            _ => Err(ExcType::type_error("expected str or tuple of str")),
-        },
-        _ => Err(ExcType::type_error("expected str or tuple of str")),
-    }
+) -> RunResult<Option<(usize, usize)>> {
+    let raw_start = match start_value {
+        Some(value) if !matches!(value, Value::None) => Some(extract_int_arg(value, heap)?),
+        _ => None,
+    };
+    let start = raw_start.map_or(0, |index| normalize_index(index, str_len));
+    let end = match end_value {
+        Some(value) => optional_index(value, str_len, str_len, heap)?,
+        None => str_len,
+    };
+    let start_past_end = raw_start.is_some_and(|index| usize::try_from(index).is_ok_and(|index| index > str_len));
+    Ok((!start_past_end && start <= end).then_some((start, end)))
 }
 
 /// Extracts a string from a Value, returning an error if not a string.
@@ -1690,11 +1745,7 @@ fn str_partition(
 ) -> RunResult<Value> {
     let sep_value = args.get_one_arg("str.partition", heap)?;
     defer_drop!(sep_value, heap);
-    let sep = extract_string_arg(sep_value, heap, interns)?;
-
-    if sep.is_empty() {
-        return Err(ExcType::value_error_empty_separator());
-    }
+    let sep = partition_sep(sep_value, heap, interns)?;
 
     let (before, sep_found, after) = match s.find(&sep) {
         Some(pos) => (&s[..pos], &sep[..], &s[pos + sep.len()..]),
@@ -1723,11 +1774,7 @@ fn str_rpartition(
 ) -> RunResult<Value> {
     let sep_value = args.get_one_arg("str.rpartition", heap)?;
     defer_drop!(sep_value, heap);
-    let sep = extract_string_arg(sep_value, heap, interns)?;
-
-    if sep.is_empty() {
-        return Err(ExcType::value_error_empty_separator());
-    }
+    let sep = partition_sep(sep_value, heap, interns)?;
 
     let (before, sep_found, after) = match s.rfind(&sep) {
         Some(pos) => (&s[..pos], &sep[..], &s[pos + sep.len()..]),
@@ -1744,6 +1791,17 @@ fn str_rpartition(
     )?)
 }
 
+/// Extracts the separator argument of `str.partition`/`str.rpartition`.
+///
+/// Raises `TypeError` for a non-str separator and `ValueError` for an empty one.
+fn partition_sep(value: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<String> {
+    match str_value(value, heap, interns) {
+        Some("") => Err(ExcType::value_error_empty_separator()),
+        Some(sep) => Ok(sep.to_owned()),
+        None => Err(ExcType::type_error(format!("must be str, not {}", value.py_type(heap)))),
+    }
+}
+
 // =============================================================================
 // Replace/modify methods
 // =============================================================================
@@ -1848,9 +1906,11 @@ fn str_center(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, i
         s.to_owned()
     } else {
         let total_pad = width - len;
-        let left_pad = total_pad / 2;
+        check_repeat_size(fillchar.len_utf8(), total_pad, heap.tracker())?;
+        // Odd padding goes on the left when width is odd, matching CPython
+        let left_pad = total_pad / 2 + (total_pad & width & 1);
         let right_pad = total_pad - left_pad;
-        let mut result = String::with_capacity(width);
+        let mut result = String::with_capacity(s.len() + total_pad * fillchar.len_utf8());
         for _ in 0..left_pad {
             result.push(fillchar);
         }
@@ -1875,7 +1935,8 @@ fn str_ljust(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
         s.to_owned()
     } else {
         let pad = width - len;
-        let mut result = String::with_capacity(width);
+        check_repeat_size(fillchar.len_utf8(), pad, heap.tracker())?;
+        let mut result = String::with_capacity(s.len() + pad * fillchar.len_utf8());
         result.push_str(s);
         for _ in 0..pad {
             result.push(fillchar);
@@ -1897,7 +1958,8 @@ fn str_rjust(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, in
         s.to_owned()
     } else {
         let pad = width - len;
-        let mut result = String::with_capacity(width);
+        check_repeat_size(fillchar.len_utf8(), pad, heap.tracker())?;
+        let mut result = String::with_capacity(s.len() + pad * fillchar.len_utf8());
         for _ in 0..pad {
             result.push(fillchar);
         }
@@ -1964,10 +2026,11 @@ fn str_zfill(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>) ->
         s.to_owned()
     } else {
         let pad = width - len;
+        check_repeat_size(1, pad, heap.tracker())?;
         let mut chars = s.chars();
         let first = chars.next();
 
-        let mut result = String::with_capacity(width);
+        let mut result = String::with_capacity(s.len() + pad);
 
         // Handle sign prefix
         if matches!(first, Some('+' | '-')) {
@@ -1988,6 +2051,337 @@ fn str_zfill(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>) ->
     allocate_string(result, heap)
 }
 
+/// Implements Python's `str.format(*args, **kwargs)` method.
+///
+/// Supports auto-numbered (`{}`), positional (`{0}`) and named (`{name}`) fields, `.attr`
+/// and `[key]` accessors, `!s`/`!r`/`!a` conversions, and format specs (including nested
+/// fields like `{:{width}}`), which are applied with the same code as f-strings.
+fn str_format(s: &str, args: ArgValues, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Value> {
+    let (positional, kwargs) = args.into_parts();
+    defer_drop!(positional, heap);
+
+    let mut kwarg_names = Vec::with_capacity(kwargs.len());
+    let mut kwarg_values = Vec::with_capacity(kwargs.len());
+    let mut error = None;
+    for (key, value) in kwargs {
+        defer_drop!(key, heap);
+        match key.as_either_str(heap) {
+            Some(name) if error.is_none() => {
+                kwarg_names.push(name.as_str(interns).to_owned());
+                kwarg_values.push(value);
+            }
+            _ => {
+                if error.is_none() {
+                    error = Some(ExcType::type_error_kwargs_nonstring_key());
+                }
+                value.drop_with_heap(heap);
+            }
+        }
+    }
+    defer_drop!(kwarg_values, heap);
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let mut fmt_args = FormatArgs {
+        positional: positional.as_slice(),
+        kwarg_names: &kwarg_names,
+        kwarg_values,
+        numbering: FieldNumbering::Unknown,
+    };
+    let result = fmt_args.format(s, 2, heap, interns)?;
+    allocate_string(result, heap)
+}
+
+/// Arguments to `str.format` and the numbering mode seen so far.
+struct FormatArgs<'a> {
+    positional: &'a [Value],
+    kwarg_names: &'a [String],
+    kwarg_values: &'a [Value],
+    numbering: FieldNumbering,
+}
+
+/// Tracks whether a `str.format` template uses automatic or manual field numbering.
+///
+/// CPython rejects templates that mix `{}` with `{0}`, in either order.
+enum FieldNumbering {
+    Unknown,
+    Auto(usize),
+    Manual,
+}
+
+impl FormatArgs<'_> {
+    /// Formats `template`, resolving each replacement field against the arguments.
+    ///
+    /// `recursion` limits nesting of fields inside format specs: CPython allows
+    /// `{:{width}}` but not a field nested inside that.
+    fn format(
+        &mut self,
+        template: &str,
+        recursion: u8,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<String> {
+        if recursion == 0 {
+            return Err(ExcType::value_error("Max string recursion exceeded"));
+        }
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '{' => {
+                    if chars.next_if(|&(_, c)| c == '{').is_some() {
+                        result.push('{');
+                        continue;
+                    }
+                    if chars.peek().is_none() {
+                        return Err(ExcType::value_error("Single '{' encountered in format string"));
+                    }
+                    // Find the matching '}', allowing one level of nested fields in the spec
+                    let mut depth = 1;
+                    let end = chars.by_ref().find_map(|(j, c)| {
+                        match c {
+                            '{' => depth += 1,
+                            '}' => depth -= 1,
+                            _ => {}
+                        }
+                        (depth == 0).then_some(j)
+                    });
+                    let Some(end) = end else {
+                        return Err(ExcType::value_error("expected '}' before end of string"));
+                    };
+                    let field = self.format_field(&template[i + 1..end], recursion, heap, interns)?;
+                    result.push_str(&field);
+                }
+                '}' => {
+                    if chars.next_if(|&(_, c)| c == '}').is_none() {
+                        return Err(ExcType::value_error("Single '}' encountered in format string"));
+                    }
+                    result.push('}');
+                }
+                _ => result.push(c),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Formats a single replacement field: `field_name[!conversion][:format_spec]`.
+    fn format_field(
+        &mut self,
+        field: &str,
+        recursion: u8,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<String> {
+        // The field name ends at the first '!' or ':' outside of an index accessor
+        let mut in_brackets = false;
+        let name_end = field
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '[' => in_brackets = true,
+                    ']' => in_brackets = false,
+                    _ => {}
+                }
+                !in_brackets && matches!(c, '!' | ':')
+            })
+            .map_or(field.len(), |(i, _)| i);
+        let (field_name, rest) = field.split_at(name_end);
+
+        let (conversion, spec) = if let Some(rest) = rest.strip_prefix('!') {
+            let mut rest_chars = rest.chars();
+            let conversion = match rest_chars.next() {
+                Some(c @ ('s' | 'r' | 'a')) => c,
+                Some(c) => return Err(ExcType::value_error(format!("Unknown conversion specifier {c}"))),
+                None => {
+                    return Err(ExcType::value_error(
+                        "end of string while looking for conversion specifier",
+                    ));
+                }
+            };
+            let rest = rest_chars.as_str();
+            let spec = if rest.is_empty() {
+                ""
+            } else if let Some(spec) = rest.strip_prefix(':') {
+                spec
+            } else {
+                return Err(ExcType::value_error("expected ':' after conversion specifier"));
+            };
+            (Some(conversion), spec)
+        } else {
+            (None, rest.strip_prefix(':').unwrap_or(""))
+        };
+
+        // Resolve the value before expanding nested fields in the spec, so auto-numbering
+        // matches CPython: in `'{:{}}'.format(value, width)` the outer field is `{0}`
+        let value = self.resolve_field(field_name, heap, interns)?;
+        defer_drop!(value, heap);
+
+        let spec = if spec.contains(['{', '}']) {
+            Cow::Owned(self.format(spec, recursion - 1, heap, interns)?)
+        } else {
+            Cow::Borrowed(spec)
+        };
+
+        let converted = match conversion {
+            None => None,
+            Some('r') => Some(value.py_repr_checked(heap, interns)?.into_owned()),
+            Some('a') => Some(ascii_escape(&value.py_repr_checked(heap, interns)?)),
+            Some(_) => Some(value.py_str_checked(heap, interns)?.into_owned()),
+        };
+        if spec.is_empty() {
+            return match converted {
+                Some(converted) => Ok(converted),
+                None => Ok(value.py_str_checked(heap, interns)?.into_owned()),
+            };
+        }
+        let parsed = spec.parse::<ParsedFormatSpec>().map_err(|invalid| {
+            let value_type = if converted.is_some() {
+                Type::Str
+            } else {
+                value.py_type(heap)
+            };
+            ExcType::value_error(format!(
+                "Invalid format specifier '{invalid}' for object of type '{value_type}'"
+            ))
+        })?;
+        match converted {
+            Some(converted) => Ok(format_string(&converted, &parsed)?),
+            None => format_with_spec(value, &parsed, heap, interns),
+        }
+    }
+
+    /// Looks up the value for a field name, applying any `.attr` / `[key]` accessors.
+    ///
+    /// Returns a new reference which the caller must drop.
+    fn resolve_field(
+        &mut self,
+        field_name: &str,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Value> {
+        let arg_end = field_name.find(['.', '[']).unwrap_or(field_name.len());
+        let (arg_name, mut accessors) = field_name.split_at(arg_end);
+
+        let base = if arg_name.is_empty() {
+            let index = match &mut self.numbering {
+                FieldNumbering::Manual => {
+                    return Err(ExcType::value_error(
+                        "cannot switch from manual field specification to automatic field numbering",
+                    ));
+                }
+                FieldNumbering::Auto(next) => {
+                    *next += 1;
+                    *next - 1
+                }
+                FieldNumbering::Unknown => {
+                    self.numbering = FieldNumbering::Auto(1);
+                    0
+                }
+            };
+            self.positional_arg(index)?
+        } else if arg_name.bytes().all(|b| b.is_ascii_digit()) {
+            if matches!(self.numbering, FieldNumbering::Auto(_)) {
+                return Err(ExcType::value_error(
+                    "cannot switch from automatic field numbering to manual field specification",
+                ));
+            }
+            self.numbering = FieldNumbering::Manual;
+            let index = arg_name
+                .parse()
+                .map_err(|_| ExcType::value_error("Too many decimal digits in format string"))?;
+            self.positional_arg(index)?
+        } else {
+            let position = self.kwarg_names.iter().position(|name| name == arg_name);
+            let Some(position) = position else {
+                return Err(SimpleException::new_msg(ExcType::KeyError, arg_name).into());
+            };
+            &self.kwarg_values[position]
+        };
+
+        let mut value = base.clone_with_heap(heap);
+        while !accessors.is_empty() {
+            let next = if let Some(rest) = accessors.strip_prefix('.') {
+                let attr_end = rest.find(['.', '[']).unwrap_or(rest.len());
+                let (attr, rest) = rest.split_at(attr_end);
+                accessors = rest;
+                if attr.is_empty() {
+                    Err(ExcType::value_error("Empty attribute in format string"))
+                } else {
+                    get_format_attr(&value, attr, heap, interns)
+                }
+            } else if let Some(rest) = accessors.strip_prefix('[') {
+                let Some(key_end) = rest.find(']') else {
+                    value.drop_with_heap(heap);
+                    return Err(ExcType::value_error("Missing ']' in format string"));
+                };
+                let key = &rest[..key_end];
+                accessors = &rest[key_end + 1..];
+                if key.is_empty() {
+                    Err(ExcType::value_error("Empty attribute in format string"))
+                } else if !accessors.is_empty() && !accessors.starts_with(['.', '[']) {
+                    Err(ExcType::value_error(
+                        "Only '.' or '[' may follow ']' in format field specifier",
+                    ))
+                } else {
+                    get_format_item(&value, key, heap, interns)
+                }
+            } else {
+                Err(ExcType::value_error(
+                    "Only '.' or '[' may follow ']' in format field specifier",
+                ))
+            };
+            value.drop_with_heap(heap);
+            value = next?;
+        }
+        Ok(value)
+    }
+
+    /// Returns the positional argument at `index`, or CPython's `IndexError`.
+    fn positional_arg(&self, index: usize) -> RunResult<&Value> {
+        self.positional.get(index).ok_or_else(|| {
+            SimpleException::new_msg(
+                ExcType::IndexError,
+                format!("Replacement index {index} out of range for positional args tuple"),
+            )
+            .into()
+        })
+    }
+}
+
+/// Resolves a `.attr` accessor in a `str.format` field.
+fn get_format_attr(
+    value: &Value,
+    attr: &str,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    match value.py_getattr(&EitherStr::from(attr.to_owned()), heap, interns)? {
+        AttrCallResult::Value(v) => Ok(v),
+        other => {
+            other.drop_with_heap(heap);
+            Err(ExcType::type_error("str.format(): attribute is not a simple value"))
+        }
+    }
+}
+
+/// Resolves a `[key]` accessor in a `str.format` field.
+///
+/// As in CPython, an all-digit key indexes with an int and anything else with a str.
+fn get_format_item(
+    value: &Value,
+    key: &str,
+    heap: &mut Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<Value> {
+    let key = match key.parse::<i64>() {
+        Ok(index) if key.bytes().all(|b| b.is_ascii_digit()) => Value::Int(index),
+        _ => allocate_string(key.to_owned(), heap)?,
+    };
+    defer_drop!(key, heap);
+    value.py_getitem(key, heap, interns)
+}
+
 /// Implements Python's `str.encode(encoding='utf-8', errors='strict')` method.
 ///
 /// Returns an encoded version of the string as a bytes object. Only supports
@@ -2042,39 +2436,26 @@ fn parse_encode_args(
 /// Implements Python's `str.isidentifier()` predicate.
 ///
 /// Returns True if the string is a valid Python identifier according to
-/// the language definition (starts with letter or underscore, followed by
-/// letters, digits, or underscores). Empty strings return False.
+/// the language definition: an `XID_Start` character or underscore, followed by
+/// `XID_Continue` characters. Empty strings return False. Keywords are not checked.
 fn str_isidentifier(s: &str) -> bool {
-    if s.is_empty() {
-        return false;
-    }
-
     let mut chars = s.chars();
-
-    // First character must be a letter (Unicode) or underscore
-    let first = chars.next().unwrap();
-    if !is_xid_start(first) && first != '_' {
+    let Some(first) = chars.next() else {
         return false;
-    }
-
-    // Remaining characters must be letters, digits (Unicode), or underscores
-    chars.all(is_xid_continue)
-}
-
-/// Checks if a character is valid at the start of an identifier (XID_Start).
-///
-/// This is a simplified implementation that covers ASCII and common Unicode letters.
-/// Python uses the full Unicode XID_Start property.
-fn is_xid_start(c: char) -> bool {
-    c.is_alphabetic()
+    };
+    (first == '_' || unicode_ident::is_xid_start(first)) && chars.all(unicode_ident::is_xid_continue)
 }
 
-/// Checks if a character is valid in the continuation of an identifier (XID_Continue).
+/// Implements Python's `str.isprintable()` predicate.
 ///
-/// This is a simplified implementation that covers ASCII and common Unicode.
-/// Python uses the full Unicode XID_Continue property.
-fn is_xid_continue(c: char) -> bool {
-    c.is_alphanumeric() || c == '_'
+/// Returns True if the string is empty or every character is printable. As in CPython,
+/// characters in the "Other" (Cc, Cf, Cs, Co, Cn) and "Separator" (Zs, Zl, Zp) general
+/// categories are not printable, except the ASCII space.
+fn str_isprintable(s: &str) -> bool {
+    s.chars().all(|c| {
+        let group = c.general_category_group();
+        c == ' ' || !matches!(group, GeneralCategoryGroup::Other | GeneralCategoryGroup::Separator)
+    })
 }
 
 /// Implements Python's `str.istitle()` predicate.
@@ -28,7 +28,7 @@ pub(crate) type TupleVec = SmallVec<[Value; TUPLE_INLINE_CAPACITY]>;
 
 use super::{
     MontyIter, PyTrait,
-    list::{get_slice_items, repr_sequence_fmt},
+    list::{get_slice_items, repr_sequence_fmt, search_bound},
 };
 use crate::{
     args::ArgValues,
@@ -211,7 +211,7 @@ impl PyTrait for Tuple {
 
         for (i1, i2) in self.items.iter().zip(&other.items) {
             heap.check_time()?;
-            if !i1.py_eq(i2, heap, interns)? {
+            if !i1.py_eq_or_is(i2, heap, interns)? {
                 return Ok(false);
             }
         }
@@ -303,12 +303,12 @@ fn tuple_index(
         [] => return Err(ExcType::type_error_at_least("tuple.index", 1, 0)),
         [value] => (value, 0, len),
         [value, start_arg] => {
-            let start = normalize_tuple_index(start_arg.as_int(heap)?, len);
+            let start = search_bound(start_arg, len, heap)?;
             (value, start, len)
         }
         [value, start_arg, end_arg] => {
-            let start = normalize_tuple_index(start_arg.as_int(heap)?, len);
-            let end = normalize_tuple_index(end_arg.as_int(heap)?, len).max(start);
+            let start = search_bound(start_arg, len, heap)?;
+            let end = search_bound(end_arg, len, heap)?.max(start);
             (value, start, end)
         }
         other => return Err(ExcType::type_error_at_most("tuple.index", 3, other.len())),
@@ -316,7 +316,8 @@ fn tuple_index(
 
     // Search for the value in the specified range
     for (i, item) in tuple.as_slice()[start..end].iter().enumerate() {
-        if value.py_eq(item, heap, interns)? {
+        heap.check_time()?;
+        if value.py_eq_or_is(item, heap, interns)? {
             let idx = i64::try_from(start + i).expect("index exceeds i64::MAX");
             return Ok(Value::Int(idx));
         }
@@ -339,7 +340,8 @@ fn tuple_count(
 
     let mut count = 0usize;
     for item in tuple.as_slice() {
-        if value.py_eq(item, heap, interns)? {
+        heap.check_time()?;
+        if value.py_eq_or_is(item, heap, interns)? {
             count += 1;
         }
     }
@@ -347,13 +349,3 @@ fn tuple_count(
     let count_i64 = i64::try_from(count).expect("count exceeds i64::MAX");
     Ok(Value::Int(count_i64))
 }
-
-/// Normalizes a Python-style tuple index to a valid index in range [0, len].
-fn normalize_tuple_index(index: i64, len: usize) -> usize {
-    if index < 0 {
-        let abs_index = usize::try_from(-index).unwrap_or(usize::MAX);
-        len.saturating_sub(abs_index)
-    } else {
-        usize::try_from(index).unwrap_or(len).min(len)
-    }
-}
@@ -4,13 +4,14 @@ use num_bigint::BigInt;
 
 use crate::{
     args::ArgValues,
+    bytecode::VM,
     defer_drop,
     exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::{Heap, HeapData},
-    intern::Interns,
     resource::ResourceTracker,
     types::{
-        Bytes, Dict, FrozenSet, List, LongInt, MontyIter, Path, PyTrait, Range, Set, Slice, Str, Tuple, str::StringRepr,
+        Bytes, Dict, FrozenSet, List, LongInt, MethodDescriptor, MethodDescriptorKind, MontyIter, Path, PropertyObject,
+        PyTrait, Range, Set, Slice, Str, Tuple, str::StringRepr,
     },
     value::Value,
 };
@@ -57,6 +58,18 @@ pub enum Type {
     Path,
     /// A property descriptor - displays as "property"
     Property,
+    /// An instance of a class defined in the script - displays as "object"
+    Instance,
+    /// A function bound to an instance of a class defined in the script - displays as "method"
+    Method,
+    /// A function wrapped by `staticmethod` - displays as "staticmethod"
+    StaticMethod,
+    /// A function wrapped by `classmethod` - displays as "classmethod"
+    ClassMethod,
+    /// A `collections.Counter`, a dict subtype - displays as "Counter"
+    Counter,
+    /// A hash object from `hashlib` - displays as "_hashlib.HASH"
+    Hash,
 }
 
 impl fmt::Display for Type {
@@ -90,6 +103,12 @@ impl fmt::Display for Type {
             Self::SpecialForm => f.write_str("typing._SpecialForm"),
             Self::Path => f.write_str("PosixPath"),
             Self::Property => f.write_str("property"),
+            Self::Instance => f.write_str("object"),
+            Self::Method => f.write_str("method"),
+            Self::StaticMethod => f.write_str("staticmethod"),
+            Self::ClassMethod => f.write_str("classmethod"),
+            Self::Counter => f.write_str("Counter"),
+            Self::Hash => f.write_str("_hashlib.HASH"),
         }
     }
 }
@@ -121,6 +140,8 @@ impl Type {
             "iter" => Some(Self::Iterator),
             "type" => Some(Self::Type),
             "property" => Some(Self::Property),
+            "staticmethod" => Some(Self::StaticMethod),
+            "classmethod" => Some(Self::ClassMethod),
             _ => None,
         }
     }
@@ -129,6 +150,7 @@ impl Type {
     ///
     /// This handles Python's subtype relationships:
     /// - `bool` is a subtype of `int` (so `isinstance(True, int)` returns True)
+    /// - `Counter` is a subtype of `dict`
     #[must_use]
     pub fn is_instance_of(self, other: Self) -> bool {
         if self == other {
@@ -136,6 +158,9 @@ impl Type {
         } else if self == Self::Bool && other == Self::Int {
             // bool is a subtype of int in Python
             true
+        } else if self == Self::Counter && other == Self::Dict {
+            // Counter is a subclass of dict
+            true
         } else {
             false
         }
@@ -194,12 +219,14 @@ impl Type {
     ///
     /// Dispatches to the appropriate type's init method for container types,
     /// or handles primitive type conversions inline.
-    pub(crate) fn call(
-        self,
-        heap: &mut Heap<impl ResourceTracker>,
-        args: ArgValues,
-        interns: &Interns,
-    ) -> RunResult<Value> {
+    ///
+    /// Takes the VM because `iter(callable, sentinel)` calls back into the script.
+    pub(crate) fn call(self, vm: &mut VM<impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        if self == Self::Iterator {
+            return MontyIter::init(vm, args);
+        }
+        let heap = &mut *vm.heap;
+        let interns = vm.interns;
         match self {
             // Container types - delegate to init methods
             Self::List => List::init(heap, args, interns),
@@ -211,8 +238,10 @@ impl Type {
             Self::Bytes => Bytes::init(heap, args, interns),
             Self::Range => Range::init(heap, args),
             Self::Slice => Slice::init(heap, args),
-            Self::Iterator => MontyIter::init(heap, args, interns),
             Self::Path => Path::init(heap, args, interns),
+            Self::Property => PropertyObject::init(heap, args),
+            Self::StaticMethod => MethodDescriptor::init(MethodDescriptorKind::Static, heap, args),
+            Self::ClassMethod => MethodDescriptor::init(MethodDescriptorKind::Class, heap, args),
 
             // Primitive types - inline implementation
             Self::Int => {
@@ -352,6 +381,14 @@ fn parse_int_from_str(value: &str, heap: &mut Heap<impl ResourceTracker>) -> Run
         return Ok(Value::Int(int));
     }
 
+    // Check the digit limit before the quadratic-time decimal parse
+    if let Some(limit) = heap.tracker().int_max_str_digits() {
+        let digits = normalized.trim_start_matches(['+', '-']).len();
+        if digits > limit {
+            return Err(ExcType::value_error_int_parse_digits(limit, digits));
+        }
+    }
+
     // Try parsing as BigInt for values too large for i64
     if let Ok(bi) = normalized.parse::<BigInt>() {
         return Ok(LongInt::new(bi).into_value(heap)?);
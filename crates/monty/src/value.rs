@@ -11,22 +11,26 @@ use std::{
 use ahash::AHashSet;
 use num_bigint::BigInt;
 use num_integer::Integer;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
 use crate::{
+    args::ArgValues,
     asyncio::CallId,
     builtins::Builtins,
+    defer_drop,
     exception_private::{ExcType, RunError, RunResult, SimpleException},
+    fstring::float_repr,
     heap::{Heap, HeapData, HeapId},
     heap_data::HeapDataMut,
     intern::{BytesId, ExtFunctionId, FunctionId, Interns, LongIntId, StaticStrings, StringId},
     modules::ModuleFunctions,
     resource::{ResourceError, ResourceTracker, check_div_size, check_lshift_size, check_pow_size, check_repeat_size},
     types::{
-        AttrCallResult, LongInt, Property, PyTrait, Str, Type,
-        bytes::{bytes_repr_fmt, get_byte_at_index, get_bytes_slice},
+        AttrCallResult, BoundMethod, ClassObject, Instance, InstanceAttr, LongInt, Property, PyTrait, Str, Type,
+        bytes::{bytes_contains, bytes_repr_fmt, get_byte_at_index, get_bytes_slice},
+        long_int::{bigint_hash, float_hash, int_hash},
         path,
-        str::{allocate_char, get_char_at_index, get_str_slice, string_repr_fmt},
+        str::{allocate_char, get_char_at_index, str_getitem_slice, string_repr_fmt},
     },
 };
 
@@ -49,7 +53,7 @@ pub(crate) enum Value {
     None,
     Bool(bool),
     Int(i64),
-    Float(f64),
+    Float(#[serde(with = "crate::serialize::float")] f64),
     /// An interned string literal. The StringId references the string in the Interns table.
     /// To get the actual string content, use `interns.get(string_id)`.
     InternString(StringId),
@@ -169,10 +173,11 @@ impl PyTrait for Value {
             (Self::Bool(v1), Self::Int(v2)) => Ok(i64::from(*v1) == *v2),
             (Self::Int(v1), Self::Bool(v2)) => Ok(*v1 == i64::from(*v2)),
             (Self::Float(v1), Self::Float(v2)) => Ok(v1 == v2),
-            (Self::Int(v1), Self::Float(v2)) => Ok((*v1 as f64) == *v2),
-            (Self::Float(v1), Self::Int(v2)) => Ok(*v1 == (*v2 as f64)),
-            (Self::Bool(v1), Self::Float(v2)) => Ok((i64::from(*v1) as f64) == *v2),
-            (Self::Float(v1), Self::Bool(v2)) => Ok(*v1 == (i64::from(*v2) as f64)),
+            // Int == Float comparison, exact so that equal values also hash equally
+            (Self::Int(i), Self::Float(f)) | (Self::Float(f), Self::Int(i)) => {
+                Ok(float_cmp_int(*f, *i) == Some(Ordering::Equal))
+            }
+            (Self::Bool(b), Self::Float(f)) | (Self::Float(f), Self::Bool(b)) => Ok(*f == f64::from(u8::from(*b))),
             (Self::None, Self::None) => Ok(true),
 
             // Int == LongInt comparison
@@ -192,6 +197,15 @@ impl PyTrait for Value {
                 }
             }
 
+            // Float == LongInt comparison, exact as in CPython
+            (Self::Float(f), Self::Ref(id)) | (Self::Ref(id), Self::Float(f)) => {
+                if let HeapData::LongInt(li) = heap.get(*id) {
+                    Ok(f.fract() == 0.0 && BigInt::from_f64(*f).is_some_and(|bi| bi == *li.inner()))
+                } else {
+                    Ok(false)
+                }
+            }
+
             // For interned interns, compare by StringId first (fast path for same interned string)
             (Self::InternString(s1), Self::InternString(s2)) => Ok(s1 == s2),
             // for strings we need to account for the fact they might be either interned or not
@@ -264,8 +278,8 @@ impl PyTrait for Value {
         match (self, other) {
             (Self::Int(s), Self::Int(o)) => Ok(s.partial_cmp(o)),
             (Self::Float(s), Self::Float(o)) => Ok(s.partial_cmp(o)),
-            (Self::Int(s), Self::Float(o)) => Ok((*s as f64).partial_cmp(o)),
-            (Self::Float(s), Self::Int(o)) => Ok(s.partial_cmp(&(*o as f64))),
+            (Self::Int(s), Self::Float(o)) => Ok(float_cmp_int(*o, *s).map(Ordering::reverse)),
+            (Self::Float(s), Self::Int(o)) => Ok(float_cmp_int(*s, *o)),
             // Bool promotion: convert to Int and re-dispatch. Recursion is bounded
             // to at most 2 levels (Bool→Int, then Int matches directly above).
             (Self::Bool(s), _) => Self::Int(i64::from(*s)).py_cmp(other, heap, interns),
@@ -286,6 +300,21 @@ impl PyTrait for Value {
                     Ok(None)
                 }
             }
+            // Float vs LongInt comparison, exact as in CPython
+            (Self::Float(f), Self::Ref(id)) => {
+                if let HeapData::LongInt(li) = heap.get(*id) {
+                    Ok(float_cmp_bigint(*f, li.inner()))
+                } else {
+                    Ok(None)
+                }
+            }
+            (Self::Ref(id), Self::Float(f)) => {
+                if let HeapData::LongInt(li) = heap.get(*id) {
+                    Ok(float_cmp_bigint(*f, li.inner()).map(Ordering::reverse))
+                } else {
+                    Ok(None)
+                }
+            }
             // Ref vs Ref comparison: handles LongInt and Str
             (Self::Ref(id1), Self::Ref(id2)) => match (heap.get(*id1), heap.get(*id2)) {
                 (HeapData::LongInt(a), HeapData::LongInt(b)) => Ok(a.inner().partial_cmp(b.inner())),
@@ -364,15 +393,15 @@ impl PyTrait for Value {
             Self::Bool(true) => f.write_str("True"),
             Self::Bool(false) => f.write_str("False"),
             Self::Int(v) => write!(f, "{v}"),
-            Self::InternLongInt(long_int_id) => write!(f, "{}", interns.get_long_int(*long_int_id)),
-            Self::Float(v) => {
-                let s = v.to_string();
-                if s.contains('.') {
-                    f.write_str(&s)
+            Self::InternLongInt(long_int_id) => {
+                let long_int = interns.get_long_int(*long_int_id);
+                if heap.long_int_str_fits(long_int) {
+                    write!(f, "{long_int}")
                 } else {
-                    write!(f, "{s}.0")
+                    f.write_str("...")
                 }
             }
+            Self::Float(v) => f.write_str(&float_repr(*v)),
             Self::Builtin(b) => b.py_repr_fmt(f),
             Self::ModuleFunction(mf) => mf.py_repr_fmt(f, self.id()),
             Self::DefFunction(f_id) => interns.get_function(*f_id).py_repr_fmt(f, interns, self.id()),
@@ -629,9 +658,10 @@ impl PyTrait for Value {
                     (*v2 != 0).then_some(0 == right_value)
                 }
             }
-            (Self::Float(v1), Self::Float(v2)) => Some(v1 % v2 == right_value as f64),
-            (Self::Float(v1), Self::Int(v2)) => Some(v1 % (*v2 as f64) == right_value as f64),
-            (Self::Int(v1), Self::Float(v2)) => Some((*v1 as f64) % v2 == right_value as f64),
+            // A zero divisor is left to `py_mod`, which raises `ZeroDivisionError`
+            (Self::Float(v1), Self::Float(v2)) if *v2 != 0.0 => Some(v1 % v2 == right_value as f64),
+            (Self::Float(v1), Self::Int(v2)) if *v2 != 0 => Some(v1 % (*v2 as f64) == right_value as f64),
+            (Self::Int(v1), Self::Float(v2)) if *v2 != 0.0 => Some((*v1 as f64) % v2 == right_value as f64),
             _ => None,
         }
     }
@@ -780,6 +810,9 @@ impl PyTrait for Value {
             (Self::InternString(s), Self::Int(n)) | (Self::Int(n), Self::InternString(s)) => {
                 let count = i64_to_repeat_count(*n)?;
                 let str_ref = interns.get_str(*s);
+                if str_ref.len().checked_mul(count).is_none() {
+                    return Err(ExcType::overflow_repeated_str().into());
+                }
                 check_repeat_size(str_ref.len(), count, heap.tracker())?;
                 let result = str_ref.repeat(count);
                 Ok(Some(Self::Ref(heap.allocate(HeapData::Str(result.into()))?)))
@@ -789,6 +822,9 @@ impl PyTrait for Value {
             (Self::InternBytes(b), Self::Int(n)) | (Self::Int(n), Self::InternBytes(b)) => {
                 let count = i64_to_repeat_count(*n)?;
                 let bytes_ref = interns.get_bytes(*b);
+                if bytes_ref.len().checked_mul(count).is_none() {
+                    return Err(ExcType::overflow_repeated_bytes().into());
+                }
                 check_repeat_size(bytes_ref.len(), count, heap.tracker())?;
                 let result: Vec<u8> = bytes_ref.repeat(count);
                 Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(result.into()))?)))
@@ -799,6 +835,9 @@ impl PyTrait for Value {
                 if let HeapData::LongInt(li) = heap.get(*id) {
                     let count = longint_to_repeat_count(li)?;
                     let str_ref = interns.get_str(*s);
+                    if str_ref.len().checked_mul(count).is_none() {
+                        return Err(ExcType::overflow_repeated_str().into());
+                    }
                     check_repeat_size(str_ref.len(), count, heap.tracker())?;
                     let result = str_ref.repeat(count);
                     Ok(Some(Self::Ref(heap.allocate(HeapData::Str(result.into()))?)))
@@ -812,6 +851,9 @@ impl PyTrait for Value {
                 if let HeapData::LongInt(li) = heap.get(*id) {
                     let count = longint_to_repeat_count(li)?;
                     let bytes_ref = interns.get_bytes(*b);
+                    if bytes_ref.len().checked_mul(count).is_none() {
+                        return Err(ExcType::overflow_repeated_bytes().into());
+                    }
                     check_repeat_size(bytes_ref.len(), count, heap.tracker())?;
                     let result: Vec<u8> = bytes_ref.repeat(count);
                     Ok(Some(Self::Ref(heap.allocate(HeapData::Bytes(result.into()))?)))
@@ -1317,12 +1359,7 @@ impl PyTrait for Value {
                 if let Self::Ref(key_id) = key
                     && let HeapData::Slice(slice_obj) = heap.get(*key_id)
                 {
-                    let s = interns.get_str(*string_id);
-                    let char_count = s.chars().count();
-                    let (start, stop, step) = slice_obj
-                        .indices(char_count)
-                        .map_err(|()| ExcType::value_error_slice_step_zero())?;
-                    let result_str = get_str_slice(s, start, stop, step);
+                    let result_str = str_getitem_slice(interns.get_str(*string_id), slice_obj)?;
                     let heap_id = heap.allocate(HeapData::Str(Str::from(result_str)))?;
                     return Ok(Self::Ref(heap_id));
                 }
@@ -1385,6 +1422,19 @@ impl PyTrait for Value {
             ))),
         }
     }
+
+    fn py_delitem(&mut self, key: Self, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<()> {
+        match self {
+            Self::Ref(id) => {
+                let id = *id;
+                heap.with_entry_mut(id, |heap, mut data| data.py_delitem(key, heap, interns))
+            }
+            _ => {
+                key.drop_with_heap(heap);
+                Err(ExcType::type_error_not_sub_deletion(self.py_type(heap)))
+            }
+        }
+    }
 }
 
 impl Value {
@@ -1466,6 +1516,29 @@ impl Value {
         self.id() == other.id()
     }
 
+    /// Equality as used by containers, where the same heap object always compares equal.
+    ///
+    /// This matches CPython's `PyObject_RichCompareBool`, which checks identity before
+    /// equality. It only differs from `py_eq` for values that are not equal to themselves:
+    /// a tuple holding a NaN can be found again as a dict key, while `nan == nan` stays false.
+    ///
+    /// Only heap values have an identity here. Immediate ints and floats have no object to
+    /// compare, and their `id()` is a hash, so `float('nan')` is never identical to
+    /// another NaN and `{float('nan'), float('nan')}` has two items, as in CPython.
+    pub fn py_eq_or_is(
+        &self,
+        other: &Self,
+        heap: &mut Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> Result<bool, ResourceError> {
+        if let (Self::Ref(a), Self::Ref(b)) = (self, other)
+            && a == b
+        {
+            return Ok(true);
+        }
+        self.py_eq(other, heap, interns)
+    }
+
     /// Computes the hash value for this value, used for dict keys.
     ///
     /// Returns `Ok(Some(hash))` for hashable types (immediate values and immutable heap types).
@@ -1483,7 +1556,7 @@ impl Value {
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> Result<Option<u64>, ResourceError> {
-        // strings bytes bigints and heap allocated values have their own hashing logic
+        // strings, bytes, numbers and heap allocated values have their own hashing logic
         match self {
             // Hash just the actual string or bytes content for consistency with heap Str/Bytes
             // hence we don't include the discriminant
@@ -1497,15 +1570,12 @@ impl Value {
                 interns.get_bytes(*bytes_id).hash(&mut hasher);
                 return Ok(Some(hasher.finish()));
             }
-            // Hash BigInt consistently with LongInt (using sign and bytes for large values)
-            Self::InternLongInt(long_int_id) => {
-                let bi = interns.get_long_int(*long_int_id);
-                let mut hasher = DefaultHasher::new();
-                let (sign, bytes) = bi.to_bytes_le();
-                sign.hash(&mut hasher);
-                bytes.hash(&mut hasher);
-                return Ok(Some(hasher.finish()));
-            }
+            // Numbers use Python's numeric hash so that equal ints, floats and bools collide,
+            // hence we don't include the discriminant
+            Self::Bool(b) => return Ok(Some(int_hash(i64::from(*b)))),
+            Self::Int(i) => return Ok(Some(int_hash(*i))),
+            Self::Float(f) => return Ok(Some(float_hash(*f))),
+            Self::InternLongInt(long_int_id) => return Ok(Some(bigint_hash(interns.get_long_int(*long_int_id)))),
             // For heap-allocated values (includes Range and Exception), compute hash lazily and cache it
             Self::Ref(id) => return heap.get_or_compute_hash(*id, interns),
             _ => {}
@@ -1517,10 +1587,6 @@ impl Value {
         match self {
             // Immediate values can be hashed directly
             Self::Undefined | Self::Ellipsis | Self::None => {}
-            Self::Bool(b) => b.hash(&mut hasher),
-            Self::Int(i) => i.hash(&mut hasher),
-            // Hash the bit representation of float for consistency
-            Self::Float(f) => f.to_bits().hash(&mut hasher),
             Self::Builtin(b) => b.hash(&mut hasher),
             Self::ModuleFunction(mf) => mf.hash(&mut hasher),
             // Hash functions based on function ID
@@ -1532,7 +1598,13 @@ impl Value {
             Self::Property(p) => p.hash(&mut hasher),
             // ExternalFutures are hashable based on their call ID
             Self::ExternalFuture(call_id) => call_id.raw().hash(&mut hasher),
-            Self::InternString(_) | Self::InternBytes(_) | Self::InternLongInt(_) | Self::Ref(_) => {
+            Self::InternString(_)
+            | Self::InternBytes(_)
+            | Self::Bool(_)
+            | Self::Int(_)
+            | Self::Float(_)
+            | Self::InternLongInt(_)
+            | Self::Ref(_) => {
                 unreachable!("covered above")
             }
             #[cfg(feature = "ref-count-panic")]
@@ -1541,14 +1613,15 @@ impl Value {
         Ok(Some(hasher.finish()))
     }
 
-    /// TODO this doesn't have many tests!!! also doesn't cover bytes
     /// Checks if `item` is contained in `self` (the container).
     ///
     /// Implements Python's `in` operator for various container types:
     /// - List/Tuple: linear search with equality
-    /// - Dict: key lookup
-    /// - Set/FrozenSet: element lookup
+    /// - Dict: O(1) key lookup via the hash table
+    /// - Set/FrozenSet: O(1) element lookup via the hash table
     /// - Str: substring search
+    /// - Bytes: byte value or subsequence search
+    /// - Range: O(1) bounds and step check
     pub fn py_contains(
         &self,
         item: &Self,
@@ -1557,13 +1630,17 @@ impl Value {
     ) -> RunResult<bool> {
         match self {
             Self::Ref(heap_id) => {
+                // The container is taken out of the heap below, so `x in x` needs its own path
+                if matches!(item, Self::Ref(item_id) if item_id == heap_id) {
+                    return contains_itself(item, heap, interns);
+                }
                 // Use with_entry_mut to temporarily take ownership of the container.
                 // This allows iterating over container elements while calling py_eq
                 // (which needs &mut Heap for comparing nested heap values).
                 heap.with_entry_mut(*heap_id, |heap, data| match data {
                     HeapDataMut::List(list) => {
                         for el in list.as_slice() {
-                            if item.py_eq(el, heap, interns)? {
+                            if item.py_eq_or_is(el, heap, interns)? {
                                 return Ok(true);
                             }
                         }
@@ -1571,7 +1648,7 @@ impl Value {
                     }
                     HeapDataMut::Tuple(tuple) => {
                         for el in tuple.as_slice() {
-                            if item.py_eq(el, heap, interns)? {
+                            if item.py_eq_or_is(el, heap, interns)? {
                                 return Ok(true);
                             }
                         }
@@ -1581,6 +1658,7 @@ impl Value {
                     HeapDataMut::Set(set) => set.contains(item, heap, interns),
                     HeapDataMut::FrozenSet(fset) => fset.contains(item, heap, interns),
                     HeapDataMut::Str(s) => str_contains(s.as_str(), item, heap, interns),
+                    HeapDataMut::Bytes(b) => bytes_contains(b.as_slice(), item, heap, interns),
                     HeapDataMut::Range(range) => {
                         // Range containment is O(1) - check bounds and step alignment
                         let n = match item {
@@ -1619,6 +1697,7 @@ impl Value {
                 let container_str = interns.get_str(*string_id);
                 str_contains(container_str, item, heap, interns)
             }
+            Self::InternBytes(bytes_id) => bytes_contains(interns.get_bytes(*bytes_id), item, heap, interns),
             _ => {
                 let type_name = self.py_type(heap);
                 Err(ExcType::type_error(format!(
@@ -1648,6 +1727,27 @@ impl Value {
                 if let Some(call_result) = opt_result {
                     return Ok(call_result);
                 }
+                // Instances and classes fall back to the class attributes, binding methods as needed
+                let lookup = match heap.get(*heap_id) {
+                    HeapData::Instance(_) => Some(Instance::lookup(*heap_id, attr, heap, interns)?),
+                    HeapData::Class(_) => Some(ClassObject::lookup(*heap_id, attr, heap, interns)?),
+                    _ => None,
+                };
+                if let Some(lookup) = lookup {
+                    let value = match lookup {
+                        InstanceAttr::Value(value) => value,
+                        InstanceAttr::Method { func, self_value } => {
+                            let method = BoundMethod::new(func, self_value);
+                            Self::Ref(heap.allocate(HeapData::BoundMethod(method))?)
+                        }
+                        InstanceAttr::Property(getter) => {
+                            heap.inc_ref(*heap_id);
+                            let args = ArgValues::One(Self::Ref(*heap_id));
+                            return Ok(AttrCallResult::CallFunction(getter, args));
+                        }
+                    };
+                    return Ok(AttrCallResult::Value(value));
+                }
             }
             Self::Builtin(Builtins::Type(t)) => {
                 // Handle type object attributes like __name__
@@ -1669,40 +1769,38 @@ impl Value {
 
     /// Sets an attribute on this value.
     ///
-    /// Currently only Dataclass objects support attribute setting.
-    /// Returns AttributeError for other types.
+    /// Currently only Dataclass objects and instances of script classes support attribute
+    /// setting. Returns AttributeError for other types.
+    ///
+    /// Accepts `EitherStr` so both `obj.attr = v` (interned name) and `setattr(obj, name, v)`
+    /// (possibly heap-allocated name) share this path.
     ///
     /// Takes ownership of `value` and drops it on error.
     /// On success, drops the old attribute value if one existed.
     pub fn py_set_attr(
         &self,
-        name_id: StringId,
+        attr: &EitherStr,
         value: Self,
         heap: &mut Heap<impl ResourceTracker>,
         interns: &Interns,
     ) -> RunResult<()> {
-        let attr_name = interns.get_str(name_id);
+        let attr_name = attr.as_str(interns);
 
         if let Self::Ref(heap_id) = self {
             let heap_id = *heap_id;
-            let is_dataclass = matches!(heap.get(heap_id), HeapData::Dataclass(_));
+            let supports_setattr = matches!(heap.get(heap_id), HeapData::Dataclass(_) | HeapData::Instance(_));
 
-            if is_dataclass {
-                let name_value = Self::InternString(name_id);
+            if supports_setattr {
                 heap.with_entry_mut(heap_id, |heap, data| {
-                    if let HeapDataMut::Dataclass(dc) = data {
-                        match dc.set_attr(name_value, value, heap, interns) {
-                            Ok(old_value) => {
-                                if let Some(old) = old_value {
-                                    old.drop_with_heap(heap);
-                                }
-                                Ok(())
-                            }
-                            Err(e) => Err(e),
-                        }
-                    } else {
-                        unreachable!("type changed during borrow")
+                    let result = match data {
+                        HeapDataMut::Dataclass(dc) => dc.set_attr(attr, value, heap, interns),
+                        HeapDataMut::Instance(instance) => instance.set_attr(attr, value, heap, interns),
+                        _ => unreachable!("type changed during borrow"),
+                    };
+                    if let Some(old) = result? {
+                        old.drop_with_heap(heap);
                     }
+                    Ok(())
                 })
             } else {
                 let type_name = heap.get(heap_id).py_type(heap);
@@ -1768,6 +1866,42 @@ impl Value {
         }
     }
 
+    /// Checks a `LongInt` against the `int_max_str_digits` limit before formatting it
+    /// with a format spec, see `LongInt::check_str_digits`.
+    ///
+    /// Every other value passes. `py_str_checked` and `py_repr_checked` cover LongInts
+    /// inside containers too.
+    pub fn check_int_str_digits(&self, heap: &Heap<impl ResourceTracker>) -> RunResult<()> {
+        if let Self::Ref(id) = self
+            && let HeapData::LongInt(li) = heap.get(*id)
+        {
+            li.check_str_digits(heap.tracker())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `str()` of the value, raising `ValueError` if that would convert a LongInt
+    /// anywhere in it past the `int_max_str_digits` limit.
+    pub fn py_str_checked(&self, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<Cow<'static, str>> {
+        match heap.catch_int_str_digits_exceeded(|| self.py_str(heap, interns)) {
+            (_, Some(limit)) => Err(ExcType::value_error_int_str_digits(limit)),
+            (s, None) => Ok(s),
+        }
+    }
+
+    /// Returns `repr()` of the value, raising `ValueError` like `py_str_checked`.
+    pub fn py_repr_checked(
+        &self,
+        heap: &Heap<impl ResourceTracker>,
+        interns: &Interns,
+    ) -> RunResult<Cow<'static, str>> {
+        match heap.catch_int_str_digits_exceeded(|| self.py_repr(heap, interns)) {
+            (_, Some(limit)) => Err(ExcType::value_error_int_str_digits(limit)),
+            (s, None) => Ok(s),
+        }
+    }
+
     /// Performs a binary bitwise operation on two values.
     ///
     /// Python only supports bitwise operations on integers (and bools, which coerce to int).
@@ -1984,6 +2118,46 @@ impl From<StaticStrings> for EitherStr {
     }
 }
 
+/// Compares a float with an int exactly, without rounding the int to a float.
+///
+/// Ints beyond 2**53 are not all representable as floats, so `i as f64` would make
+/// `2**53 + 1` equal to `2.0**53`. Floats within the i64 range are floored to an i64
+/// instead, which is exact. Returns `None` for NaN, which is unordered.
+#[expect(clippy::cast_possible_truncation)]
+fn float_cmp_int(f: f64, i: i64) -> Option<Ordering> {
+    // 2**63, the first float above the i64 range
+    const I64_END: f64 = 9_223_372_036_854_775_808.0;
+    if f.is_nan() {
+        None
+    } else if f >= I64_END {
+        Some(Ordering::Greater)
+    } else if f < -I64_END {
+        Some(Ordering::Less)
+    } else {
+        let floor = f.floor();
+        // In range and integral, so the cast is exact
+        let fraction = if f > floor { Ordering::Greater } else { Ordering::Equal };
+        Some((floor as i64).cmp(&i).then(fraction))
+    }
+}
+
+/// Compares a float with an arbitrary precision int exactly, without rounding the int to a float.
+///
+/// Returns `None` for NaN, which is unordered.
+fn float_cmp_bigint(f: f64, bi: &BigInt) -> Option<Ordering> {
+    if f.is_nan() {
+        return None;
+    }
+    if f.is_infinite() {
+        return Some(if f > 0.0 { Ordering::Greater } else { Ordering::Less });
+    }
+    let floor = f.floor();
+    let floor_bi = BigInt::from_f64(floor).expect("finite float converts to BigInt");
+    // A fractional part only matters when the integer parts are equal
+    let fraction = if f > floor { Ordering::Greater } else { Ordering::Equal };
+    Some(floor_bi.cmp(bi).then(fraction))
+}
+
 /// Convert String to EitherStr: use Interned for known static strings,
 /// otherwise use Heap for user-defined field names.
 impl From<String> for EitherStr {
@@ -2041,6 +2215,18 @@ impl EitherStr {
         }
     }
 
+    /// Converts this `EitherStr` into an owned Python `str` value.
+    ///
+    /// Interned strings become `Value::InternString` without allocating; heap strings
+    /// are copied into a new heap `Str`, so the caller owns the returned reference and
+    /// must drop it with `drop_with_heap`.
+    pub fn to_value(&self, heap: &mut Heap<impl ResourceTracker>) -> Result<Value, ResourceError> {
+        match self {
+            Self::Interned(id) => Ok(Value::InternString(*id)),
+            Self::Heap(s) => Ok(Value::Ref(heap.allocate(HeapData::Str(Str::from(s.as_str())))?)),
+        }
+    }
+
     pub fn py_estimate_size(&self) -> usize {
         match self {
             Self::Interned(_) => 0,
@@ -2305,12 +2491,15 @@ fn i64_to_repeat_count(n: i64) -> RunResult<usize> {
 
 /// Converts a LongInt repeat count to usize, handling negative values and overflow.
 ///
-/// Returns 0 for negative values (Python treats negative repeat counts as 0).
-/// Returns `OverflowError` if the value exceeds `usize::MAX`.
+/// Returns 0 for negative values that fit in an `i64` (Python treats negative repeat
+/// counts as 0). Returns `OverflowError` if the value is outside the index-sized range
+/// in either direction, matching CPython.
 #[inline]
 fn longint_to_repeat_count(li: &LongInt) -> RunResult<usize> {
     if li.is_negative() {
-        Ok(0)
+        li.to_i64()
+            .map(|_| 0)
+            .ok_or_else(|| ExcType::overflow_repeat_count().into())
     } else if let Some(count) = li.to_usize() {
         Ok(count)
     } else {
@@ -2318,11 +2507,11 @@ fn longint_to_repeat_count(li: &LongInt) -> RunResult<usize> {
     }
 }
 
-/// Extracts a BigInt from a Value for bitwise operations.
+/// Extracts a BigInt from a Value for bitwise operations and modular `pow()`.
 ///
 /// Returns `Some(BigInt)` for Int, Bool, and LongInt values.
 /// Returns `None` for other types (Float, Str, etc.).
-fn extract_bigint(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<BigInt> {
+pub(crate) fn extract_bigint(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<BigInt> {
     match value {
         Value::Int(i) => Some(BigInt::from(*i)),
         Value::Bool(b) => Some(BigInt::from(i64::from(*b))),
@@ -2337,6 +2526,38 @@ fn extract_bigint(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<Bi
     }
 }
 
+/// Implements `x in x`, where `item` is a reference to the container itself.
+///
+/// Strings and bytes always contain themselves. Lists and tuples are searched by
+/// equality against a snapshot of their items, so comparisons can still read the
+/// container. Sets and ranges never contain themselves, and dicts reject themselves
+/// as unhashable keys, matching CPython.
+fn contains_itself(item: &Value, heap: &mut Heap<impl ResourceTracker>, interns: &Interns) -> RunResult<bool> {
+    let Value::Ref(id) = item else {
+        unreachable!("contains_itself called with a non-heap item")
+    };
+    let items: Vec<Value> = match heap.get(*id) {
+        HeapData::Str(_) | HeapData::Bytes(_) => return Ok(true),
+        HeapData::Set(_) | HeapData::FrozenSet(_) | HeapData::Range(_) => return Ok(false),
+        HeapData::Dict(_) => return Err(ExcType::type_error_unhashable_dict_key(Type::Dict)),
+        HeapData::List(list) => list.as_slice().iter().map(|v| v.clone_with_heap(heap)).collect(),
+        HeapData::Tuple(tuple) => tuple.as_slice().iter().map(|v| v.clone_with_heap(heap)).collect(),
+        other => {
+            let type_name = other.py_type(heap);
+            return Err(ExcType::type_error(format!(
+                "argument of type '{type_name}' is not iterable"
+            )));
+        }
+    };
+    defer_drop!(items, heap);
+    for el in items {
+        if item.py_eq_or_is(el, heap, interns)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 /// Helper for substring containment check in strings.
 ///
 /// Called by `py_contains` when the container is a string.
@@ -2409,7 +2630,7 @@ mod tests {
     use num_bigint::BigInt;
 
     use super::*;
-    use crate::resource::NoLimitTracker;
+    use crate::{heap::DropWithHeap, resource::NoLimitTracker};
 
     /// Creates a heap and directly allocates a LongInt with the given BigInt value.
     ///
@@ -2531,4 +2752,75 @@ mod tests {
         assert!(result.is_err());
         value.drop_with_heap(&mut heap);
     }
+
+    /// Creates a minimal Interns for testing.
+    fn create_test_interns() -> Interns {
+        Interns::new(crate::intern::InternerBuilder::new(""), vec![], vec![])
+    }
+
+    /// Tests that an i64-range LongInt compares equal to, and ordered against, the same `Value::Int`.
+    ///
+    /// Such LongInts are normally demoted by `LongInt::into_value()`, but must still behave as
+    /// the same number if they occur, e.g. via deserialization.
+    #[test]
+    fn longint_in_i64_range_matches_int() {
+        let interns = create_test_interns();
+        let (mut heap, heap_id) = create_heap_with_longint(BigInt::from(i64::MAX));
+        let long = Value::Ref(heap_id);
+        let int = Value::Int(i64::MAX);
+
+        assert!(long.py_eq(&int, &mut heap, &interns).unwrap());
+        assert!(int.py_eq(&long, &mut heap, &interns).unwrap());
+        assert_eq!(long.py_cmp(&int, &mut heap, &interns).unwrap(), Some(Ordering::Equal));
+        assert_eq!(
+            long.py_cmp(&Value::Int(i64::MAX - 1), &mut heap, &interns).unwrap(),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            long.py_hash(&mut heap, &interns).unwrap(),
+            int.py_hash(&mut heap, &interns).unwrap()
+        );
+        long.drop_with_heap(&mut heap);
+    }
+
+    /// Tests that an i64-range LongInt and the equal `Value::Int` are the same dict key.
+    #[test]
+    fn longint_in_i64_range_is_same_dict_key_as_int() {
+        let interns = create_test_interns();
+        let (mut heap, heap_id) = create_heap_with_longint(BigInt::from(-7));
+        let mut dict = crate::types::Dict::new();
+
+        dict.set(Value::Ref(heap_id), Value::Int(1), &mut heap, &interns)
+            .unwrap();
+        let old = dict.set(Value::Int(-7), Value::Int(2), &mut heap, &interns).unwrap();
+        assert!(matches!(old, Some(Value::Int(1))));
+        assert_eq!(dict.len(), 1);
+        assert!(matches!(
+            dict.get(&Value::Int(-7), &mut heap, &interns).unwrap(),
+            Some(Value::Int(2))
+        ));
+        dict.drop_with_heap(&mut heap);
+    }
+
+    /// Tests that floats compare exactly against LongInts beyond f64 precision.
+    #[test]
+    fn float_compares_exactly_with_longint() {
+        let interns = create_test_interns();
+        // 2**64 + 1 rounds to 2**64 as a float, but must still compare greater
+        let (mut heap, heap_id) = create_heap_with_longint(BigInt::from(2).pow(64) + 1);
+        let long = Value::Ref(heap_id);
+        let float = Value::Float(18_446_744_073_709_551_616.0);
+
+        assert_eq!(
+            long.py_cmp(&float, &mut heap, &interns).unwrap(),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(float.py_cmp(&long, &mut heap, &interns).unwrap(), Some(Ordering::Less));
+        assert_eq!(
+            Value::Float(f64::INFINITY).py_cmp(&long, &mut heap, &interns).unwrap(),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(Value::Float(f64::NAN).py_cmp(&long, &mut heap, &interns).unwrap(), None);
+        long.drop_with_heap(&mut heap);
+    }
 }
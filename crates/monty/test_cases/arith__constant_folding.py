@@ -0,0 +1,68 @@
+# Operations on literals are folded at prepare time; results must match runtime evaluation.
+x = 7
+y = 2.5
+
+# === Int arithmetic ===
+assert 2 + 3 == x - 2, 'add'
+assert 10 - 12 == -2, 'sub'
+assert 6 * 7 == 42, 'mult'
+assert 7 // 2 == x // 2, 'floordiv'
+assert -7 // 2 == -x // 2, 'floordiv negative'
+assert -7 % 3 == -x % 3, 'mod negative'
+assert 7 % -3 == x % -3, 'mod negative divisor'
+assert 6 & 3 == 2, 'bitand'
+assert 6 | 3 == 7, 'bitor'
+assert 6 ^ 3 == 5, 'bitxor'
+assert 7 / 2 == x / 2, 'true division'
+assert -(-5) == 5, 'double negation'
+assert 1 + 2 * 3 - 4 == 3, 'nested'
+
+# === Float arithmetic ===
+assert 1.5 + 1 == y, 'float add'
+assert 2 * 1.25 == y, 'int times float'
+assert 1.0 / 4 == 0.25, 'float division'
+assert -2.5 == -y, 'float negation'
+assert repr(-0.0) == '-0.0', 'negative zero'
+
+# === Strings ===
+s = 'ab' + 'cd' + 'ef'
+assert s == 'abcdef', 'string concatenation'
+assert 'ab' + 'cd' + str(x) + 'ef' == 'abcd7ef', 'string concatenation before a non-literal'
+assert len('héllo') == 5, 'len of literal counts characters'
+assert len('') == 0, 'len of empty literal'
+
+# === Overflow is left to runtime ===
+assert 1e308 * 10 == float('inf'), 'float overflow'
+assert 1e308 * 10 - 1e308 * 10 != 0, 'float overflow to nan'
+big = 9223372036854775807 + 1
+assert big == 9223372036854775808, 'add overflow promotes'
+assert 3037000500 * 3037000500 == 9223372037000250000, 'mult overflow promotes'
+assert -9223372036854775807 - 1 - 1 == -9223372036854775809, 'sub overflow promotes'
+
+# === Errors still raise at runtime ===
+try:
+    1 // 0
+    assert False, 'floordiv by zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    1 % 0
+    assert False, 'mod by zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    1.0 / 0
+    assert False, 'float division by zero should raise'
+except ZeroDivisionError:
+    pass
+
+try:
+    'a' + 1
+    assert False, 'str plus int should raise'
+except TypeError:
+    pass
+
+1 / 0
+# Raise=ZeroDivisionError('division by zero')
@@ -0,0 +1,5 @@
+# xfail=cpython
+a = 1
+b = 2
+assert a == b
+# Raise=AssertionError('assert 1 == 2')
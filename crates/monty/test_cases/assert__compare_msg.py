@@ -0,0 +1,57 @@
+# xfail=cpython
+# Failed comparisons without a message show the reprs of both operands
+def assert_message(f):
+    try:
+        f()
+    except AssertionError as e:
+        return str(e)
+    return None
+
+
+def lt():
+    x = 'abc'
+    assert len(x) < 2
+
+
+def not_in():
+    assert 3 not in [1, 2, 3]
+
+
+def is_none():
+    assert 'x' is None
+
+
+def explicit_message():
+    assert 1 == 2, 'custom'
+
+
+def not_a_comparison():
+    assert 1 - 1
+
+
+calls = []
+
+
+def side_effect():
+    calls.append(1)
+    return 1
+
+
+def single_evaluation():
+    assert side_effect() == 2
+
+
+assert assert_message(lt) == 'assert 3 < 2', 'operator and repr of call result'
+assert assert_message(not_in) == 'assert 3 not in [1, 2, 3]', 'two word operator'
+assert assert_message(is_none) == "assert 'x' is None", 'string operand uses repr'
+assert assert_message(explicit_message) == 'custom', 'explicit message is kept'
+assert assert_message(not_a_comparison) == '', 'non-comparison tests have no message'
+assert assert_message(single_evaluation) == 'assert 1 == 2', 'side effect message'
+assert calls == [1], 'operands are evaluated once'
+
+# Passing comparisons leave the stack balanced, including in loops
+total = 0
+for i in range(5):
+    assert i < 10
+    total += i
+assert total == 10, 'loop with passing assert'
@@ -1,2 +1,3 @@
+# xfail=cpython
 assert 1 == 2
-# Raise=AssertionError()
+# Raise=AssertionError('assert 1 == 2')
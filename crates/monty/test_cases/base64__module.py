@@ -0,0 +1,64 @@
+import base64
+
+# === Encoding ===
+assert base64.b64encode(b'') == b'', 'encode empty'
+assert base64.b64encode(b'f') == b'Zg==', 'encode one byte'
+assert base64.b64encode(b'fo') == b'Zm8=', 'encode two bytes'
+assert base64.b64encode(b'foo') == b'Zm9v', 'encode three bytes'
+assert base64.b64encode(b'foobar') == b'Zm9vYmFy', 'encode six bytes'
+assert base64.b64encode(b'\xfb\xff\xfe') == b'+//+', 'encode uses + and /'
+
+# === Decoding ===
+assert base64.b64decode(b'SGVsbG8sIFdvcmxkIQ==') == b'Hello, World!', 'decode known constant'
+assert base64.b64decode('SGVsbG8sIFdvcmxkIQ==') == b'Hello, World!', 'decode ascii str'
+assert base64.b64decode(b'') == b'', 'decode empty'
+assert base64.b64decode('+//+') == b'\xfb\xff\xfe', 'decode + and /'
+
+# === Round trips ===
+data = b'\x00\x01\x02\x7f\x80\x81\xfe\xffarbitrary bytes\n\t\x10'
+assert base64.b64decode(base64.b64encode(data)) == data, 'round trip arbitrary bytes'
+for n in range(10):
+    chunk = data[:n]
+    encoded = base64.b64encode(chunk)
+    assert len(encoded) % 4 == 0, 'encoded length is a multiple of 4'
+    assert base64.b64decode(encoded) == chunk, 'round trip each length'
+
+# === Errors ===
+try:
+    base64.b64decode('abc')
+    assert False, 'missing padding should raise'
+except ValueError as e:
+    assert str(e) == 'Incorrect padding', 'missing padding message'
+
+try:
+    base64.b64decode(b'abcde')
+    assert False, 'invalid length should raise'
+except ValueError as e:
+    assert (
+        str(e)
+        == 'Invalid base64-encoded string: number of data characters (5) cannot be 1 more than a multiple of 4'
+    ), 'invalid length message'
+
+try:
+    base64.b64decode('YW$j')
+    assert False, 'invalid character should raise'
+except ValueError:
+    pass
+
+try:
+    base64.b64decode('é')
+    assert False, 'non-ascii str should raise'
+except ValueError as e:
+    assert str(e) == 'string argument should contain only ASCII characters', 'non-ascii message'
+
+try:
+    base64.b64encode('text')
+    assert False, 'encoding str should raise'
+except TypeError as e:
+    assert str(e) == "a bytes-like object is required, not 'str'", 'encode str message'
+
+try:
+    base64.b64decode(5)
+    assert False, 'decoding int should raise'
+except TypeError as e:
+    assert str(e) == "argument should be a bytes-like object or ASCII string, not 'int'", 'decode int message'
@@ -0,0 +1,65 @@
+import bisect
+
+# === Insertion points ===
+a = [1, 3, 5, 7, 9]
+assert bisect.bisect_left(a, 5) == 2, 'bisect_left existing item'
+assert bisect.bisect_right(a, 5) == 3, 'bisect_right existing item'
+assert bisect.bisect_left(a, 4) == 2, 'bisect_left missing item'
+assert bisect.bisect_right(a, 4) == 2, 'bisect_right missing item'
+assert bisect.bisect_left(a, 0) == 0, 'before the first item'
+assert bisect.bisect_right(a, 10) == 5, 'after the last item'
+assert bisect.bisect_left([], 1) == 0, 'empty list'
+assert bisect.bisect_left((1, 2, 3), 2) == 1, 'tuple'
+assert bisect.bisect_left([1.5, 2.5], 2) == 1, 'mixed int and float'
+assert bisect.bisect_right(['a', 'c'], 'b') == 1, 'strings'
+
+# === Duplicates ===
+d = [1, 2, 2, 2, 3]
+assert bisect.bisect_left(d, 2) == 1, 'bisect_left goes before equal items'
+assert bisect.bisect_right(d, 2) == 4, 'bisect_right goes after equal items'
+
+# === lo and hi ===
+assert bisect.bisect_left(a, 1, 2) == 2, 'positional lo'
+assert bisect.bisect_right(a, 9, 0, 3) == 3, 'positional hi'
+assert bisect.bisect_left(a, 7, lo=1, hi=4) == 3, 'keyword lo and hi'
+assert bisect.bisect_right(a, 7, hi=None) == 4, 'hi=None means len(a)'
+assert bisect.bisect_left(a, 5, 4, 2) == 4, 'empty range returns lo'
+
+# === Insort ===
+s = []
+for x in [5, 1, 4, 2, 3, 2]:
+    assert bisect.insort_right(s, x) is None, 'insort returns None'
+assert s == [1, 2, 2, 3, 4, 5], 'insort_right keeps the list sorted'
+
+pairs = [(1, 'a'), (3, 'c')]
+bisect.insort_left(pairs, (2, 'b'))
+assert pairs == [(1, 'a'), (2, 'b'), (3, 'c')], 'insort_left tuples'
+
+eq = [1.0, 2.0]
+bisect.insort_left(eq, 1)
+bisect.insort_right(eq, 2)
+assert eq == [1, 1.0, 2.0, 2], 'insort_left before and insort_right after equal items'
+assert type(eq[0]) is int and type(eq[3]) is int, 'inserted ints are in place'
+
+t = [1, 5]
+bisect.insort_left(t, 9, hi=1)
+assert t == [1, 9, 5], 'insort respects hi'
+
+# === Errors ===
+try:
+    bisect.bisect_left(a, 1, -1)
+    assert False, 'negative lo should raise'
+except ValueError as e:
+    assert str(e) == 'lo must be non-negative', 'negative lo message'
+
+try:
+    bisect.insort_left((1, 2), 3)
+    assert False, 'insort into a tuple should raise'
+except AttributeError as e:
+    assert str(e) == "'tuple' object has no attribute 'insert'", 'insort tuple message'
+
+try:
+    bisect.bisect_left([1, 2], 'a')
+    assert False, 'incomparable items should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'int' and 'str'", 'incomparable message'
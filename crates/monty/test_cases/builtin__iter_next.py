@@ -64,3 +64,47 @@ assert next(it1) == 2, 'independent iterators: it1 second should be 2'
 assert next(it2) == 1, 'independent iterators: it2 first should be 1 (independent)'
 assert next(it1) == 3, 'independent iterators: it1 third should be 3'
 assert next(it2) == 2, 'independent iterators: it2 second should be 2'
+
+# === iter(callable, sentinel) ===
+# Calls the callable until it returns the sentinel
+
+values = [1, 2, 3, 0, 4]
+
+
+def pop_front():
+    return values.pop(0)
+
+
+assert [v for v in iter(pop_front, 0)] == [1, 2, 3], 'iter sentinel: stops at sentinel'
+assert values == [4], 'iter sentinel: callable not called after sentinel'
+
+counter = 0
+
+
+def count_up():
+    global counter
+    counter += 1
+    return counter
+
+
+it = iter(count_up, 3)
+assert counter == 0, 'iter sentinel: callable not called before next()'
+assert next(it) == 1, 'iter sentinel: first value'
+assert counter == 1, 'iter sentinel: callable called once per next()'
+assert next(it) == 2, 'iter sentinel: second value'
+assert next(it, 'done') == 'done', 'iter sentinel: exhausted at sentinel'
+assert next(it, 'done') == 'done', 'iter sentinel: stays exhausted'
+assert counter == 3, 'iter sentinel: callable not called once exhausted'
+assert [v for v in iter(lambda: 'x', 'x')] == [], 'iter sentinel: immediate sentinel'
+
+counter = 0
+forever = iter(count_up, -1)
+assert next(forever) == 1, 'iter sentinel: infinite callable is called lazily'
+for n in forever:
+    if n == 5:
+        break
+assert counter == 5, 'iter sentinel: for loop stops calling on break'
+total = 0
+for n in iter(lambda: values.pop() if values else None, None):
+    total += n
+assert total == 4, 'iter sentinel: usable in for loop'
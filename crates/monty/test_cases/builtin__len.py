@@ -0,0 +1,41 @@
+# === str (codepoints, not bytes) ===
+assert len('') == 0, 'empty str'
+assert len('hello') == 5, 'ascii str'
+assert len('héllo') == 5, 'str with multi-byte char'
+assert len('日本語') == 3, 'cjk str'
+assert len('🎉a') == 2, 'str with emoji'
+
+# === bytes ===
+assert len(b'') == 0, 'empty bytes'
+assert len(b'abc') == 3, 'bytes'
+assert len('é'.encode()) == 2, 'encoded bytes'
+
+# === list / tuple ===
+assert len([]) == 0, 'empty list'
+assert len([1, [2, 3], 4]) == 3, 'list'
+assert len(()) == 0, 'empty tuple'
+assert len((1, 2)) == 2, 'tuple'
+
+# === dict / set / frozenset ===
+assert len({}) == 0, 'empty dict'
+assert len({'a': 1, 'b': 2}) == 2, 'dict'
+assert len(set()) == 0, 'empty set'
+assert len({1, 2, 2, 3}) == 3, 'set dedupes'
+assert len(frozenset([1, 1])) == 1, 'frozenset'
+
+# === range ===
+assert len(range(10)) == 10, 'range stop'
+assert len(range(2, 10, 3)) == 3, 'range step'
+assert len(range(10, 0, -3)) == 4, 'range negative step'
+assert len(range(5, 5)) == 0, 'empty range'
+assert len(range(0, 10, -1)) == 0, 'empty range wrong direction'
+assert len(range(0, 10**18, 7)) == 142857142857142858, 'range computed arithmetically'
+assert len(range(-9223372036854775807, 9223372036854775807, 2)) == 9223372036854775807, 'range at i64 bounds'
+
+# === no len() ===
+for v in [1.5, None, True]:
+    try:
+        len(v)
+        assert False, 'len should raise'
+    except TypeError as e:
+        assert str(e) == f"object of type '{type(v).__name__}' has no len()", 'len type error message'
@@ -134,6 +134,23 @@ assert pow(7, 256, 13) == 9, 'pow modular large exp'
 # Modular exponentiation edge cases
 assert pow(2, 0, 5) == 1, 'pow x^0 mod n'
 assert pow(0, 5, 3) == 0, 'pow 0^n mod m'
+assert pow(-2, 3, 5) == 2, 'pow negative base mod n'
+assert pow(2, 3, -5) == -2, 'pow result takes sign of negative modulus'
+assert pow(5, 3, 1) == 0, 'pow mod 1 is 0'
+assert pow(True, 5, 3) == 1, 'pow modular handles bool base'
+
+# Modular exponentiation with big ints
+assert pow(2**100, 3, 7) == (2**300) % 7, 'pow modular bigint base'
+assert pow(3, 2**70, 1000) == 481, 'pow modular bigint exponent'
+assert pow(2, 100, 10**20 + 7) == 2**100 % (10**20 + 7), 'pow modular bigint modulus'
+assert pow(-(2**80), 3, -(2**65)) == (-(2**240)) % -(2**65), 'pow modular bigint negative operands'
+assert pow(2**64, 0, 2**64 + 1) == 1, 'pow modular bigint zero exponent'
+
+# Two-argument pow matches the ** operator
+assert pow(3, 4) == 3**4, 'pow matches ** for ints'
+assert pow(2, -2) == 2**-2, 'pow matches ** for negative exponent'
+assert pow(2.5, 2) == 2.5**2, 'pow matches ** for floats'
+assert pow(2, 80) == 2**80, 'pow matches ** for big results'
 assert pow(True, 2) == 1, 'pow handles bool base'
 assert pow(2, True) == 2, 'pow handles bool exponent'
 assert pow(True, True) == 1, 'pow handles bool base and exponent'
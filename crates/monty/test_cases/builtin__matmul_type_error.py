@@ -0,0 +1,2 @@
+[1] @ [2]
+# Raise=TypeError("unsupported operand type(s) for @: 'list' and 'list'")
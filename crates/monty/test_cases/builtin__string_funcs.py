@@ -12,6 +12,13 @@ assert ord('\u00e9') == 233, 'ord e-acute'
 assert ord('\u4e2d') == 20013, 'ord Chinese character'
 assert ord('\U0001f600') == 128512, 'ord emoji grinning face'
 
+# Bytes of length 1
+assert ord(b'A') == 65, 'ord bytes A'
+assert ord(b'\x00') == 0, 'ord bytes null'
+assert ord(b'\xff') == 255, 'ord bytes high byte'
+assert ord(b'xyz'[1:2]) == 121, 'ord heap bytes'
+assert ord('xyz'[2]) == 122, 'ord heap str'
+
 # === chr() ===
 # Basic chr operations
 assert chr(97) == 'a', 'chr 97 = a'
@@ -32,6 +39,36 @@ assert chr(0x10FFFF) != '', 'chr max unicode'
 # Round-trip test
 assert chr(ord('x')) == 'x', 'ord/chr roundtrip'
 assert ord(chr(1000)) == 1000, 'chr/ord roundtrip'
+assert ord(chr(0x10FFFF)) == 0x10FFFF, 'chr/ord roundtrip max code point'
+assert ord(chr(0x1F40D)) == 0x1F40D, 'chr/ord roundtrip astral code point'
+assert chr(True) == '\x01', 'chr of bool'
+
+# Errors
+for bad in [-1, 0x110000, 2**70, -(2**70)]:
+    try:
+        chr(bad)
+        assert False, f'chr({bad}) should raise ValueError'
+    except ValueError as e:
+        assert str(e) == 'chr() arg not in range(0x110000)', f'chr({bad}) error: {e}'
+
+try:
+    chr('a')
+    assert False, 'chr(str) should raise TypeError'
+except TypeError as e:
+    assert str(e) == "'str' object cannot be interpreted as an integer", f'chr str error: {e}'
+
+for bad in ['', 'ab', b'', b'ab']:
+    try:
+        ord(bad)
+        assert False, f'ord({bad!r}) should raise TypeError'
+    except TypeError as e:
+        assert str(e) == f'ord() expected a character, but string of length {len(bad)} found', f'ord error: {e}'
+
+try:
+    ord(1)
+    assert False, 'ord(int) should raise TypeError'
+except TypeError as e:
+    assert str(e) == 'ord() expected string of length 1, but int found', f'ord int error: {e}'
 
 # === bin() ===
 # Basic bin operations
@@ -71,3 +108,24 @@ assert oct(-56) == '-0o70', 'oct negative'
 assert oct(True) == '0o1', 'oct True'
 assert oct(False) == '0o0', 'oct False'
 assert oct(MIN_I64) == '-0o' + MIN_I64_OCT, 'oct handles i64::MIN without overflow'
+
+# === bin()/hex()/oct() with LongInt ===
+BIG = 2**100
+assert bin(BIG) == '0b1' + '0' * 100, 'bin LongInt'
+assert hex(BIG) == '0x1' + '0' * 25, 'hex LongInt'
+assert oct(BIG) == '0o2' + '0' * 33, 'oct LongInt'
+assert bin(-BIG) == '-0b1' + '0' * 100, 'bin negative LongInt'
+assert hex(-BIG - 255) == '-0x1' + '0' * 23 + 'ff', 'hex negative LongInt'
+assert oct(-BIG) == '-0o2' + '0' * 33, 'oct negative LongInt'
+assert hex(MIN_I64 - 1) == '-0x8000000000000001', 'hex just below i64::MIN'
+assert hex(BIG * 3 + 7) == '0x3' + '0' * 24 + '7', 'hex LongInt with low digits'
+
+# === bin()/hex()/oct() type errors ===
+for func in [bin, hex, oct]:
+    for bad in [1.0, '1', None, [1]]:
+        try:
+            func(bad)
+            assert False, f'{bad!r} should raise TypeError'
+        except TypeError as e:
+            expected = f"'{type(bad).__name__}' object cannot be interpreted as an integer"
+            assert str(e) == expected, f'type error for {bad!r}: {e}'
@@ -0,0 +1,2 @@
+256 in b'abc'
+# Raise=ValueError('byte must be in range(0, 256)')
@@ -238,6 +238,7 @@ assert b'aaa'.replace(b'aa', b'b') == b'ba', 'replace non-overlapping'
 assert b'hello'.center(10) == b'  hello   ', 'center basic'
 assert b'hello'.center(10, b'*') == b'**hello***', 'center fillbyte'
 assert b'hello'.center(3) == b'hello', 'center too short'
+assert b'ab'.center(5, b'*') == b'**ab*', 'center odd width puts extra fill left'
 
 # === bytes.ljust() ===
 assert b'hello'.ljust(10) == b'hello     ', 'ljust basic'
@@ -21,6 +21,8 @@ assert b'x' * 0 == b'', 'bytes mult zero'
 assert b'x' * -1 == b'', 'bytes mult negative'
 assert b'' * 5 == b'', 'empty bytes mult'
 assert b'ab' * 1 == b'ab', 'bytes mult one'
+assert b'x' * -(2**63) == b'', 'bytes mult min index negative'
+assert b'' * (2**63 - 1) == b'', 'empty bytes mult huge'
 
 # === Bytes indexing (getitem) ===
 # Basic indexing - returns integer byte values
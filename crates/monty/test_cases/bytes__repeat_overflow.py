@@ -0,0 +1,2 @@
+b'ab' * (2**63 - 1)
+# Raise=OverflowError('repeated bytes are too long')
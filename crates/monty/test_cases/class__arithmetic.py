@@ -0,0 +1,85 @@
+# call-external
+# === Classes defining arithmetic special methods ===
+class Money:
+    def __init__(self, cents):
+        self.cents = cents
+
+    def __add__(self, other):
+        return Money(self.cents + other.cents)
+
+    def __radd__(self, other):
+        return Money(self.cents + other)
+
+    def __sub__(self, other):
+        return Money(self.cents - other.cents)
+
+    def __mul__(self, factor):
+        return Money(self.cents * factor)
+
+    def __truediv__(self, divisor):
+        return Money(self.cents // divisor)
+
+
+a = Money(150)
+b = Money(275)
+assert (a + b).cents == 425, '__add__'
+assert (b - a).cents == 125, '__sub__'
+assert (a * 3).cents == 450, '__mul__'
+assert (b / 5).cents == 55, '__truediv__'
+assert (a + b * 2).cents == 700, 'operators keep their precedence'
+
+# === __radd__ is used when the left operand doesn't implement + ===
+assert (25 + a).cents == 175, '__radd__ with an int on the left'
+
+# === Augmented assignment falls back to the binary methods ===
+total = Money(0)
+for m in [a, b]:
+    total += m
+assert total.cents == 425, '+= uses __add__'
+assert a.cents == 150, 'operands are not modified'
+
+
+# === Operators neither side implements raise TypeError ===
+class Plain:
+    pass
+
+
+try:
+    Plain() + Plain()
+    assert False, 'adding instances without __add__ should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for +: 'Plain' and 'Plain'", f'wrong message: {e}'
+
+try:
+    1 - a
+    assert False, 'int - instance without __rsub__ should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for -: 'int' and 'Money'", f'wrong message: {e}'
+
+try:
+    2 * Plain()
+    assert False, 'multiplying by an instance without __rmul__ should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for *: 'int' and 'Plain'", f'wrong message: {e}'
+
+try:
+    [1] + Plain()
+    assert False, 'list + instance without __radd__ should raise'
+except TypeError as e:
+    assert str(e) == 'can only concatenate list (not "Plain") to list', f'wrong message: {e}'
+
+# === Special methods calling external functions ===
+class Meter:
+    def __init__(self, n):
+        self.n = n
+
+    def __add__(self, other):
+        return Meter(add_ints(self.n, other.n))
+
+    def __radd__(self, other):
+        return Meter(add_ints(other, self.n))
+
+
+assert (Meter(2) + Meter(3)).n == 5, 'external call in __add__'
+assert (4 + Meter(3)).n == 7, 'external call in __radd__'
+assert [(Meter(i) + Meter(1)).n for i in range(3)] == [1, 2, 3], 'external call in __add__ inside comprehension'
@@ -0,0 +1,34 @@
+# Attribute dicts can hold both interned names (from source) and heap strings (built at runtime).
+class Box:
+    pass
+
+
+b = Box()
+
+# === Heap key, interned lookup ===
+name = ''.join(['co', 'unt'])
+setattr(b, name, 1)
+assert b.count == 1, 'interned lookup finds heap key'
+
+# === Interned key, heap lookup ===
+b.total = 2
+assert getattr(b, ''.join(['to', 'tal'])) == 2, 'heap lookup finds interned key'
+
+# === Overwriting keeps one entry per name ===
+b.count = 5
+assert getattr(b, name) == 5, 'interned assignment overwrites heap key'
+setattr(b, ''.join(['to', 'tal']), 6)
+assert b.total == 6, 'heap assignment overwrites interned key'
+assert len(vars(b)) == 2, 'no duplicate keys'
+
+# === Missing names ===
+assert not hasattr(b, 'missing'), 'missing interned name'
+assert not hasattr(b, ''.join(['mis', 'sing'])), 'missing heap name'
+assert getattr(b, 'count', None) == 5, 'getattr with default'
+
+# === Many interned attributes ===
+b.a1 = 1
+b.a2 = 2
+b.a3 = 3
+b.a4 = 4
+assert b.a1 + b.a2 + b.a3 + b.a4 == 10, 'several interned attributes'
@@ -0,0 +1,172 @@
+# call-external
+# === Classes defining rich comparison methods ===
+class Version:
+    def __init__(self, major, minor):
+        self.major = major
+        self.minor = minor
+
+    def __eq__(self, other):
+        return self.major == other.major and self.minor == other.minor
+
+    def __lt__(self, other):
+        return (self.major, self.minor) < (other.major, other.minor)
+
+
+v1 = Version(1, 2)
+v2 = Version(1, 10)
+v3 = Version(2, 0)
+assert v1 < v2, '__lt__ is called'
+assert not (v2 < v1), '__lt__ returning False'
+assert v2 > v1, '> falls back to the reflected __lt__'
+assert v1 == Version(1, 2), '__eq__ is called'
+assert v1 != v2, '!= negates __eq__ when __ne__ is not defined'
+assert not (v1 != Version(1, 2)), '!= of equal versions'
+
+# === Sorting uses __lt__ ===
+versions = [v3, v1, v2]
+ordered = sorted(versions)
+assert [(v.major, v.minor) for v in ordered] == [(1, 2), (1, 10), (2, 0)], 'sorted() with __lt__'
+assert [(v.major, v.minor) for v in sorted(versions, reverse=True)] == [(2, 0), (1, 10), (1, 2)], 'reverse sort'
+versions.sort()
+assert versions[0] is v1 and versions[2] is v3, 'list.sort() with __lt__'
+
+# Sorting is stable for items which are not less than each other
+a = Version(1, 0)
+b = Version(1, 0)
+assert sorted([b, a])[0] is b, 'stable sort keeps equal items in order'
+
+
+# === The list being sorted is empty while __lt__ and the key run, like CPython ===
+class Peek:
+    def __init__(self, n):
+        self.n = n
+
+    def __lt__(self, other):
+        seen.append(len(peeks))
+        return self.n < other.n
+
+
+seen = []
+peeks = [Peek(3), Peek(1), Peek(2)]
+peeks.sort()
+assert [pk.n for pk in peeks] == [1, 2, 3], 'list.sort() while __lt__ reads the list'
+assert seen and all(n == 0 for n in seen), 'the list is empty while __lt__ runs'
+lengths = []
+numbers = [3, 1, 2]
+numbers.sort(key=lambda n: lengths.append(len(numbers)) or n)
+assert numbers == [1, 2, 3] and lengths == [0, 0, 0], 'the list is empty while the key runs'
+
+
+class Grow:
+    def __init__(self, n):
+        self.n = n
+
+    def __lt__(self, other):
+        grown.append(Grow(0))
+        return self.n < other.n
+
+
+grown = [Grow(2), Grow(1)]
+try:
+    grown.sort()
+    assert False, 'changing the list during a sort should raise'
+except ValueError as e:
+    assert str(e) == 'list modified during sort', f'modified message {e}'
+assert [g.n for g in grown] == [1, 2], 'the sorted items replace the changes'
+
+# === heapq and bisect use __lt__ ===
+import bisect
+import heapq
+
+heap = []
+for v in [v3, v1, v2]:
+    heapq.heappush(heap, v)
+assert heapq.heappop(heap) is v1, 'heappush and heappop with __lt__'
+assert heapq.heappushpop(heap, v1) is v1, 'heappushpop with __lt__'
+row = [v1, v3]
+bisect.insort_right(row, v2)
+assert row[1] is v2, 'insort_right with __lt__'
+assert bisect.bisect_left(row, v2) == 1, 'bisect_left with __lt__'
+
+
+# === Comparison methods may return any value ===
+class Loose:
+    def __lt__(self, other):
+        return 'yes'
+
+    def __ge__(self, other):
+        return 0
+
+
+assert (Loose() < 1) == 'yes', 'result of __lt__ is returned as is'
+assert (Loose() >= 1) == 0, 'result of __ge__ is returned as is'
+assert (1 > Loose()) == 'yes', 'reflected __lt__ for a builtin left operand'
+
+
+# === Without __eq__, instances compare by identity ===
+class Plain:
+    pass
+
+
+x = Plain()
+assert x == x, 'instance equals itself'
+assert x != Plain(), 'distinct instances are unequal'
+
+
+# === Sorting tolerates a __lt__ that is not a consistent order ===
+lt_calls = 0
+
+
+class Flaky:
+    def __init__(self, n):
+        self.n = n
+
+    def __lt__(self, other):
+        global lt_calls
+        lt_calls += 1
+        return lt_calls % 3 == 0
+
+
+flaky = [Flaky(n) for n in range(50)]
+result = sorted(flaky)
+assert len(result) == 50, 'inconsistent __lt__ keeps every item'
+assert sorted(f.n for f in result) == list(range(50)), 'inconsistent __lt__ returns a permutation'
+
+
+class Hand:
+    def __init__(self, name):
+        self.name = name
+
+    def __lt__(self, other):
+        beats = {'rock': 'scissors', 'scissors': 'paper', 'paper': 'rock'}
+        return beats[other.name] == self.name
+
+
+hands = [Hand(name) for name in ['rock', 'paper', 'scissors'] * 10]
+hands.sort()
+assert sorted(h.name for h in hands) == sorted(['rock', 'paper', 'scissors'] * 10), 'intransitive __lt__'
+
+# === Incomparable items report the later item first, like CPython ===
+try:
+    sorted([1, 'a'])
+    assert False, 'sorting int and str should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'str' and 'int'", f'sort message {e}'
+
+# === Comparison methods calling external functions ===
+class Tag:
+    def __init__(self, name):
+        self.name = name
+
+    def __eq__(self, other):
+        return return_value(self.name) == other.name
+
+    def __lt__(self, other):
+        return return_value(self.name) < other.name
+
+
+assert Tag('a') == Tag('a'), 'external call in __eq__'
+assert Tag('a') != Tag('b'), 'external call in __eq__ negated for !='
+assert not (Tag('a') != Tag('a')), 'external call in __eq__ negated for == values'
+assert Tag('a') < Tag('b'), 'external call in __lt__'
+assert Tag('c') > Tag('b'), 'external call in reflected __lt__'
@@ -0,0 +1,86 @@
+# === Raising and catching a custom exception ===
+class MyError(Exception):
+    pass
+
+
+caught = None
+try:
+    raise MyError('boom')
+except MyError as e:
+    caught = e
+assert caught is not None, 'custom exception caught by its own class'
+assert caught.args == ('boom',), 'custom exception keeps its args'
+assert str(caught) == 'boom', 'custom exception str'
+assert repr(caught) == "MyError('boom')", 'custom exception repr uses the class name'
+
+# === Catching via the base class ===
+try:
+    raise MyError('base')
+except Exception as e:
+    assert isinstance(e, MyError), 'caught by base is still an instance of the class'
+    assert isinstance(e, Exception), 'instance of the base class'
+
+# === Subclass of a builtin exception other than Exception ===
+class BadValue(ValueError):
+    pass
+
+
+try:
+    raise BadValue('nope')
+except ValueError as e:
+    assert isinstance(e, BadValue), 'caught as ValueError'
+    assert not isinstance(e, MyError), 'not an instance of an unrelated class'
+
+# === Subclass of a custom exception ===
+class MySubError(MyError):
+    pass
+
+
+try:
+    raise MySubError('sub')
+except MyError as e:
+    assert repr(e) == "MySubError('sub')", 'subclass caught by parent class'
+
+try:
+    raise MyError('parent')
+except MySubError:
+    assert False, 'parent should not match a subclass handler'
+except MyError:
+    pass
+
+# === Tuples of handlers and raising the class itself ===
+try:
+    raise MyError
+except (KeyError, MyError) as e:
+    assert e.args == (), 'class raised without arguments'
+
+# === Unrelated builtin handlers don't match ===
+try:
+    try:
+        raise MyError('inner')
+    except ValueError:
+        assert False, 'MyError is not a ValueError'
+except MyError as e:
+    assert str(e) == 'inner', 'propagated past the unrelated handler'
+
+# === Classes with the same name are still different classes ===
+def make_error():
+    class FactoryError(Exception):
+        pass
+
+    return FactoryError
+
+
+FirstError = make_error()
+SecondError = make_error()
+try:
+    try:
+        raise FirstError('first')
+    except SecondError:
+        assert False, 'a same-named class should not match'
+except FirstError as e:
+    assert repr(e) == "FactoryError('first')", 'caught by its own class'
+    assert not isinstance(e, SecondError), 'not an instance of a same-named class'
+
+raise MyError('uncaught')
+# Raise=MyError('uncaught')
@@ -0,0 +1,8 @@
+# xfail=cpython
+class Base:
+    pass
+
+
+class Child(Base):
+    pass
+# Raise=NotImplementedError('inheriting from classes other than exceptions is not supported')
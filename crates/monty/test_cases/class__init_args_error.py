@@ -0,0 +1,6 @@
+class Empty:
+    pass
+
+
+Empty(1)
+# Raise=TypeError('Empty() takes no arguments')
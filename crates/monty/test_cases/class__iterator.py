@@ -0,0 +1,117 @@
+# === for loop over a custom iterator ===
+class Countdown:
+    def __init__(self, start):
+        self.current = start
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if self.current <= 0:
+            raise StopIteration
+        self.current -= 1
+        return self.current + 1
+
+
+result = []
+for n in Countdown(3):
+    result.append(n)
+assert result == [3, 2, 1], 'for loop stops when __next__ raises StopIteration'
+
+result = []
+for n in Countdown(0):
+    result.append(n)
+assert result == [], 'for loop over an empty custom iterator'
+
+result = []
+for n in Countdown(10):
+    if n == 8:
+        break
+    result.append(n)
+assert result == [10, 9], 'break out of a custom iterator loop'
+
+total = 0
+for a in Countdown(2):
+    for b in Countdown(2):
+        total += a * b
+assert total == 9, 'nested custom iterator loops'
+
+
+# === __iter__ returning a separate iterator ===
+class Letters:
+    def __init__(self, text):
+        self.text = text
+
+    def __iter__(self):
+        return iter(self.text)
+
+
+assert [c for c in Letters('abc')] == ['a', 'b', 'c'], '__iter__ returning a builtin iterator'
+
+
+class Repeat:
+    def __init__(self, value, times):
+        self.value = value
+        self.times = times
+
+    def __iter__(self):
+        return Countdown(self.times)
+
+
+result = []
+for n in Repeat('x', 2):
+    result.append(n)
+assert result == [2, 1], '__iter__ returning another custom iterator'
+
+
+# === next() on custom and exhausted iterators ===
+it = Countdown(2)
+assert iter(it) is it, 'iter() calls __iter__'
+assert next(it) == 2, 'next() calls __next__'
+assert next(it) == 1, 'next() calls __next__ again'
+assert next(it, 'done') == 'done', 'next() default on exhausted custom iterator'
+
+try:
+    next(it)
+    assert False, 'next() on exhausted custom iterator should raise'
+except StopIteration:
+    pass
+
+it = iter([1])
+assert next(it) == 1, 'next() on builtin iterator'
+try:
+    next(it)
+    assert False, 'next() on exhausted builtin iterator should raise'
+except StopIteration:
+    caught = True
+assert caught, 'StopIteration is catchable'
+
+
+# === other exceptions from __next__ propagate ===
+class Broken:
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        raise ValueError('broken')
+
+
+try:
+    for x in Broken():
+        pass
+    assert False, 'ValueError from __next__ should propagate'
+except ValueError as e:
+    assert str(e) == 'broken', 'error message from __next__'
+
+
+# === iterator without __next__ ===
+class NoNext:
+    def __iter__(self):
+        return self
+
+
+try:
+    next(NoNext())
+    assert False, 'next() without __next__ should raise'
+except TypeError as e:
+    assert str(e) == "'NoNext' object is not an iterator", 'next() without __next__ message'
@@ -0,0 +1,52 @@
+# === Class defining __matmul__ ===
+class Vec:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+    def __matmul__(self, other):
+        return self.x * other.x + self.y * other.y
+
+
+a = Vec(1, 2)
+b = Vec(3, 4)
+assert a @ b == 11, 'matmul dispatches to __matmul__'
+assert b @ a == 11, 'matmul with operands swapped'
+assert (a @ b) + 1 == 12, 'matmul result used in an expression'
+
+
+# === __matmul__ may return any object ===
+class Matrix:
+    def __init__(self, rows):
+        self.rows = rows
+
+    def __matmul__(self, other):
+        return [sum(r * c for r, c in zip(row, other)) for row in self.rows]
+
+
+m = Matrix([[1, 0], [0, 2]])
+assert m @ [5, 6] == [5, 12], 'matmul with a non-instance right operand'
+
+# === Operands without __matmul__ raise TypeError ===
+try:
+    1 @ 2
+    assert False, 'int @ int should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for @: 'int' and 'int'", f'int message {e}'
+
+try:
+    [1, 2] @ a
+    assert False, 'list @ instance should raise'
+except TypeError:
+    pass
+
+
+class NoMatmul:
+    pass
+
+
+try:
+    NoMatmul() @ NoMatmul()
+    assert False, 'instance without __matmul__ should raise'
+except TypeError:
+    pass
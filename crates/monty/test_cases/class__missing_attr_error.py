@@ -0,0 +1,14 @@
+class Point:
+    def __init__(self, x):
+        self.x = x
+
+
+p = Point(1)
+p.y
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "class__missing_attr_error.py", line 7, in <module>
+    p.y
+AttributeError: 'Point' object has no attribute 'y'
+"""
@@ -0,0 +1,91 @@
+# === Class with __init__ and methods ===
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+    def norm2(self):
+        return self.x * self.x + self.y * self.y
+
+    def scaled(self, factor):
+        return Point(self.x * factor, self.y * factor)
+
+    def add(self, other):
+        return Point(self.x + other.x, self.y + other.y)
+
+
+p = Point(3, 4)
+assert p.x == 3, 'attribute set in __init__'
+assert p.y == 4, 'second attribute set in __init__'
+assert p.norm2() == 25, 'method call'
+assert p.scaled(2).norm2() == 100, 'method returning new instance'
+q = p.add(Point(1, 1))
+assert (q.x, q.y) == (4, 5), 'method taking another instance'
+
+# === Attribute assignment after construction ===
+p.x = 6
+p.label = 'origin'
+assert p.norm2() == 52, 'method sees updated attribute'
+assert p.label == 'origin', 'new attribute added outside __init__'
+
+# === Bound methods ===
+m = p.norm2
+assert m() == 52, 'bound method called later'
+p.y = 0
+assert m() == 36, 'bound method reads current state'
+
+# === Methods calling other methods ===
+class Counter:
+    def __init__(self, start=0):
+        self.count = start
+
+    def increment(self, by=1):
+        self.count += by
+        return self
+
+    def twice(self):
+        self.increment()
+        self.increment()
+        return self.count
+
+
+c = Counter()
+assert c.twice() == 2, 'method calling method via self'
+assert c.increment(5).increment().count == 8, 'chained methods'
+assert Counter(10).count == 10, 'default argument overridden'
+
+# === Independent instances ===
+a = Counter()
+b = Counter()
+a.increment()
+assert (a.count, b.count) == (1, 0), 'instances do not share attributes'
+
+# === Class without __init__ ===
+class Empty:
+    pass
+
+
+e = Empty()
+e.value = 1
+assert e.value == 1, 'attributes on instance of class without __init__'
+
+# === Docstring in class body ===
+class Documented:
+    """A class with a docstring."""
+
+    def get(self):
+        return 'ok'
+
+
+assert Documented().get() == 'ok', 'class docstring ignored'
+
+# === type and isinstance ===
+assert type(p) is Point, 'type of instance is its class'
+assert isinstance(p, Point), 'isinstance with class'
+assert not isinstance(p, Counter), 'isinstance with other class'
+assert not isinstance(1, Point), 'isinstance of int with class'
+assert Point.__name__ == 'Point', 'class __name__'
+assert p.__dict__ == {'x': 6, 'y': 0, 'label': 'origin'}, 'instance __dict__'
+
+# === Calling methods via the class ===
+assert Point.norm2(Point(1, 2)) == 5, 'unbound method call via class'
@@ -0,0 +1,48 @@
+# === Computed property ===
+class Rect:
+    def __init__(self, width, height):
+        self.width = width
+        self.height = height
+
+    @property
+    def area(self):
+        return self.width * self.height
+
+    @property
+    def is_square(self):
+        return self.width == self.height
+
+    def describe(self):
+        return f'{self.width}x{self.height} ({self.area})'
+
+
+r = Rect(3, 4)
+assert r.area == 12, 'property computed on access'
+assert not r.is_square, 'second property'
+assert r.describe() == '3x4 (12)', 'property used from a method'
+
+# === Property reflects attribute changes ===
+r.width = 4
+assert r.area == 16, 'property recomputed after attribute change'
+assert r.is_square, 'property recomputed after attribute change'
+
+# === Property accessed via the class ===
+assert type(Rect.area) is property, 'property object on the class'
+assert isinstance(Rect.area, property), 'isinstance with property'
+assert getattr(r, 'width') == 4, 'getattr still works for plain attributes'
+assert hasattr(r, 'area'), 'hasattr with property'
+
+# === Property returning a callable ===
+class Greeter:
+    def __init__(self, name):
+        self.name = name
+
+    def hello(self):
+        return 'hello ' + self.name
+
+    @property
+    def greet(self):
+        return self.hello
+
+
+assert Greeter('bob').greet() == 'hello bob', 'calling the value of a property'
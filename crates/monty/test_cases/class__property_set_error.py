@@ -0,0 +1,20 @@
+class Rect:
+    def __init__(self, width, height):
+        self.width = width
+        self.height = height
+
+    @property
+    def area(self):
+        return self.width * self.height
+
+
+r = Rect(3, 4)
+r.area = 10
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "class__property_set_error.py", line 12, in <module>
+    r.area = 10
+    ~~~~~~
+AttributeError: property 'area' of 'Rect' object has no setter
+"""
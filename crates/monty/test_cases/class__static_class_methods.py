@@ -0,0 +1,48 @@
+# === staticmethod ===
+class MathUtils:
+    @staticmethod
+    def add(a, b):
+        return a + b
+
+    def add_twice(self, a, b):
+        return self.add(a, b) * 2
+
+
+assert MathUtils.add(1, 2) == 3, 'staticmethod via class'
+utils = MathUtils()
+assert utils.add(3, 4) == 7, 'staticmethod via instance'
+assert utils.add_twice(1, 1) == 4, 'staticmethod via self in a method'
+add = utils.add
+assert add(5, 5) == 10, 'staticmethod looked up on instance is unbound'
+
+
+# === classmethod alternate constructor ===
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+    @classmethod
+    def origin(cls):
+        return cls(0, 0)
+
+    @classmethod
+    def from_tuple(cls, pair):
+        return cls(pair[0], pair[1])
+
+    @classmethod
+    def name(cls):
+        return cls.__name__
+
+
+p = Point.from_tuple((1, 2))
+assert (p.x, p.y) == (1, 2), 'classmethod via class'
+assert isinstance(p, Point), 'classmethod constructs an instance of the class'
+o = p.origin()
+assert (o.x, o.y) == (0, 0), 'classmethod via instance'
+assert type(o) is Point, 'classmethod via instance receives the class'
+assert Point.name() == 'Point', 'classmethod receives the class via class'
+assert p.name() == 'Point', 'classmethod receives the class via instance'
+make = Point.from_tuple
+q = make((3, 4))
+assert (q.x, q.y) == (3, 4), 'classmethod looked up on class is bound'
@@ -0,0 +1,99 @@
+# === Adder factory ===
+def make_adder(n):
+    def adder(x):
+        return x + n
+
+    return adder
+
+
+assert make_adder(5)(3) == 8, 'adder factory'
+add10 = make_adder(10)
+add20 = make_adder(20)
+assert (add10(1), add20(1)) == (11, 21), 'each closure captures its own value'
+
+
+# === Closure reads loop variable by reference ===
+def make_loop_closures():
+    funcs = []
+    for i in range(3):
+
+        def get():
+            return i
+
+        funcs.append(get)
+    return funcs
+
+
+assert [f() for f in make_loop_closures()] == [2, 2, 2], 'closures see final loop value'
+
+
+# === Default argument binds loop variable eagerly ===
+def make_bound_closures():
+    funcs = []
+    for i in range(3):
+
+        def get(i=i):
+            return i
+
+        funcs.append(get)
+    return funcs
+
+
+assert [f() for f in make_bound_closures()] == [0, 1, 2], 'default args capture current value'
+
+
+# === Reads see later mutations of the enclosing variable ===
+def late_rebind():
+    value = 'before'
+
+    def read():
+        return value
+
+    value = 'after'
+    return read()
+
+
+assert late_rebind() == 'after', 'closure sees rebinding after definition'
+
+
+def counter_with_reader():
+    count = 0
+
+    def increment():
+        nonlocal count
+        count += 1
+
+    def read():
+        return count
+
+    return increment, read
+
+
+increment, read = counter_with_reader()
+increment()
+increment()
+assert read() == 2, 'reader sees nonlocal writes from sibling closure'
+
+
+# === Closure over mutable container ===
+def make_accumulator():
+    items = []
+
+    def add(x):
+        items.append(x)
+        return items
+
+    return add
+
+
+acc = make_accumulator()
+acc(1)
+assert acc(2) == [1, 2], 'closure shares mutable container'
+
+
+# === Closure over a parameter in a lambda ===
+def make_multiplier(factor):
+    return lambda x: x * factor
+
+
+assert list(map(make_multiplier(3), [1, 2])) == [3, 6], 'lambda captures parameter'
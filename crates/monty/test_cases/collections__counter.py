@@ -0,0 +1,43 @@
+from collections import Counter
+
+# === Counting ===
+c = Counter('hello')
+assert c['l'] == 2, 'repeated element'
+assert c['h'] == 1, 'single element'
+assert c['z'] == 0, 'missing elements count as zero'
+assert 'z' not in c, 'looking up a missing element does not add it'
+assert len(c) == 4, 'one entry per distinct element'
+assert c == {'h': 1, 'e': 1, 'l': 2, 'o': 1}, 'compares equal to a dict'
+assert isinstance(c, dict), 'counter is a dict'
+assert repr(c) == "Counter({'l': 2, 'h': 1, 'e': 1, 'o': 1})", 'repr is ordered by count'
+
+c = Counter([1, 2, 1, (3, 4), 1, (3, 4)])
+assert c[1] == 3, 'count ints'
+assert c[(3, 4)] == 2, 'count tuples'
+
+c = Counter({'a': 3, 'b': 5})
+assert c['b'] == 5, 'counter from mapping'
+assert c['c'] == 0, 'counter from mapping missing element'
+
+# === most_common ===
+c = Counter('hello')
+assert c.most_common(2) == [('l', 2), ('h', 1)], 'top two, ties in insertion order'
+assert c.most_common(0) == [], 'most_common(0)'
+assert c.most_common(10) == [('l', 2), ('h', 1), ('e', 1), ('o', 1)], 'n larger than the counter'
+assert c.most_common() == [('l', 2), ('h', 1), ('e', 1), ('o', 1)], 'most_common with no argument'
+assert Counter('abracadabra').most_common(3) == [('a', 5), ('b', 2), ('r', 2)], 'most_common(3)'
+
+# === Empty ===
+c = Counter([])
+assert c == {}, 'empty iterable'
+assert len(c) == 0, 'empty length'
+assert repr(c) == 'Counter()', 'empty repr'
+assert c.most_common() == [], 'empty most_common'
+assert Counter() == {}, 'no argument'
+
+# === Errors ===
+try:
+    Counter([[1]])
+    assert False, 'unhashable element should raise'
+except TypeError as e:
+    assert str(e) == "unhashable type: 'list'", 'unhashable message'
@@ -0,0 +1,73 @@
+# === str: substring membership ===
+assert 'ell' in 'hello', 'substring in str'
+assert 'hello' in 'hello', 'str contains itself'
+assert '' in 'hello', 'empty str is in any str'
+assert '' in '', 'empty str is in empty str'
+assert 'lo!' not in 'hello', 'non-substring not in str'
+assert 'L' not in 'hello', 'substring membership is case sensitive'
+s = 'ab' * 3
+assert s in s, 'heap str contains itself'
+assert 'ba' in s, 'substring in heap str'
+
+# === bytes: subsequence and byte value membership ===
+assert b'ell' in b'hello', 'subsequence in bytes'
+assert b'' in b'hello', 'empty bytes in any bytes'
+assert b'xyz' not in b'hello', 'non-subsequence not in bytes'
+assert 104 in b'hello', 'byte value in bytes'
+assert 0 not in b'hello', 'missing byte value not in bytes'
+assert True not in b'hello', 'bool is treated as byte value 1'
+b = b'ab' * 3
+assert b in b, 'heap bytes contains itself'
+assert b'ba' in b, 'subsequence in heap bytes'
+assert 97 in b, 'byte value in heap bytes'
+
+# === list and tuple: element equality ===
+assert 2 in [1, 2, 3], 'int in list'
+assert 2.0 in [1, 2, 3], 'equal float in list'
+assert [1, 2] in [[1, 2], [3]], 'nested list compared by equality'
+assert (1, 2) not in [[1, 2]], 'tuple does not equal list'
+assert 'b' in ('a', 'b'), 'str in tuple'
+assert None in (0, None), 'None in tuple'
+assert 1 not in [], 'nothing in empty list'
+assert 1 not in (), 'nothing in empty tuple'
+a = [[1]]
+assert a not in a, 'list not containing itself'
+a.append(a)
+assert a in a, 'list containing itself'
+t = ([1], 2)
+assert t not in t, 'tuple not containing itself'
+
+# === dict: key membership ===
+d = {'a': 1, 'b': 2}
+assert 'a' in d, 'key in dict'
+assert 1 not in d, 'value is not a key'
+assert 'c' not in d, 'missing key not in dict'
+assert (1, 2) in {(1, 2): 'x'}, 'tuple key in dict'
+assert 1.0 in {1: 'x'}, 'equal float key in dict'
+
+# === set and frozenset ===
+assert 2 in {1, 2, 3}, 'in set'
+assert 4 not in {1, 2, 3}, 'not in set'
+assert 'a' in frozenset(['a', 'b']), 'in frozenset'
+fs = frozenset([1])
+assert fs in {fs}, 'frozenset in set'
+assert fs not in fs, 'frozenset does not contain itself'
+
+# === range: arithmetic membership ===
+assert 4 in range(0, 10, 2), 'even number in even range'
+assert 5 not in range(0, 10, 2), 'odd number not in even range'
+assert 10 not in range(0, 10, 2), 'stop not in range'
+assert 9 in range(10, 0, -1), 'value in descending range'
+assert 0 not in range(10, 0, -1), 'stop not in descending range'
+assert 'a' not in range(10), 'str not in range'
+assert 9223372036854775806 in range(-9223372036854775807, 9223372036854775807), 'wide range membership'
+assert 9223372036854775806 not in range(-9223372036854775807, 9223372036854775807, 2), 'wide range step'
+
+# === not in is the negation of in ===
+for container in ['abc', [1, 2], (1, 2), {1: 2}, {1, 2}, range(3), b'abc']:
+    for item in [1, 'a', 97]:
+        try:
+            found = item in container
+        except TypeError:
+            continue
+        assert (item not in container) == (not found), 'not in negates in'
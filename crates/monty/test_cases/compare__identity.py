@@ -0,0 +1,49 @@
+# === Singletons are identity-stable ===
+assert None is None, 'None is None'
+assert True is True, 'True is True'
+assert False is False, 'False is False'
+assert True is not False, 'True is not False'
+assert None is not False, 'None is not False'
+x = None
+y = None
+assert x is y, 'None bound to two names is identical'
+assert (1 == 1) is True, 'comparison result is the True singleton'
+assert (1 == 2) is False, 'comparison result is the False singleton'
+assert bool(0) is False, 'bool() returns the False singleton'
+
+# === Separately built containers are distinct ===
+a = [1, 2, 3]
+b = [1, 2, 3]
+assert a == b, 'equal lists'
+assert a is not b, 'separately built lists are not identical'
+assert not (a is b), 'is is False for separately built lists'
+assert {} is not {}, 'separately built dicts are not identical'
+assert [] is not [], 'separately built empty lists are not identical'
+
+# === Same object bound to two names ===
+c = a
+assert c is a, 'same list bound to two names is identical'
+assert not (c is not a), 'is not is False for the same list'
+c.append(4)
+assert a == [1, 2, 3, 4], 'mutation is visible through both names'
+d = {'k': 1}
+e = d
+assert e is d, 'same dict bound to two names is identical'
+
+# === Identity survives containers and calls ===
+items = [a, b]
+assert items[0] is a, 'item stored in list keeps its identity'
+assert items[1] is not a, 'other item is a different object'
+
+
+def identity(value):
+    return value
+
+
+assert identity(a) is a, 'passing through a function keeps identity'
+assert identity(None) is None, 'None returned from function is None'
+
+# === Copies are different objects ===
+assert a.copy() is not a, 'list.copy returns a new list'
+assert list(a) is not a, 'list() returns a new list'
+assert a[:] is not a, 'full slice returns a new list'
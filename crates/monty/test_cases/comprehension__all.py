@@ -191,3 +191,29 @@ assert list(a + b for a, b in pairs) == [3, 7], 'generator with unpacking'
 assert ''.join(str(x) for x in range(5)) == '01234', 'list of strings join'
 a = '1', '2', '3'
 assert ''.join(a) == '123', 'tuple of strings join'
+
+# === Multiple for clauses with conditions ===
+a = [1, 2, 3]
+b = [2, 3]
+assert [x * y for x in a for y in b if x != y] == [2, 3, 6, 6], 'product skipping equal pairs'
+assert [(x, y) for x in a for y in b] == [(1, 2), (1, 3), (2, 2), (2, 3), (3, 2), (3, 3)], 'cartesian product'
+assert len([(x, y, z) for x in a for y in b for z in 'ab']) == 12, 'three clause product'
+assert [x + y for x in a if x % 2 for y in b if y > x] == [3, 4], 'filter after each clause'
+assert [(x, y) for x in range(4) for y in range(x) if (x + y) % 2] == [(1, 0), (2, 1), (3, 0), (3, 2)], (
+    'filter uses both loop vars'
+)
+assert {x * y for x in a for y in b if x < y} == {2, 3, 6}, 'set comp with multiple clauses'
+assert {(x, y): x - y for x in a for y in b if x > y} == {(3, 2): 1}, 'dict comp with multiple clauses'
+assert sum(x * y for x in a for y in b if x != y) == 17, 'generator with multiple clauses'
+
+x = 'outer_x'
+y = 'outer_y'
+result = [x * y for x in a for y in b if x != y]
+assert (x, y) == ('outer_x', 'outer_y'), 'multiple clause loop vars do not leak'
+
+
+def pairs_with_step(limit, step):
+    return [(i, j) for i in range(0, limit, step) for j in range(i, limit, step) if i != j]
+
+
+assert pairs_with_step(5, 2) == [(0, 2), (0, 4), (2, 4)], 'multiple clauses capture function locals'
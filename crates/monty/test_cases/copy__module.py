@@ -0,0 +1,86 @@
+import copy
+from copy import deepcopy
+
+# === list.copy() / dict.copy() ===
+inner = [1, 2]
+lst = [inner, 3]
+lst_copy = lst.copy()
+assert lst_copy == lst and lst_copy is not lst, 'list.copy makes a new equal list'
+assert lst_copy[0] is inner, 'list.copy shares nested objects'
+
+d = {'a': inner}
+d_copy = d.copy()
+assert d_copy == d and d_copy is not d, 'dict.copy makes a new equal dict'
+assert d_copy['a'] is inner, 'dict.copy shares nested objects'
+
+# === copy.copy() ===
+shallow = copy.copy(lst)
+assert shallow == lst and shallow is not lst, 'copy.copy of list makes a new list'
+assert shallow[0] is inner, 'copy.copy of list shares nested objects'
+shallow.append(4)
+assert lst == [[1, 2], 3], 'appending to shallow copy leaves original unchanged'
+shallow[0].append(5)
+assert lst == [[1, 2, 5], 3], 'mutating nested object is visible through the original'
+
+shallow = copy.copy(d)
+assert shallow == d and shallow is not d, 'copy.copy of dict makes a new dict'
+assert shallow['a'] is inner, 'copy.copy of dict shares values'
+
+s = {1, 2, 3}
+shallow = copy.copy(s)
+assert shallow == s and shallow is not s, 'copy.copy of set makes a new set'
+
+t = (inner, 1)
+assert copy.copy(t) is t, 'copy.copy of tuple returns the same tuple'
+assert copy.copy(42) == 42, 'copy.copy of int'
+assert copy.copy('abc') == 'abc', 'copy.copy of str'
+assert copy.copy(None) is None, 'copy.copy of None'
+
+# === copy.deepcopy() ===
+inner = [1, 2]
+nested = [inner, {'k': [3, inner]}, (inner,)]
+deep = deepcopy(nested)
+assert deep == nested, 'deepcopy is equal to the original'
+assert deep is not nested, 'deepcopy makes a new outer list'
+assert deep[0] is not inner, 'deepcopy makes new nested lists'
+assert deep[1] is not nested[1], 'deepcopy makes new nested dicts'
+assert deep[2] is not nested[2], 'deepcopy makes new tuples holding mutable items'
+deep[0].append(99)
+assert inner == [1, 2], 'mutating the deep copy leaves the original unchanged'
+assert deep[1]['k'][1] is deep[0], 'deepcopy preserves shared references'
+assert deep[2][0] is deep[0], 'deepcopy preserves shared references through tuples'
+
+t = (1, 'a', (2, 3))
+assert deepcopy(t) is t, 'deepcopy of immutable tuple returns the same tuple'
+assert deepcopy('abc') == 'abc', 'deepcopy of str'
+assert deepcopy(frozenset({1})) == frozenset({1}), 'deepcopy of frozenset'
+
+s = {1, (2, 3)}
+deep = deepcopy(s)
+assert deep == s and deep is not s, 'deepcopy of set makes a new set'
+
+d = {(1, 2): [3], 'x': {'y': [4]}}
+deep = deepcopy(d)
+assert deep == d, 'deepcopy of dict is equal'
+assert deep['x'] is not d['x'] and deep['x']['y'] is not d['x']['y'], 'deepcopy of dict copies values'
+assert list(deep) == [(1, 2), 'x'], 'deepcopy of dict keeps key order'
+
+# === deepcopy of self-referential structures ===
+a = [1]
+a.append(a)
+b = deepcopy(a)
+assert b is not a, 'deepcopy of self-referential list makes a new list'
+assert b[1] is b, 'deepcopy of self-referential list refers to itself'
+assert b[0] == 1, 'deepcopy of self-referential list keeps items'
+
+d = {}
+d['self'] = d
+d2 = deepcopy(d)
+assert d2['self'] is d2 and d2 is not d, 'deepcopy of self-referential dict refers to itself'
+
+cyc = []
+t = (cyc,)
+cyc.append(t)
+t2 = deepcopy(t)
+assert t2 is not t, 'deepcopy of tuple in a cycle makes a new tuple'
+assert t2[0][0] is t2, 'deepcopy of tuple in a cycle refers to the copied tuple'
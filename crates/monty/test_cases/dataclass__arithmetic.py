@@ -0,0 +1,20 @@
+# call-external
+# === Binary operators are dispatched to the host ===
+a = make_vector(1, 2)
+b = make_vector(10, 20)
+assert a + b == make_vector(11, 22), '__add__'
+assert b - a == make_vector(9, 18), '__sub__'
+assert a * 3 == make_vector(3, 6), '__mul__'
+assert b / 5 == make_vector(2, 4), '__truediv__'
+
+# === Reflected methods are used when the left operand is a builtin ===
+assert 1 + a == make_vector(2, 3), '__radd__ with an int on the left'
+assert 2 * a == make_vector(2, 4), '__rmul__ with an int on the left'
+
+# === Augmented assignment falls back to the binary methods ===
+c = a
+c += b
+assert c == make_vector(11, 22), '+= uses __add__'
+assert a == make_vector(1, 2), 'frozen dataclass is not modified by +='
+c *= 2
+assert c == make_vector(22, 44), '*= uses __mul__'
@@ -0,0 +1,24 @@
+# call-external
+# === Ordering comparisons are dispatched to the host ===
+p = make_point()
+q = p.add(0, 1)
+r = p.add(1, -5)
+assert p < q, 'lt compares fields in order'
+assert q > p, 'gt'
+assert p <= q and p <= make_point(), 'le'
+assert r >= q and not q >= r, 'ge'
+assert not (p < make_point()), 'lt is false for equal points'
+
+# === Equality compares fields ===
+assert p == make_point(), 'equal fields compare equal'
+assert p != q, 'different fields compare unequal'
+assert not (p != make_point()), 'ne is false for equal points'
+
+# === Sorting asks the host for __lt__ ===
+points = [p.add(i % 5, -i) for i in range(40)]
+ordered = sorted(points)
+assert [(pt.x, pt.y) for pt in ordered] == sorted([(pt.x, pt.y) for pt in points]), 'sorted orders by __lt__'
+assert sorted(points, reverse=True) == ordered[::-1], 'reverse sorts by __lt__ too'
+points.sort()
+assert points == ordered, 'list.sort orders by __lt__'
+assert sorted([q, p], key=lambda pt: pt) == [p, q], 'keys that are dataclasses are compared by the host'
@@ -0,0 +1,72 @@
+# call-external
+# === getattr/setattr/hasattr on dataclass instances ===
+point = make_point()
+mut_point = make_mutable_point()
+
+# === getattr on existing fields ===
+assert getattr(point, 'x') == 1, 'getattr existing field'
+assert getattr(point, 'y', 99) == 2, 'getattr existing field ignores default'
+
+# === getattr on missing fields ===
+assert getattr(point, 'z', None) is None, 'getattr missing field with None default'
+assert getattr(point, 'z', 42) == 42, 'getattr missing field with default'
+
+try:
+    getattr(point, 'z')
+    assert False, 'getattr missing field without default should raise'
+except AttributeError as e:
+    assert str(e) == "'Point' object has no attribute 'z'", str(e)
+
+# === getattr with a name built at runtime ===
+name = 'x' + ''
+assert getattr(point, name) == 1, 'getattr with non-literal name'
+
+# === setattr ===
+assert setattr(mut_point, 'x', 10) is None, 'setattr returns None'
+assert mut_point.x == 10, 'setattr updates field'
+assert getattr(mut_point, 'x') == 10, 'getattr sees setattr update'
+
+field = ''.join(['y'])
+setattr(mut_point, field, 20)
+assert mut_point.y == 20, 'setattr with non-literal name'
+assert repr(mut_point) == 'MutablePoint(x=10, y=20)', repr(mut_point)
+
+setattr(mut_point, 'extra', [1, 2])
+assert mut_point.extra == [1, 2], 'setattr adds new attribute'
+
+try:
+    setattr(point, 'x', 5)
+    assert False, 'setattr on frozen dataclass should raise'
+except AttributeError as e:
+    assert str(e) == "cannot assign to field 'x'", str(e)
+
+try:
+    setattr(mut_point, 1, 2)
+    assert False, 'setattr with non-string name should raise'
+except TypeError as e:
+    assert str(e) == "attribute name must be string, not 'int'", str(e)
+
+try:
+    setattr(mut_point, 'x')
+    assert False, 'setattr with 2 args should raise'
+except TypeError as e:
+    assert str(e) == 'setattr expected 3 arguments, got 2', str(e)
+
+# === hasattr ===
+assert hasattr(point, 'x'), 'hasattr existing field'
+assert not hasattr(point, 'z'), 'hasattr missing field'
+assert hasattr(mut_point, 'extra'), 'hasattr attribute added by setattr'
+assert hasattr(slice(1, 2), 'start'), 'hasattr on builtin type attribute'
+assert not hasattr(1, 'foo'), 'hasattr on int'
+
+try:
+    hasattr(point, None)
+    assert False, 'hasattr with non-string name should raise'
+except TypeError as e:
+    assert str(e) == "attribute name must be string, not 'NoneType'", str(e)
+
+try:
+    hasattr(point)
+    assert False, 'hasattr with 1 arg should raise'
+except TypeError as e:
+    assert str(e) == 'hasattr expected 2 arguments, got 1', str(e)
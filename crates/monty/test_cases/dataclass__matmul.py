@@ -0,0 +1,10 @@
+# call-external
+# === Dataclass __matmul__ is dispatched to the host ===
+p = make_point()
+q = p.add(2, 3)
+assert p @ q == 13, 'matmul on dataclasses calls the host __matmul__'
+assert q @ p == 13, 'matmul with operands swapped'
+
+# === Result can be used like any other value ===
+total = (p @ p) + (q @ q)
+assert total == 5 + 34, 'matmul results in an expression'
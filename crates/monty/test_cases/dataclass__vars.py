@@ -0,0 +1,25 @@
+# call-external
+# === vars() and __dict__ on dataclass instances ===
+point = make_point()
+mut_point = make_mutable_point()
+
+assert vars(point) == {'x': 1, 'y': 2}, f'vars(point) {vars(point)=}'
+assert point.__dict__ == {'x': 1, 'y': 2}, 'point.__dict__'
+assert list(vars(point).keys()) == ['x', 'y'], 'vars preserves field order'
+
+# dynamically added attributes are included
+mut_point.z = 3
+assert vars(mut_point) == {'x': 1, 'y': 2, 'z': 3}, 'vars includes added attributes'
+
+# === vars() errors ===
+try:
+    vars(1)
+    assert False, 'vars(int) should raise TypeError'
+except TypeError as e:
+    assert str(e) == 'vars() argument must have __dict__ attribute', str(e)
+
+try:
+    vars([1, 2])
+    assert False, 'vars(list) should raise TypeError'
+except TypeError as e:
+    assert str(e) == 'vars() argument must have __dict__ attribute', str(e)
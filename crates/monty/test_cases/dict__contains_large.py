@@ -0,0 +1,29 @@
+# Membership on a large dict or set uses the hash table, so these lookups complete
+# well inside the test timeout. A linear scan would take ~10^8 comparisons.
+n = 100_000
+d = {i: i for i in range(n)}
+s = set(range(n))
+assert len(d) == n, 'dict size'
+assert len(s) == n, 'set size'
+
+# === Present keys ===
+found = 0
+for i in range(0, n, 100):
+    if i in d and i in s:
+        found += 1
+assert found == 1000, 'every present key is found'
+assert n - 1 in d, 'last key is found'
+assert 0 in s, 'first element is found'
+
+# === Absent keys ===
+missing = 0
+for i in range(n, n + 1000):
+    if i not in d and i not in s:
+        missing += 1
+assert missing == 1000, 'no absent key is found'
+assert -1 not in d, 'negative key is absent'
+assert 'x' not in s, 'other type is absent'
+
+# === Equal keys of other types ===
+assert 5.0 in d, 'float equal to a key is found'
+assert True in s, 'bool equal to an element is found'
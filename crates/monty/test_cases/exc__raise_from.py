@@ -0,0 +1,45 @@
+# === raise ... from sets __cause__ ===
+try:
+    raise ValueError('b') from KeyError('a')
+except ValueError as e:
+    assert repr(e) == "ValueError('b')", 'raised exception is unchanged'
+    assert repr(e.__cause__) == "KeyError('a')", 'cause is preserved'
+    assert isinstance(e.__cause__, KeyError), 'cause has its own type'
+    assert e.__cause__.args == ('a',), 'cause keeps its args'
+
+# === cause defaults to None ===
+try:
+    raise ValueError('no cause')
+except ValueError as e:
+    assert e.__cause__ is None, 'plain raise has no cause'
+
+# === raise ... from None ===
+try:
+    raise ValueError('suppressed') from None
+except ValueError as e:
+    assert e.__cause__ is None, 'from None has no cause'
+
+# === cause given as an exception type ===
+try:
+    raise ValueError('typed') from TypeError
+except ValueError as e:
+    assert repr(e.__cause__) == 'TypeError()', 'cause type is instantiated'
+
+# === chaining a caught exception ===
+try:
+    try:
+        {}['missing']
+    except KeyError as inner:
+        raise RuntimeError('lookup failed') from inner
+except RuntimeError as e:
+    assert repr(e.__cause__) == "KeyError('missing')", 'caught exception is the cause'
+
+# === nested causes ===
+try:
+    try:
+        raise ValueError('middle') from KeyError('root')
+    except ValueError as middle:
+        raise TypeError('top') from middle
+except TypeError as e:
+    assert repr(e.__cause__) == "ValueError('middle')", 'direct cause'
+    assert repr(e.__cause__.__cause__) == "KeyError('root')", 'cause of the cause'
@@ -1,3 +1,4 @@
+# === Raised exceptions without a message ===
 try:
     raise ValueError
 except ValueError as e:
@@ -13,3 +14,36 @@ except ValueError as e:
     assert repr(e) == 'ValueError()'
 else:
     raise AssertionError('should raise an error')
+
+# === Raised exceptions with a message ===
+try:
+    raise ValueError('msg')
+except ValueError as e:
+    assert str(e) == 'msg', 'str is the message'
+    assert repr(e) == "ValueError('msg')", 'repr quotes the message'
+else:
+    raise AssertionError('should raise an error')
+
+# === Exceptions that are never raised ===
+assert str(ValueError('msg')) == 'msg', 'str of unraised exception'
+assert repr(ValueError('msg')) == "ValueError('msg')", 'repr of unraised exception'
+assert str(TypeError()) == '', 'str of unraised exception without message'
+assert repr(TypeError()) == 'TypeError()', 'repr of unraised exception without message'
+assert str(RuntimeError('')) == '', 'empty message'
+assert repr(RuntimeError('')) == "RuntimeError('')", 'repr keeps an empty message'
+
+# === Quoting in repr ===
+assert repr(ValueError("it's")) == 'ValueError("it\'s")', 'single quote in message'
+assert repr(ValueError('a\nb')) == "ValueError('a\\nb')", 'newline is escaped in repr'
+assert str(ValueError('a\nb')) == 'a\nb', 'newline is kept in str'
+
+# === KeyError uses the repr of its key for str ===
+assert str(KeyError('k')) == "'k'", 'KeyError str is repr of key'
+assert repr(KeyError('k')) == "KeyError('k')", 'KeyError repr'
+assert str(KeyError()) == '', 'KeyError without key'
+
+# === Formatting exceptions ===
+e = IndexError('out of range')
+assert f'{e}' == 'out of range', 'f-string uses str'
+assert f'{e!r}' == "IndexError('out of range')", 'f-string !r uses repr'
+assert str([e]) == "[IndexError('out of range')]", 'containers use repr'
@@ -0,0 +1,2 @@
+raise ValueError('b') from 1
+# Raise=TypeError('exception causes must derive from BaseException')
@@ -0,0 +1,29 @@
+# === Exponent forms ===
+assert 1e10 == 10000000000.0, 'lower case exponent'
+assert 1E10 == 1e10, 'upper case exponent'
+assert 1.5E-3 == 0.0015, 'negative exponent'
+assert 2.5e+2 == 250.0, 'explicit positive exponent'
+assert 1e0 == 1.0, 'zero exponent'
+assert 007e2 == 700.0, 'leading zeros before exponent'
+assert type(1e10) is float, 'exponent literal is a float'
+
+# === Missing integer or fraction part ===
+assert .5 == 0.5, 'no integer part'
+assert 5. == 5.0, 'no fraction part'
+assert -.25 == -0.25, 'negative without integer part'
+assert .5e1 == 5.0, 'no integer part with exponent'
+assert 5.e-1 == 0.5, 'no fraction part with exponent'
+assert type(5.) is float, 'trailing point literal is a float'
+
+# === Underscores ===
+assert 1_000.000_1 == 1000.0001, 'underscores in both parts'
+assert 1_0e1_0 == 1e11, 'underscores in mantissa and exponent'
+assert .000_5 == 0.0005, 'underscore without integer part'
+assert 1_5. == 15.0, 'underscore without fraction part'
+
+# === Extremes ===
+assert 1e308 * 10 == float('inf'), 'largest exponent overflows on multiply'
+assert 1e400 == float('inf'), 'out of range literal is inf'
+assert 1e-400 == 0.0, 'underflowing literal is zero'
+assert 0.1 + 0.2 != 0.3, 'binary rounding is preserved'
+assert 1.7976931348623157e308 > 1e308, 'max finite float'
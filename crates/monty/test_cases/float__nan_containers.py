@@ -0,0 +1,48 @@
+nan = float('nan')
+
+# === Comparisons ===
+assert nan != nan, 'nan != nan'
+assert not (nan == nan), 'nan == nan is false'
+assert not (nan < 1), 'nan < 1 is false'
+assert not (nan > 1), 'nan > 1 is false'
+assert not (nan <= nan), 'nan <= nan is false'
+assert not (1 >= nan), '1 >= nan is false'
+
+# === Distinct nans are never the same key or item ===
+assert len({float('nan'), float('nan')}) == 2, 'distinct nans are distinct set items'
+assert float('nan') not in [float('nan')], 'distinct nan not in list'
+d = {float('nan'): 'a'}
+d[float('nan')] = 'b'
+assert len(d) == 2, 'distinct nans are distinct dict keys'
+assert [1, 2**53 + 1].count(2**53) == 0, 'unequal ints are never identical'
+
+# === Dict keys are found by identity ===
+key = (nan,)
+d = {key: 'a'}
+assert d[key] == 'a', 'nan tuple key retrieved by the same object'
+assert key in d, 'nan tuple key membership'
+d[key] = 'b'
+assert len(d) == 1, 'setting the same key replaces the value'
+assert d[key] == 'b', 'replaced value'
+assert d.pop(key) == 'b', 'pop nan tuple key'
+assert len(d) == 0, 'dict empty after pop'
+
+# === Sets deduplicate the same object ===
+s = {key, key, 1.0}
+assert len(s) == 2, 'same nan tuple added once'
+assert key in s, 'nan tuple set membership'
+s.discard(key)
+assert s == {1.0}, 'discard nan tuple'
+
+# === Sequences use identity before equality ===
+inner = [nan]
+assert inner in [1, inner], 'nan list in list'
+assert inner in (inner,), 'nan list in tuple'
+assert [inner] == [inner], 'lists containing the same nan list are equal'
+assert (inner, 1) == (inner, 1), 'tuples containing the same nan list are equal'
+assert [inner, inner].count(inner) == 2, 'count nan list'
+assert [1, inner].index(inner) == 1, 'index of nan list'
+assert {1: inner} == {1: inner}, 'dicts with the same nan list value are equal'
+lst = [1, inner, 2]
+lst.remove(inner)
+assert lst == [1, 2], 'remove nan list'
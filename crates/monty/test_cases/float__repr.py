@@ -0,0 +1,39 @@
+# === Shortest round-tripping repr ===
+assert repr(0.1) == '0.1', 'repr 0.1'
+assert repr(1.0) == '1.0', 'repr whole float keeps .0'
+assert repr(-2.5) == '-2.5', 'repr negative'
+assert repr(0.1 + 0.2) == '0.30000000000000004', 'repr keeps needed digits'
+assert repr(1 / 3) == '0.3333333333333333', 'repr 1/3'
+assert repr(0.0) == '0.0', 'repr zero'
+assert repr(-0.0) == '-0.0', 'repr negative zero'
+assert str(0.1) == '0.1', 'str matches repr'
+assert str(1e300) == '1e+300', 'str large matches repr'
+
+# === Fixed vs scientific notation ===
+assert repr(1e300) == '1e+300', 'repr huge'
+assert repr(1.5e300) == '1.5e+300', 'repr huge with fraction'
+assert repr(1e16) == '1e+16', 'repr switches to exponent at 1e16'
+assert repr(1e15) == '1000000000000000.0', 'repr fixed below 1e16'
+assert repr(123456789012345678.0) == '1.2345678901234568e+17', 'repr large with digits'
+assert repr(0.0001) == '0.0001', 'repr fixed at 1e-4'
+assert repr(0.00001) == '1e-05', 'repr exponent below 1e-4'
+assert repr(2.5e-7) == '2.5e-07', 'repr small exponent padded'
+assert repr(-1e-300) == '-1e-300', 'repr tiny negative'
+assert repr(5e-324) == '5e-324', 'repr smallest subnormal'
+assert repr(1.7976931348623157e308) == '1.7976931348623157e+308', 'repr max float'
+
+# === Special values ===
+assert repr(float('inf')) == 'inf', 'repr inf'
+assert repr(float('-inf')) == '-inf', 'repr negative inf'
+assert repr(float('nan')) == 'nan', 'repr nan'
+
+# === Containers and formatting use the same digits ===
+assert repr([0.1, 1e20]) == '[0.1, 1e+20]', 'repr in list'
+assert f'{0.1}' == '0.1', 'f-string without spec'
+assert f'{1 / 3:>20}' == '  0.3333333333333333', 'f-string with width only'
+assert f'{1e20:+}' == '+1e+20', 'f-string with sign only'
+assert f'{1.0:<6}|' == '1.0   |', 'f-string whole float with width'
+
+# === Round trip ===
+for x in [0.1, 1 / 3, 1e300, 1e-300, 2.5e-7, 123456789012345678.0, 1e16, 0.0001, -1.5]:
+    assert float(repr(x)) == x, 'repr round-trips'
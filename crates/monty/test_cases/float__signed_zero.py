@@ -0,0 +1,31 @@
+# === Equality and hashing ===
+assert 0.0 == -0.0, '0.0 == -0.0'
+assert hash(0.0) == hash(-0.0), 'hash(0.0) == hash(-0.0)'
+assert hash(-0.0) == hash(0), 'hash(-0.0) == hash(0)'
+assert not (-0.0 < 0.0), '-0.0 is not less than 0.0'
+
+# === repr keeps the sign ===
+assert repr(-0.0) == '-0.0', 'repr(-0.0)'
+assert repr(0.0) == '0.0', 'repr(0.0)'
+assert str(-0.0) == '-0.0', 'str(-0.0)'
+assert f'{-0.0}' == '-0.0', 'f-string of -0.0'
+assert f'{-0.0:.2f}' == '-0.00', 'fixed format of -0.0'
+assert repr([0.0 * -1]) == '[-0.0]', 'repr of computed -0.0 in list'
+
+# === Interchangeable as dict keys and set elements ===
+assert {0.0: 1}[-0.0] == 1, 'lookup -0.0 in dict keyed by 0.0'
+assert {-0.0: 1}[0.0] == 1, 'lookup 0.0 in dict keyed by -0.0'
+assert {0: 'a'}[-0.0] == 'a', 'lookup -0.0 in dict keyed by int 0'
+assert -0.0 in {0.0}, '-0.0 in set of 0.0'
+assert len({0.0, -0.0}) == 1, 'set deduplicates signed zeros'
+
+# === The first key is kept on update ===
+d = {0.0: 1}
+d[-0.0] = 2
+assert repr(d) == '{0.0: 2}', 'updating with -0.0 keeps the 0.0 key'
+d = {-0.0: 1, 0.0: 2}
+assert repr(d) == '{-0.0: 2}', 'literal keeps the first signed zero key'
+d = {1: 'a'}
+d[1.0] = 'b'
+assert repr(d) == "{1: 'b'}", 'updating with 1.0 keeps the int key'
+assert repr({0.0, -0.0}) == '{0.0}', 'set keeps the first signed zero'
@@ -147,6 +147,27 @@ assert f'{"test":{align}{width}}' == '   test   ', 'nested align and width'
 prec = 3
 assert f'{"xylophone":.{prec}}' == 'xyl', 'nested precision'
 
+# nested width and precision with numeric types
+w = 6
+p = 2
+y = 3.14159
+assert f'{42:{w}d}' == '    42', 'dynamic width int'
+assert f'{y:.{p}f}' == '3.14', 'dynamic precision float'
+assert f'{y:{w}.{p}f}' == '  3.14', 'dynamic width and precision'
+assert f'{y:>{w + 2}.{p + 1}f}' == '   3.142', 'expressions in nested spec'
+assert f'{42:{"0"}{w}}' == '000042', 'string literal in nested spec'
+
+# nested replacement fields take their own conversion and spec
+assert f'{42:{w:03}}' == '000042', 'nested spec formats nested value'
+assert f'{42:{w!s}}' == '    42', 'nested conversion'
+assert f'{42:{w=}}' == 'wwww42', 'nested debug text becomes part of spec'
+
+# quotes matching the outer quote style inside expressions
+assert f'{'a' + 'b'}' == 'ab', 'same quotes inside expression'
+assert f'{', '.join(['x', 'y'])}' == 'x, y', 'same quotes in method call'
+assert f'{f'{w}'}' == '6', 'nested f-string with same quotes'
+assert f"{"hi":>{w}}" == '    hi', 'same double quotes with nested spec'
+
 
 # === f-string in function ===
 def greet(name):
@@ -170,12 +191,10 @@ assert f'{{x}}' == '{x}', 'escaped braces with content'
 assert f'{{{42}}}' == '{42}', 'value inside escaped braces'
 
 # === Complex expressions ===
-# TODO: method call on literal - parser doesn't support this yet
-# assert f'{"hello".upper()}' == 'HELLO', 'method call on literal'
+assert f'{"hello".upper()}' == 'HELLO', 'method call on literal'
 
-# TODO: method call on variable - str.upper() not implemented yet
-# s = 'hello'
-# assert f'{s.upper()}' == 'HELLO', 'method call on variable'
+s = 'hello'
+assert f'{s.upper()}' == 'HELLO', 'method call on variable'
 
 # subscript in f-string
 lst = [10, 20, 30]
@@ -234,3 +253,12 @@ assert f'{name = }' == "name = 'test'", 'debug uses repr for strings'
 assert f'{name=!s}' == 'name=test', 'debug with !s conversion'
 assert f'{name=!r}' == "name='test'", 'debug with !r conversion'
 assert f'{1+1=}' == '1+1=2', 'debug with expression'
+x = 5
+assert f'{x=}' == 'x=5', 'debug int'
+f = 5.0
+assert f'{f = :.2f}' == 'f = 5.00', 'debug with spec formats value and keeps whitespace'
+assert f'{f=:>8}' == 'f=     5.0', 'debug with alignment spec'
+assert f'{name=:>5}' == 'name= test', 'debug with spec formats str without repr'
+assert f'{name=!r:>7}' == "name= 'test'", 'debug with conversion and spec'
+width = 6
+assert f'{f=:{width}.1f}' == 'f=   5.0', 'debug with nested spec'
@@ -0,0 +1,14 @@
+def create():
+    global later
+    later = 1
+
+
+print(later)
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "global__error_read_before_created.py", line 6, in <module>
+    print(later)
+          ~~~~~
+NameError: name 'later' is not defined
+"""
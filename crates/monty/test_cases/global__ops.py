@@ -161,3 +161,52 @@ def shadow_unchanged():
 
 assert shadow_unchanged() == 99, 'shadowing returns local'
 assert x7 == 10, 'global unchanged after shadowing'
+
+
+# === Creating new globals from functions ===
+def create_global():
+    global created
+    created = 'made'
+
+
+create_global()
+assert created == 'made', 'function creates a new module global'
+
+
+def update_created():
+    global created
+    created += '!'
+
+
+update_created()
+assert created == 'made!', 'created global can be updated by another function'
+
+
+def read_created():
+    return created
+
+
+assert read_created() == 'made!', 'created global is visible to other functions'
+
+
+def outer_creates():
+    def inner_creates():
+        global nested_created
+        nested_created = 1
+
+    inner_creates()
+
+
+outer_creates()
+assert nested_created == 1, 'nested function creates a new module global'
+
+
+def create_in_loop():
+    global loop_total
+    loop_total = 0
+    for i in range(4):
+        loop_total += i
+
+
+create_in_loop()
+assert loop_total == 6, 'created global assigned in a loop'
@@ -151,3 +151,53 @@ td[ValueError] = 'value_error'
 assert td[int] == 'int_type', 'int type as dict key'
 assert td[str] == 'str_type', 'str type as dict key'
 assert td[ValueError] == 'value_error', 'exception type as dict key'
+
+# === Numeric hash consistency across int, float and bool ===
+assert hash(1) == hash(1.0) == hash(True), 'equal int, float and bool hash equally'
+assert hash(0) == hash(0.0) == hash(-0.0) == hash(False), 'zeros hash equally'
+assert hash(-5) == hash(-5.0), 'negative int and float hash equally'
+assert hash(2**70) == hash(2.0**70), 'large int and float hash equally'
+assert hash(-(2**70)) == hash(-(2.0**70)), 'negative large int and float hash equally'
+assert hash(2**100 + 1) == hash(2**100 + 1), 'large int hash consistent'
+assert hash(5) == 5, 'small int hashes to itself'
+assert hash(-1) == -2, 'hash of -1 is -2'
+assert hash(2**61 - 1) == 0, 'hash modulus wraps to 0'
+assert hash(2**61) == 1, 'hash modulus wraps to 1'
+assert hash(0.5) == 2**60, 'fractional float hash'
+assert hash(1.5) == hash(1.5), 'fractional float hash consistent'
+assert hash(float('inf')) == 314159, 'inf hash'
+assert hash(float('-inf')) == -314159, 'negative inf hash'
+assert 2**70 == 2.0**70, 'large int equals float'
+assert 2.0**70 == 2**70, 'float equals large int'
+assert 2**70 + 1 != 2.0**70, 'large int not equal to nearby float'
+assert 2**53 + 1 != 2.0**53, 'int is not rounded to float for equality'
+assert 2.0**53 != 2**53 + 1, 'float is not equal to nearby int'
+assert 2**53 == 2.0**53, 'exact int equals float'
+assert -(2**63) == -(2.0**63), 'most negative int equals float'
+assert 2**63 - 1 != 2.0**63, 'largest int not equal to float above it'
+
+# === Equal numbers are the same dict key ===
+nd = {}
+nd[1] = 'a'
+assert nd[1.0] == 'a', 'float key finds int key'
+assert nd[True] == 'a', 'bool key finds int key'
+nd[1.0] = 'b'
+assert len(nd) == 1, 'float key overwrites int key'
+assert nd == {1: 'b'}, 'original int key is kept'
+assert {2**70: 'x'}[2.0**70] == 'x', 'float key finds large int key'
+assert {0.5: 'half'}.get(0.5) == 'half', 'fractional float key'
+
+# === Equal numbers are the same set element ===
+assert len({1, 1.0}) == 1, 'int and float dedupe in set'
+assert len({1, 1.0, True}) == 1, 'int, float and bool dedupe in set'
+assert len({0, 0.0, -0.0, False}) == 1, 'zeros dedupe in set'
+assert 1.0 in {1, 2}, 'float found in int set'
+assert 2**70 in {2.0**70}, 'large int found in float set'
+assert len({(1, 2), (1.0, 2.0)}) == 1, 'tuples of equal numbers dedupe'
+assert len({2**53 + 1, 2.0**53}) == 2, 'int and nearby float stay distinct'
+
+# === Keys with structured hashes ===
+shifted = {i << 32: i for i in range(2000)}
+assert len(shifted) == 2000, 'shifted int keys are all kept'
+assert shifted[1999 << 32] == 1999, 'shifted int key lookup'
+assert len({i << 40 for i in range(2000)}) == 2000, 'shifted int set elements are all kept'
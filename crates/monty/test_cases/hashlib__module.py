@@ -0,0 +1,61 @@
+import hashlib
+
+# === Known vectors ===
+assert hashlib.sha256(b'abc').hexdigest() == 'ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad', 'sha256 abc'
+assert hashlib.sha256(b'').hexdigest() == 'e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855', 'sha256 empty'
+assert hashlib.sha256().hexdigest() == hashlib.sha256(b'').hexdigest(), 'sha256 no data'
+assert hashlib.md5(b'abc').hexdigest() == '900150983cd24fb0d6963f7d28e17f72', 'md5 abc'
+assert hashlib.md5(b'').hexdigest() == 'd41d8cd98f00b204e9800998ecf8427e', 'md5 empty'
+
+# messages spanning more than one block
+long = b'abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq'
+assert hashlib.sha256(long).hexdigest() == '248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1', 'sha256 two blocks'
+assert hashlib.md5(b'a' * 1000).hexdigest() == 'cabe45dcc9ae5b66ba86600cca6b8ba8', 'md5 many blocks'
+
+# === digest() ===
+digest = hashlib.sha256(b'abc').digest()
+assert len(digest) == 32, 'sha256 digest length'
+assert digest[:4] == b'\xba\x78\x16\xbf', 'sha256 digest bytes'
+assert len(hashlib.md5(b'abc').digest()) == 16, 'md5 digest length'
+
+# === update() ===
+h = hashlib.sha256()
+h.update(b'a')
+h.update(b'bc')
+assert h.hexdigest() == hashlib.sha256(b'abc').hexdigest(), 'update concatenates'
+h2 = h.copy()
+h2.update(b'd')
+assert h.hexdigest() == hashlib.sha256(b'abc').hexdigest(), 'copy is independent'
+assert h2.hexdigest() == hashlib.sha256(b'abcd').hexdigest(), 'copy keeps data'
+
+# updates which straddle block boundaries
+data = bytes(range(256)) * 3
+for algo in (hashlib.md5, hashlib.sha256):
+    h = algo()
+    h.update(data[:1])
+    h.update(data[1:64])
+    h.update(data[64:200])
+    assert h.hexdigest() == algo(data[:200]).hexdigest(), 'partial blocks'
+    h.update(data[200:])
+    assert h.hexdigest() == algo(data).hexdigest(), 'digest does not finalize the object'
+    assert h.digest() == algo(data).digest(), 'repeated digest'
+
+# === Attributes ===
+assert h.name == 'sha256', 'sha256 name'
+assert h.digest_size == 32, 'sha256 digest_size'
+assert h.block_size == 64, 'sha256 block_size'
+assert hashlib.md5().name == 'md5', 'md5 name'
+assert hashlib.md5().digest_size == 16, 'md5 digest_size'
+
+# === str is rejected ===
+try:
+    hashlib.sha256('abc')
+    assert False, 'str data should raise'
+except TypeError as e:
+    assert str(e) == 'Strings must be encoded before hashing', f'constructor message: {e}'
+
+try:
+    hashlib.md5().update('abc')
+    assert False, 'str update should raise'
+except TypeError as e:
+    assert str(e) == 'Strings must be encoded before hashing', f'update message: {e}'
@@ -0,0 +1,68 @@
+import heapq
+
+# === heappush and heappop ===
+data = [5, 1, 8, 3, 9, 2, 7, 4, 6, 0]
+h = []
+for x in data:
+    assert heapq.heappush(h, x) is None, 'heappush returns None'
+assert h[0] == 0, 'smallest item is first'
+assert h == [0, 1, 2, 4, 3, 8, 7, 5, 6, 9], 'heap layout matches CPython'
+assert [heapq.heappop(h) for _ in range(len(data))] == list(range(10)), 'pops in ascending order'
+assert h == [], 'heap is empty after popping everything'
+
+# === heapify ===
+h = [9, 4, 7, 1, 0, 8, 2, 6, 3, 5]
+assert heapq.heapify(h) is None, 'heapify returns None'
+assert h == [0, 1, 2, 3, 4, 8, 7, 6, 9, 5], 'heapify layout matches CPython'
+out = []
+while h:
+    out.append(heapq.heappop(h))
+assert out == [0, 1, 2, 3, 4, 5, 6, 7, 8, 9], 'heapify then pop in ascending order'
+
+h = []
+heapq.heapify(h)
+assert h == [], 'heapify empty list'
+
+h = ['pear', 'apple', 'fig', 'banana']
+heapq.heapify(h)
+assert [heapq.heappop(h) for _ in range(4)] == ['apple', 'banana', 'fig', 'pear'], 'strings'
+
+h = [(2, 'b'), (1, 'z'), (1, 'a'), (3, 'c')]
+heapq.heapify(h)
+assert heapq.heappop(h) == (1, 'a'), 'tuples compare item by item'
+assert heapq.heappop(h) == (1, 'z'), 'tuples second pop'
+
+# === heappushpop ===
+h = [1, 3, 5]
+assert heapq.heappushpop(h, 0) == 0, 'item smaller than the heap is returned straight away'
+assert h == [1, 3, 5], 'heap unchanged'
+assert heapq.heappushpop(h, 1) == 1, 'item equal to the smallest is returned straight away'
+assert heapq.heappushpop(h, 4) == 1, 'smallest item is popped'
+assert h == [3, 4, 5], 'item pushed'
+assert heapq.heappushpop([], 7) == 7, 'empty heap returns the item'
+
+# === Errors ===
+try:
+    heapq.heappop([])
+    assert False, 'popping an empty heap should raise'
+except IndexError as e:
+    assert str(e) == 'index out of range', 'empty heappop message'
+
+try:
+    heapq.heappush((1, 2), 3)
+    assert False, 'tuple heap should raise'
+except TypeError as e:
+    assert str(e) == 'heappush() argument 1 must be list, not tuple', 'heappush non-list message'
+
+try:
+    heapq.heapify('abc')
+    assert False, 'str heap should raise'
+except TypeError as e:
+    assert str(e) == 'heapify() argument must be list, not str', 'heapify non-list message'
+
+h = [1]
+try:
+    heapq.heappush(h, 'a')
+    assert False, 'incomparable items should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'str' and 'int'", 'incomparable message'
@@ -0,0 +1,4 @@
+# syntax errors in a branch dropped by dead code elimination are still reported
+if False:
+    nonlocal x  # type: ignore
+# Raise=SyntaxError('nonlocal declaration not allowed at module level')
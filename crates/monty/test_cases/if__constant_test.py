@@ -0,0 +1,52 @@
+# Branches under a literal test are dropped at prepare time; behaviour must match Python.
+
+# === Dead branches don't run ===
+if False:
+    undefined_name()  # type: ignore
+if 0:
+    raise ValueError('dead')
+if '':
+    undefined_name()  # type: ignore
+else:
+    reached = True
+assert reached, 'else of a falsy literal runs'
+
+# === Only the true branch is kept ===
+if True:
+    x = 1
+else:
+    y = z  # type: ignore
+assert x == 1, 'true branch runs'
+
+if None:
+    a = 1
+elif 2 - 2:
+    a = 2
+elif 'text':
+    a = 3
+else:
+    a = 4
+assert a == 3, 'elif chain with literal tests'
+
+if 1 + 1 == 2:
+    b = 'computed'
+assert b == 'computed', 'non-literal test still evaluated'
+
+# === Builtins bound only in dead code stay builtins ===
+if False:
+    len = None
+assert len('abc') == 3, 'dead assignment does not shadow builtin'
+
+
+# === In functions, dead assignments still make a name local ===
+def f():
+    if False:
+        v = 1
+    return v
+
+
+try:
+    f()
+    assert False, 'should raise UnboundLocalError'
+except UnboundLocalError:
+    pass
@@ -260,7 +260,12 @@ assert d3[big_copy] == 'original', 'lookup with equal large bigint works'
 neg_min = 0 - MIN_I64
 assert neg_min == MAX_I64 + 1, 'neg i64::MIN promotes'
 
-# Note: ~bigger (bitwise not) tests skipped - Monty parser doesn't support ~ yet
+# === Unary invert ===
+assert ~(2**100) == -(2**100) - 1, '~bigint is -x - 1'
+assert ~(-(2**100)) == 2**100 - 1, '~negative bigint is -x - 1'
+assert ~~(2**100) == 2**100, 'double invert round-trips'
+assert ~MAX_I64 == MIN_I64, '~i64::MAX stays in i64 range'
+assert ~(MAX_I64 + 1) == MIN_I64 - 1, '~ just past i64::MAX'
 
 # === Floor division ===
 fd_result = bigger // 2
@@ -395,6 +400,19 @@ assert big ^ big == 0, 'bigint ^ same bigint'
 assert big >> 50 == 2**50, '2**100 >> 50'
 assert 1 << 100 == big, '1 << 100'
 assert (big + 0xFF) & 0xFF == 0xFF, 'bigint with low bits & mask'
+assert (1 << 100) | 1 == 1267650600228229401496703205377, '(1 << 100) | 1'
+assert (2**64 - 1) & 0xFF == 0xFF, '(2**64 - 1) & 0xFF'
+assert (2**64 - 1) & (2**64 - 1) == 2**64 - 1, 'bigint & bigint'
+assert (2**64) ^ (2**64 + 5) == 5, 'bigint ^ bigint demotes'
+assert -(2**100) & 0xFF == 0, 'negative bigint & mask'
+assert -(2**100) | 1 == -(2**100) + 1, 'negative bigint | 1'
+assert (-(2**100) - 1) & 0xF == 0xF, 'negative bigint & mask uses twos complement'
+assert -1 & (2**100) == 2**100, '-1 & bigint'
+assert -(2**100) >> 99 == -2, 'negative bigint >> int'
+assert -(2**100) >> 200 == -1, 'negative bigint >> huge shift'
+assert (2**100) >> 200 == 0, 'bigint >> huge shift'
+assert (2**100) << 1 == 2**101, 'bigint << int'
+assert True << 100 == big, 'bool << int promotes'
 
 # === Large result operations (should succeed with NoLimitTracker) ===
 # These are large but allowed since test runner uses NoLimitTracker
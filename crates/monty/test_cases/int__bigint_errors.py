@@ -175,6 +175,24 @@ except ValueError as e:
     # Accept either message since Monty doesn't support modular inverse yet
     assert 'not invertible' in str(e) or 'cannot be negative' in str(e), f'pow non-invertible error: {e}'
 
+try:
+    pow(2**70, -1, 2**66)  # both even, no inverse exists
+    assert False, 'pow(bigint, -1, bigint) should raise ValueError'
+except ValueError as e:
+    assert 'not invertible' in str(e) or 'cannot be negative' in str(e), f'pow bigint non-invertible error: {e}'
+
+try:
+    pow(2**70, 2, 0)
+    assert False, 'pow(bigint, 2, 0) should raise ValueError'
+except ValueError as e:
+    assert 'pow() 3rd argument cannot be 0' in str(e), f'pow bigint mod=0 error: {e}'
+
+try:
+    pow(2**70, 2, 5.0)
+    assert False, 'pow(bigint, int, float) should raise TypeError'
+except TypeError as e:
+    assert 'pow() 3rd argument not allowed unless all arguments are integers' in str(e), f'pow bigint float mod error: {e}'
+
 try:
     pow(2.0, 2, 5)
     assert False, 'pow(float, int, int) should raise TypeError'
@@ -0,0 +1,40 @@
+# === Big ints computed different ways are equal and hash alike ===
+a = 2**63
+b = (1 << 62) * 2
+c = 9223372036854775807 + 1
+assert a == b == c, 'same big int from power, shift and addition'
+assert hash(a) == hash(b) == hash(c), 'equal big ints hash alike'
+d = {a: 'pow'}
+d[b] = 'shift'
+assert len(d) == 1, 'equal big ints are the same dict key'
+assert d[c] == 'shift', 'lookup by a third computation'
+
+# === Big ints that shrink back into i64 range match small ints ===
+small = 2**64 - 2**64 + 5
+assert small == 5, 'demoted big int equals small int'
+assert hash(small) == hash(5), 'demoted big int hashes like small int'
+assert {5: 'x'}[small] == 'x', 'demoted big int as dict key'
+assert (2**63 - 1) == 9223372036854775807, 'i64 max from a big int'
+assert {9223372036854775807: 1}[2**63 - 1] == 1, 'i64 max dict key'
+
+# === Ordering against small ints and floats ===
+assert 2**63 > 9223372036854775807, 'big int greater than i64 max'
+assert -(2**63) - 1 < -9223372036854775807, 'negative big int less than small int'
+assert 2**64 > 1.5, 'big int greater than float'
+assert 1.5 < 2**64, 'float less than big int'
+assert 2**64 + 1 > 18446744073709551616.0, 'big int compared exactly with float'
+assert not (2**64 + 1 <= 18446744073709551616.0), 'big int not less or equal to rounded float'
+assert 2**64 == 18446744073709551616.0, 'big int equals float'
+assert float('inf') > 2**1000, 'inf greater than big int'
+assert -(2**1000) > float('-inf'), 'big int greater than -inf'
+assert not (float('nan') < 2**100), 'nan unordered with big int'
+assert max(2**70, 1.0e20) == 2**70, 'max of big int and float'
+assert sorted([2**65, 3.5, 2**64, -(2**70)]) == [-(2**70), 3.5, 2**64, 2**65], 'sorted mixed'
+assert 2**53 + 1 > 2.0**53, 'i64 int compared exactly with float'
+assert 2.0**53 < 2**53 + 1, 'float compared exactly with i64 int'
+assert not (2**53 + 1 <= 2.0**53), 'i64 int not less or equal to rounded float'
+assert 2**63 - 1 < 2.0**63, 'i64 max less than float above it'
+assert -(2**63) >= -(2.0**63), 'i64 min equal to float'
+assert 3 > 2.5 and -3 < -2.5, 'small int compared with fractional float'
+assert not (float('nan') > 1), 'nan unordered with small int'
+assert max(2**53 + 1, 2.0**53) == 2**53 + 1, 'max of i64 int and nearby float'
@@ -0,0 +1,59 @@
+# Every int operator promotes to a big int instead of wrapping or panicking
+maxsize = 9223372036854775807
+minsize = -maxsize - 1
+
+# === Power ===
+assert 2**70 == 1180591620717411303424, '2 ** 70'
+assert (-3) ** 41 == -36472996377170786403, 'negative base power'
+assert pow(2, 64) == 18446744073709551616, 'pow builtin'
+x = 2
+x **= 100
+assert x == 1267650600228229401496703205376, 'in-place power'
+
+# === Multiplication ===
+assert 10**30 * 10**30 == 10**60, '10**30 * 10**30'
+assert maxsize * 2 == 18446744073709551614, 'maxsize * 2'
+assert minsize * -1 == 9223372036854775808, 'minsize * -1'
+y = maxsize
+y *= maxsize
+assert y == 85070591730234615847396907784232501249, 'in-place multiplication'
+
+# === Left shift ===
+assert (1 << 100) == 1267650600228229401496703205376, '1 << 100'
+assert (1 << 63) == 9223372036854775808, '1 << 63'
+assert (-1 << 64) == -18446744073709551616, 'negative left shift'
+z = 3
+z <<= 70
+assert z == 3541774862152233910272, 'in-place left shift'
+
+# === Addition and subtraction ===
+assert maxsize + 1 == 9223372036854775808, 'maxsize + 1'
+assert minsize - 1 == -9223372036854775809, 'minsize - 1'
+assert 1 - minsize == 9223372036854775809, '1 - minsize'
+w = maxsize
+w += maxsize
+assert w == 18446744073709551614, 'in-place addition'
+assert sum([maxsize, maxsize, maxsize]) == 27670116110564327421, 'sum overflow'
+
+# === Negation, abs and division ===
+assert -minsize == 9223372036854775808, 'negate minsize'
+assert abs(minsize) == 9223372036854775808, 'abs minsize'
+assert minsize // -1 == 9223372036854775808, 'minsize // -1'
+assert divmod(minsize, -1) == (9223372036854775808, 0), 'divmod minsize'
+
+# === Results shrink back to small ints ===
+assert (maxsize + 1) - 1 == maxsize, 'big int back to small int'
+assert (1 << 100) >> 100 == 1, 'shift back down'
+assert type(maxsize + 1 - 1) is int, 'still int'
+
+# === round and formatting at the edges ===
+assert round(maxsize, -1) == 9223372036854775810, 'round maxsize to tens'
+assert round(minsize, -1) == -9223372036854775810, 'round minsize to tens'
+assert round(123456789012345678, -1) == 123456789012345680, 'round keeps every digit'
+assert round(maxsize, -19) == 10**19, 'round up past maxsize'
+assert round(15, -1) == 20, 'round half to even up'
+assert round(25, -1) == 20, 'round half to even down'
+assert round(5, -100) == 0, 'round with huge negative digits'
+assert f'{minsize}' == '-9223372036854775808', 'format minsize'
+assert f'{minsize:d}' == '-9223372036854775808', 'format minsize with d'
+assert f'{minsize:>25}' == '     -9223372036854775808', 'format minsize padded'
@@ -0,0 +1,31 @@
+# === Decimal ===
+assert 1_000 == 1000, 'decimal underscore'
+assert 1_000_000 == 1000000, 'decimal multiple underscores'
+assert -1_0 == -10, 'negative decimal underscore'
+assert 1_2_3_4 == 1234, 'underscore between every digit'
+
+# === Hex, octal and binary ===
+assert 0xFF_FF == 65535, 'hex underscore'
+assert 0x_FF == 255, 'hex underscore after prefix'
+assert 0X_ff_00 == 65280, 'upper case hex prefix'
+assert 0o7_7_7 == 511, 'octal underscore'
+assert 0o_17 == 15, 'octal underscore after prefix'
+assert 0b1010_1010 == 170, 'binary underscore'
+assert 0b_1 == 1, 'binary underscore after prefix'
+
+# === Big ints ===
+assert 1_000_000_000_000_000_000_000 == 10**21, 'big decimal underscore'
+assert 0xFFFF_FFFF_FFFF_FFFF_FF == 2**72 - 1, 'big hex underscore'
+assert 0b1_0000000000_0000000000_0000000000_0000000000_0000000000_0000000000_000 == 2**63, 'big binary underscore'
+assert 0o_1_000_000_000_000_000_000_000 == 8**21, 'big octal underscore'
+
+# === Floats ===
+assert 1_0.0_5 == 10.05, 'float underscores on both sides of the point'
+assert 1_000.5 == 1000.5, 'float underscore in integer part'
+assert 0.000_001 == 1e-6, 'float underscore in fraction'
+assert 1_0e1_0 == 1e11, 'float underscore in exponent'
+assert 1_5.2_5e-0_1 == 1.525, 'float underscores with negative exponent'
+
+# === int() parsing ===
+assert int('1_000') == 1000, 'int() accepts underscores'
+assert int('1_000_000_000_000_000_000_000') == 10**21, 'int() big with underscores'
@@ -0,0 +1,3 @@
+for *a in [[1, 2]]:
+    pass
+# Raise=SyntaxError('starred assignment target must be in a list or tuple')
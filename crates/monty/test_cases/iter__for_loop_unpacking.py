@@ -64,3 +64,40 @@ for k, v in d.items():
     vals.append(v)
 assert sorted(keys) == ['x', 'y'], 'dict items unpacking keys'
 assert sorted(vals) == [1, 2], 'dict items unpacking values'
+
+# === Starred unpacking ===
+rows = [[1, 2, 3], [4], (5, 6)]
+heads = []
+tails = []
+for a, *b in rows:
+    heads.append(a)
+    tails.append(b)
+assert heads == [1, 4, 5], 'for loop starred unpacking heads'
+assert tails == [[2, 3], [], [6]], 'for loop starred unpacking collects rest as lists'
+
+result = []
+for *init, last in ['abc', 'de']:
+    result.append((init, last))
+assert result == [(['a', 'b'], 'c'), (['d'], 'e')], 'for loop leading starred unpacking'
+
+result = []
+for first, *middle, last in [(1, 2, 3, 4), (5, 6)]:
+    result.append((first, middle, last))
+assert result == [(1, [2, 3], 4), (5, [], 6)], 'for loop middle starred unpacking'
+
+result = []
+for (a, *b), c in [((1, 2, 3), 'x'), ((4,), 'y')]:
+    result.append((a, b, c))
+assert result == [(1, [2, 3], 'x'), (4, [], 'y')], 'for loop nested starred unpacking'
+
+result = []
+for [a, *b] in [[1, 2], [3]]:
+    result.append((a, b))
+assert result == [(1, [2]), (3, [])], 'for loop list starred target'
+
+result = []
+for *a, in [(1, 2), ()]:
+    result.append(a)
+assert result == [[1, 2], []], 'for loop starred-only tuple target'
+
+assert [b for a, *b in rows] == [[2, 3], [], [6]], 'comprehension starred unpacking'
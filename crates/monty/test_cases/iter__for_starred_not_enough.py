@@ -0,0 +1,12 @@
+rows = [[1, 2, 3], [4]]
+seen = []
+for a, *b, c in rows:
+    seen.append((a, b, c))
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "iter__for_starred_not_enough.py", line 3, in <module>
+    for a, *b, c in rows:
+        ~~~~~~~~
+ValueError: not enough values to unpack (expected at least 2, got 1)
+"""
@@ -0,0 +1,6 @@
+# xfail=cpython
+# CPython would try to build the list until memory runs out
+import itertools
+
+list(itertools.count())
+# Raise=MemoryError('cannot materialize an infinite iterator')
@@ -0,0 +1,80 @@
+from itertools import chain, count, cycle, islice, repeat
+
+# === count ===
+assert list(islice(count(1), 5)) == [1, 2, 3, 4, 5], 'islice of count'
+assert list(islice(count(10, -3), 4)) == [10, 7, 4, 1], 'count with negative step'
+c = count()
+assert next(c) == 0, 'count starts at 0'
+assert next(c) == 1, 'count steps by 1'
+assert [n for n, _ in zip(count(5), 'abc')] == [5, 6, 7], 'zip with count'
+
+# === repeat ===
+assert list(repeat('x', 3)) == ['x', 'x', 'x'], 'repeat with times'
+assert list(repeat('x', 0)) == [], 'repeat zero times'
+assert list(repeat('x', -2)) == [], 'repeat negative times'
+assert list(islice(repeat(7), 2)) == [7, 7], 'repeat forever'
+r = repeat([1], 2)
+first = next(r)
+assert first is next(r), 'repeat yields the same object'
+
+# === cycle ===
+assert list(islice(cycle('ab'), 5)) == ['a', 'b', 'a', 'b', 'a'], 'cycle str'
+assert list(islice(cycle([1, 2, 3]), 7)) == [1, 2, 3, 1, 2, 3, 1], 'cycle list'
+assert list(cycle([])) == [], 'cycle empty'
+
+# === chain ===
+assert list(chain([1], [2, 3])) == [1, 2, 3], 'chain lists'
+assert list(chain()) == [], 'chain nothing'
+assert list(chain('ab', (1,), [], range(2))) == ['a', 'b', 1, 0, 1], 'chain mixed iterables'
+assert list(islice(chain([0], count(1)), 3)) == [0, 1, 2], 'chain with infinite iterator'
+
+# === islice ===
+assert list(islice(range(10), 2, 8, 3)) == [2, 5], 'islice start stop step'
+assert list(islice(range(10), 7, None)) == [7, 8, 9], 'islice without stop'
+assert list(islice('abc', None)) == ['a', 'b', 'c'], 'islice with None stop'
+assert list(islice([1, 2], 5)) == [1, 2], 'islice past the end'
+assert list(islice([1, 2], 3, 1)) == [], 'islice start after stop'
+
+# islice only takes the items it needs
+it = iter([1, 2, 3, 4])
+assert list(islice(it, 2)) == [1, 2], 'islice first two'
+assert next(it) == 3, 'source iterator resumes after the slice'
+
+# === lazy iterators in for loops ===
+total = 0
+for n in count(1):
+    if n > 4:
+        break
+    total += n
+assert total == 10, 'for over count'
+
+items = []
+for x in chain(islice(count(), 2), repeat('z', 2)):
+    items.append(x)
+assert items == [0, 1, 'z', 'z'], 'for over chained lazy iterators'
+assert sum(islice(count(1), 100)) == 5050, 'sum of islice'
+
+# === errors ===
+try:
+    islice([1], -1)
+    assert False, 'negative stop should raise'
+except ValueError as e:
+    assert str(e) == 'Stop argument for islice() must be None or an integer: 0 <= x <= sys.maxsize.', 'stop msg'
+
+try:
+    islice([1], 0, 1, 0)
+    assert False, 'zero step should raise'
+except ValueError as e:
+    assert str(e) == 'Step for islice() must be a positive integer or None.', 'step msg'
+
+try:
+    islice([1])
+    assert False, 'missing stop should raise'
+except TypeError as e:
+    assert str(e) == 'islice expected at least 2 arguments, got 1', 'islice arg count msg'
+
+try:
+    list(chain([1], 2))
+    assert False, 'non-iterable should raise'
+except TypeError as e:
+    assert str(e) == "'int' object is not iterable", 'chain non-iterable msg'
@@ -0,0 +1,33 @@
+# === List of literals ===
+def make():
+    return [1, 2, 3, 4, 5, 6, 7, 8, 'nine', 10.0, None, True, b'x']
+
+
+a = make()
+assert a == [1, 2, 3, 4, 5, 6, 7, 8, 'nine', 10.0, None, True, b'x'], 'list display of literals'
+assert len(a) == 13, 'list display length'
+
+# === Each evaluation builds a new list ===
+a.append(14)
+a[0] = 100
+b = make()
+assert b[0] == 1, 'mutation does not leak into later displays'
+assert len(b) == 13, 'append does not leak into later displays'
+assert a is not b, 'displays are distinct objects'
+
+# === Tuple of literals ===
+t = (1, 2, 3, 4, 5, 6, 7, 8, 'nine', 10)
+assert t == (1, 2, 3, 4, 5, 6, 7, 8, 'nine', 10), 'tuple display of literals'
+assert t[8] == 'nine', 'tuple display indexing'
+assert sum(t[:8]) == 36, 'tuple display slice'
+
+# === Long ints and non-literal elements ===
+big = [1, 2, 3, 4, 5, 6, 7, 8, 2**70, 100000000000000000000000]
+assert big[9] == 100000000000000000000000, 'long int literal in display'
+x = 5
+mixed = [1, 2, 3, 4, 5, 6, 7, 8, x, x + 1]
+assert mixed[8:] == [5, 6], 'display with non-literal elements'
+
+# === Short displays ===
+assert [1, 2] == [1, 2], 'short list display'
+assert (1,) == (1,), 'short tuple display'
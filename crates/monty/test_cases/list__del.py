@@ -0,0 +1,75 @@
+# === Single items ===
+a = [1, 2, 3, 4]
+del a[0]
+assert a == [2, 3, 4], 'del first item'
+del a[-1]
+assert a == [2, 3], 'del negative index'
+
+# === Contiguous slices ===
+a = [0, 1, 2, 3, 4, 5]
+del a[1:3]
+assert a == [0, 3, 4, 5], 'del contiguous slice'
+del a[2:]
+assert a == [0, 3], 'del open-ended slice'
+del a[5:10]
+assert a == [0, 3], 'del slice past the end'
+del a[:]
+assert a == [], 'del whole list'
+
+# === Stepped slices ===
+a = list(range(10))
+del a[::2]
+assert a == [1, 3, 5, 7, 9], 'del stepped slice'
+a = list(range(10))
+del a[1:8:3]
+assert a == [0, 2, 3, 5, 6, 8, 9], 'del stepped slice with bounds'
+a = list(range(10))
+del a[::-3]
+assert a == [1, 2, 4, 5, 7, 8], 'del negative stepped slice'
+a = list(range(10))
+del a[7:2:-2]
+assert a == [0, 1, 2, 4, 6, 8, 9], 'del negative stepped slice with bounds'
+
+# === Items holding references ===
+inner = [1]
+a = [inner, [2], inner]
+del a[0:2]
+assert a == [[1]], 'del slice of lists'
+assert inner == [1], 'deleted reference still alive'
+
+# === Dict keys ===
+d = {'a': 1, 'b': 2}
+del d['a']
+assert d == {'b': 2}, 'del dict key'
+
+# === Errors ===
+try:
+    del a[5]
+    assert False, 'out of range index should raise'
+except IndexError as e:
+    assert str(e) == 'list assignment index out of range', f'index message: {e}'
+
+try:
+    del a['x']
+    assert False, 'str index should raise'
+except TypeError as e:
+    assert str(e) == 'list indices must be integers or slices, not str', f'type message: {e}'
+
+try:
+    del a[::0]
+    assert False, 'zero step should raise'
+except ValueError as e:
+    assert str(e) == 'slice step cannot be zero', f'step message: {e}'
+
+try:
+    del d['missing']
+    assert False, 'missing key should raise'
+except KeyError:
+    pass
+
+t = (1, 2)
+try:
+    del t[0]
+    assert False, 'tuple deletion should raise'
+except TypeError as e:
+    assert str(e) == "'tuple' object doesn't support item deletion", f'tuple message: {e}'
@@ -0,0 +1,3 @@
+# the value exists, but only after the end bound
+[1, 2, 3].index(3, 0, 2)
+# Raise=ValueError('list.index(x): x not in list')
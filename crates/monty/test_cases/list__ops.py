@@ -57,6 +57,8 @@ assert [1] * -1 == [], 'list mult negative'
 assert [] * 5 == [], 'empty list mult'
 assert [1, 2] * 1 == [1, 2], 'list mult one'
 assert [[1]] * 2 == [[1], [1]], 'nested list mult'
+assert [1] * -(2**63) == [], 'list mult min index negative'
+assert [] * (2**63 - 1) == [], 'empty list mult huge'
 
 # === List repetition augmented assignment (*=) ===
 lst = [1, 2]
@@ -122,6 +124,14 @@ lst = [1, 2, 3]
 lst.insert(-100, 'a')
 assert lst == ['a', 1, 2, 3], 'insert very negative clamps to 0'
 
+lst = [1, 2, 3]
+lst.insert(-(2**63), 'a')
+assert lst == ['a', 1, 2, 3], 'insert i64 min clamps to 0'
+
+lst = [1, 2, 3]
+lst.insert(2**63 - 1, 'a')
+assert lst == [1, 2, 3, 'a'], 'insert i64 max appends'
+
 # === list.pop() ===
 lst = [1, 2, 3]
 assert lst.pop() == 3, 'pop without argument returns last'
@@ -152,6 +162,11 @@ lst = ['a', 'b', 'c']
 lst.remove('b')
 assert lst == ['a', 'c'], 'remove string element'
 
+item = [5]
+lst = [item, item]
+lst.remove([5])
+assert lst == [[5]] and lst[0] is item, 'remove keeps remaining shared element alive'
+
 # === list.clear() ===
 lst = [1, 2, 3]
 lst.clear()
@@ -190,12 +205,31 @@ lst = []
 lst.extend([])
 assert lst == [], 'extend empty with empty'
 
+lst = [1, 2]
+lst.extend(lst)
+assert lst == [1, 2, 1, 2], 'extend with self'
+
+lst = [[1]]
+lst.extend(lst)
+assert lst[0] is lst[1], 'extend with self shares elements'
+
+lst = [1]
+lst.extend(x * 2 for x in [1, 2])
+assert lst == [1, 2, 4], 'extend with generator'
+
 # === list.index() ===
 lst = [1, 2, 3, 2]
 assert lst.index(2) == 1, 'index finds first occurrence'
 assert lst.index(3) == 2, 'index finds element'
 assert lst.index(2, 2) == 3, 'index with start'
 assert lst.index(2, 1, 4) == 1, 'index with start and end'
+assert lst.index(2, -2) == 3, 'index with negative start'
+assert lst.index(3, -3, -1) == 2, 'index with negative start and end'
+assert lst.index(1, -100) == 0, 'index with very negative start clamps to 0'
+assert lst.index(2, 2, 100) == 3, 'index with large end clamps to len'
+assert lst.index(1, -(2**63)) == 0, 'index with i64 min start'
+assert lst.index(1, -(10**100), 10**100) == 0, 'index with huge start and end clamps'
+assert lst.index(2, True) == 1, 'index with bool start'
 
 # === list.count() ===
 lst = [1, 2, 2, 3, 2]
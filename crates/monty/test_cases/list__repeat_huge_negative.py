@@ -0,0 +1,2 @@
+[1] * -(10**100)
+# Raise=OverflowError("cannot fit 'int' into an index-sized integer")
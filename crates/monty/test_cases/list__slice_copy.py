@@ -0,0 +1,35 @@
+# === Primitive-only slices ===
+a = list(range(10))
+s = a[2:6]
+assert s == [2, 3, 4, 5], 'contiguous slice'
+assert a[:] == a, 'full slice'
+assert a[:] is not a, 'full slice is a new list'
+assert a[8:100] == [8, 9], 'stop past end'
+assert a[7:3] == [], 'start after stop'
+assert a[::3] == [0, 3, 6, 9], 'stepped slice'
+assert a[::-4] == [9, 5, 1], 'negative step'
+
+# === Mutating the original doesn't change the slice ===
+a[3] = 99
+a.append(10)
+del a[0]
+assert s == [2, 3, 4, 5], 'slice unaffected by original'
+
+# === Mutating the slice doesn't change the original ===
+b = [1.5, None, True, 'x']
+c = b[1:]
+c[0] = 'changed'
+c.append(0)
+assert b == [1.5, None, True, 'x'], 'original unaffected by slice'
+assert c == ['changed', True, 'x', 0], 'slice mutated'
+
+# === Slices holding heap values ===
+inner = [1, 2]
+d = [inner, 'a' + 'b', (3, 4)]
+e = d[0:2]
+assert e == [[1, 2], 'ab'], 'slice of heap values'
+assert e[0] is inner, 'slice shares elements'
+inner.append(3)
+assert e[0] == [1, 2, 3], 'shared element mutation is visible'
+d[0] = None
+assert e[0] is inner, 'slice keeps its element'
@@ -0,0 +1,107 @@
+# A loop running inside an except handler must leave that handler's exception
+# alone when it breaks or continues; only handlers entered inside the loop are cleared.
+
+
+# === break in a loop inside an except handler ===
+def break_in_handler():
+    try:
+        raise ValueError('outer')
+    except ValueError:
+        found = None
+        for x in [1, 2, 3]:
+            if x == 2:
+                found = x
+                break
+        return found
+
+
+assert break_in_handler() == 2, 'break inside except handler finds item'
+
+
+# === continue in a loop inside an except handler ===
+def continue_in_handler():
+    try:
+        raise ValueError('outer')
+    except ValueError:
+        result = []
+        for x in [1, 2, 3, 4]:
+            if x % 2 == 0:
+                continue
+            result.append(x)
+        return result
+
+
+assert continue_in_handler() == [1, 3], 'continue inside except handler skips items'
+
+
+# === break in a while loop inside an except handler ===
+def while_break_in_handler():
+    try:
+        raise ValueError('outer')
+    except ValueError:
+        n = 0
+        while True:
+            n += 1
+            if n == 3:
+                break
+        return n
+
+
+assert while_break_in_handler() == 3, 'while break inside except handler'
+
+
+# === handler exception is still active after the loop ===
+def reraise_after_break():
+    try:
+        try:
+            raise ValueError('outer')
+        except ValueError:
+            for x in [1, 2, 3]:
+                if x == 2:
+                    break
+            for x in [1, 2, 3]:
+                continue
+            raise
+    except ValueError as e:
+        return str(e)
+
+
+assert reraise_after_break() == 'outer', 'bare raise after loop re-raises handler exception'
+
+
+# === break from a nested handler only clears handlers inside the loop ===
+def nested_handler_break():
+    result = []
+    try:
+        raise ValueError('outer')
+    except ValueError:
+        for x in [1, 2, 3]:
+            try:
+                raise TypeError('inner')
+            except TypeError:
+                result.append(x)
+                if x == 2:
+                    break
+                continue
+        result.append('after')
+    return result
+
+
+assert nested_handler_break() == [1, 2, 'after'], 'break from inner handler inside outer handler'
+
+
+# === break and else inside an except handler ===
+def search_in_handler(items, target):
+    try:
+        raise KeyError(target)
+    except KeyError:
+        for item in items:
+            if item == target:
+                break
+        else:
+            return 'missing'
+        return 'found'
+
+
+assert search_in_handler([1, 2, 3], 2) == 'found', 'break skips else inside handler'
+assert search_in_handler([1, 2, 3], 5) == 'missing', 'else runs without break inside handler'
@@ -0,0 +1,14 @@
+# a name declared global in the enclosing function is not a nonlocal binding
+def outer():
+    global y
+    y = 1
+
+    def inner():
+        nonlocal y
+        y = 2
+
+    return inner
+
+
+outer()
+# Raise=SyntaxError("no binding for nonlocal 'y' found")
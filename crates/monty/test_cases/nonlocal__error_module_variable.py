@@ -0,0 +1,14 @@
+# nonlocal cannot reach module-level variables, only enclosing function scopes
+x = 1
+
+
+def outer():
+    def inner():
+        nonlocal x
+        x = 2
+
+    return inner
+
+
+outer()
+# Raise=SyntaxError("no binding for nonlocal 'x' found")
@@ -0,0 +1,11 @@
+# nonlocal of a name not bound in any enclosing function is a syntax error
+def outer():
+    def inner():
+        nonlocal missing
+        missing = 1
+
+    return inner
+
+
+outer()
+# Raise=SyntaxError("no binding for nonlocal 'missing' found")
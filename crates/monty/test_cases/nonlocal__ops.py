@@ -351,3 +351,19 @@ def cell_contains_closure():
 
 
 assert cell_contains_closure() == 100, 'cell contains closure'
+
+
+# === Enclosing global is not captured ===
+def outer_declares_global():
+    global g3
+    g3 = 'module'
+
+    def inner():
+        return g3
+
+    return inner
+
+
+reader = outer_declares_global()
+g3 = 'changed'
+assert reader() == 'changed', 'inner reads the module global, not a cell'
@@ -0,0 +1,22 @@
+# call-external
+# xfail=cpython
+# `os.read_line()` and `os.read_chunk()` are Monty extensions, the test runner's host
+# has no input so both always answer with the empty end-of-input sentinel
+import os
+
+# === Streams are empty in tests ===
+assert os.read_line() == '', 'read_line at end of input'
+assert os.read_chunk(10) == '', 'read_chunk at end of input'
+
+# === read_chunk size validation ===
+try:
+    os.read_chunk(0)
+    assert False, 'zero size should raise'
+except ValueError as e:
+    assert str(e) == 'read_chunk size must be positive', 'zero size message'
+
+try:
+    os.read_chunk('10')
+    assert False, 'str size should raise'
+except TypeError as e:
+    assert str(e) == 'read_chunk size must be int, not str', 'str size message'
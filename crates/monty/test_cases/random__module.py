@@ -0,0 +1,71 @@
+# xfail=cpython
+# Monty's `random` uses an in-core xorshift generator, so seeded sequences differ from CPython's
+import random
+
+# === Seeded sequences are reproducible ===
+random.seed(42)
+assert [random.randint(1, 100) for _ in range(10)] == [43, 24, 60, 64, 3, 44, 92, 20, 58, 24], 'seeded randint'
+random.seed(42)
+assert [random.randint(1, 100) for _ in range(5)] == [43, 24, 60, 64, 3], 'reseeding restarts the sequence'
+
+random.seed(42)
+items = list(range(10))
+assert random.shuffle(items) is None, 'shuffle returns None'
+assert items == [0, 1, 6, 8, 3, 9, 5, 7, 4, 2], 'seeded shuffle is in place'
+
+random.seed(42)
+assert random.choice(['a', 'b', 'c']) == 'b', 'seeded choice from list'
+assert random.choice('xyz') == 'y', 'seeded choice from str'
+
+random.seed(0)
+assert random.random() == 0.4833481342839381, 'seeded random'
+random.seed()
+assert random.random() == 0.4833481342839381, 'seed() resets to the default seed'
+random.seed(None)
+assert random.randint(True, 10) == 9, 'bools are ints'
+
+random.seed(-1)
+assert random.randint(-5, 5) == -4, 'negative seeds and ranges'
+
+# === Values are in range ===
+for _ in range(200):
+    x = random.random()
+    assert 0.0 <= x < 1.0, 'random() in [0, 1)'
+    n = random.randint(-3, 3)
+    assert -3 <= n <= 3, 'randint includes both ends'
+    assert random.choice((1, 2, 3)) in (1, 2, 3), 'choice from tuple'
+assert random.randint(7, 7) == 7, 'single value range'
+big = random.randint(-(2**63), 2**63 - 1)
+assert -(2**63) <= big <= 2**63 - 1, 'full i64 range'
+
+items = [3, 1, 2, 5, 4]
+random.shuffle(items)
+assert sorted(items) == [1, 2, 3, 4, 5], 'shuffle keeps the items'
+empty = []
+random.shuffle(empty)
+assert empty == [], 'shuffle empty list'
+
+# === Errors ===
+try:
+    random.randint(5, 1)
+    assert False, 'empty range should raise'
+except ValueError as e:
+    assert str(e) == 'empty range in randrange(5, 2)', 'randint empty range message'
+
+try:
+    random.randint(1.5, 3)
+    assert False, 'float should raise'
+except TypeError as e:
+    assert str(e) == "'float' object cannot be interpreted as an integer", 'randint float message'
+
+try:
+    random.choice([])
+    assert False, 'empty choice should raise'
+except IndexError as e:
+    assert str(e) == 'Cannot choose from an empty sequence', 'choice empty message'
+
+try:
+    random.shuffle((1, 2))
+    assert False, 'tuple shuffle should raise'
+except TypeError as e:
+    assert str(e) == "'tuple' object does not support item assignment", 'shuffle tuple message'
@@ -0,0 +1,2 @@
+len(range(-9223372036854775807, 9223372036854775807))
+# Raise=OverflowError('Python int too large to convert to C ssize_t')
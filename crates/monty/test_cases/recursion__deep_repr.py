@@ -1,12 +1,13 @@
-# Test that deeply nested lists don't crash during repr()
-# Monty truncates with "..." at depth limit, CPython handles in C code
+# Test that deeply nested lists repr fully, independent of the recursion limit
+# (the test runner uses a recursion limit of 50, repr has its own depth budget)
 x = []
 for _ in range(200):
     x = [x]
 
-# Should not crash - either returns full repr or truncated with "..."
-result = repr(x)
-assert isinstance(result, str), 'repr should return a string'
-assert result.startswith('['), 'repr should start with ['
-# Either full repr or truncated
-assert result.endswith(']') or '...' in result, 'repr should end with ] or contain ...'
+assert repr(x) == '[' * 201 + ']' * 201, 'deep repr should not be truncated'
+assert str(x) == repr(x), 'str of nested list matches repr'
+
+d = {}
+for _ in range(200):
+    d = {'k': d}
+assert repr(d) == "{'k': " * 200 + '{}' + '}' * 200, 'deep dict repr should not be truncated'
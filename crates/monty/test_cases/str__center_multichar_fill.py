@@ -0,0 +1,2 @@
+'hi'.center(6, '->')
+# Raise=TypeError('The fill character must be exactly one character long')
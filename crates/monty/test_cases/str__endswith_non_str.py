@@ -0,0 +1,2 @@
+'hello'.endswith(['lo'])
+# Raise=TypeError('endswith first arg must be str or a tuple of str, not list')
@@ -0,0 +1,34 @@
+# === Hex escapes ===
+assert '\x41' == 'A', 'hex escape'
+assert '\xe9' == 'é', 'hex escape above ascii'
+assert len('\x00') == 1, 'hex null escape'
+
+# === \u and \U escapes ===
+assert '\u00e9' == 'é', 'short unicode escape'
+assert '\u20AC' == '€', 'upper case hex digits'
+assert '\U0001F600' == '😀', 'long unicode escape'
+assert len('\U0001F600') == 1, 'long unicode escape is one codepoint'
+assert ord('\U0001F600') == 0x1F600, 'long unicode escape codepoint'
+assert '\U00000041' == 'A', 'long unicode escape for ascii'
+
+# === Named escapes ===
+assert '\N{LATIN SMALL LETTER E WITH ACUTE}' == 'é', 'named escape'
+assert '\N{EURO SIGN}' == '€', 'named escape symbol'
+assert '\N{GRINNING FACE}' == '😀', 'named escape outside the BMP'
+assert '\N{latin small letter a}' == 'a', 'named escape is case insensitive'
+
+# === Simple and octal escapes ===
+assert '\t\n\r' == chr(9) + chr(10) + chr(13), 'control escapes'
+assert '\a\b\f\v' == chr(7) + chr(8) + chr(12) + chr(11), 'rare control escapes'
+assert '\\' == chr(92), 'escaped backslash'
+assert '\101' == 'A', 'octal escape'
+assert '\0' == chr(0), 'octal null escape'
+
+# === Escapes in bytes ===
+assert b'\x41\x42' == b'AB', 'bytes hex escapes'
+assert b'\x7f'[0] == 127, 'bytes hex escape value'
+assert b'\101' == b'A', 'bytes octal escape'
+
+# === Raw strings skip escape processing ===
+assert r'\u00e9' == '\\u00e9', 'raw string keeps \\u'
+assert len(r'\N{EURO SIGN}') == 13, 'raw string keeps \\N{...}'
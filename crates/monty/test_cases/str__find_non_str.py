@@ -0,0 +1,2 @@
+'hello'.find(1)
+# Raise=TypeError('find() argument 1 must be str, not int')
@@ -0,0 +1,88 @@
+# === Auto-numbered fields ===
+assert '{} {}'.format('a', 'b') == 'a b', 'auto numbering'
+assert '{}'.format(42) == '42', 'auto numbering int'
+assert 'x{}y'.format(None) == 'xNoney', 'auto numbering with literal text'
+assert ''.format(1, 2) == '', 'unused args are ignored'
+
+# === Positional fields ===
+assert '{0}{0}'.format('ab') == 'abab', 'positional reuse'
+assert '{1} {0}'.format('a', 'b') == 'b a', 'positional reorder'
+assert '{0}-{1}-{0}'.format(1, 2) == '1-2-1', 'positional mixed reuse'
+
+# === Named fields ===
+assert '{name} is {age}'.format(name='Ann', age=30) == 'Ann is 30', 'named fields'
+assert '{0} and {x}'.format('a', x='b') == 'a and b', 'positional mixed with named'
+assert '{} and {x}'.format('a', x='b') == 'a and b', 'auto mixed with named'
+
+# === Escaped braces ===
+assert '{{}}'.format() == '{}', 'escaped braces'
+assert '{{{}}}'.format(1) == '{1}', 'escaped braces around field'
+
+# === Conversions ===
+assert '{!r}'.format('hi') == "'hi'", 'repr conversion'
+assert '{0!s}'.format(1.5) == '1.5', 'str conversion'
+assert '{!a}'.format('é') == "'\\xe9'", 'ascii conversion'
+assert '{!r:>6}'.format('hi') == "  'hi'", 'conversion with spec'
+
+# === Format specs ===
+assert '{:>5}'.format('ab') == '   ab', 'right align'
+assert '{:*^7}'.format('ab') == '**ab***', 'center with fill'
+assert '{:.2f}'.format(3.14159) == '3.14', 'float precision'
+assert '{0:05d}'.format(42) == '00042', 'zero padded int'
+assert '{:x}'.format(255) == 'ff', 'hex'
+assert '{x:>{w}}'.format(x='ab', w=4) == '  ab', 'nested spec field'
+assert '{:{}}'.format('ab', 4) == 'ab  ', 'nested auto spec field'
+
+# === Accessors ===
+assert '{0[1]}'.format([10, 20]) == '20', 'index accessor'
+assert '{d[k]}'.format(d={'k': 'v'}) == 'v', 'key accessor'
+assert '{0[0][1]}'.format([[1, 2]]) == '2', 'chained index accessor'
+
+# === Errors ===
+try:
+    '{} {0}'.format(1)
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == 'cannot switch from automatic field numbering to manual field specification', 'auto then manual'
+
+try:
+    '{0} {}'.format(1)
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == 'cannot switch from manual field specification to automatic field numbering', 'manual then auto'
+
+try:
+    '{} {}'.format(1)
+    assert False, 'should raise'
+except IndexError as e:
+    assert str(e) == 'Replacement index 1 out of range for positional args tuple', 'index out of range'
+
+try:
+    '{missing}'.format()
+    assert False, 'should raise'
+except KeyError:
+    pass
+
+try:
+    'a } b'.format()
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == "Single '}' encountered in format string", 'single close brace'
+
+try:
+    'a {'.format()
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == "Single '{' encountered in format string", 'single open brace'
+
+try:
+    '{0'.format(1)
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == "expected '}' before end of string", 'unterminated field'
+
+try:
+    '{!x}'.format(1)
+    assert False, 'should raise'
+except ValueError as e:
+    assert str(e) == 'Unknown conversion specifier x', 'bad conversion'
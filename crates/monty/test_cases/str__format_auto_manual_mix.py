@@ -0,0 +1,2 @@
+'{} {1}'.format('a', 'b')
+# Raise=ValueError('cannot switch from automatic field numbering to manual field specification')
@@ -0,0 +1,86 @@
+# === Appending in a loop ===
+v = ''
+for i in range(1_000):
+    if i % 13 == 0:
+        v += 'x'
+assert v == 'x' * 77, 'loop append'
+
+s = ''
+for i in range(100):
+    s += str(i % 10)
+assert len(s) == 100, 'loop append length'
+assert s[:12] == '012345678901', 'loop append content'
+
+# === Aliases are not mutated ===
+# `* 1` builds a heap string, literal concatenation would be folded to a constant
+a = 'abc' * 1
+b = a
+a += 'd'
+assert a == 'abcd', 'target is extended'
+assert b == 'abc', 'alias keeps old value'
+
+a = 'abc' * 1
+items = [a]
+a += 'd'
+assert items == ['abc'], 'container keeps old value'
+
+a = 'abc' * 1
+a += a
+assert a == 'abcabc', 'self append'
+
+# === Bytes ===
+bb = b''
+for _ in range(5):
+    bb += b'ab'
+assert bb == b'ababababab', 'bytes loop append'
+c = bb
+bb += b'!'
+assert c == b'ababababab', 'bytes alias keeps old value'
+assert bb == b'ababababab!', 'bytes target is extended'
+
+# === Shared through function arguments, closures and globals ===
+def append_suffix(x):
+    x += '!'
+    return x
+
+
+orig = 'arg' * 1
+assert append_suffix(orig) == 'arg!', 'argument target is extended'
+assert orig == 'arg', 'caller keeps old value'
+
+
+def make_appender():
+    buf = 'cl' * 1
+    snapshot = buf
+
+    def append(t):
+        nonlocal buf
+        buf += t
+        return buf
+
+    return append, lambda: snapshot
+
+
+append, get_snapshot = make_appender()
+append('a')
+assert append('b') == 'clab', 'closure cell target is extended'
+assert get_snapshot() == 'cl', 'closure alias keeps old value'
+
+g = 'glob' * 1
+g_alias = g
+
+
+def extend_global():
+    global g
+    g += 'al'
+
+
+extend_global()
+assert g == 'global', 'global target is extended'
+assert g_alias == 'glob', 'global alias keeps old value'
+
+t = ('tu' * 1,)
+u = t[0]
+u += 'ple'
+assert t == ('tu',), 'tuple element keeps old value'
+assert u == 'tuple', 'tuple element alias is extended'
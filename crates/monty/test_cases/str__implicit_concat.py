@@ -0,0 +1,27 @@
+# === Plain literals ===
+assert 'foo' 'bar' == 'foobar', 'two adjacent literals'
+assert 'a' "b" 'c' == 'abc', 'mixed quote styles'
+assert 'x' '' 'y' == 'xy', 'empty literal in the middle'
+assert len('ab' 'cd') == 4, 'concatenated length'
+multi = (
+    'first line\n'
+    'second line\n'
+    'third line'
+)
+assert multi.count('\n') == 2, 'parenthesized multiline concatenation'
+assert multi.startswith('first line\nsecond'), 'parenthesized multiline content'
+assert 'tri' '''ple''' == 'triple', 'plain and triple-quoted'
+assert '\\' r'\n' == '\\\\n', 'escaped and raw literals'
+
+# === f-strings ===
+name = 'world'
+assert f'hello {name}' '!' == 'hello world!', 'f-string then plain'
+assert 'say: ' f'{name}' == 'say: world', 'plain then f-string'
+assert f'{1}' f'{2}' == '12', 'two f-strings'
+assert 'a' f'{name!r}' 'b' == "a'world'b", 'plain, f-string with conversion, plain'
+assert 'width: ' f'{name:>7}' '|' == 'width:   world|', 'f-string with format spec between literals'
+assert '{x}' f'{name}' == '{x}world', 'braces in plain literal are not interpolated'
+
+# === Bytes ===
+assert b'foo' b'bar' == b'foobar', 'adjacent bytes literals'
+assert b'\x00' rb'\x00' == b'\x00\\x00', 'bytes and raw bytes'
@@ -0,0 +1,2 @@
+'héllo'.index('l', 4)
+# Raise=ValueError('substring not found')
@@ -0,0 +1,44 @@
+# === Raw strings ===
+assert r'a\nb' == 'a\\nb', 'raw string keeps backslash escapes'
+assert len(r'a\nb') == 4, 'raw string length counts backslash'
+assert R'\t' == '\\t', 'upper case raw prefix'
+assert r'C:\Users\name' == 'C:\\Users\\name', 'raw windows path'
+assert r'\d+\.\d*' == '\\d+\\.\\d*', 'raw regex pattern'
+assert r'\'' == "\\'", 'raw string escaped quote keeps backslash'
+assert r"\"" == '\\"', 'raw double quoted escaped quote keeps backslash'
+
+# === Triple-quoted strings ===
+multi = """first
+second
+third"""
+assert multi == 'first\nsecond\nthird', 'triple double quoted multiline'
+assert multi.count('\n') == 2, 'triple quoted newlines'
+single = '''a 'quoted' "word"'''
+assert single == 'a \'quoted\' "word"', 'triple single quoted with inner quotes'
+assert """\tx""" == '\tx', 'triple quoted processes escapes'
+continued = """one \
+two"""
+assert continued == 'one two', 'backslash newline continuation'
+raw_multi = r"""a\n
+b"""
+assert raw_multi == 'a\\n\nb', 'raw triple quoted'
+
+# === Byte strings ===
+assert b'abc' == b'\x61\x62\x63', 'byte string'
+assert list(B'\x00\xff') == [0, 255], 'upper case bytes prefix with escapes'
+assert len(b'\n') == 1, 'byte string processes escapes'
+assert b"""x
+y""" == b'x\ny', 'triple quoted bytes'
+
+# === Raw byte strings ===
+assert rb'\x00' == b'\\x00', 'raw bytes keeps backslash'
+assert len(rb'\x00') == 4, 'raw bytes length'
+assert br'\n' == b'\\n', 'br prefix order'
+assert Rb'\t' == b'\\t', 'mixed case Rb prefix'
+assert bR'\t' == b'\\t', 'mixed case bR prefix'
+assert rb'''\d
+''' == b'\\d\n', 'raw triple quoted bytes'
+
+# === Implicit concatenation across prefixes ===
+assert 'a' r'\b' 'c' == 'a\\bc', 'str and raw str concatenate'
+assert b'a' rb'\b' == b'a\\b', 'bytes and raw bytes concatenate'
@@ -196,6 +196,19 @@ assert 'hello world'.rpartition(' ') == ('hello', ' ', 'world'), 'rpartition bas
 assert 'hello'.rpartition('x') == ('', '', 'hello'), 'rpartition not found'
 assert 'hello world test'.rpartition(' ') == ('hello world', ' ', 'test'), 'rpartition last'
 
+# partition/rpartition with multi-character and multi-byte separators
+assert 'key=value=x'.partition('=') == ('key', '=', 'value=x'), 'partition splits at first'
+assert 'key=value=x'.rpartition('=') == ('key=value', '=', 'x'), 'rpartition splits at last'
+assert 'aaa'.partition('aa') == ('', 'aa', 'a'), 'partition overlapping separator'
+assert 'aaa'.rpartition('aa') == ('a', 'aa', ''), 'rpartition overlapping separator'
+assert 'ñaña'.partition('ñ') == ('', 'ñ', 'aña'), 'partition multi-byte separator'
+assert 'ñaña'.rpartition('ñ') == ('ña', 'ñ', 'a'), 'rpartition multi-byte separator'
+assert 'a→b→c'.partition('→') == ('a', '→', 'b→c'), 'partition arrow separator'
+assert ''.partition('x') == ('', '', ''), 'partition empty string'
+assert ''.rpartition('x') == ('', '', ''), 'rpartition empty string'
+assert 'abc'.partition('abc') == ('', 'abc', ''), 'partition whole string'
+assert 'abc'.rpartition('abcd') == ('', '', 'abc'), 'rpartition separator longer than string'
+
 # === Phase 6: Replace/modify methods ===
 
 # replace()
@@ -210,6 +223,10 @@ assert 'hi'.center(6) == '  hi  ', 'center basic'
 assert 'hi'.center(6, '-') == '--hi--', 'center fillchar'
 assert 'hi'.center(2) == 'hi', 'center no padding'
 assert 'hi'.center(1) == 'hi', 'center smaller'
+assert 'ab'.center(5, '*') == '**ab*', 'center odd width puts extra fill left'
+assert 'abc'.center(6, '*') == '*abc**', 'center even width puts extra fill right'
+assert 'é'.center(4, 'ñ') == 'ñéññ', 'center multi-byte fillchar'
+assert 'hi'.center(-5) == 'hi', 'center negative width'
 
 # ljust()
 assert 'hi'.ljust(6) == 'hi    ', 'ljust basic'
@@ -227,6 +244,9 @@ assert '-42'.zfill(5) == '-0042', 'zfill negative'
 assert '+42'.zfill(5) == '+0042', 'zfill positive'
 assert '42'.zfill(2) == '42', 'zfill no padding'
 assert ''.zfill(3) == '000', 'zfill empty'
+assert '-42'.zfill(2) == '-42', 'zfill negative no padding'
+assert '-'.zfill(3) == '-00', 'zfill sign only'
+assert 'é'.zfill(3) == '00é', 'zfill counts codepoints'
 
 # === Phase 7: Additional tests for Python compatibility ===
 
@@ -238,6 +258,21 @@ assert 'hello'.endswith(('he', 'lo')) == True, 'endswith tuple first match'
 assert 'hello'.endswith(('lo', 'he')) == True, 'endswith tuple second match'
 assert 'hello'.endswith(('x', 'y')) == False, 'endswith tuple no match'
 assert 'hello'.startswith(('ell',), 1) == True, 'startswith tuple with start'
+assert 'hello'.endswith(('he', 'hel'), 0, 3) == True, 'endswith tuple with bounds'
+assert 'hello'.startswith(('he', 'lo'), 1, 4) == False, 'startswith tuple with bounds no match'
+assert 'hello'.startswith(()) == False, 'startswith empty tuple'
+assert 'héllo'.startswith(('l', 'é'), 1) == True, 'startswith tuple bounds are codepoints'
+assert 'héllo'.endswith(('é',), 0, 2) == True, 'endswith tuple end is codepoint'
+assert 'hello'.startswith(('lo',), -2) == True, 'startswith tuple negative start'
+assert 'hello'.endswith(('lo',), -2) == True, 'endswith tuple negative start'
+assert 'a'.startswith(('a', 1)) == True, 'tuple items after a match are not checked'
+
+# startswith/endswith with out-of-range bounds
+assert 'abc'.startswith('', 3) == True, 'empty prefix at end'
+assert 'abc'.startswith('', 5) == False, 'start past end matches nothing'
+assert 'abc'.endswith(('',), 4) == False, 'start past end matches nothing in tuple'
+assert 'hello'.startswith('', 3, 1) == False, 'end before start matches nothing'
+assert 'hello'.endswith('lo', 0, 100) == True, 'end past end is clamped'
 
 # find/rfind/index/rindex/count with None as start/end
 assert 'hello'.find('l', None) == 2, 'find with None start'
@@ -245,6 +280,29 @@ assert 'hello'.find('l', None, None) == 2, 'find with None start and end'
 assert 'hello'.find('l', 0, None) == 2, 'find with None end'
 assert 'hello'.rfind('l', None, None) == 3, 'rfind with None start and end'
 assert 'hello'.count('l', None, None) == 2, 'count with None start and end'
+
+# search methods use codepoint indices for bounds and results
+text = 'héllo wörld ñ'
+assert text.find('ö') == 7, 'find multi-byte codepoint index'
+assert text.find('l', 4) == 9, 'find with start after multi-byte chars'
+assert text.find('l', 4, 9) == -1, 'find end is codepoint'
+assert text.rfind('l', 0, 4) == 3, 'rfind with codepoint end'
+assert text.rfind('ñ') == 12, 'rfind multi-byte at end'
+assert text.index('wö', 2) == 6, 'index with start'
+assert text.rindex('l', -5, -2) == 9, 'rindex negative bounds'
+assert text.count('l', 1, 4) == 2, 'count with codepoint bounds'
+assert 'ñaña'.count('ñ', 1) == 1, 'count multi-byte with start'
+assert '日本語日本'.find('本', 2) == 4, 'find cjk with start'
+
+# search methods with out-of-range bounds
+assert 'abc'.find('', 3) == 3, 'find empty at end'
+assert 'abc'.find('', 5) == -1, 'find start past end'
+assert 'abc'.find('', 2, 1) == -1, 'find end before start'
+assert 'abc'.rfind('', 5) == -1, 'rfind start past end'
+assert 'abc'.find('', -10, 1) == 0, 'find negative start clamped'
+assert 'abc'.count('', 3) == 1, 'count empty at end'
+assert 'abc'.count('', 5) == 0, 'count start past end'
+assert 'abc'.count('', 2, 1) == 0, 'count end before start'
 assert 'hello'.startswith('he', None) == True, 'startswith with None start'
 assert 'hello'.endswith('lo', None, None) == True, 'endswith with None start and end'
 
@@ -292,6 +350,31 @@ assert '123hello'.isidentifier() == False, 'isidentifier digit start'
 assert 'hello world'.isidentifier() == False, 'isidentifier with space'
 assert 'hello-world'.isidentifier() == False, 'isidentifier with dash'
 assert 'class'.isidentifier() == True, 'isidentifier keyword'  # isidentifier doesn't check keywords
+assert 'foo'.isidentifier() == True, 'isidentifier simple'
+assert '1abc'.isidentifier() == False, 'isidentifier leading digit'
+assert 'café'.isidentifier() == True, 'isidentifier non-ascii letter'
+assert 'λx'.isidentifier() == True, 'isidentifier greek'
+assert '_1'.isidentifier() == True, 'isidentifier underscore then digit'
+assert 'a·b'.isidentifier() == True, 'isidentifier middle dot continues'
+assert '·b'.isidentifier() == False, 'isidentifier middle dot cannot start'
+assert '²'.isidentifier() == False, 'isidentifier superscript digit'
+assert '€'.isidentifier() == False, 'isidentifier currency symbol'
+
+# isprintable()
+assert 'hello'.isprintable() == True, 'isprintable basic'
+assert ''.isprintable() == True, 'isprintable empty'
+assert 'hello world'.isprintable() == True, 'isprintable space'
+assert 'héllo ñ 日本 €'.isprintable() == True, 'isprintable non-ascii'
+assert 'tab\there'.isprintable() == False, 'isprintable tab'
+assert 'line\n'.isprintable() == False, 'isprintable newline'
+assert '\x00'.isprintable() == False, 'isprintable nul'
+assert '\x7f'.isprintable() == False, 'isprintable delete'
+assert 'a\u00a0b'.isprintable() == False, 'isprintable no-break space'
+assert 'a\u200bb'.isprintable() == False, 'isprintable zero-width space'
+assert '\u2028'.isprintable() == False, 'isprintable line separator'
+assert '\ue000'.isprintable() == False, 'isprintable private use'
+assert '\U0001f970'.isprintable() == True, 'isprintable emoji from unicode 11'
+assert '\U0001fae0'.isprintable() == True, 'isprintable emoji from unicode 14'
 
 # istitle()
 assert 'Hello World'.istitle() == True, 'istitle basic'
@@ -47,6 +47,8 @@ assert 'x' * 0 == '', 'str mult zero'
 assert 'x' * -1 == '', 'str mult negative'
 assert '' * 5 == '', 'empty str mult'
 assert 'a' * 1 == 'a', 'str mult one'
+assert 'x' * -(2**63) == '', 'str mult min index negative'
+assert '' * (2**63 - 1) == '', 'empty str mult huge'
 
 # === String repetition augmented assignment (*=) ===
 s = 'ab'
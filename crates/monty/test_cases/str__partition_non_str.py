@@ -0,0 +1,2 @@
+'hello'.partition(1)
+# Raise=TypeError('must be str, not int')
@@ -0,0 +1,2 @@
+'ab' * (2**63 - 1)
+# Raise=OverflowError('repeated string is too long')
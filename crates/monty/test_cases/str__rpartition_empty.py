@@ -0,0 +1,2 @@
+'hello'.rpartition('')
+# Raise=ValueError('empty separator')
@@ -0,0 +1,30 @@
+# === Reversing multi-byte strings ===
+assert 'héllo'[::-1] == 'olléh', 'reverse latin-1'
+assert '日本語'[::-1] == '語本日', 'reverse cjk'
+assert 'a🎉b😀c'[::-1] == 'c😀b🎉a', 'reverse with emoji'
+assert ''[::-1] == '', 'reverse empty'
+
+# === Stepped slicing ===
+assert 'aébícó'[::2] == 'abc', 'step 2 over mixed width'
+assert 'aébícó'[1::2] == 'éíó', 'step 2 offset over mixed width'
+assert 'αβγδεζ'[::-2] == 'ζδβ', 'negative step 2'
+assert 'αβγδεζ'[4:0:-2] == 'εγ', 'negative step with bounds'
+assert 'αβγδεζ'[::10] == 'α', 'step larger than length'
+assert 'αβγδεζ'[::-9223372036854775808] == 'ζ', 'min i64 step'
+assert 'abc'[::-9223372036854775808] == 'c', 'min i64 step ascii'
+
+# === Omitted and negative bounds ===
+assert 'héllo'[:-1] == 'héll', 'drop last'
+assert 'héllo'[-3:] == 'llo', 'last three'
+assert 'héllo'[1:-1] == 'éll', 'strip ends'
+assert 'héllo'[-100:100] == 'héllo', 'out of range bounds clamp'
+assert 'héllo'[3:1] == '', 'start after stop'
+assert 'héllo'[:] == 'héllo', 'full copy'
+assert 'héllo'[-1:-4:-1] == 'oll', 'negative bounds negative step'
+assert 'héllo'[10:-10:-1] == 'olléh', 'out of range negative step'
+
+# === Heap strings (not interned) ===
+s = 'é' * 3 + 'abc'
+assert s[::-1] == 'cbaééé', 'heap string reverse'
+assert s[2:5] == 'éab', 'heap string contiguous'
+assert s[slice(None, None, -2)] == 'caé', 'heap string slice object'
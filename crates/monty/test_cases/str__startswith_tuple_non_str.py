@@ -0,0 +1,2 @@
+'hello'.startswith(('x', 1))
+# Raise=TypeError('tuple for startswith must only contain str, not int')
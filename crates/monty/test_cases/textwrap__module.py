@@ -0,0 +1,42 @@
+# xfail=cpython
+# Monty's textwrap never breaks long words, CPython breaks them by default
+import textwrap
+
+text = 'The quick brown fox jumps over the lazy dog and keeps running far away'
+
+# === wrap ===
+lines = textwrap.wrap(text, 20)
+assert lines == ['The quick brown fox', 'jumps over the lazy', 'dog and keeps', 'running far away'], 'wrap at 20'
+for line in lines:
+    assert len(line) <= 20, 'no line is longer than the width'
+assert ' '.join(lines) == text, 'no words are lost'
+assert textwrap.wrap(text, width=20) == lines, 'width as keyword'
+assert textwrap.wrap(text) == [text], 'default width is 70'
+
+assert textwrap.wrap('', 10) == [], 'empty text'
+assert textwrap.wrap('   \n\t ', 10) == [], 'whitespace only'
+assert textwrap.wrap('  one\ntwo\t three  ', 9) == ['one two', 'three'], 'any whitespace separates words'
+assert textwrap.wrap('exactly ten', 11) == ['exactly ten'], 'line exactly at the width'
+
+# === Long words ===
+lines = textwrap.wrap('a supercalifragilistic word', 10)
+assert lines == ['a', 'supercalifragilistic', 'word'], 'long words get their own line'
+for line in lines:
+    assert len(line) <= 10 or ' ' not in line, 'only single over-long words exceed the width'
+
+# === fill ===
+assert textwrap.fill(text, 20) == 'The quick brown fox\njumps over the lazy\ndog and keeps\nrunning far away', 'fill at 20'
+assert textwrap.fill('', 5) == '', 'fill empty text'
+
+# === Errors ===
+try:
+    textwrap.wrap('some text', 0)
+    assert False, 'zero width should raise'
+except ValueError as e:
+    assert str(e) == 'invalid width 0 (must be > 0)', 'zero width message'
+
+try:
+    textwrap.fill('some text', size=3)
+    assert False, 'unknown keyword should raise'
+except TypeError as e:
+    assert str(e) == "'size' is an invalid keyword argument for fill()", 'unknown keyword message'
@@ -0,0 +1,55 @@
+# xfail=cpython
+# `time.isoformat()` and `time.fromisoformat()` are Monty extensions, the test runner's
+# host answers `time.time()` with 2024-01-02T03:04:05.5 and `time.monotonic()` with 1234.25
+import time
+
+# === Clocks yield to the host ===
+now = time.time()
+assert now == 1704164645.5, 'time.time() returns the host time'
+assert time.monotonic() == 1234.25, 'time.monotonic() returns the host clock'
+
+# === Formatting timestamps ===
+assert time.isoformat(now) == '2024-01-02T03:04:05.500000', 'format host time'
+assert time.isoformat(0) == '1970-01-01T00:00:00', 'epoch without fraction'
+assert time.isoformat(0.000001) == '1970-01-01T00:00:00.000001', 'microseconds'
+assert time.isoformat(-1) == '1969-12-31T23:59:59', 'before the epoch'
+assert time.isoformat(951782400) == '2000-02-29T00:00:00', 'leap day'
+assert time.isoformat(-62135596800) == '0001-01-01T00:00:00', 'earliest time'
+assert time.isoformat(253402300799) == '9999-12-31T23:59:59', 'latest time'
+
+# === Parsing timestamps ===
+assert time.fromisoformat('2024-01-02T03:04:05.500000') == now, 'round trip'
+assert time.fromisoformat('2024-01-02T03:04:05.5Z') == now, 'short fraction and Z'
+assert time.fromisoformat('1970-01-01') == 0.0, 'date only'
+assert time.fromisoformat('1970-01-01 01:30') == 5400.0, 'space separator and minutes'
+assert time.fromisoformat('1970-01-02T01') == 90000.0, 'hours only'
+assert time.fromisoformat('1969-12-31T23:59:59') == -1.0, 'before the epoch'
+assert time.isoformat(time.fromisoformat('2000-02-29T12:00:00')) == '2000-02-29T12:00:00', 'leap day'
+
+# === Date math ===
+start = time.fromisoformat('2024-02-28T12:00:00')
+assert time.isoformat(start + 24 * 60 * 60) == '2024-02-29T12:00:00', 'add a day in a leap year'
+assert time.isoformat(start + 2 * 24 * 60 * 60) == '2024-03-01T12:00:00', 'add two days'
+
+# === Errors ===
+for bad in ['2024-13-01', '2023-02-29', '2024-01-02T24:00', '2024-01-02T03:04:05:06', '2024-01-02T03:04.5', '24-01-02', '']:
+    try:
+        time.fromisoformat(bad)
+    except ValueError as e:
+        assert str(e) == f"Invalid isoformat string: '{bad}'", 'invalid string message'
+    else:
+        assert False, f'{bad!r} should not parse'
+
+try:
+    time.isoformat(1e20)
+except ValueError as e:
+    assert str(e) == 'timestamp out of range for isoformat', 'out of range'
+else:
+    assert False, 'out of range timestamp should fail'
+
+try:
+    time.isoformat('0')
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', 'wrong type'
+else:
+    assert False, 'string timestamp should fail'
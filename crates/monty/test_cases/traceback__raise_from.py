@@ -0,0 +1,21 @@
+def check(value):
+    if value < 0:
+        raise ValueError('negative value') from TypeError('bad input')
+    return value
+
+
+check(-1)
+"""
+TRACEBACK:
+TypeError: bad input
+
+The above exception was the direct cause of the following exception:
+
+Traceback (most recent call last):
+  File "traceback__raise_from.py", line 7, in <module>
+    check(-1)
+    ~~~~~~~~~
+  File "traceback__raise_from.py", line 3, in check
+    raise ValueError('negative value') from TypeError('bad input')
+ValueError: negative value
+"""
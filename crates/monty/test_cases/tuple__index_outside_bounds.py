@@ -0,0 +1,3 @@
+# the value exists, but only before the start bound
+(1, 2, 3).index(1, 1)
+# Raise=ValueError('tuple.index(x): x not in tuple')
@@ -4,6 +4,12 @@ assert t.index(2) == 1, 'index finds first occurrence'
 assert t.index(3) == 2, 'index finds element'
 assert t.index(2, 2) == 3, 'index with start'
 assert t.index(2, 1, 4) == 1, 'index with start and end'
+assert t.index(2, -2) == 3, 'index with negative start'
+assert t.index(3, -3, -1) == 2, 'index with negative start and end'
+assert t.index(1, -100) == 0, 'index with very negative start clamps to 0'
+assert t.index(2, 2, 100) == 3, 'index with large end clamps to len'
+assert t.index(1, -(2**63)) == 0, 'index with i64 min start'
+assert t.index(1, -(10**100), 10**100) == 0, 'index with huge start and end clamps'
 
 t = ('a', 'b', 'c')
 assert t.index('b') == 1, 'index string in tuple'
@@ -17,3 +23,8 @@ assert ().count(1) == 0, 'count on empty tuple'
 
 t = ('a', 'b', 'a')
 assert t.count('a') == 2, 'count strings'
+
+t = ([1], (2, 3), 1.0)
+assert t.count(1) == 1, 'count compares with py_eq across int and float'
+assert t.count([1]) == 1, 'count nested list'
+assert t.index((2, 3)) == 1, 'index nested tuple'
@@ -45,6 +45,8 @@ assert (1,) * 0 == (), 'tuple mult zero'
 assert (1,) * -1 == (), 'tuple mult negative'
 assert () * 5 == (), 'empty tuple mult'
 assert (1, 2) * 1 == (1, 2), 'tuple mult one'
+assert (1,) * -(2**63) == (), 'tuple mult min index negative'
+assert () * (2**63 - 1) == (), 'empty tuple mult huge'
 
 # === tuple() constructor ===
 assert tuple() == (), 'tuple() empty'
@@ -102,11 +102,10 @@ assert (cmp := 10) > 5, 'walrus in comparison'
 assert cmp == 10, 'walrus assigned in comparison'
 
 # === Walrus in chained comparisons ===
-# Note: Chained comparisons like `0 < (mid := 5) < 10` are not yet supported
-# Testing a simpler comparison chain
-mid = (chain := 5)
-assert 0 < chain and chain < 10, 'walrus result used in comparison chain'
-assert mid == 5, 'walrus assigned correctly'
+assert 0 < (mid := 5) < 10, 'walrus in middle of comparison chain'
+assert mid == 5, 'walrus in chain assigned'
+assert not (10 < (skipped := 1) < (never := 2)), 'chain short-circuits after walrus'
+assert skipped == 1, 'walrus before short-circuit assigned'
 
 # === Walrus in boolean expressions ===
 # Short-circuit with and
@@ -176,3 +175,40 @@ tup = ((t1 := 'a'), (t2 := 'b'))
 assert tup == ('a', 'b'), 'walrus in tuple'
 assert t1 == 'a', 'first tuple walrus'
 assert t2 == 'b', 'second tuple walrus'
+
+
+# === Walrus binding a computed value in an if ===
+data = list(range(12))
+if (count := len(data)) > 10:
+    size = 'large'
+else:
+    size = 'small'
+assert size == 'large', 'walrus len in if condition'
+assert count == 12, 'walrus len assigned'
+
+# === Walrus consuming a queue in a while loop ===
+queue = [1, 2, 3]
+drained = []
+while queue and (head := queue.pop(0)) != 3:
+    drained.append(head)
+assert drained == [1, 2], 'walrus pops items until sentinel'
+assert head == 3, 'walrus holds sentinel after loop'
+
+# === Walrus reused by comprehension element ===
+values = [1, -2, 3, -4]
+doubled = [y for x in values if (y := x * 2) > 0]
+assert doubled == [2, 6], 'walrus in filter used by element'
+assert y == -8, 'walrus keeps last filter value'
+
+totals = {k: t for k in 'ab' if (t := k * 2)}
+assert totals == {'a': 'aa', 'b': 'bb'}, 'walrus in dict comprehension filter'
+assert t == 'bb', 'walrus in dict comprehension leaks'
+
+
+# === Walrus in comprehension binds function local ===
+def last_even(nums):
+    evens = [last := n for n in nums if n % 2 == 0]
+    return evens, last
+
+
+assert last_even([1, 2, 3, 4, 5]) == ([2, 4], 4), 'walrus in comprehension binds function local'
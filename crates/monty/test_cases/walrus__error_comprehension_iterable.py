@@ -0,0 +1,2 @@
+[x for x in (y := [1, 2])]
+# Raise=SyntaxError('assignment expression cannot be used in a comprehension iterable expression')
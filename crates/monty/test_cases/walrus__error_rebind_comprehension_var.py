@@ -0,0 +1,2 @@
+[(i := 0) for i in range(3)]
+# Raise=SyntaxError("assignment expression cannot rebind comprehension iteration variable 'i'")
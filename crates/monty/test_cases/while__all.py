@@ -204,3 +204,61 @@ while i < 2:
         result.append('inner-else')
     i += 1
 assert result == [0, 0], 'break skips inner else only'
+
+# === Condition uses truthiness of non-bool values ===
+items = [1, 2, 3]
+result = []
+while items:
+    result.append(items.pop())
+assert result == [3, 2, 1], 'while list condition stops when list is empty'
+
+s = 'abc'
+count = 0
+while s:
+    s = s[1:]
+    count += 1
+assert count == 3, 'while str condition stops at empty string'
+
+n = 3
+while n:
+    n -= 1
+assert n == 0, 'while int condition stops at zero'
+
+value = None
+ran = False
+while value:
+    ran = True
+else:
+    ran = 'else'
+assert ran == 'else', 'while None condition runs else immediately'
+
+
+# === While-else search in a function ===
+def find_index(items, target):
+    i = 0
+    while i < len(items):
+        if items[i] == target:
+            break
+        i += 1
+    else:
+        return -1
+    return i
+
+
+assert find_index([5, 6, 7], 6) == 1, 'while-else search skips else when found'
+assert find_index([5, 6, 7], 8) == -1, 'while-else search runs else when not found'
+assert find_index([], 1) == -1, 'while-else search on empty list runs else'
+
+
+# === Else runs once after the condition becomes false ===
+def count_else_runs():
+    i = 0
+    runs = 0
+    while i < 3:
+        i += 1
+    else:
+        runs += 1
+    return runs, i
+
+
+assert count_else_runs() == (1, 3), 'while else runs exactly once on normal exit'
@@ -4,7 +4,7 @@
 //! - Caching parsed code to avoid re-parsing
 //! - Snapshotting execution state for external function calls
 
-use monty::{MontyObject, MontyRun, NoLimitTracker, PrintWriter, RunProgress};
+use monty::{LoadError, MontyObject, MontyRun, NoLimitTracker, PrintWriter, RunProgress, SERIALIZATION_FORMAT_VERSION};
 
 // === MontyRun dump/load Tests ===
 
@@ -181,3 +181,64 @@ fn run_progress_complete_roundtrip() {
 
     assert_eq!(loaded.into_complete().unwrap(), MontyObject::Int(3));
 }
+
+// === Version Header Tests ===
+
+#[test]
+fn dump_starts_with_format_version() {
+    let runner = MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let bytes = runner.dump().unwrap();
+    assert_eq!(bytes[0], SERIALIZATION_FORMAT_VERSION);
+}
+
+#[test]
+fn load_rejects_different_format_version() {
+    let runner = MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut bytes = runner.dump().unwrap();
+    bytes[0] = SERIALIZATION_FORMAT_VERSION + 1;
+    let err = MontyRun::load(&bytes).unwrap_err();
+    assert!(matches!(err, LoadError::FormatVersion(_)));
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "serialized data has format version {}, expected {SERIALIZATION_FORMAT_VERSION}",
+            SERIALIZATION_FORMAT_VERSION + 1
+        )
+    );
+}
+
+#[test]
+fn load_rejects_different_monty_version() {
+    let runner = MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let bytes = runner.dump().unwrap();
+    // Rewrite the header with another crate version, keeping the payload
+    let (_, payload): (&str, &[u8]) = postcard::take_from_bytes(&bytes[1..]).unwrap();
+    let mut other = postcard::to_extend("0.0.0-other", vec![SERIALIZATION_FORMAT_VERSION]).unwrap();
+    other.extend_from_slice(payload);
+
+    let err = MontyRun::load(&other).unwrap_err();
+    assert!(matches!(err, LoadError::MontyVersion(_)));
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "serialized data was written by Monty 0.0.0-other, but this is Monty {}",
+            env!("CARGO_PKG_VERSION")
+        )
+    );
+}
+
+#[test]
+fn load_rejects_empty_data() {
+    let err = MontyRun::load(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "serialized data is empty");
+}
+
+#[test]
+fn run_progress_load_rejects_different_format_version() {
+    let runner = MontyRun::new("func()".to_owned(), "test.py", vec![], vec!["func".to_owned()]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, &mut PrintWriter::Stdout).unwrap();
+    let mut bytes = progress.dump().unwrap();
+    bytes[0] = SERIALIZATION_FORMAT_VERSION + 1;
+    let err = RunProgress::<NoLimitTracker>::load(&bytes).unwrap_err();
+    assert!(matches!(err, LoadError::FormatVersion(_)));
+}
@@ -0,0 +1,158 @@
+//! Tests for the process-wide compile cache used by `MontyRun::with_options`.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use monty::{
+    CompileCacheStats, DEFAULT_COMPILE_CACHE_CAPACITY, ExcType, MontyException, MontyObject, MontyRun, PrepareOptions,
+};
+
+/// The cache is shared by the whole process, so tests using it must not run concurrently.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Takes the test lock and resets the cache to its default state.
+fn fresh_cache() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    MontyRun::set_compile_cache_capacity(DEFAULT_COMPILE_CACHE_CAPACITY);
+    MontyRun::clear_compile_cache();
+    guard
+}
+
+fn new_cached(code: &str, input_names: &[&str]) -> MontyRun {
+    new_cached_with_options(code, input_names, PrepareOptions::new()).unwrap()
+}
+
+fn new_cached_with_options(
+    code: &str,
+    input_names: &[&str],
+    options: PrepareOptions,
+) -> Result<MontyRun, MontyException> {
+    let input_names = input_names.iter().map(|&name| name.to_owned()).collect();
+    MontyRun::with_options(
+        code.to_owned(),
+        "test.py",
+        input_names,
+        vec![],
+        options.compile_cache(true),
+    )
+}
+
+#[test]
+fn second_construction_reuses_cached_program() {
+    let _guard = fresh_cache();
+    let first = new_cached("x + 1", &["x"]);
+    let second = new_cached("x + 1", &["x"]);
+    assert_eq!(
+        MontyRun::compile_cache_stats(),
+        CompileCacheStats {
+            hits: 1,
+            misses: 1,
+            len: 1,
+            capacity: DEFAULT_COMPILE_CACHE_CAPACITY,
+        }
+    );
+    assert_eq!(
+        first.run_no_limits(vec![MontyObject::Int(1)]).unwrap(),
+        MontyObject::Int(2)
+    );
+    assert_eq!(
+        second.run_no_limits(vec![MontyObject::Int(2)]).unwrap(),
+        MontyObject::Int(3)
+    );
+}
+
+#[test]
+fn different_inputs_are_cached_separately() {
+    let _guard = fresh_cache();
+    new_cached("x + 1", &["x"]);
+    new_cached("x + 1", &["x", "y"]);
+    new_cached("x + 2", &["x"]);
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 3, 3));
+}
+
+#[test]
+fn different_options_are_cached_separately() {
+    let _guard = fresh_cache();
+    new_cached("x = 1", &[]);
+    let err = new_cached_with_options("x = 1", &[], PrepareOptions::new().frozen(true)).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::RuntimeError);
+    let script = new_cached_with_options("1 + 2", &[], PrepareOptions::new().no_implicit_return(true)).unwrap();
+    assert_eq!(script.run_no_limits(vec![]).unwrap(), MontyObject::None);
+    assert_eq!(new_cached("1 + 2", &[]).run_no_limits(vec![]).unwrap(), MontyObject::Int(3));
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 4, 3));
+}
+
+#[test]
+fn options_are_combined() {
+    let _guard = fresh_cache();
+    let options = PrepareOptions::new()
+        .frozen(true)
+        .no_implicit_return(true)
+        .denied_builtins(vec!["print".to_owned()])
+        .module_name("my_module");
+    let runner = new_cached_with_options("__name__ == 'my_module'", &[], options.clone()).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::None);
+    let err = new_cached_with_options("print(1)", &[], options.clone()).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::NameError);
+    let err = new_cached_with_options("x = 1", &[], options).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::RuntimeError);
+}
+
+#[test]
+fn uncached_programs_skip_the_cache() {
+    let _guard = fresh_cache();
+    MontyRun::with_options("1".to_owned(), "test.py", vec![], vec![], PrepareOptions::new()).unwrap();
+    MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 0, 0));
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let _guard = fresh_cache();
+    for _ in 0..2 {
+        let err = new_cached_with_options("1 +", &[], PrepareOptions::new()).unwrap_err();
+        assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    }
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 2, 0));
+}
+
+#[test]
+fn least_recently_used_program_is_evicted() {
+    let _guard = fresh_cache();
+    MontyRun::set_compile_cache_capacity(2);
+    new_cached("1", &[]);
+    new_cached("2", &[]);
+    // Using "1" again makes "2" the least recently used, so it's evicted by "3"
+    new_cached("1", &[]);
+    new_cached("3", &[]);
+    assert_eq!(MontyRun::compile_cache_stats().len, 2);
+    new_cached("1", &[]);
+    new_cached("2", &[]);
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses), (2, 4), "\"2\" should have been evicted");
+}
+
+#[test]
+fn zero_capacity_disables_caching() {
+    let _guard = fresh_cache();
+    MontyRun::set_compile_cache_capacity(0);
+    new_cached("1", &[]);
+    new_cached("1", &[]);
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 2, 0));
+}
+
+#[test]
+fn clear_removes_programs_and_resets_counters() {
+    let _guard = fresh_cache();
+    new_cached("1", &[]);
+    new_cached("1", &[]);
+    MontyRun::clear_compile_cache();
+    let stats = MontyRun::compile_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.len), (0, 0, 0));
+    new_cached("1", &[]);
+    assert_eq!(MontyRun::compile_cache_stats().misses, 1);
+}
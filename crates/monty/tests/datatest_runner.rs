@@ -241,6 +241,7 @@ const ITER_EXT_FUNCTIONS: &[&str] = &[
     "make_mutable_point", // () -> Dataclass Point(x=1, y=2) (mutable)
     "make_user",          // (name) -> Dataclass User(name=name, active=True) (immutable)
     "make_empty",         // () -> Dataclass Empty() (immutable, no fields)
+    "make_vector",        // (x, y) -> Dataclass Vector(x=x, y=y) (immutable, with arithmetic)
     "async_call",         // (x) -> async: returns x (coroutine that returns its argument)
 ];
 
@@ -413,6 +414,12 @@ fn dispatch_external_call(name: &str, args: Vec<MontyObject>) -> DispatchResult
                 .into(),
             )
         }
+        "make_vector" => {
+            assert!(args.len() == 2, "make_vector requires 2 arguments");
+            let x = i64::try_from(&args[0]).expect("make_vector: first arg must be int");
+            let y = i64::try_from(&args[1]).expect("make_vector: second arg must be int");
+            DispatchResult::Sync(vector_object(x, y).into())
+        }
         "async_call" => {
             // async_call(x) -> coroutine that returns x
             // This is an async function - use run_pending() and resolve later
@@ -494,6 +501,55 @@ fn dispatch_method_call(
             };
             MontyObject::String(format!("{label}({x}, {y})")).into()
         }
+        // Point.__matmul__(self, other) -> int (dot product)
+        ("Point", "__matmul__") => {
+            assert!(args.len() == 2, "Point.__matmul__ requires self, other");
+            let (x1, y1) = extract_point_fields(&args[0]);
+            let (x2, y2) = extract_point_fields(&args[1]);
+            MontyObject::Int(x1 * x2 + y1 * y2).into()
+        }
+        // Point ordering from `@dataclass(order=True)`, comparing (x, y) tuples
+        ("Point", "__lt__" | "__le__" | "__gt__" | "__ge__") => {
+            assert!(args.len() == 2, "Point.{method_name} requires self, other");
+            let ordering = extract_point_fields(&args[0]).cmp(&extract_point_fields(&args[1]));
+            let result = match method_name {
+                "__lt__" => ordering.is_lt(),
+                "__le__" => ordering.is_le(),
+                "__gt__" => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            };
+            MontyObject::Bool(result).into()
+        }
+        // Equality generated by `@dataclass`, comparing the class and fields
+        (_, "__eq__" | "__ne__") => {
+            assert!(args.len() == 2, "{class_name}.{method_name} requires self, other");
+            let equal = args[0] == args[1];
+            MontyObject::Bool(if method_name == "__eq__" { equal } else { !equal }).into()
+        }
+        // Vector arithmetic: `+`/`-` with another vector, `*`/`/` and reflected `+`/`*` with an int
+        ("Vector", "__add__" | "__sub__" | "__radd__" | "__mul__" | "__rmul__" | "__truediv__") => {
+            assert!(args.len() == 2, "Vector.{method_name} requires self, other");
+            let (x, y) = extract_point_fields(&args[0]);
+            let (x, y) = match method_name {
+                "__add__" => {
+                    let (ox, oy) = extract_point_fields(&args[1]);
+                    (x + ox, y + oy)
+                }
+                "__sub__" => {
+                    let (ox, oy) = extract_point_fields(&args[1]);
+                    (x - ox, y - oy)
+                }
+                _ => {
+                    let n = i64::try_from(&args[1]).expect("scalar must be int");
+                    match method_name {
+                        "__radd__" => (x + n, y + n),
+                        "__truediv__" => (x.div_euclid(n), y.div_euclid(n)),
+                        _ => (x * n, y * n),
+                    }
+                }
+            };
+            vector_object(x, y).into()
+        }
         // MutablePoint.shift(self, dx, dy) -> None (mutates in-place via host)
         // Note: In the test runner, we can't actually mutate the dataclass in-place
         // since the host doesn't have direct heap access. Return None as the method
@@ -513,7 +569,7 @@ fn dispatch_method_call(
     }
 }
 
-/// Extracts (x, y) fields from a Point or MutablePoint `MontyObject::Dataclass`.
+/// Extracts (x, y) fields from a Point, MutablePoint or Vector `MontyObject::Dataclass`.
 fn extract_point_fields(obj: &MontyObject) -> (i64, i64) {
     match obj {
         MontyObject::Dataclass { attrs, .. } => {
@@ -534,6 +590,21 @@ fn extract_point_fields(obj: &MontyObject) -> (i64, i64) {
     }
 }
 
+/// Builds an immutable `Vector(x=x, y=y)` dataclass.
+fn vector_object(x: i64, y: i64) -> MontyObject {
+    MontyObject::Dataclass {
+        name: "Vector".to_string(),
+        type_id: 0, // Test fixture has no real Python type
+        field_names: vec!["x".to_string(), "y".to_string()],
+        attrs: vec![
+            (MontyObject::String("x".to_string()), MontyObject::Int(x)),
+            (MontyObject::String("y".to_string()), MontyObject::Int(y)),
+        ]
+        .into(),
+        frozen: true,
+    }
+}
+
 /// Extracts a string kwarg value by key name.
 fn get_kwarg_str(kwargs: &[(MontyObject, MontyObject)], name: &str) -> Option<String> {
     for (key, value) in kwargs {
@@ -786,6 +857,11 @@ fn get_kwarg_bool(kwargs: &[(MontyObject, MontyObject)], name: &str) -> bool {
     false
 }
 
+/// Seconds since the epoch returned by `time.time()`, `2024-01-02T03:04:05.5`.
+const VIRTUAL_TIME: f64 = 1_704_164_645.5;
+/// Seconds returned by `time.monotonic()`.
+const VIRTUAL_MONOTONIC_TIME: f64 = 1234.25;
+
 /// Dispatches an OS function call using the virtual filesystem.
 ///
 /// Returns an `ExternalResult` to pass back to the Monty interpreter.
@@ -815,6 +891,14 @@ fn dispatch_os_call(
         ];
         return MontyObject::Dict(env_dict.into()).into();
     }
+    // The clocks take no arguments, and answer with a fixed time so tests are deterministic
+    match function {
+        OsFunction::Time => return MontyObject::Float(VIRTUAL_TIME).into(),
+        OsFunction::MonotonicTime => return MontyObject::Float(VIRTUAL_MONOTONIC_TIME).into(),
+        // There is no virtual input, so streams are always at their end
+        OsFunction::ReadLine | OsFunction::ReadChunk => return MontyObject::String(String::new()).into(),
+        _ => {}
+    }
 
     // Extract path from MontyObject::Path (or String for backwards compatibility)
     let path = match &args[0] {
@@ -824,7 +908,11 @@ fn dispatch_os_call(
     };
 
     match function {
-        OsFunction::GetEnviron => unreachable!("handled above"),
+        OsFunction::GetEnviron
+        | OsFunction::Time
+        | OsFunction::MonotonicTime
+        | OsFunction::ReadLine
+        | OsFunction::ReadChunk => unreachable!("handled above"),
         OsFunction::Exists => {
             let exists = get_virtual_file(&path).is_some() || is_virtual_dir(&path);
             MontyObject::Bool(exists).into()
@@ -0,0 +1,67 @@
+use monty::{ExcType, MontyException, MontyObject, MontyRun, PrepareOptions};
+
+/// Prepares `code` with `denied` builtins disabled.
+fn run_denied(code: &str, denied: &[&str]) -> Result<MontyRun, MontyException> {
+    let denied = denied.iter().map(|name| (*name).to_owned()).collect();
+    MontyRun::with_options(
+        code.to_owned(),
+        "test.py",
+        vec![],
+        vec![],
+        PrepareOptions::new().denied_builtins(denied),
+    )
+}
+
+#[test]
+fn denied_builtin_call_fails_at_prepare() {
+    let exc = run_denied("print('hello')", &["print"]).expect_err("expected denied builtin error");
+    assert_eq!(exc.exc_type(), ExcType::NameError);
+    assert_eq!(exc.message(), Some("name 'print' is not defined"));
+}
+
+#[test]
+fn denied_builtin_reference_fails_at_prepare() {
+    let exc = run_denied("f = len\nf([1])", &["len"]).expect_err("expected denied builtin error");
+    assert_eq!(exc.exc_type(), ExcType::NameError);
+    assert_eq!(exc.message(), Some("name 'len' is not defined"));
+}
+
+#[test]
+fn denied_builtin_in_function_fails_at_prepare() {
+    let exc = run_denied("def f(x):\n    return sorted(x)\nf([2, 1])", &["sorted"])
+        .expect_err("expected denied builtin error");
+    assert_eq!(exc.exc_type(), ExcType::NameError);
+    assert_eq!(exc.message(), Some("name 'sorted' is not defined"));
+}
+
+#[test]
+fn denied_types_and_exceptions_fail_at_prepare() {
+    let exc = run_denied("list('ab')", &["list"]).expect_err("expected denied builtin error");
+    assert_eq!(exc.message(), Some("name 'list' is not defined"));
+    let exc = run_denied("ValueError('x')", &["ValueError"]).expect_err("expected denied builtin error");
+    assert_eq!(exc.message(), Some("name 'ValueError' is not defined"));
+}
+
+#[test]
+fn other_builtins_still_work() {
+    let runner = run_denied("len([1, 2, 3])", &["print"]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(3));
+}
+
+#[test]
+fn denied_name_can_be_bound() {
+    let runner = run_denied("def print(x):\n    return x * 2\nprint(21)", &["print"]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(42));
+}
+
+#[test]
+fn unknown_names_are_ignored() {
+    let runner = run_denied("abs(-1)", &["open", "eval"]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(1));
+}
+
+#[test]
+fn denied_builtin_in_dead_branch_is_ignored() {
+    let runner = run_denied("if False:\n    print('x')\n1", &["print"]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(1));
+}
@@ -0,0 +1,132 @@
+use monty::{ExcType, MontyException, MontyObject, MontyRun, PrepareOptions};
+
+/// Prepares `code` in frozen mode with the given inputs.
+fn new_frozen(code: &str, input_names: &[&str]) -> Result<MontyRun, MontyException> {
+    let input_names = input_names.iter().map(|&name| name.to_owned()).collect();
+    MontyRun::with_options(
+        code.to_owned(),
+        "test.py",
+        input_names,
+        vec![],
+        PrepareOptions::new().frozen(true),
+    )
+}
+
+/// Prepares `code` in frozen mode and returns the error message, asserting it is a `RuntimeError`.
+fn frozen_error(code: &str) -> String {
+    let exc = new_frozen(code, &[]).expect_err("expected frozen error");
+    assert_eq!(
+        exc.exc_type(),
+        ExcType::RuntimeError,
+        "unexpected error for {code:?}: {exc}"
+    );
+    exc.message().expect("frozen error has a message").to_owned()
+}
+
+#[test]
+fn pure_expression_runs() {
+    let runner = new_frozen("x * 2 + 1", &["x"]).unwrap();
+    let result = runner.run_no_limits(vec![MontyObject::Int(20)]).unwrap();
+    assert_eq!(result, MontyObject::Int(41));
+}
+
+#[test]
+fn pure_constructs_are_allowed() {
+    let code = "
+assert n > 0, 'n must be positive'
+if n > 2:
+    'big'
+[i * i for i in range(n) if i % 2 == 0] + sorted({3, 1, 2}) + list(map(lambda v: v + 1, [n]))
+";
+    let runner = new_frozen(code, &["n"]).unwrap();
+    let result = runner.run_no_limits(vec![MontyObject::Int(5)]).unwrap();
+    let expected = [0, 4, 16, 1, 2, 3, 6].into_iter().map(MontyObject::Int).collect();
+    assert_eq!(result, MontyObject::List(expected));
+}
+
+#[test]
+fn non_mutating_methods_are_allowed() {
+    let runner = new_frozen("'a,b'.split(',') + [s.upper()]", &["s"]).unwrap();
+    let result = runner.run_no_limits(vec![MontyObject::String("c".to_owned())]).unwrap();
+    let expected = ["a", "b", "C"]
+        .into_iter()
+        .map(|s| MontyObject::String(s.to_owned()))
+        .collect();
+    assert_eq!(result, MontyObject::List(expected));
+}
+
+#[test]
+fn assignment_is_rejected() {
+    assert_eq!(frozen_error("y = 1\ny"), "assignment is not allowed in frozen mode");
+    assert_eq!(frozen_error("a, b = 1, 2"), "assignment is not allowed in frozen mode");
+}
+
+#[test]
+fn assignment_is_accepted_without_frozen() {
+    let runner = MontyRun::new("y = 1\ny".to_owned(), "test.py", vec![], vec![]).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(1));
+}
+
+#[test]
+fn mutating_statements_are_rejected() {
+    let cases = [
+        ("x += 1", "augmented assignment is not allowed in frozen mode"),
+        ("x[0] = 1", "item assignment is not allowed in frozen mode"),
+        ("del x[0]", "item deletion is not allowed in frozen mode"),
+        ("x.a = 1", "attribute assignment is not allowed in frozen mode"),
+        ("for i in x:\n    pass", "for loop is not allowed in frozen mode"),
+        (
+            "def f():\n    return 1",
+            "function definition is not allowed in frozen mode",
+        ),
+        ("import sys", "import is not allowed in frozen mode"),
+        ("from typing import Any", "import is not allowed in frozen mode"),
+        ("global x", "global declaration is not allowed in frozen mode"),
+        (
+            "try:\n    1 / 0\nexcept ZeroDivisionError as e:\n    0",
+            "exception binding is not allowed in frozen mode",
+        ),
+    ];
+    for (code, expected) in cases {
+        let exc = new_frozen(code, &["x"]).expect_err("expected frozen error");
+        assert_eq!(exc.exc_type(), ExcType::RuntimeError, "unexpected error for {code:?}");
+        assert_eq!(exc.message(), Some(expected), "unexpected message for {code:?}");
+    }
+}
+
+#[test]
+fn mutating_expressions_are_rejected() {
+    assert_eq!(
+        frozen_error("(y := 1)"),
+        "assignment expression is not allowed in frozen mode"
+    );
+    assert_eq!(
+        frozen_error("(lambda: (y := 1))()"),
+        "assignment expression is not allowed in frozen mode"
+    );
+    assert_eq!(
+        frozen_error("[1].append(2)"),
+        "mutating method 'append' is not allowed in frozen mode"
+    );
+    assert_eq!(
+        frozen_error("{}.update(a=1)"),
+        "mutating method 'update' is not allowed in frozen mode"
+    );
+    assert_eq!(frozen_error("f = [].pop"), "assignment is not allowed in frozen mode");
+    assert_eq!(
+        frozen_error("([].sort)()"),
+        "mutating method 'sort' is not allowed in frozen mode"
+    );
+    assert_eq!(
+        frozen_error("setattr(1, 'a', 2)"),
+        "setattr() is not allowed in frozen mode"
+    );
+}
+
+#[test]
+fn frozen_error_points_at_construct() {
+    let exc = new_frozen("1 + 2\nvalue = 3", &[]).expect_err("expected frozen error");
+    let frame = &exc.traceback()[0];
+    assert_eq!(frame.start.line, 2);
+    assert_eq!(frame.preview_line.as_deref(), Some("value = 3"));
+}
@@ -0,0 +1,56 @@
+//! Tests for running code repeatedly on pooled heap storage with `MontyRun::run_reusing`.
+
+use monty::{ExcType, HeapPool, MontyObject, MontyRun, NoLimitTracker, PrintWriter};
+
+#[test]
+fn run_reusing_matches_run() {
+    let ex = MontyRun::new(
+        "x * 2 + len([x, x])".to_owned(),
+        "test.py",
+        vec!["x".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let mut pool = HeapPool::default();
+    for i in 0..5 {
+        let result = ex
+            .run_reusing(
+                &mut pool,
+                vec![MontyObject::Int(i)],
+                NoLimitTracker,
+                &mut PrintWriter::Stdout,
+            )
+            .unwrap();
+        assert_eq!(result, ex.run_no_limits(vec![MontyObject::Int(i)]).unwrap());
+    }
+}
+
+#[test]
+fn run_reusing_starts_each_run_empty() {
+    let code = "items = [str(i) for i in range(100)]\nitems[-1]";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut pool = HeapPool::default();
+    for _ in 0..3 {
+        let result = ex
+            .run_reusing(&mut pool, vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+            .unwrap();
+        assert_eq!(result, MontyObject::String("99".to_owned()));
+    }
+}
+
+#[test]
+fn run_reusing_after_exception() {
+    let mut pool = HeapPool::default();
+    let failing = MontyRun::new("[1, 2][5]".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let exc = failing
+        .run_reusing(&mut pool, vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::IndexError);
+
+    // The pool can be shared between runners
+    let ok = MontyRun::new("sum([1, 2, 3])".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let result = ok
+        .run_reusing(&mut pool, vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap();
+    assert_eq!(result, MontyObject::Int(6));
+}
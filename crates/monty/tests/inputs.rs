@@ -3,8 +3,10 @@
 //! These tests verify that `MontyObject` inputs are correctly converted to `Object`
 //! and can be used in Python code execution.
 
+use std::collections::HashMap;
+
 use indexmap::IndexMap;
-use monty::{ExcType, MontyObject, MontyRun};
+use monty::{ExcType, MontyException, MontyObject, MontyRun, NoLimitTracker, PrepareOptions, PrintWriter};
 
 // === Immediate Value Tests ===
 
@@ -431,3 +433,173 @@ double(2)
     let result = ex.run_no_limits(vec![MontyObject::Int(7)]).unwrap();
     assert_eq!(result, MontyObject::Int(4));
 }
+
+// === Input Validation Tests ===
+
+#[test]
+fn input_names_reported() {
+    let ex = MontyRun::new(
+        "x + y".to_owned(),
+        "test.py",
+        vec!["x".to_owned(), "y".to_owned()],
+        vec!["ext".to_owned()],
+    )
+    .unwrap();
+    assert_eq!(ex.input_names(), ["x", "y"]);
+}
+
+#[test]
+fn validate_inputs_ok_any_order() {
+    let ex = MontyRun::new(
+        "x + y".to_owned(),
+        "test.py",
+        vec!["x".to_owned(), "y".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    ex.validate_inputs(&["y", "x"]).unwrap();
+}
+
+#[test]
+fn validate_inputs_missing() {
+    let ex = MontyRun::new(
+        "x + y".to_owned(),
+        "test.py",
+        vec!["x".to_owned(), "y".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let exc = ex.validate_inputs(&["x"]).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::KeyError);
+    assert_eq!(exc.message(), Some("Missing required input: 'y'"));
+}
+
+#[test]
+fn validate_inputs_unexpected() {
+    let ex = MontyRun::new("x".to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    let exc = ex.validate_inputs(&["x", "z"]).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+    assert_eq!(exc.message(), Some("Unexpected input: 'z'"));
+}
+
+#[test]
+fn validate_inputs_duplicate() {
+    let ex = MontyRun::new("x".to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    let exc = ex.validate_inputs(&["x", "x"]).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+    assert_eq!(exc.message(), Some("Duplicate input: 'x'"));
+}
+
+// === Named Input Tests ===
+
+fn named(pairs: &[(&str, MontyObject)]) -> HashMap<String, MontyObject> {
+    pairs.iter().map(|(k, v)| ((*k).to_owned(), v.clone())).collect()
+}
+
+#[test]
+fn run_named_places_by_name() {
+    let ex = MontyRun::new(
+        "x - y".to_owned(),
+        "test.py",
+        vec!["x".to_owned(), "y".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let inputs = named(&[("y", MontyObject::Int(2)), ("x", MontyObject::Int(10))]);
+    let result = ex.run_named(inputs, NoLimitTracker, &mut PrintWriter::Stdout).unwrap();
+    assert_eq!(result, MontyObject::Int(8));
+}
+
+#[test]
+fn run_named_missing_input() {
+    let ex = MontyRun::new(
+        "x - y".to_owned(),
+        "test.py",
+        vec!["x".to_owned(), "y".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let inputs = named(&[("x", MontyObject::Int(10))]);
+    let exc = ex
+        .run_named(inputs, NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::KeyError);
+    assert_eq!(exc.message(), Some("Missing required input: 'y'"));
+}
+
+#[test]
+fn run_named_unexpected_input() {
+    let ex = MontyRun::new("x".to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    let inputs = named(&[("x", MontyObject::Int(1)), ("z", MontyObject::Int(2))]);
+    let exc = ex
+        .run_named(inputs, NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+    assert_eq!(exc.message(), Some("Unexpected input: 'z'"));
+}
+
+// === Read-only Inputs Tests ===
+
+/// Prepares `code` with `x` as a read-only input.
+fn new_read_only_inputs(code: &str) -> Result<MontyRun, MontyException> {
+    let options = PrepareOptions::new().read_only_inputs(true);
+    MontyRun::with_options(code.to_owned(), "test.py", vec!["x".to_owned()], vec![], options)
+}
+
+/// Prepares `code` with `x` as a read-only input and returns the `SyntaxError` message.
+fn read_only_input_error(code: &str) -> String {
+    let exc = new_read_only_inputs(code).expect_err("expected read-only input error");
+    assert_eq!(
+        exc.exc_type(),
+        ExcType::SyntaxError,
+        "unexpected error for {code:?}: {exc}"
+    );
+    exc.message().expect("syntax error has a message").to_owned()
+}
+
+#[test]
+fn read_only_input_assignment_rejected() {
+    assert_eq!(read_only_input_error("x = 1"), "cannot assign to input 'x'");
+}
+
+#[test]
+fn read_only_input_rebinding_forms_rejected() {
+    let cases = [
+        "x += 1",
+        "a, (b, x) = 1, (2, 3)",
+        "for x in range(3):\n    pass",
+        "(x := 1)",
+        "try:\n    1 / 0\nexcept ZeroDivisionError as x:\n    pass",
+        "def x():\n    pass",
+        "import sys as x",
+        "def f():\n    global x\n    x = 1",
+    ];
+    for code in cases {
+        assert_eq!(
+            read_only_input_error(code),
+            "cannot assign to input 'x'",
+            "code: {code:?}"
+        );
+    }
+}
+
+#[test]
+fn read_only_input_allows_other_bindings() {
+    let code = "
+y = x + 1
+def f():
+    x = 10
+    return x
+y + f()
+";
+    let ex = new_read_only_inputs(code).unwrap();
+    let result = ex.run_no_limits(vec![MontyObject::Int(1)]).unwrap();
+    assert_eq!(result, MontyObject::Int(12));
+}
+
+#[test]
+fn input_assignment_allowed_by_default() {
+    let ex = MontyRun::new("x = x + 1\nx".to_owned(), "test.py", vec!["x".to_owned()], vec![]).unwrap();
+    let result = ex.run_no_limits(vec![MontyObject::Int(1)]).unwrap();
+    assert_eq!(result, MontyObject::Int(2));
+}
@@ -1,9 +1,9 @@
-//! Tests for JSON serialization and deserialization of `MontyObject`.
+//! Tests for JSON serialization and deserialization of `MontyObject` and `MontyRun`.
 //!
 //! `MontyObject` uses derived serde with externally tagged enum format.
 //! This means each variant is wrapped in an object with the variant name as key.
 
-use monty::{ExcType, MontyObject, MontyRun};
+use monty::{ExcType, LoadError, MontyObject, MontyRun};
 
 // === JSON Serialization Tests ===
 
@@ -189,6 +189,63 @@ fn json_roundtrip_empty() {
     assert_eq!(serde_json::to_string(&dict).unwrap(), r#"{"Dict":[]}"#);
 }
 
+#[test]
+fn json_runner_roundtrip() {
+    // A runner round-trips through JSON and still executes to the same result
+    let ex = MontyRun::new(
+        "def f(a):\n    return [a, b'hi', 1.5, {'k': (a, None)}]\nf(x + 1)".to_owned(),
+        "test.py",
+        vec!["x".to_owned()],
+        vec![],
+    )
+    .unwrap();
+    let json = ex.dump_json().unwrap();
+    let loaded = MontyRun::load_json(&json).unwrap();
+    assert_eq!(loaded.code(), ex.code());
+    assert_eq!(
+        loaded.run_no_limits(vec![MontyObject::Int(1)]).unwrap(),
+        ex.run_no_limits(vec![MontyObject::Int(1)]).unwrap()
+    );
+}
+
+#[test]
+fn json_runner_load_invalid() {
+    assert!(MontyRun::load_json("{}").is_err());
+}
+
+#[test]
+fn json_runner_roundtrip_non_finite_floats() {
+    // serde_json writes inf and nan as null, so they're encoded as strings instead
+    let ex = MontyRun::new("[1e400, -1e400]".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let loaded = MontyRun::load_json(&ex.dump_json().unwrap()).unwrap();
+    assert_eq!(
+        loaded.run_no_limits(vec![]).unwrap(),
+        MontyObject::List(vec![
+            MontyObject::Float(f64::INFINITY),
+            MontyObject::Float(f64::NEG_INFINITY)
+        ])
+    );
+}
+
+#[test]
+fn json_runner_load_wrong_format_version() {
+    let ex = MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut json: serde_json::Value = serde_json::from_str(&ex.dump_json().unwrap()).unwrap();
+    json["format_version"] = serde_json::Value::from(0);
+    let err = MontyRun::load_json(&json.to_string()).unwrap_err();
+    assert!(matches!(err, LoadError::FormatVersion(Some(0))));
+    assert_eq!(err.to_string(), "serialized data has format version 0, expected 1");
+}
+
+#[test]
+fn json_runner_load_wrong_monty_version() {
+    let ex = MontyRun::new("1".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut json: serde_json::Value = serde_json::from_str(&ex.dump_json().unwrap()).unwrap();
+    json["monty_version"] = serde_json::Value::from("0.0.0");
+    let err = MontyRun::load_json(&json.to_string()).unwrap_err();
+    assert!(matches!(err, LoadError::MontyVersion(ref version) if version == "0.0.0"));
+}
+
 // === Cycle Equality Tests ===
 
 #[test]
@@ -1,4 +1,4 @@
-use monty::{MontyObject, MontyRun};
+use monty::{ExcType, MontyObject, MontyRun};
 
 /// Test we can reuse exec without borrow checker issues.
 #[test]
@@ -53,3 +53,73 @@ fn dataclass_method_call_in_standard_mode_errors() {
         "Expected NotImplementedError for method call, got: {msg}"
     );
 }
+
+/// `vars()` on a dataclass returns a copy of its attributes, so mutating the dict
+/// (including on a frozen instance) must leave the instance untouched.
+#[test]
+fn dataclass_vars_returns_copy() {
+    let point = MontyObject::Dataclass {
+        name: "Point".to_string(),
+        type_id: 0,
+        field_names: vec!["x".to_string(), "y".to_string()],
+        attrs: vec![
+            (MontyObject::String("x".to_string()), MontyObject::Int(1)),
+            (MontyObject::String("y".to_string()), MontyObject::Int(2)),
+        ]
+        .into(),
+        frozen: true,
+    };
+
+    let code = "d = vars(point)\nd['x'] = 100\nd['z'] = 3\nd.pop('y')\n(point, d)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec!["point".to_string()], vec![]).unwrap();
+
+    let result = ex.run_no_limits(vec![point]).unwrap();
+    assert_eq!(result.py_repr(), "(Point(x=1, y=2), {'x': 100, 'z': 3})");
+}
+
+/// `raise ... from ...` exposes the chained exception via `MontyException::cause`.
+#[test]
+fn raise_from_preserves_cause() {
+    let ex = MontyRun::new(
+        "raise ValueError('b') from KeyError('a')".to_owned(),
+        "test.py",
+        vec![],
+        vec![],
+    )
+    .unwrap();
+
+    let err = ex.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ValueError);
+    assert_eq!(err.message(), Some("b"));
+
+    let cause = err.cause().expect("cause should be preserved");
+    assert_eq!(cause.exc_type(), ExcType::KeyError);
+    assert_eq!(cause.message(), Some("a"));
+    assert!(cause.cause().is_none());
+}
+
+/// A `raise ... from` chain built up in a loop is cut off at 100 exceptions.
+#[test]
+fn raise_from_chain_is_capped() {
+    let code = "
+e = ValueError(str(0))
+for i in range(1, 150):
+    try:
+        raise ValueError(str(i)) from e
+    except ValueError as exc:
+        e = exc
+raise e
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let err = ex.run_no_limits(vec![]).unwrap_err();
+    assert_eq!(err.message(), Some("149"));
+    let mut depth = 1;
+    let mut exc = &err;
+    while let Some(cause) = exc.cause() {
+        depth += 1;
+        exc = cause;
+    }
+    assert_eq!(depth, 100);
+    assert_eq!(exc.message(), Some("50"));
+}
@@ -0,0 +1,57 @@
+//! Tests for `PrepareOptions::no_mod_eq`, which keeps `x % n == k` from being fused
+//! into a single comparison.
+
+use monty::{ExcType, MontyException, MontyObject, MontyRun, PrepareOptions};
+
+/// Runs `code` with the given `no_mod_eq` option and returns its result.
+fn run(code: &str, no_mod_eq: bool) -> Result<MontyObject, MontyException> {
+    let options = PrepareOptions::new().no_mod_eq(no_mod_eq);
+    let ex = MontyRun::with_options(code.to_owned(), "test.py", vec![], vec![], options).unwrap();
+    ex.run_no_limits(vec![])
+}
+
+#[test]
+fn mod_eq_gives_the_same_results_when_disabled() {
+    let code = r"
+values = list(range(-7, 8)) + [2**70, -(2**70) - 1, 7.5, 9.0]
+(
+    [i % 3 == 0 for i in values],
+    [i % 3 == 1 for i in values],
+    [i % -4 == -1 for i in values],
+    [i % 2.5 == 0 for i in values],
+)
+";
+    let fused = run(code, false).unwrap();
+    assert_eq!(run(code, true).unwrap(), fused);
+
+    let MontyObject::Tuple(results) = fused else {
+        panic!("expected tuple, got: {fused:?}");
+    };
+    let divisible_by_3: Vec<MontyObject> = (-7i64..8)
+        .map(|i| i % 3 == 0)
+        .chain([false, false, false, true])
+        .map(MontyObject::Bool)
+        .collect();
+    assert_eq!(results[0], MontyObject::List(divisible_by_3));
+}
+
+#[test]
+fn mod_eq_gives_the_same_errors_when_disabled() {
+    let cases = [
+        ("x = 5\nx % 0 == 0", ExcType::ZeroDivisionError, "division by zero"),
+        ("x = 5.0\nx % 0 == 0", ExcType::ZeroDivisionError, "division by zero"),
+        ("x = 5\nx % 0.0 == 0", ExcType::ZeroDivisionError, "division by zero"),
+        (
+            "x = []\nx % 3 == 0",
+            ExcType::TypeError,
+            "unsupported operand type(s) for %: 'list' and 'int'",
+        ),
+    ];
+    for (code, exc_type, message) in cases {
+        for no_mod_eq in [false, true] {
+            let exc = run(code, no_mod_eq).unwrap_err();
+            assert_eq!(exc.exc_type(), exc_type, "{code:?} with no_mod_eq={no_mod_eq}");
+            assert_eq!(exc.message(), Some(message), "{code:?} with no_mod_eq={no_mod_eq}");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Tests for the predefined `__name__` module constant.
+
+use monty::{MontyObject, MontyRun, NoLimitTracker, PrepareOptions, PrintWriter};
+
+/// Runs `code` with no inputs and returns its result.
+fn run(code: &str) -> MontyObject {
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    ex.run_no_limits(vec![]).unwrap()
+}
+
+#[test]
+fn dunder_name_defaults_to_main() {
+    assert_eq!(run("__name__"), MontyObject::String("__main__".to_owned()));
+    assert_eq!(run("__name__ == '__main__'"), MontyObject::Bool(true));
+}
+
+#[test]
+fn dunder_name_is_visible_in_functions() {
+    let code = r"
+def get_name():
+    return __name__
+
+result = []
+if __name__ == '__main__':
+    result.append(get_name())
+result.append((lambda: __name__)())
+result
+";
+    assert_eq!(
+        run(code),
+        MontyObject::List(vec![
+            MontyObject::String("__main__".to_owned()),
+            MontyObject::String("__main__".to_owned()),
+        ])
+    );
+}
+
+#[test]
+fn dunder_name_can_be_rebound() {
+    let code = r"
+before = __name__
+__name__ = 'renamed'
+(before, __name__)
+";
+    assert_eq!(
+        run(code),
+        MontyObject::Tuple(vec![
+            MontyObject::String("__main__".to_owned()),
+            MontyObject::String("renamed".to_owned()),
+        ])
+    );
+}
+
+#[test]
+fn dunder_name_can_be_configured() {
+    let code = r"
+ran_main = False
+if __name__ == '__main__':
+    ran_main = True
+(__name__, ran_main)
+";
+    let options = PrepareOptions::new().module_name("my_module");
+    let ex = MontyRun::with_options(code.to_owned(), "test.py", vec![], vec![], options).unwrap();
+    assert_eq!(
+        ex.run_no_limits(vec![]).unwrap(),
+        MontyObject::Tuple(vec![
+            MontyObject::String("my_module".to_owned()),
+            MontyObject::Bool(false),
+        ])
+    );
+}
+
+#[test]
+fn dunder_name_does_not_appear_in_namespace() {
+    let ex = MontyRun::new("x = __name__".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let (_, namespace) = ex
+        .run_with_namespace(vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap();
+    assert_eq!(namespace.len(), 1);
+    assert_eq!(namespace["x"], MontyObject::String("__main__".to_owned()));
+}
@@ -0,0 +1,72 @@
+//! Tests for reading back the global namespace after a run with `MontyRun::run_with_namespace`.
+
+use std::collections::HashMap;
+
+use monty::{ExcType, MontyObject, MontyRun, NoLimitTracker, PrintWriter};
+
+/// Runs `code` with the given inputs and returns the result and final namespace.
+fn run_namespace(
+    code: &str,
+    input_names: &[&str],
+    inputs: Vec<MontyObject>,
+) -> (MontyObject, HashMap<String, MontyObject>) {
+    let input_names = input_names.iter().map(|&s| s.to_owned()).collect();
+    let ex = MontyRun::new(code.to_owned(), "test.py", input_names, vec![]).unwrap();
+    ex.run_with_namespace(inputs, NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap()
+}
+
+#[test]
+fn namespace_contains_assigned_names() {
+    let (result, namespace) = run_namespace("a = 1\nb = 2", &[], vec![]);
+    assert_eq!(result, MontyObject::None);
+    let expected = HashMap::from([
+        ("a".to_owned(), MontyObject::Int(1)),
+        ("b".to_owned(), MontyObject::Int(2)),
+    ]);
+    assert_eq!(namespace, expected);
+}
+
+#[test]
+fn namespace_includes_inputs_and_heap_values() {
+    let (result, namespace) = run_namespace("items = [x, x * 2]\nlen(items)", &["x"], vec![MontyObject::Int(3)]);
+    assert_eq!(result, MontyObject::Int(2));
+    assert_eq!(namespace["x"], MontyObject::Int(3));
+    assert_eq!(
+        namespace["items"],
+        MontyObject::List(vec![MontyObject::Int(3), MontyObject::Int(6)])
+    );
+    assert_eq!(namespace.len(), 2);
+}
+
+#[test]
+fn namespace_skips_unassigned_names() {
+    let (_, namespace) = run_namespace("if False:\n    never = 1\nseen = 2", &[], vec![]);
+    assert!(!namespace.contains_key("never"));
+    assert_eq!(namespace["seen"], MontyObject::Int(2));
+}
+
+#[test]
+fn namespace_skips_external_functions() {
+    let ex = MontyRun::new("y = 1".to_owned(), "test.py", vec![], vec!["fetch".to_owned()]).unwrap();
+    let (_, namespace) = ex
+        .run_with_namespace(vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap();
+    assert_eq!(namespace, HashMap::from([("y".to_owned(), MontyObject::Int(1))]));
+}
+
+#[test]
+fn namespace_run_error() {
+    let ex = MontyRun::new("a = 1\n1 / 0".to_owned(), "test.py", vec![], vec![]).unwrap();
+    let exc = ex
+        .run_with_namespace(vec![], NoLimitTracker, &mut PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ZeroDivisionError);
+}
+
+#[test]
+fn namespace_constant_if_keeps_only_taken_branch() {
+    let (result, namespace) = run_namespace("if True:\n    x = 1\nelse:\n    y = z", &[], vec![]);
+    assert_eq!(result, MontyObject::None);
+    assert_eq!(namespace, HashMap::from([("x".to_owned(), MontyObject::Int(1))]));
+}
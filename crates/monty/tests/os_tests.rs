@@ -38,6 +38,8 @@ fn run_to_oscall(code: &str) -> (OsFunction, Vec<MontyObject>) {
                 | OsFunction::Rename => MontyObject::None,
                 OsFunction::Getenv => MontyObject::String("mock_env_value".to_owned()),
                 OsFunction::GetEnviron => MontyObject::Dict(vec![].into()),
+                OsFunction::Time | OsFunction::MonotonicTime => MontyObject::Float(0.0),
+                OsFunction::ReadLine | OsFunction::ReadChunk => MontyObject::String(String::new()),
             };
             let _ = state.run(mock_result, &mut PrintWriter::Stdout);
             (function, args)
@@ -351,6 +353,42 @@ import os
     assert_eq!(result, MontyObject::String("HOME=/home/user".to_owned()));
 }
 
+#[test]
+fn os_getenv_missing_returns_none() {
+    let code = r"
+import os
+os.getenv('MISSING') is None
+";
+    let (func, args, result) = run_oscall_with_result(code, MontyObject::None);
+    assert_eq!(func, OsFunction::Getenv);
+    assert_eq!(args[0], MontyObject::String("MISSING".to_owned()));
+    assert_eq!(result, MontyObject::Bool(true));
+}
+
+#[test]
+fn os_getenv_missing_returns_default() {
+    // The host answers a missing variable with the default it was passed
+    let code = r"
+import os
+os.getenv('MISSING', 'fallback') + '!'
+";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, &mut PrintWriter::Stdout).unwrap();
+    let RunProgress::OsCall {
+        function, args, state, ..
+    } = progress
+    else {
+        panic!("expected OsCall, got {progress:?}");
+    };
+    assert_eq!(function, OsFunction::Getenv);
+    let default = args[1].clone();
+    let result = state.run(default, &mut PrintWriter::Stdout).unwrap();
+    assert_eq!(
+        result.into_complete().expect("expected Complete after resume"),
+        MontyObject::String("fallback!".to_owned())
+    );
+}
+
 // =============================================================================
 // os.environ tests
 // =============================================================================
@@ -457,3 +495,88 @@ import os
     assert_eq!(func, OsFunction::GetEnviron);
     assert_eq!(result, MontyObject::Bool(true));
 }
+
+// =============================================================================
+// time module tests
+// =============================================================================
+
+#[test]
+fn time_time_yields_oscall() {
+    let code = r"
+import time
+time.time()
+";
+    let (func, args) = run_to_oscall(code);
+    assert_eq!(func, OsFunction::Time);
+    assert_eq!(args, vec![]);
+}
+
+#[test]
+fn time_monotonic_yields_oscall() {
+    let code = r"
+import time
+time.monotonic()
+";
+    let (func, args) = run_to_oscall(code);
+    assert_eq!(func, OsFunction::MonotonicTime);
+    assert_eq!(args, vec![]);
+}
+
+#[test]
+fn time_time_result_formatted() {
+    let code = r"
+import time
+time.isoformat(time.time())
+";
+    let (func, _, result) = run_oscall_with_result(code, MontyObject::Float(1_700_000_000.25));
+    assert_eq!(func, OsFunction::Time);
+    assert_eq!(result, MontyObject::String("2023-11-14T22:13:20.250000".to_owned()));
+}
+
+// =============================================================================
+// Streaming input tests
+// =============================================================================
+
+#[test]
+fn os_read_line_streams_until_empty_sentinel() {
+    let code = r"
+import os
+lines = []
+while True:
+    line = os.read_line()
+    if not line:
+        break
+    lines.append(line.strip())
+'|'.join(lines)
+";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+    let mut host_lines = ["alpha\n", "beta\n", "gamma\n", ""].into_iter();
+    let mut progress = runner.start(vec![], NoLimitTracker, &mut PrintWriter::Stdout).unwrap();
+
+    let result = loop {
+        match progress {
+            RunProgress::OsCall {
+                function, args, state, ..
+            } => {
+                assert_eq!(function, OsFunction::ReadLine);
+                assert_eq!(args, vec![]);
+                let line = host_lines.next().expect("script read past the empty sentinel");
+                progress = state
+                    .run(MontyObject::String(line.to_owned()), &mut PrintWriter::Stdout)
+                    .unwrap();
+            }
+            RunProgress::Complete(value) => break value,
+            other => panic!("expected OsCall or Complete, got {other:?}"),
+        }
+    };
+
+    assert_eq!(result, MontyObject::String("alpha|beta|gamma".to_owned()));
+    assert_eq!(host_lines.next(), None, "every line should have been requested");
+}
+
+#[test]
+fn os_read_chunk_yields_oscall() {
+    let (func, args) = run_to_oscall("import os; os.read_chunk(4096)");
+    assert_eq!(func, OsFunction::ReadChunk);
+    assert_eq!(args, vec![MontyObject::Int(4096)]);
+}
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use monty::{ExcType, MontyException, MontyRun};
+use monty::{ExcType, MontyException, MontyObject, MontyRun};
 
 /// Helper to extract the exception type from a parse error.
 fn get_exc_type(result: Result<MontyRun, MontyException>) -> ExcType {
@@ -38,9 +38,15 @@ fn yield_expressions_return_not_implemented_error() {
 }
 
 #[test]
-fn classes_return_not_implemented_error() {
-    let result = MontyRun::new("class Foo: pass".to_owned(), "test.py", vec![], vec![]);
+fn class_multiple_inheritance_returns_not_implemented_error() {
+    let result = MontyRun::new("class Foo(Bar, Baz): pass".to_owned(), "test.py", vec![], vec![]);
     assert_eq!(get_exc_type(result), ExcType::NotImplementedError);
+    let result = MontyRun::new("class Foo(Bar, Baz): pass".to_owned(), "test.py", vec![], vec![]);
+    let exc = result.expect_err("expected parse error");
+    assert!(
+        exc.message().is_some_and(|m| m.contains("multiple inheritance")),
+        "message should mention 'multiple inheritance', got: {exc}"
+    );
 }
 
 #[test]
@@ -398,6 +404,40 @@ fn deeply_nested_boolean_or_exceed_limit() {
     assert_eq!(get_exc_type(result), ExcType::SyntaxError);
 }
 
+#[test]
+fn very_deeply_nested_parentheses_return_syntax_error() {
+    // Deep enough to overflow the native stack if the parser recursed into it
+    let code = format!("{}1{}", "(".repeat(5000), ")".repeat(5000));
+    let result = MontyRun::new(code, "test.py", vec![], vec![]);
+    let err = result.expect_err("expected parse error");
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(err.message(), Some("too many nested parentheses"));
+}
+
+#[test]
+fn brackets_in_strings_and_comments_do_not_count_towards_limit() {
+    let brackets = "(".repeat(5000);
+    let code = format!("x = '{brackets}' + \"{brackets}\" + '''{brackets}''' # {brackets}");
+    let result = MontyRun::new(code, "test.py", vec![], vec![]);
+    assert!(result.is_ok(), "brackets in strings and comments should be ignored");
+}
+
+#[test]
+fn long_boolean_chain_succeeds() {
+    // `x and x and ...` is a single node however long it is, so it doesn't count as nesting
+    let code = format!("x = 1\n{}", vec!["x"; 5000].join(" and "));
+    let run = MontyRun::new(code, "test.py", vec![], vec![]).expect("long boolean chain should parse");
+    let result = run.run_no_limits(vec![]).expect("long boolean chain should run");
+    assert_eq!(result, MontyObject::Int(1));
+}
+
+#[test]
+fn boolean_chain_within_limit_succeeds() {
+    let code = vec!["x"; 20].join(" or ");
+    let result = MontyRun::new(code, "test.py", vec![], vec![]);
+    assert!(result.is_ok(), "boolean chain within limit should succeed");
+}
+
 // === Runtime NotImplementedError tests ===
 // These test that unimplemented features return proper errors instead of panicking.
 
@@ -409,9 +449,9 @@ fn run_and_get_exc_type(code: &str) -> ExcType {
 }
 
 #[test]
-fn matrix_multiplication_returns_not_implemented_error() {
-    // The @ operator (matrix multiplication) is not supported at runtime
-    assert_eq!(run_and_get_exc_type("1 @ 2"), ExcType::NotImplementedError);
+fn matrix_multiplication_on_builtins_returns_type_error() {
+    // No builtin type implements the @ operator, only objects defining __matmul__
+    assert_eq!(run_and_get_exc_type("1 @ 2"), ExcType::TypeError);
 }
 
 #[test]
@@ -435,7 +475,61 @@ fn matrix_multiplication_augmented_assignment_has_descriptive_message() {
 
 #[test]
 fn del_statement_returns_not_implemented_error() {
-    // The del statement is not supported at parse time
+    // Only deleting items is supported, `del name` is rejected at parse time
     let result = MontyRun::new("x = 1\ndel x".to_owned(), "test.py", vec![], vec![]);
     assert_eq!(get_exc_type(result), ExcType::NotImplementedError);
 }
+
+/// Underscores in numeric literals must separate digits: no doubled, trailing or
+/// prefix-only underscores.
+#[test]
+fn malformed_underscore_literals_return_syntax_error() {
+    for code in [
+        "1__0",
+        "1_",
+        "x = 1_000_",
+        "0x__FF",
+        "0x_",
+        "0b_",
+        "0o1__7",
+        "1.5_",
+        "1_.5",
+        "1e_5",
+    ] {
+        let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+        assert_eq!(get_exc_type(result), ExcType::SyntaxError, "code: {code}");
+    }
+}
+
+/// Float literals with an exponent marker but no exponent digits are rejected.
+#[test]
+fn incomplete_float_exponent_returns_syntax_error() {
+    for code in ["1e", "1.5E", "1e+", "1e-", ".5e"] {
+        let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+        assert_eq!(get_exc_type(result), ExcType::SyntaxError, "code: {code}");
+    }
+}
+
+/// Truncated or unknown escapes in string literals are rejected.
+#[test]
+fn malformed_string_escapes_return_syntax_error() {
+    for code in [
+        r"'\u12'",
+        r"'\U0001F6'",
+        r"'\x4'",
+        r"'\N{NOT A REAL CHARACTER NAME}'",
+        r"'\N{EURO SIGN'",
+    ] {
+        let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+        assert_eq!(get_exc_type(result), ExcType::SyntaxError, "code: {code}");
+    }
+}
+
+/// Adjacent str and bytes literals can't be concatenated.
+#[test]
+fn mixed_str_and_bytes_concatenation_returns_syntax_error() {
+    for code in ["'a' b'b'", "b'a' 'b'", "b'a' f'{1}'"] {
+        let result = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]);
+        assert_eq!(get_exc_type(result), ExcType::SyntaxError, "code: {code}");
+    }
+}
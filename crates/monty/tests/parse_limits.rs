@@ -0,0 +1,64 @@
+//! Tests for parse-time size limits (`PrepareOptions::parse_limits`).
+
+use monty::{ExcType, MontyException, MontyObject, MontyRun, ParseLimits, PrepareOptions};
+
+/// Parses `code` with the given limits and no inputs.
+fn parse_with_limits(code: &str, limits: ParseLimits) -> Result<MontyRun, MontyException> {
+    MontyRun::with_options(
+        code.to_owned(),
+        "test.py",
+        vec![],
+        vec![],
+        PrepareOptions::new().parse_limits(limits),
+    )
+}
+
+#[test]
+fn program_exceeding_node_limit_returns_syntax_error() {
+    // Each line is an assignment statement and its value expression: 200 nodes in total
+    let code = "x = 1\n".repeat(100);
+    let err = parse_with_limits(&code, ParseLimits::new().max_nodes(150)).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(
+        err.message(),
+        Some("program too large: more than 150 statements and expressions")
+    );
+}
+
+#[test]
+fn program_within_node_limit_succeeds() {
+    let code = "x = 1\n".repeat(100);
+    let ex = parse_with_limits(&code, ParseLimits::new().max_nodes(200)).unwrap();
+    assert_eq!(ex.run_no_limits(vec![]).unwrap(), MontyObject::None);
+}
+
+#[test]
+fn program_exceeding_nesting_limit_returns_syntax_error() {
+    // The statement, three lists and the int are nested 5 deep
+    let err = parse_with_limits("[[[1]]]", ParseLimits::new().max_nesting_depth(4)).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(err.message(), Some("too many nested parentheses"));
+}
+
+#[test]
+fn program_within_nesting_limit_succeeds() {
+    let ex = parse_with_limits("[[[1]]]", ParseLimits::new().max_nesting_depth(5)).unwrap();
+    let expected = MontyObject::List(vec![MontyObject::List(vec![MontyObject::List(vec![
+        MontyObject::Int(1),
+    ])])]);
+    assert_eq!(ex.run_no_limits(vec![]).unwrap(), expected);
+}
+
+#[test]
+fn nesting_limit_applies_to_brackets_before_parsing() {
+    let err = parse_with_limits("((((1))))", ParseLimits::new().max_nesting_depth(3)).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(err.message(), Some("too many nested parentheses"));
+}
+
+#[test]
+fn default_limits_match_new() {
+    let code = "x = [1, 2]\nx[0] + x[1]";
+    let ex = parse_with_limits(code, ParseLimits::default()).unwrap();
+    assert_eq!(ex.run_no_limits(vec![]).unwrap(), MontyObject::Int(3));
+}
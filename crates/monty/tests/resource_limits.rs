@@ -5,6 +5,7 @@
 use std::time::{Duration, Instant};
 
 use monty::{ExcType, LimitedTracker, MontyObject, MontyRun, PrintWriter, ResourceLimits};
+use num_bigint::BigInt;
 
 /// Test that GC properly collects dict cycles via the has_refs() check in allocate().
 ///
@@ -125,6 +126,39 @@ len(result)
     );
 }
 
+/// Test that `list.append` grows the list in place without leaving heap entries behind.
+///
+/// Appending ints allocates nothing, and appending the same string only bumps its
+/// refcount, so the heap must not grow with the number of appends.
+#[test]
+#[cfg(feature = "ref-count-return")]
+fn list_append_does_not_leak_heap_entries() {
+    let code = r"
+a = []
+s = 'abcd' * 1
+for i in range(100_000):
+    a.append(i)
+    a.append(s)
+len(a)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let output = ex.run_ref_counts(vec![]).expect("should succeed");
+
+    assert_eq!(output.py_object, MontyObject::Int(200_000));
+    assert_eq!(output.counts.get("a"), Some(&1), "list should not be copied by append");
+    assert_eq!(
+        output.counts.get("s"),
+        Some(&100_001),
+        "each append should hold exactly one reference"
+    );
+    assert!(
+        output.heap_count < 10,
+        "append should not allocate per call: {} heap objects (expected < 10)",
+        output.heap_count
+    );
+}
+
 /// Test that allocation limits return an error.
 #[test]
 fn allocation_limit_exceeded() {
@@ -237,6 +271,56 @@ result
     );
 }
 
+/// Test that `max_output_bytes` stops a print loop with an `OSError`, distinct from the memory limit,
+/// without emitting the write that would cross the limit.
+#[test]
+fn output_limit_exceeded() {
+    let code = r"
+for i in range(1000):
+    print('abcdefghi')
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    // Each print writes 10 bytes ("abcdefghi\n"); a generous memory limit ensures
+    // the output limit is what triggers
+    let limits = ResourceLimits::new().max_output_bytes(55).max_memory(1024 * 1024);
+    let mut writer = PrintWriter::Collect(String::new());
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut writer);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::OSError);
+    assert_eq!(exc.message(), Some("output limit exceeded: 59 bytes > 55 bytes"));
+    assert_eq!(writer.collected_output(), Some("abcdefghi\n".repeat(5).as_str()));
+}
+
+/// Test that `repr_max_items` elides the middle of long containers in `print()` output
+/// with `...`, while the script's own `repr()` and `str()` still show every item.
+#[test]
+fn repr_max_items_elides_long_containers() {
+    let code = r"
+x = list(range(10_000))
+assert len(repr(x)) == 58_890
+assert str(tuple(x)) == '(' + ', '.join(str(i) for i in x) + ')'
+print(x)
+print(tuple(x), {i: i for i in x})
+print(set(range(7)), [1, 2, 3], [[0] * 7])
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().repr_max_items(6);
+    let mut writer = PrintWriter::Collect(String::new());
+    ex.run(vec![], LimitedTracker::new(limits), &mut writer).unwrap();
+
+    assert_eq!(
+        writer.collected_output(),
+        Some(concat!(
+            "[0, 1, 2, ..., 9997, 9998, 9999]\n",
+            "(0, 1, 2, ..., 9997, 9998, 9999) {0: 0, 1: 1, 2: 2, ..., 9997: 9997, 9998: 9998, 9999: 9999}\n",
+            "{0, 1, 2, ..., 4, 5, 6} [1, 2, 3] [[0, 0, 0, ..., 0, 0, 0]]\n",
+        ))
+    );
+}
+
 #[test]
 fn combined_limits() {
     // Test multiple limits together
@@ -1041,6 +1125,70 @@ fn tuple_mult_within_limit() {
     assert_eq!(result.unwrap(), MontyObject::Bool(true));
 }
 
+/// Runs `code` without resource limits and asserts it fails promptly with the
+/// `MAX_ALLOCATION_SIZE` guard and `message` rather than attempting the allocation.
+fn assert_allocation_size_guard(code: &str, message: &str) {
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let start = Instant::now();
+    let exc = ex.run_no_limits(vec![]).unwrap_err();
+    let elapsed = start.elapsed();
+
+    assert_eq!(exc.exc_type(), ExcType::MemoryError, "{code}");
+    assert_eq!(exc.message(), Some(message), "{code}");
+    assert!(elapsed < Duration::from_secs(1), "{code} took {elapsed:?}");
+}
+
+/// Test that huge sequence repeats are rejected before allocating even without a memory limit.
+#[test]
+fn huge_repeat_rejected_without_limits() {
+    assert_allocation_size_guard(
+        "[0] * 10**9",
+        "allocation size limit exceeded: 16000000000 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "'x' * 10**9",
+        "allocation size limit exceeded: 1000000000 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "10**9 * b'x'",
+        "allocation size limit exceeded: 1000000000 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "(1, 2) * 10**9",
+        "allocation size limit exceeded: 32000000000 bytes > 536870912 bytes",
+    );
+}
+
+/// Test that padding methods pre-check the padded size rather than allocating it.
+#[test]
+fn huge_padding_rejected_without_limits() {
+    assert_allocation_size_guard(
+        "'x'.center(10**9)",
+        "allocation size limit exceeded: 999999999 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "'x'.ljust(2**62, '*')",
+        "allocation size limit exceeded: 4611686018427387903 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "'x'.rjust(10**9)",
+        "allocation size limit exceeded: 999999999 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "'1'.zfill(10**9)",
+        "allocation size limit exceeded: 999999999 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "b'x'.center(10**9)",
+        "allocation size limit exceeded: 999999999 bytes > 536870912 bytes",
+    );
+    assert_allocation_size_guard(
+        "b'1'.zfill(10**9)",
+        "allocation size limit exceeded: 999999999 bytes > 536870912 bytes",
+    );
+}
+
 // === Timeout enforcement in builtin iteration loops ===
 // These tests verify that `max_duration_secs` is enforced inside Rust-side loops
 // within builtin functions. Previously, builtins like sum(), sorted(), min(), max()
@@ -1267,6 +1415,15 @@ s.splitlines()
     assert_timeout_in_builtin(code, "bytes.splitlines()");
 }
 
+/// Test that three-argument `pow()` with a huge exponent respects the time limit.
+///
+/// The result is smaller than the modulus, so no size pre-check applies; the
+/// square-and-multiply loop calls `heap.check_time()` on every exponent bit.
+#[test]
+fn timeout_in_three_arg_pow() {
+    assert_timeout_in_builtin("pow(3, 2**1000000, 2**100000 + 1)", "pow(3, 2**1000000, 2**100000 + 1)");
+}
+
 // === Timeout truncation in repr ===
 // These tests verify that `repr()` on large containers respects the time limit
 // and terminates promptly instead of hanging indefinitely. The repr methods
@@ -1369,3 +1526,299 @@ repr(x)
 ";
     assert_repr_timeout(code, "set repr");
 }
+
+/// A display of literals is built straight from the constant pool, so the only heap
+/// entry left afterwards is the list itself.
+#[test]
+#[cfg(feature = "ref-count-return")]
+fn list_literal_allocates_only_the_list() {
+    let elements: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+    let code = format!("a = [{}, 'x', 1.5, None]\nlen(a)", elements.join(", "));
+    let ex = MontyRun::new(code, "test.py", vec![], vec![]).unwrap();
+
+    let output = ex.run_ref_counts(vec![]).expect("should succeed");
+
+    assert_eq!(output.py_object, MontyObject::Int(10_003));
+    assert_eq!(output.counts.get("a"), Some(&1));
+    assert_eq!(output.heap_count, 1, "only the list should be on the heap");
+}
+
+/// Test that `max_iterations` stops iteration over an infinite iterator with a
+/// resource error, which the script cannot catch.
+#[test]
+fn iteration_limit_stops_infinite_iterator() {
+    let code = r"
+import itertools
+total = 0
+try:
+    for i in itertools.count():
+        total += i
+except Exception:
+    pass
+total
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_iterations(1_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TimeoutError);
+    assert_eq!(exc.message(), Some("iteration limit exceeded: 1001 > 1000"));
+}
+
+/// Test that items skipped by `islice` count towards `max_iterations`, even when the
+/// source iterator is advanced without going through `for_next`.
+#[test]
+fn iteration_limit_counts_islice_skips() {
+    let code = r"
+import itertools
+next(itertools.islice(iter(range(10**18)), 10**18 - 1, None))
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_iterations(1_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TimeoutError);
+    assert_eq!(exc.message(), Some("iteration limit exceeded: 1001 > 1000"));
+}
+
+/// Test that iteration below `max_iterations` is unaffected.
+#[test]
+fn iteration_limit_allows_finite_iteration() {
+    let code = r"
+import itertools
+sum(itertools.islice(itertools.count(), 100))
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_iterations(1_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    assert_eq!(result.unwrap(), MontyObject::Int(4_950));
+}
+
+/// Test that `max_int_bits` rejects `2 ** (10**7)` up front with a `ValueError`
+/// instead of computing a multi-megabyte integer.
+#[test]
+fn int_bits_limit_rejects_huge_pow() {
+    let ex = MontyRun::new("2 ** (10**7)".to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_int_bits(1_000_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        exc.message(),
+        Some("Exceeds the limit (1000000 bits) for integer size: value has 10000001 bits")
+    );
+}
+
+/// Test that the integer bit limit applies to shifts and multiplication, and that
+/// the `ValueError` can be caught like CPython's int-to-str digit limit.
+#[test]
+fn int_bits_limit_is_catchable() {
+    let code = r"
+caught = []
+try:
+    1 << 2_000
+except ValueError:
+    caught.append('lshift')
+x = 2 ** 600
+try:
+    x * x
+except ValueError:
+    caught.append('mult')
+try:
+    x ** 2
+except ValueError:
+    caught.append('pow')
+caught.append((x * 2 ** 300) > 0)
+caught
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_int_bits(1_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(vec![
+            MontyObject::String("lshift".to_owned()),
+            MontyObject::String("mult".to_owned()),
+            MontyObject::String("pow".to_owned()),
+            MontyObject::Bool(true),
+        ])
+    );
+}
+
+/// Test that `int_max_str_digits` makes `str()` of a huge int raise `ValueError`.
+#[test]
+fn int_max_str_digits_rejects_str() {
+    let ex = MontyRun::new("str(10**5000)".to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(4300);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        exc.message(),
+        Some("Exceeds the limit (4300 digits) for integer string conversion")
+    );
+}
+
+/// Test that `int_max_str_digits` makes `int()` of a huge decimal string raise `ValueError`.
+#[test]
+fn int_max_str_digits_rejects_int_parse() {
+    let ex = MontyRun::new("int('1' * 5000)".to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(4300);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let exc = result.unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        exc.message(),
+        Some("Exceeds the limit (4300 digits) for integer string conversion: value has 5000 digits")
+    );
+}
+
+/// Test the digit limit boundary across `repr()`, f-strings and `int()`, and that
+/// the `ValueError` is catchable and leaves hex conversion alone.
+#[test]
+fn int_max_str_digits_boundary() {
+    let code = r"
+results = []
+results.append(len(str(10**99)))
+results.append(len(repr(-(10**99))))
+results.append(int('9' * 100) == 10**100 - 1)
+for make in (lambda: str(10**100), lambda: f'{10**100}', lambda: f'{10**100:>200}', lambda: int('1' * 101)):
+    try:
+        make()
+        results.append('ok')
+    except ValueError:
+        results.append('ValueError')
+results.append(hex(10**100) == hex(10**100))
+results
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(100);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(vec![
+            MontyObject::Int(100),
+            MontyObject::Int(101),
+            MontyObject::Bool(true),
+            MontyObject::String("ValueError".to_owned()),
+            MontyObject::String("ValueError".to_owned()),
+            MontyObject::String("ValueError".to_owned()),
+            MontyObject::String("ValueError".to_owned()),
+            MontyObject::Bool(true),
+        ])
+    );
+}
+
+/// Test that `int_max_str_digits` also applies to huge ints inside containers.
+#[test]
+fn int_max_str_digits_applies_inside_containers() {
+    let code = r"
+x = 10**100
+results = [len(str([10**99]))]
+for make in (lambda: str([x]), lambda: repr({'k': x}), lambda: f'{[x]}', lambda: print((x,))):
+    try:
+        make()
+        results.append('ok')
+    except ValueError as e:
+        results.append(str(e))
+results
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(100);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let error = MontyObject::String("Exceeds the limit (100 digits) for integer string conversion".to_owned());
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(vec![
+            MontyObject::Int(102),
+            error.clone(),
+            error.clone(),
+            error.clone(),
+            error,
+        ])
+    );
+}
+
+/// Test that every path formatting a huge int, including `str.format`, f-string conversions,
+/// missing dict and set keys and failed asserts, raises the digit limit's `ValueError`
+/// instead of showing a placeholder for it.
+#[test]
+fn int_max_str_digits_applies_to_format_and_error_messages() {
+    let code = r"
+x = 10**100
+results = []
+
+def fail_assert():
+    assert x == 0
+
+def fail_assert_nested():
+    assert [1] == [x]
+
+makers = (
+    lambda: '{}'.format(x),
+    lambda: '{!r}'.format([x]),
+    lambda: '{!a}'.format({x: 1}),
+    lambda: '{!s}'.format((x,)),
+    lambda: '{:>300}'.format(x),
+    lambda: f'{x!r}',
+    lambda: f'{[x]!a}',
+    lambda: {}[x],
+    lambda: {}.pop(x),
+    lambda: set().remove(x),
+    fail_assert,
+    fail_assert_nested,
+)
+for make in makers:
+    try:
+        make()
+        results.append('ok')
+    except ValueError as e:
+        results.append(str(e))
+results
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(100);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let error = MontyObject::String("Exceeds the limit (100 digits) for integer string conversion".to_owned());
+    assert_eq!(result.unwrap(), MontyObject::List(vec![error; 12]));
+}
+
+/// Test that huge ints returned to the host are passed as integers, untouched by
+/// `int_max_str_digits`, including inside containers.
+#[test]
+fn int_max_str_digits_does_not_affect_returned_values() {
+    let ex = MontyRun::new("x = 10**100\n[x, (x,), {'k': x}]".to_owned(), "test.py", vec![], vec![]).unwrap();
+
+    let limits = ResourceLimits::new().int_max_str_digits(100);
+    let result = ex.run(vec![], LimitedTracker::new(limits), &mut PrintWriter::Stdout);
+
+    let big = MontyObject::BigInt(BigInt::from(10).pow(100));
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(vec![
+            big.clone(),
+            MontyObject::Tuple(vec![big.clone()]),
+            MontyObject::Dict(vec![(MontyObject::String("k".to_owned()), big)].into()),
+        ])
+    );
+}
@@ -0,0 +1,39 @@
+//! Tests for script mode (`PrepareOptions::no_implicit_return`), where the last expression isn't returned.
+
+use monty::{ExcType, MontyException, MontyObject, MontyRun, PrepareOptions};
+
+/// Prepares `code` in script mode with the given inputs.
+fn new_script(code: &str, input_names: &[&str]) -> MontyRun {
+    let input_names = input_names.iter().map(|&name| name.to_owned()).collect();
+    let options = PrepareOptions::new().no_implicit_return(true);
+    MontyRun::with_options(code.to_owned(), "test.py", input_names, vec![], options).unwrap()
+}
+
+/// Runs `code` in script mode with no inputs.
+fn run_script(code: &str) -> Result<MontyObject, MontyException> {
+    new_script(code, &[]).run_no_limits(vec![])
+}
+
+#[test]
+fn trailing_expression_returns_none_in_script_mode() {
+    assert_eq!(run_script("1 + 2").unwrap(), MontyObject::None);
+    assert_eq!(run_script("x = 5\nx * 2").unwrap(), MontyObject::None);
+}
+
+#[test]
+fn trailing_expression_is_returned_by_default() {
+    let ex = MontyRun::new("1 + 2".to_owned(), "test.py", vec![], vec![]).unwrap();
+    assert_eq!(ex.run_no_limits(vec![]).unwrap(), MontyObject::Int(3));
+}
+
+#[test]
+fn trailing_expression_is_still_evaluated_in_script_mode() {
+    let exc = run_script("x = 1\n1 / 0").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ZeroDivisionError);
+}
+
+#[test]
+fn script_mode_uses_inputs() {
+    let ex = new_script("assert x == 3\nx", &["x"]);
+    assert_eq!(ex.run_no_limits(vec![MontyObject::Int(3)]).unwrap(), MontyObject::None);
+}
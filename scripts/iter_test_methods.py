@@ -43,7 +43,7 @@ def raise_error(exc_type: str, message: str) -> None:
     raise exc_types[exc_type](message)
 
 
-@dataclass(frozen=True)
+@dataclass(frozen=True, order=True)
 class Point:
     x: int
     y: int
@@ -60,6 +60,9 @@ class Point:
     def describe(self, label: str = 'point') -> str:
         return f'{label}({self.x}, {self.y})'
 
+    def __matmul__(self, other: 'Point') -> int:
+        return self.x * other.x + self.y * other.y
+
 
 def make_point() -> Point:
     return Point(x=1, y=2)
@@ -104,6 +107,34 @@ def make_empty() -> Empty:
     return Empty()
 
 
+@dataclass(frozen=True)
+class Vector:
+    x: int
+    y: int
+
+    def __add__(self, other: 'Vector') -> 'Vector':
+        return Vector(x=self.x + other.x, y=self.y + other.y)
+
+    def __radd__(self, other: int) -> 'Vector':
+        return Vector(x=self.x + other, y=self.y + other)
+
+    def __sub__(self, other: 'Vector') -> 'Vector':
+        return Vector(x=self.x - other.x, y=self.y - other.y)
+
+    def __mul__(self, factor: int) -> 'Vector':
+        return Vector(x=self.x * factor, y=self.y * factor)
+
+    def __rmul__(self, factor: int) -> 'Vector':
+        return Vector(x=self.x * factor, y=self.y * factor)
+
+    def __truediv__(self, divisor: int) -> 'Vector':
+        return Vector(x=self.x // divisor, y=self.y // divisor)
+
+
+def make_vector(x: int, y: int) -> Vector:
+    return Vector(x=x, y=y)
+
+
 async def async_call(x: object) -> object:
     """Async function that returns its argument.
 
@@ -526,5 +557,6 @@ ITER_MODE_GLOBALS: dict[str, object] = {
     'make_mutable_point': make_mutable_point,
     'make_user': make_user,
     'make_empty': make_empty,
+    'make_vector': make_vector,
     'async_call': async_call,
 }